@@ -264,6 +264,23 @@ public class PagerDutyCodegen extends RustServerCodegen {
                 } else {
                     schemas = composedSchema.getOneOf();
                 }
+                // A composition of primitive types, such as the value of
+                // an incident custom field, which can be a string, a
+                // number, a boolean or an array of these. There is no
+                // model to reference, so we fall back to a JSON value.
+                boolean allPrimitive = true;
+                for (Schema subSchema : schemas) {
+                    if (subSchema.get$ref() != null || subSchema.getProperties() != null) {
+                        allPrimitive = false;
+                    }
+                }
+                if (allPrimitive) {
+                    prop.datatype = "Value";
+                    prop.datatypeWithEnum = "Value";
+                    prop.getVendorExtensions().put("x-rustgen-is-any-value", true);
+                    return prop;
+                }
+
                 HashMap<String, Object> allowableValues = new HashMap();
                 ArrayList<String> values = new ArrayList();
                 ArrayList<HashMap<String, String>> enumVars = new ArrayList();
@@ -0,0 +1,1207 @@
+//! Declarative account configuration: plan and apply a desired state document.
+//!
+//! A [DesiredState] describes teams, schedules, escalation policies and services, tracked by name
+//! rather than by id. The [AccountConfigClient] fetches the current [AccountState], computes a
+//! [Plan] of create, update and delete operations, and applies it in dependency order: teams,
+//! then schedules, then escalation policies, then services. Deletions are only planned when
+//! `prune` is set, and are applied in reverse order so that nothing is removed while still
+//! referenced.
+
+use std::collections::HashMap;
+use std::fmt;
+use std::path::Path;
+
+use chrono::{DateTime, FixedOffset};
+use futures_util::TryStreamExt;
+use log::info;
+
+use crate::endpoints::escalation_policies::ListEscalationPoliciesParamsBuilder;
+use crate::endpoints::schedules::{
+    CreateScheduleParamsBuilder, GetScheduleParamsBuilder, ListSchedulesParamsBuilder,
+    UpdateScheduleParamsBuilder,
+};
+use crate::endpoints::services::ListServicesParamsBuilder;
+use crate::endpoints::teams::ListTeamsParamsBuilder;
+use crate::endpoints::users::ListUsersParamsBuilder;
+use crate::errors::Error;
+use crate::models::*;
+use crate::{ParamsBuilder, Praiya};
+
+/// A client to plan and apply declarative account configuration
+pub struct AccountConfigClient {
+    pub(crate) client: Praiya,
+    pub(crate) from_email: Option<String>,
+}
+
+impl Praiya {
+    pub fn account_config(&self, from_email: Option<String>) -> AccountConfigClient {
+        AccountConfigClient {
+            client: Praiya::clone(self),
+            from_email,
+        }
+    }
+}
+
+/// The desired configuration of an account, usually loaded from a YAML or JSON document.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DesiredState {
+    /// Delete teams, schedules, escalation policies and services that are absent from this
+    /// document.
+    #[serde(default)]
+    pub prune: bool,
+    #[serde(default)]
+    pub teams: Vec<TeamSpec>,
+    #[serde(default)]
+    pub schedules: Vec<ScheduleSpec>,
+    #[serde(default)]
+    pub escalation_policies: Vec<EscalationPolicySpec>,
+    #[serde(default)]
+    pub services: Vec<ServiceSpec>,
+}
+
+impl DesiredState {
+    pub fn from_yaml(document: &str) -> Result<Self, Error> {
+        Ok(serde_yaml::from_str(document)?)
+    }
+
+    pub fn from_json(document: &str) -> Result<Self, Error> {
+        Ok(serde_json::from_str(document)?)
+    }
+
+    /// Read a desired state document, parsed as JSON for a `.json` extension and YAML otherwise.
+    pub async fn from_file<P: AsRef<Path>>(path: P) -> Result<Self, Error> {
+        let document = tokio::fs::read_to_string(path.as_ref()).await?;
+        match path.as_ref().extension().and_then(|ext| ext.to_str()) {
+            Some("json") => Self::from_json(&document),
+            _ => Self::from_yaml(&document),
+        }
+    }
+
+    /// Whether the document manages teams, or assigns escalation policies to them.
+    fn uses_teams(&self) -> bool {
+        !self.teams.is_empty()
+            || self
+                .escalation_policies
+                .iter()
+                .any(|escalation_policy| !escalation_policy.teams.is_empty())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TeamSpec {
+    pub name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// Parent team name.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub parent: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ScheduleSpec {
+    pub name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    pub time_zone: String,
+    pub layers: Vec<ScheduleLayerSpec>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ScheduleLayerSpec {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    pub start: DateTime<FixedOffset>,
+    /// Defaults to `start` when omitted.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rotation_virtual_start: Option<DateTime<FixedOffset>>,
+    pub rotation_turn_length_seconds: isize,
+    /// User email addresses, in rotation order.
+    pub users: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EscalationPolicySpec {
+    pub name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(default)]
+    pub num_loops: usize,
+    pub rules: Vec<EscalationRuleSpec>,
+    /// Team names.
+    #[serde(default)]
+    pub teams: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EscalationRuleSpec {
+    pub escalation_delay_in_minutes: isize,
+    /// User email addresses to notify.
+    #[serde(default)]
+    pub users: Vec<String>,
+    /// Schedule names to notify.
+    #[serde(default)]
+    pub schedules: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ServiceSpec {
+    pub name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// Escalation policy name.
+    pub escalation_policy: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub auto_resolve_timeout: Option<isize>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub acknowledgement_timeout: Option<isize>,
+}
+
+/// The resources of an account that are managed declaratively.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct AccountState {
+    pub users: Vec<User>,
+    pub teams: Vec<Team>,
+    pub schedules: Vec<Schedule>,
+    pub escalation_policies: Vec<EscalationPolicy>,
+    pub services: Vec<Service>,
+}
+
+/// Name to id lookups used to translate between specs and models.
+#[derive(Debug, Default, Clone, PartialEq)]
+struct References {
+    users: HashMap<String, String>,
+    teams: HashMap<String, String>,
+    schedules: HashMap<String, String>,
+    escalation_policies: HashMap<String, String>,
+    /// The names and ids of the active layers of each schedule, by schedule id.
+    schedule_layers: HashMap<String, Vec<(Option<String>, String)>>,
+}
+
+impl References {
+    fn from_state(state: &AccountState) -> Self {
+        fn index<'a, I: Iterator<Item = (&'a Option<String>, &'a Option<String>)>>(
+            iter: I,
+        ) -> HashMap<String, String> {
+            iter.filter_map(|(key, id)| {
+                Some((String::clone(key.as_ref()?), String::clone(id.as_ref()?)))
+            })
+            .collect()
+        }
+
+        Self {
+            users: index(state.users.iter().map(|u| (&u.email, &u.id))),
+            teams: index(state.teams.iter().map(|t| (&t.name, &t.id))),
+            schedules: index(state.schedules.iter().map(|s| (&s.name, &s.id))),
+            escalation_policies: index(state.escalation_policies.iter().map(|e| (&e.name, &e.id))),
+            schedule_layers: state
+                .schedules
+                .iter()
+                .filter_map(|schedule| {
+                    let layers = schedule
+                        .schedule_layers
+                        .iter()
+                        .flatten()
+                        .filter(|layer| layer.end.is_none())
+                        .filter_map(|layer| Some((layer.name.clone(), layer.id.clone()?)))
+                        .collect();
+                    Some((schedule.id.clone()?, layers))
+                })
+                .collect(),
+        }
+    }
+
+    fn resolve(map: &HashMap<String, String>, kind: &str, name: &str) -> Result<String, Error> {
+        map.get(name)
+            .cloned()
+            .ok_or_else(|| Error::UnresolvedReferenceError {
+                kind: String::from(kind),
+                name: String::from(name),
+            })
+    }
+
+    fn name_of(map: &HashMap<String, String>, id: &str) -> String {
+        map.iter()
+            .find(|(_, v)| v.as_str() == id)
+            .map(|(k, _)| String::clone(k))
+            .unwrap_or_else(|| String::from(id))
+    }
+}
+
+fn optional_eq<T: PartialEq>(desired: &Option<T>, current: &Option<T>) -> bool {
+    desired.is_none() || desired == current
+}
+
+/// A resource type managed through a [Plan].
+pub trait Resource {
+    const KIND: &'static str;
+
+    fn name(&self) -> &str;
+}
+
+impl Resource for TeamSpec {
+    const KIND: &'static str = "team";
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+}
+
+impl Resource for ScheduleSpec {
+    const KIND: &'static str = "schedule";
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+}
+
+impl Resource for EscalationPolicySpec {
+    const KIND: &'static str = "escalation_policy";
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+}
+
+impl Resource for ServiceSpec {
+    const KIND: &'static str = "service";
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+}
+
+impl TeamSpec {
+    fn from_model(team: &Team, refs: &References) -> Self {
+        Self {
+            name: team.name.clone().unwrap_or_default(),
+            description: team.description.clone(),
+            parent: team
+                .parent
+                .as_ref()
+                .and_then(|p| p.id.as_deref())
+                .map(|id| References::name_of(&refs.teams, id)),
+        }
+    }
+
+    fn matches(&self, current: &Self) -> bool {
+        self.parent == current.parent && optional_eq(&self.description, &current.description)
+    }
+
+    fn to_model(&self, refs: &References) -> Result<Team, Error> {
+        let parent = match &self.parent {
+            Some(name) => Some(TeamReference {
+                id: Some(References::resolve(&refs.teams, "team", name)?),
+                _type: TeamReferenceTypeEnum::TEAM_REFERENCE,
+                ..Default::default()
+            }),
+            None => None,
+        };
+
+        Ok(Team {
+            _type: TeamTypeEnum::TEAM,
+            name: Some(String::clone(&self.name)),
+            description: self.description.clone(),
+            parent,
+            ..Default::default()
+        })
+    }
+}
+
+impl ScheduleSpec {
+    fn from_model(schedule: &Schedule, refs: &References) -> Self {
+        Self {
+            name: schedule.name.clone().unwrap_or_default(),
+            description: schedule.description.clone(),
+            time_zone: schedule.time_zone.clone().unwrap_or_default(),
+            layers: schedule
+                .schedule_layers
+                .iter()
+                .flatten()
+                .filter(|layer| layer.end.is_none())
+                .map(|layer| ScheduleLayerSpec {
+                    name: layer.name.clone(),
+                    start: layer.start,
+                    rotation_virtual_start: Some(layer.rotation_virtual_start),
+                    rotation_turn_length_seconds: layer.rotation_turn_length_seconds,
+                    users: layer
+                        .users
+                        .iter()
+                        .filter_map(|u| u.user.id.as_ref())
+                        .map(|id| References::name_of(&refs.users, id))
+                        .collect(),
+                })
+                .collect(),
+        }
+    }
+
+    fn matches(&self, current: &Self) -> bool {
+        self.time_zone == current.time_zone
+            && optional_eq(&self.description, &current.description)
+            && self.layers.len() == current.layers.len()
+            && self.layers.iter().zip(&current.layers).all(|(d, c)| {
+                optional_eq(&d.name, &c.name)
+                    && d.start == c.start
+                    && d.rotation_virtual_start.unwrap_or(d.start)
+                        == c.rotation_virtual_start.unwrap_or(c.start)
+                    && d.rotation_turn_length_seconds == c.rotation_turn_length_seconds
+                    && d.users == c.users
+            })
+    }
+
+    fn to_model(&self, refs: &References) -> Result<Schedule, Error> {
+        let mut layers = vec![];
+        for layer in &self.layers {
+            let mut users = vec![];
+            for email in &layer.users {
+                users.push(ScheduleLayerUser {
+                    user: User {
+                        id: Some(References::resolve(&refs.users, "user", email)?),
+                        _type: UserTypeEnum::USER_REFERENCE,
+                        ..Default::default()
+                    },
+                });
+            }
+            layers.push(ScheduleLayer {
+                name: layer.name.clone(),
+                start: layer.start,
+                rotation_virtual_start: layer.rotation_virtual_start.unwrap_or(layer.start),
+                rotation_turn_length_seconds: layer.rotation_turn_length_seconds,
+                users,
+                ..Default::default()
+            });
+        }
+
+        Ok(Schedule {
+            _type: ScheduleTypeEnum::SCHEDULE,
+            name: Some(String::clone(&self.name)),
+            description: self.description.clone(),
+            time_zone: Some(String::clone(&self.time_zone)),
+            schedule_layers: Some(layers),
+            ..Default::default()
+        })
+    }
+
+    /// Like [ScheduleSpec::to_model], keeping the ids of the existing layers of the schedule.
+    /// PagerDuty ends every layer left out of an update and starts a new one in its place, so
+    /// layers are matched by name, then in order.
+    fn to_update_model(&self, id: &str, refs: &References) -> Result<Schedule, Error> {
+        let mut schedule = self.to_model(refs)?;
+        let existing = match refs.schedule_layers.get(id) {
+            Some(existing) => existing,
+            None => return Ok(schedule),
+        };
+        let layers = schedule.schedule_layers.get_or_insert_with(Vec::new);
+
+        let mut used = vec![false; existing.len()];
+        for layer in layers.iter_mut() {
+            let position = existing
+                .iter()
+                .enumerate()
+                .position(|(i, (name, _))| !used[i] && layer.name.is_some() && *name == layer.name);
+            if let Some(i) = position {
+                used[i] = true;
+                layer.id = Some(String::clone(&existing[i].1));
+            }
+        }
+        for layer in layers.iter_mut().filter(|layer| layer.id.is_none()) {
+            if let Some(i) = used.iter().position(|used| !used) {
+                used[i] = true;
+                layer.id = Some(String::clone(&existing[i].1));
+            }
+        }
+
+        Ok(schedule)
+    }
+}
+
+impl EscalationPolicySpec {
+    fn from_model(escalation_policy: &EscalationPolicy, refs: &References) -> Self {
+        Self {
+            name: escalation_policy.name.clone().unwrap_or_default(),
+            description: escalation_policy.description.clone(),
+            num_loops: escalation_policy.num_loops.unwrap_or_default(),
+            rules: escalation_policy
+                .escalation_rules
+                .iter()
+                .flatten()
+                .map(|rule| {
+                    let mut spec = EscalationRuleSpec {
+                        escalation_delay_in_minutes: rule.escalation_delay_in_minutes,
+                        users: vec![],
+                        schedules: vec![],
+                    };
+                    for target in &rule.targets {
+                        let id = target.id.as_deref().unwrap_or_default();
+                        match target._type {
+                            EscalationTargetReferenceTypeEnum::SCHEDULE
+                            | EscalationTargetReferenceTypeEnum::SCHEDULE_REFERENCE => spec
+                                .schedules
+                                .push(References::name_of(&refs.schedules, id)),
+                            _ => spec.users.push(References::name_of(&refs.users, id)),
+                        }
+                    }
+                    spec
+                })
+                .collect(),
+            teams: escalation_policy
+                .teams
+                .iter()
+                .flatten()
+                .filter_map(|team| team.id.as_deref())
+                .map(|id| References::name_of(&refs.teams, id))
+                .collect(),
+        }
+    }
+
+    fn matches(&self, current: &Self) -> bool {
+        self.num_loops == current.num_loops
+            && optional_eq(&self.description, &current.description)
+            && self.rules == current.rules
+            && self.teams == current.teams
+    }
+
+    fn to_model(&self, refs: &References) -> Result<EscalationPolicy, Error> {
+        let mut rules = vec![];
+        for rule in &self.rules {
+            let mut targets = vec![];
+            for email in &rule.users {
+                targets.push(EscalationTargetReference {
+                    id: Some(References::resolve(&refs.users, "user", email)?),
+                    _type: EscalationTargetReferenceTypeEnum::USER_REFERENCE,
+                    ..Default::default()
+                });
+            }
+            for name in &rule.schedules {
+                targets.push(EscalationTargetReference {
+                    id: Some(References::resolve(&refs.schedules, "schedule", name)?),
+                    _type: EscalationTargetReferenceTypeEnum::SCHEDULE_REFERENCE,
+                    ..Default::default()
+                });
+            }
+            rules.push(EscalationRule {
+                escalation_delay_in_minutes: rule.escalation_delay_in_minutes,
+                targets,
+                ..Default::default()
+            });
+        }
+
+        let mut teams = vec![];
+        for name in &self.teams {
+            teams.push(Team {
+                id: Some(References::resolve(&refs.teams, "team", name)?),
+                _type: TeamTypeEnum::TEAM_REFERENCE,
+                ..Default::default()
+            });
+        }
+
+        Ok(EscalationPolicy {
+            _type: EscalationPolicyTypeEnum::ESCALATION_POLICY,
+            name: Some(String::clone(&self.name)),
+            description: self.description.clone(),
+            num_loops: Some(self.num_loops),
+            escalation_rules: Some(rules),
+            teams: Some(teams),
+            ..Default::default()
+        })
+    }
+}
+
+impl ServiceSpec {
+    fn from_model(service: &Service, refs: &References) -> Self {
+        Self {
+            name: service.name.clone().unwrap_or_default(),
+            description: service.description.clone(),
+            escalation_policy: service
+                .escalation_policy
+                .as_ref()
+                .and_then(|e| e.id.as_deref())
+                .map(|id| References::name_of(&refs.escalation_policies, id))
+                .unwrap_or_default(),
+            auto_resolve_timeout: service.auto_resolve_timeout,
+            acknowledgement_timeout: service.acknowledgement_timeout,
+        }
+    }
+
+    fn matches(&self, current: &Self) -> bool {
+        self.escalation_policy == current.escalation_policy
+            && optional_eq(&self.description, &current.description)
+            && optional_eq(&self.auto_resolve_timeout, &current.auto_resolve_timeout)
+            && optional_eq(
+                &self.acknowledgement_timeout,
+                &current.acknowledgement_timeout,
+            )
+    }
+
+    fn to_model(&self, refs: &References) -> Result<Service, Error> {
+        Ok(Service {
+            _type: ServiceTypeEnum::SERVICE,
+            name: Some(String::clone(&self.name)),
+            description: self.description.clone(),
+            escalation_policy: Some(EscalationPolicy {
+                id: Some(References::resolve(
+                    &refs.escalation_policies,
+                    "escalation_policy",
+                    &self.escalation_policy,
+                )?),
+                _type: EscalationPolicyTypeEnum::ESCALATION_POLICY_REFERENCE,
+                ..Default::default()
+            }),
+            auto_resolve_timeout: self.auto_resolve_timeout,
+            acknowledgement_timeout: self.acknowledgement_timeout,
+            ..Default::default()
+        })
+    }
+}
+
+/// A single planned change to a resource.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Operation<S> {
+    Create(S),
+    Update { id: String, spec: S },
+    Delete { id: String, name: String },
+}
+
+impl<S: Resource> fmt::Display for Operation<S> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Operation::Create(spec) => write!(f, "+ {} \"{}\"", S::KIND, spec.name()),
+            Operation::Update { id, spec } => {
+                write!(f, "~ {} \"{}\" ({})", S::KIND, spec.name(), id)
+            }
+            Operation::Delete { id, name } => write!(f, "- {} \"{}\" ({})", S::KIND, name, id),
+        }
+    }
+}
+
+/// The set of changes needed to bring an account in line with a [DesiredState].
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct Plan {
+    pub teams: Vec<Operation<TeamSpec>>,
+    pub schedules: Vec<Operation<ScheduleSpec>>,
+    pub escalation_policies: Vec<Operation<EscalationPolicySpec>>,
+    pub services: Vec<Operation<ServiceSpec>>,
+    references: References,
+}
+
+impl Plan {
+    /// Compare the desired state against the current account state.
+    pub fn compute(desired: &DesiredState, current: &AccountState) -> Self {
+        let references = References::from_state(current);
+
+        let teams = order_teams(
+            diff(
+                &desired.teams,
+                current
+                    .teams
+                    .iter()
+                    .map(|t| (t.id.as_deref(), TeamSpec::from_model(t, &references))),
+                TeamSpec::matches,
+                desired.prune,
+            ),
+            &desired.teams,
+            &current.teams,
+        );
+        let schedules = diff(
+            &desired.schedules,
+            current
+                .schedules
+                .iter()
+                .map(|s| (s.id.as_deref(), ScheduleSpec::from_model(s, &references))),
+            ScheduleSpec::matches,
+            desired.prune,
+        );
+        let escalation_policies = diff(
+            &desired.escalation_policies,
+            current.escalation_policies.iter().map(|e| {
+                (
+                    e.id.as_deref(),
+                    EscalationPolicySpec::from_model(e, &references),
+                )
+            }),
+            EscalationPolicySpec::matches,
+            desired.prune,
+        );
+        let services = diff(
+            &desired.services,
+            current
+                .services
+                .iter()
+                .map(|s| (s.id.as_deref(), ServiceSpec::from_model(s, &references))),
+            ServiceSpec::matches,
+            desired.prune,
+        );
+
+        Self {
+            teams,
+            schedules,
+            escalation_policies,
+            services,
+            references,
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.teams.is_empty()
+            && self.schedules.is_empty()
+            && self.escalation_policies.is_empty()
+            && self.services.is_empty()
+    }
+
+    /// The account abilities needed to apply the plan, checked by [AccountConfigClient::apply]
+    /// before making any changes.
+    pub fn required_abilities(&self) -> Vec<&'static str> {
+        let teams = !self.teams.is_empty()
+            || self
+                .escalation_policies
+                .iter()
+                .any(|operation| match operation {
+                    Operation::Create(spec) | Operation::Update { spec, .. } => {
+                        !spec.teams.is_empty()
+                    }
+                    Operation::Delete { .. } => false,
+                });
+
+        if teams {
+            vec!["teams"]
+        } else {
+            vec![]
+        }
+    }
+}
+
+fn diff<'a, S: Resource + Clone, I: Iterator<Item = (Option<&'a str>, S)>>(
+    desired: &[S],
+    current: I,
+    matches: fn(&S, &S) -> bool,
+    prune: bool,
+) -> Vec<Operation<S>> {
+    let current: HashMap<String, (String, S)> = current
+        .filter_map(|(id, spec)| Some((String::from(spec.name()), (String::from(id?), spec))))
+        .collect();
+
+    let mut operations = vec![];
+    for spec in desired {
+        match current.get(spec.name()) {
+            None => operations.push(Operation::Create(S::clone(spec))),
+            Some((id, existing)) if !matches(spec, existing) => {
+                operations.push(Operation::Update {
+                    id: String::clone(id),
+                    spec: S::clone(spec),
+                })
+            }
+            Some(_) => (),
+        }
+    }
+
+    if prune {
+        let mut stale: Vec<(String, String)> = current
+            .into_iter()
+            .filter(|(name, _)| !desired.iter().any(|spec| spec.name() == name))
+            .map(|(name, (id, _))| (name, id))
+            .collect();
+        stale.sort();
+        operations.extend(
+            stale
+                .into_iter()
+                .map(|(name, id)| Operation::Delete { id, name }),
+        );
+    }
+
+    operations
+}
+
+// Order team operations parents first, so that a created team can resolve a parent created by
+// the same plan, and deletions, which are applied in reverse, remove children before parents.
+fn order_teams(
+    mut operations: Vec<Operation<TeamSpec>>,
+    desired: &[TeamSpec],
+    current: &[Team],
+) -> Vec<Operation<TeamSpec>> {
+    let desired_parents: HashMap<&str, &str> = desired
+        .iter()
+        .filter_map(|team| Some((team.name.as_str(), team.parent.as_deref()?)))
+        .collect();
+    let current_parents: HashMap<&str, &str> = current
+        .iter()
+        .filter_map(|team| Some((team.id.as_deref()?, team.parent.as_ref()?.id.as_deref()?)))
+        .collect();
+
+    operations.sort_by_key(|operation| match operation {
+        Operation::Create(spec) | Operation::Update { spec, .. } => {
+            (false, team_depth(&desired_parents, &spec.name))
+        }
+        Operation::Delete { id, .. } => (true, team_depth(&current_parents, id)),
+    });
+
+    operations
+}
+
+// The number of ancestors of a team, stopping at a parent cycle.
+pub(crate) fn team_depth(parents: &HashMap<&str, &str>, team: &str) -> usize {
+    let mut depth = 0;
+    let mut team = team;
+    while let Some(parent) = parents.get(team) {
+        if depth >= parents.len() {
+            break;
+        }
+        depth += 1;
+        team = parent;
+    }
+
+    depth
+}
+
+impl fmt::Display for Plan {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.is_empty() {
+            return writeln!(f, "No changes.");
+        }
+        for operation in &self.teams {
+            writeln!(f, "{}", operation)?;
+        }
+        for operation in &self.schedules {
+            writeln!(f, "{}", operation)?;
+        }
+        for operation in &self.escalation_policies {
+            writeln!(f, "{}", operation)?;
+        }
+        for operation in &self.services {
+            writeln!(f, "{}", operation)?;
+        }
+        Ok(())
+    }
+}
+
+impl AccountConfigClient {
+    /// Fetch the users, teams, schedules, escalation policies and services of the account. Teams
+    /// are left empty when the account does not have the `teams` ability.
+    pub async fn fetch_state(&self) -> Result<AccountState, Error> {
+        let users_client = self.client.users();
+        let users: Vec<User> = users_client
+            .list_users(ListUsersParamsBuilder::new().build())
+            .try_collect()
+            .await?;
+
+        let teams: Vec<Team> = if self.client.capabilities().await?.has("teams") {
+            let teams_client = self.client.teams();
+            teams_client
+                .list_teams(ListTeamsParamsBuilder::new().build())
+                .try_collect()
+                .await?
+        } else {
+            vec![]
+        };
+
+        // Schedule layers are only returned when fetching a single schedule.
+        let schedules_client = self.client.schedules();
+        let schedule_refs: Vec<Schedule> = schedules_client
+            .list_schedules(ListSchedulesParamsBuilder::new().build())
+            .try_collect()
+            .await?;
+        let mut schedules = vec![];
+        for schedule in schedule_refs {
+            if let Some(id) = &schedule.id {
+                schedules.push(
+                    schedules_client
+                        .get_schedule(id, GetScheduleParamsBuilder::new().build())
+                        .await?,
+                );
+            }
+        }
+
+        let escalation_policies_client = self.client.escalation_policies(self.from_email.clone());
+        let escalation_policies: Vec<EscalationPolicy> = escalation_policies_client
+            .list_escalation_policies(ListEscalationPoliciesParamsBuilder::new().build())
+            .try_collect()
+            .await?;
+
+        let services_client = self.client.services();
+        let services: Vec<Service> = services_client
+            .list_services(ListServicesParamsBuilder::new().build())
+            .try_collect()
+            .await?;
+
+        Ok(AccountState {
+            users,
+            teams,
+            schedules,
+            escalation_policies,
+            services,
+        })
+    }
+
+    /// Fetch the current account state and compute the changes needed to reach `desired`. Fails
+    /// with [Error::MissingAbilityError] before fetching anything when `desired` uses teams and
+    /// the account does not have the `teams` ability.
+    pub async fn plan(&self, desired: &DesiredState) -> Result<Plan, Error> {
+        if desired.uses_teams() {
+            self.client.require_ability("teams").await?;
+        }
+
+        let current = self.fetch_state().await?;
+
+        Ok(Plan::compute(desired, &current))
+    }
+
+    /// Apply a plan: teams, then schedules, then escalation policies, then services, followed by
+    /// any deletions in the reverse order. Fails before making any change when the account lacks
+    /// one of the [Plan::required_abilities].
+    pub async fn apply(&self, plan: &Plan) -> Result<(), Error> {
+        for ability in plan.required_abilities() {
+            self.client.require_ability(ability).await?;
+        }
+
+        let mut refs = plan.references.clone();
+
+        let teams = self.client.teams();
+        for operation in &plan.teams {
+            match operation {
+                Operation::Create(spec) => {
+                    info!("{}", operation);
+                    let body = CreateTeam {
+                        team: spec.to_model(&refs)?,
+                    };
+                    let team = teams.create_team(body).await?;
+                    if let Some(id) = team.id {
+                        refs.teams.insert(String::clone(&spec.name), id);
+                    }
+                }
+                Operation::Update { id, spec } => {
+                    info!("{}", operation);
+                    let body = UpdateTeam {
+                        team: spec.to_model(&refs)?,
+                    };
+                    teams.update_team(id, body).await?;
+                }
+                Operation::Delete { .. } => (),
+            }
+        }
+
+        let schedules = self.client.schedules();
+        for operation in &plan.schedules {
+            match operation {
+                Operation::Create(spec) => {
+                    info!("{}", operation);
+                    let body = CreateSchedule {
+                        schedule: spec.to_model(&refs)?,
+                    };
+                    let schedule = schedules
+                        .create_schedule(CreateScheduleParamsBuilder::new().build(), body)
+                        .await?;
+                    if let Some(id) = schedule.id {
+                        refs.schedules.insert(String::clone(&spec.name), id);
+                    }
+                }
+                Operation::Update { id, spec } => {
+                    info!("{}", operation);
+                    let body = UpdateSchedule {
+                        schedule: spec.to_update_model(id, &refs)?,
+                    };
+                    schedules
+                        .update_schedule(id, UpdateScheduleParamsBuilder::new().build(), body)
+                        .await?;
+                }
+                Operation::Delete { .. } => (),
+            }
+        }
+
+        let escalation_policies = self.client.escalation_policies(self.from_email.clone());
+        for operation in &plan.escalation_policies {
+            match operation {
+                Operation::Create(spec) => {
+                    info!("{}", operation);
+                    let body = CreateEscalationPolicy {
+                        escalation_policy: spec.to_model(&refs)?,
+                    };
+                    let escalation_policy =
+                        escalation_policies.create_escalation_policy(body).await?;
+                    if let Some(id) = escalation_policy.id {
+                        refs.escalation_policies
+                            .insert(String::clone(&spec.name), id);
+                    }
+                }
+                Operation::Update { id, spec } => {
+                    info!("{}", operation);
+                    let body = UpdateEscalationPolicy {
+                        escalation_policy: spec.to_model(&refs)?,
+                    };
+                    escalation_policies
+                        .update_escalation_policy(id, body)
+                        .await?;
+                }
+                Operation::Delete { .. } => (),
+            }
+        }
+
+        let services = self.client.services();
+        for operation in &plan.services {
+            match operation {
+                Operation::Create(spec) => {
+                    info!("{}", operation);
+                    let body = CreateService {
+                        service: spec.to_model(&refs)?,
+                    };
+                    services.create_service(body).await?;
+                }
+                Operation::Update { id, spec } => {
+                    info!("{}", operation);
+                    let body = UpdateService {
+                        service: spec.to_model(&refs)?,
+                    };
+                    services.update_service(id, body).await?;
+                }
+                Operation::Delete { id, .. } => {
+                    info!("{}", operation);
+                    services.delete_service(id).await?;
+                }
+            }
+        }
+
+        for operation in &plan.escalation_policies {
+            if let Operation::Delete { id, .. } = operation {
+                info!("{}", operation);
+                escalation_policies.delete_escalation_policy(id).await?;
+            }
+        }
+
+        for operation in &plan.schedules {
+            if let Operation::Delete { id, .. } = operation {
+                info!("{}", operation);
+                schedules.delete_schedule(id).await?;
+            }
+        }
+
+        for operation in plan.teams.iter().rev() {
+            if let Operation::Delete { id, .. } = operation {
+                info!("{}", operation);
+                teams.delete_team(id).await?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DOCUMENT: &str = r#"
+prune: true
+teams:
+  - name: Platform
+  - name: SRE
+    parent: Platform
+schedules:
+  - name: Primary
+    time_zone: Europe/London
+    layers:
+      - start: 2022-01-01T09:00:00Z
+        rotation_turn_length_seconds: 604800
+        users: [alice@example.com, bob@example.com]
+escalation_policies:
+  - name: Engineering
+    rules:
+      - escalation_delay_in_minutes: 30
+        schedules: [Primary]
+    teams: [SRE]
+services:
+  - name: API
+    escalation_policy: Engineering
+"#;
+
+    fn current_state() -> AccountState {
+        let start = DateTime::parse_from_rfc3339("2022-01-01T09:00:00Z").unwrap();
+        AccountState {
+            users: vec![
+                User {
+                    id: Some(String::from("PALICE")),
+                    email: Some(String::from("alice@example.com")),
+                    ..Default::default()
+                },
+                User {
+                    id: Some(String::from("PBOB")),
+                    email: Some(String::from("bob@example.com")),
+                    ..Default::default()
+                },
+            ],
+            teams: vec![Team {
+                id: Some(String::from("PPLATFORM")),
+                name: Some(String::from("Platform")),
+                ..Default::default()
+            }],
+            schedules: vec![Schedule {
+                id: Some(String::from("PSCHED")),
+                name: Some(String::from("Primary")),
+                time_zone: Some(String::from("Europe/London")),
+                schedule_layers: Some(vec![ScheduleLayer {
+                    id: Some(String::from("PLAYER1")),
+                    start,
+                    rotation_virtual_start: start,
+                    rotation_turn_length_seconds: 604800,
+                    users: vec![ScheduleLayerUser {
+                        user: User {
+                            id: Some(String::from("PALICE")),
+                            ..Default::default()
+                        },
+                    }],
+                    ..Default::default()
+                }]),
+                ..Default::default()
+            }],
+            escalation_policies: vec![],
+            services: vec![Service {
+                id: Some(String::from("PLEGACY")),
+                name: Some(String::from("Legacy")),
+                ..Default::default()
+            }],
+        }
+    }
+
+    #[test]
+    fn test_desired_state_from_yaml() {
+        let desired = DesiredState::from_yaml(DOCUMENT).unwrap();
+
+        assert!(desired.prune);
+        assert_eq!(desired.schedules[0].layers[0].users.len(), 2);
+        assert_eq!(
+            desired.escalation_policies[0].rules[0].schedules,
+            vec!["Primary"]
+        );
+        assert_eq!(desired.services[0].escalation_policy, "Engineering");
+        assert_eq!(desired.teams[1].parent, Some(String::from("Platform")));
+        assert_eq!(desired.escalation_policies[0].teams, vec!["SRE"]);
+        assert!(desired.uses_teams());
+        assert!(!DesiredState::from_yaml("services: []")
+            .unwrap()
+            .uses_teams());
+    }
+
+    #[test]
+    fn test_plan_compute() {
+        let desired = DesiredState::from_yaml(DOCUMENT).unwrap();
+        let plan = Plan::compute(&desired, &current_state());
+
+        assert!(matches!(
+            &plan.teams[..],
+            [Operation::Create(spec)] if spec.name == "SRE"
+        ));
+        assert!(matches!(
+            &plan.schedules[..],
+            [Operation::Update { id, .. }] if id == "PSCHED"
+        ));
+        assert!(matches!(
+            &plan.escalation_policies[..],
+            [Operation::Create(spec)] if spec.name == "Engineering"
+        ));
+        assert_eq!(
+            plan.services[1],
+            Operation::Delete {
+                id: String::from("PLEGACY"),
+                name: String::from("Legacy")
+            }
+        );
+        assert_eq!(
+            plan.to_string(),
+            "+ team \"SRE\"\n~ schedule \"Primary\" (PSCHED)\n+ escalation_policy \"Engineering\"\n+ service \"API\"\n- service \"Legacy\" (PLEGACY)\n"
+        );
+        assert_eq!(plan.required_abilities(), vec!["teams"]);
+
+        let services_only = Plan {
+            teams: vec![],
+            schedules: vec![],
+            escalation_policies: vec![],
+            ..plan
+        };
+        assert!(services_only.required_abilities().is_empty());
+    }
+
+    #[test]
+    fn test_plan_orders_teams_by_parent() {
+        let desired = DesiredState::from_yaml(
+            r#"
+prune: true
+teams:
+  - name: Database
+    parent: Storage
+  - name: Storage
+    parent: Platform
+  - name: Platform
+"#,
+        )
+        .unwrap();
+        let current = AccountState {
+            teams: vec![
+                Team {
+                    id: Some(String::from("PALERTS")),
+                    name: Some(String::from("Alerts")),
+                    parent: Some(TeamReference {
+                        id: Some(String::from("POPS")),
+                        ..Default::default()
+                    }),
+                    ..Default::default()
+                },
+                Team {
+                    id: Some(String::from("POPS")),
+                    name: Some(String::from("Ops")),
+                    ..Default::default()
+                },
+            ],
+            ..Default::default()
+        };
+        let plan = Plan::compute(&desired, &current);
+
+        // Deletions are applied in reverse, so "Alerts" is deleted before its parent "Ops".
+        assert_eq!(
+            plan.to_string(),
+            "+ team \"Platform\"\n+ team \"Storage\"\n+ team \"Database\"\n- team \"Ops\" (POPS)\n- team \"Alerts\" (PALERTS)\n"
+        );
+    }
+
+    #[test]
+    fn test_schedule_update_keeps_layer_ids() {
+        let mut desired = DesiredState::from_yaml(DOCUMENT).unwrap();
+        let mut night = desired.schedules[0].layers[0].clone();
+        night.name = Some(String::from("Night"));
+        desired.schedules[0].layers.insert(0, night);
+
+        let mut current = current_state();
+        let layers = current.schedules[0].schedule_layers.as_mut().unwrap();
+        layers.push(ScheduleLayer {
+            id: Some(String::from("PLAYER2")),
+            name: Some(String::from("Night")),
+            ..layers[0].clone()
+        });
+        layers.push(ScheduleLayer {
+            id: Some(String::from("PENDED")),
+            end: Some(DateTime::parse_from_rfc3339("2022-01-01T00:00:00Z").unwrap()),
+            ..layers[0].clone()
+        });
+
+        let plan = Plan::compute(&desired, &current);
+        let schedule = desired.schedules[0]
+            .to_update_model("PSCHED", &plan.references)
+            .unwrap();
+        let ids: Vec<_> = schedule
+            .schedule_layers
+            .unwrap()
+            .into_iter()
+            .map(|layer| layer.id)
+            .collect();
+
+        assert_eq!(
+            ids,
+            vec![Some(String::from("PLAYER2")), Some(String::from("PLAYER1"))]
+        );
+    }
+
+    #[test]
+    fn test_plan_unresolved_reference() {
+        let desired = DesiredState::from_yaml(DOCUMENT).unwrap();
+        let plan = Plan::compute(&desired, &current_state());
+
+        let err = desired.services[0].to_model(&plan.references).unwrap_err();
+
+        assert!(matches!(err, Error::UnresolvedReferenceError { .. }));
+    }
+}
@@ -0,0 +1,725 @@
+//! Full account export and restore.
+//!
+//! The [AccountArchiveClient] streams every user, team, schedule, escalation policy, service
+//! (with its integrations and event rules) and Slack connection into a versioned JSON [Archive]
+//! on disk, and recreates those objects in another account. Ids are remapped on import: every
+//! reference to an exported object is rewritten to point at its counterpart in the target
+//! account. Users, matched by email, and teams, schedules, escalation policies and services,
+//! matched by name, that already exist in the target account are reused rather than recreated,
+//! so that an interrupted import can be run again.
+
+use std::collections::HashMap;
+use std::path::Path;
+
+use chrono::{DateTime, Utc};
+use futures_core::Stream;
+use futures_util::{StreamExt, TryStreamExt};
+use log::{debug, info};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::Value;
+use tokio::io::AsyncWriteExt;
+
+use crate::account_config::team_depth;
+use crate::endpoints::abilities::Capabilities;
+use crate::endpoints::escalation_policies::ListEscalationPoliciesParamsBuilder;
+use crate::endpoints::schedules::{
+    CreateScheduleParamsBuilder, GetScheduleParamsBuilder, ListSchedulesParamsBuilder,
+};
+use crate::endpoints::services::ListServicesParamsBuilder;
+use crate::endpoints::teams::ListTeamsParamsBuilder;
+use crate::endpoints::users::ListUsersParamsBuilder;
+use crate::errors::Error;
+use crate::models::*;
+use crate::slack_models::{CreateConnection, SlackConnection};
+use crate::{ParamsBuilder, Praiya};
+
+/// The archive format version written by this release.
+pub const ARCHIVE_VERSION: u32 = 1;
+
+/// Mapping from ids in the exported account to ids in the target account.
+pub type IdMap = HashMap<String, String>;
+
+/// A client to export and restore a whole account
+pub struct AccountArchiveClient {
+    pub(crate) client: Praiya,
+    pub(crate) from_email: String,
+    pub(crate) slack_workspace_id: Option<String>,
+}
+
+impl Praiya {
+    pub fn account_archive(
+        &self,
+        from_email: &str,
+        slack_workspace_id: Option<&str>,
+    ) -> AccountArchiveClient {
+        AccountArchiveClient {
+            client: Praiya::clone(self),
+            from_email: String::from(from_email),
+            slack_workspace_id: slack_workspace_id.map(String::from),
+        }
+    }
+}
+
+/// The on-disk representation of an exported account.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Archive {
+    pub version: u32,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub exported_at: Option<DateTime<Utc>>,
+    #[serde(default)]
+    pub users: Vec<User>,
+    #[serde(default)]
+    pub teams: Vec<Team>,
+    #[serde(default)]
+    pub schedules: Vec<Schedule>,
+    #[serde(default)]
+    pub escalation_policies: Vec<EscalationPolicy>,
+    #[serde(default)]
+    pub services: Vec<ServiceArchive>,
+    #[serde(default)]
+    pub slack_connections: Vec<SlackConnection>,
+}
+
+impl Archive {
+    /// Read an archive from disk, rejecting versions newer than [ARCHIVE_VERSION].
+    pub async fn read<P: AsRef<Path>>(path: P) -> Result<Self, Error> {
+        let bytes = tokio::fs::read(path).await?;
+        let archive: Archive = serde_json::from_slice(&bytes)?;
+        if archive.version > ARCHIVE_VERSION {
+            return Err(Error::UnsupportedArchiveVersionError {
+                version: archive.version,
+            });
+        }
+
+        Ok(archive)
+    }
+
+    /// Fail with [Error::MissingAbilityError] if importing this archive needs an ability the
+    /// target account lacks: `teams` for any team, `urgencies` for services whose urgency
+    /// depends on support hours.
+    pub fn check_capabilities(&self, capabilities: &Capabilities) -> Result<(), Error> {
+        if !self.teams.is_empty() {
+            capabilities.require("teams")?;
+        }
+        let uses_support_hours = self.services.iter().any(|archive| {
+            archive
+                .service
+                .incident_urgency_rule
+                .as_ref()
+                .map(|rule| &rule._type)
+                == Some(&IncidentUrgencyRuleTypeEnum::USE_SUPPORT_HOURS)
+        });
+        if uses_support_hours {
+            capabilities.require("urgencies")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// A service together with its integrations and event rules.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ServiceArchive {
+    pub service: Service,
+    #[serde(default)]
+    pub integrations: Vec<Integration>,
+    #[serde(default)]
+    pub event_rules: Vec<ServiceEventRule>,
+}
+
+/// Incrementally writes the sections of an [Archive] as JSON, so that the account never needs to
+/// be held in memory at once.
+struct ArchiveWriter {
+    file: tokio::io::BufWriter<tokio::fs::File>,
+}
+
+impl ArchiveWriter {
+    async fn create<P: AsRef<Path>>(path: P) -> Result<Self, Error> {
+        let file = tokio::fs::File::create(path).await?;
+        let mut writer = Self {
+            file: tokio::io::BufWriter::new(file),
+        };
+        let header = format!(
+            "{{\"version\":{},\"exported_at\":{}",
+            ARCHIVE_VERSION,
+            serde_json::to_string(&Utc::now())?
+        );
+        writer.file.write_all(header.as_bytes()).await?;
+
+        Ok(writer)
+    }
+
+    async fn section<T, S>(&mut self, name: &str, mut items: S) -> Result<usize, Error>
+    where
+        T: Serialize,
+        S: Stream<Item = Result<T, Error>> + Unpin,
+    {
+        self.file
+            .write_all(format!(",\"{}\":[", name).as_bytes())
+            .await?;
+        let mut count = 0;
+        while let Some(item) = items.try_next().await? {
+            if count > 0 {
+                self.file.write_all(b",").await?;
+            }
+            self.file.write_all(&serde_json::to_vec(&item)?).await?;
+            count += 1;
+        }
+        self.file.write_all(b"]").await?;
+        info!("Exported {} {}", count, name);
+
+        Ok(count)
+    }
+
+    async fn finish(mut self) -> Result<(), Error> {
+        self.file.write_all(b"}").await?;
+        self.file.flush().await?;
+
+        Ok(())
+    }
+}
+
+/// Serialize a model, drop its own id and any API urls, rewrite references found in `ids`, and
+/// deserialize it again as a creation payload for the target account.
+///
+/// Nested ids missing from `ids` belong to sub-objects created along with the model, such as
+/// schedule layers or integrations, and are dropped too. Only references to objects outside the
+/// archive, such as vendors, keep their id.
+fn remap<T: Serialize + DeserializeOwned>(model: &T, ids: &IdMap) -> Result<T, Error> {
+    fn remap_value(value: &mut Value, ids: &IdMap) {
+        match value {
+            Value::Object(map) => {
+                map.remove("self");
+                map.remove("html_url");
+
+                let is_reference = matches!(
+                    map.get("type"),
+                    Some(Value::String(t)) if t.ends_with("_reference")
+                );
+                let new_id = match map.get("id") {
+                    Some(Value::String(id)) => Some(ids.get(id.as_str()).cloned()),
+                    _ => None,
+                };
+                match new_id {
+                    Some(Some(new_id)) => {
+                        map.insert(String::from("id"), Value::String(new_id));
+                    }
+                    Some(None) if !is_reference => {
+                        map.remove("id");
+                    }
+                    _ => (),
+                }
+                if let Some(Value::String(id)) = map.get_mut("source_id") {
+                    if let Some(new_id) = ids.get(id.as_str()) {
+                        *id = String::clone(new_id);
+                    }
+                }
+
+                for (key, value) in map.iter_mut() {
+                    if key != "id" && key != "source_id" {
+                        remap_value(value, ids);
+                    }
+                }
+            }
+            Value::Array(items) => items.iter_mut().for_each(|v| remap_value(v, ids)),
+            _ => (),
+        }
+    }
+
+    let mut value = serde_json::to_value(model)?;
+    if let Value::Object(map) = &mut value {
+        map.remove("id");
+    }
+    remap_value(&mut value, ids);
+
+    Ok(serde_json::from_value(value)?)
+}
+
+// Order teams parents first, so that their children can refer to their new id.
+fn parents_first(teams: &[Team]) -> Vec<&Team> {
+    let parents: HashMap<&str, &str> = teams
+        .iter()
+        .filter_map(|team| Some((team.id.as_deref()?, team.parent.as_ref()?.id.as_deref()?)))
+        .collect();
+    let mut ordered: Vec<&Team> = teams.iter().collect();
+    ordered.sort_by_key(|team| team.id.as_deref().map_or(0, |id| team_depth(&parents, id)));
+
+    ordered
+}
+
+fn record(ids: &mut IdMap, old_id: &Option<String>, new_id: &Option<String>) {
+    if let (Some(old_id), Some(new_id)) = (old_id, new_id) {
+        debug!("Remapped {} to {}", old_id, new_id);
+        ids.insert(String::clone(old_id), String::clone(new_id));
+    }
+}
+
+impl AccountArchiveClient {
+    /// ---
+    ///
+    /// # Export an account
+    ///
+    /// Stream every user, team, schedule, escalation policy, service and Slack connection into a
+    /// JSON archive at `path`. Slack connections are only exported when a Slack workspace id was
+    /// given, and teams only when the account has the `teams` ability.
+    ///
+    /// ---
+    pub async fn export_account<P: AsRef<Path>>(&self, path: P) -> Result<(), Error> {
+        let mut writer = ArchiveWriter::create(path).await?;
+
+        let users = self.client.users();
+        let users_params = {
+            let mut builder = ListUsersParamsBuilder::new();
+            builder.include(vec!["contact_methods", "notification_rules"]);
+            builder.build()
+        };
+        writer
+            .section("users", users.list_users(users_params))
+            .await?;
+
+        // Accounts without the teams ability reject listing teams.
+        if self.client.capabilities().await?.has("teams") {
+            let teams = self.client.teams();
+            writer
+                .section(
+                    "teams",
+                    teams.list_teams(ListTeamsParamsBuilder::new().build()),
+                )
+                .await?;
+        }
+
+        // Schedule layers are only returned when fetching a single schedule, which needs its id.
+        let schedules = self.client.schedules();
+        let schedules_stream = schedules
+            .list_schedules(ListSchedulesParamsBuilder::new().build())
+            .try_filter_map(|schedule| {
+                let schedules = &schedules;
+                async move {
+                    match &schedule.id {
+                        Some(id) => {
+                            let params = GetScheduleParamsBuilder::new().build();
+                            schedules.get_schedule(id, params).await.map(Some)
+                        }
+                        None => Ok(None),
+                    }
+                }
+            })
+            .boxed();
+        writer.section("schedules", schedules_stream).await?;
+
+        let escalation_policies = self
+            .client
+            .escalation_policies(Some(String::clone(&self.from_email)));
+        writer
+            .section(
+                "escalation_policies",
+                escalation_policies
+                    .list_escalation_policies(ListEscalationPoliciesParamsBuilder::new().build()),
+            )
+            .await?;
+
+        let services = self.client.services();
+        let services_params = {
+            let mut builder = ListServicesParamsBuilder::new();
+            builder.include(vec!["integrations"]);
+            builder.build()
+        };
+        let services_stream = services
+            .list_services(services_params)
+            .and_then(|mut service| {
+                let services = &services;
+                async move {
+                    let event_rules = match &service.id {
+                        Some(id) => services.list_service_event_rules(id).try_collect().await?,
+                        None => vec![],
+                    };
+                    let integrations = service.integrations.take().unwrap_or_default();
+                    Ok(ServiceArchive {
+                        service,
+                        integrations,
+                        event_rules,
+                    })
+                }
+            })
+            .boxed();
+        writer.section("services", services_stream).await?;
+
+        if let Some(slack_workspace_id) = &self.slack_workspace_id {
+            let slack_connections = self.client.slack_connections(slack_workspace_id);
+            writer
+                .section("slack_connections", slack_connections.get_connections())
+                .await?;
+        }
+
+        writer.finish().await
+    }
+
+    /// ---
+    ///
+    /// # Import an account
+    ///
+    /// Recreate the objects of an archive at `path` in this account, in dependency order: teams,
+    /// parents first, users, schedules, escalation policies, services with their integrations and
+    /// event rules, and finally Slack connections. Returns the mapping from exported to created
+    /// ids.
+    ///
+    /// Objects that already exist are reused, see the [module documentation](self). The
+    /// integrations and event rules of an existing service are left untouched.
+    ///
+    /// Nothing is created when the archive needs an ability that this account lacks, see
+    /// [Archive::check_capabilities].
+    ///
+    /// ---
+    pub async fn import_account<P: AsRef<Path>>(&self, path: P) -> Result<IdMap, Error> {
+        let archive = Archive::read(path).await?;
+        archive.check_capabilities(&self.client.capabilities().await?)?;
+        let mut ids = IdMap::new();
+
+        let teams = self.client.teams();
+        // The teams ability was checked above whenever the archive has teams.
+        let existing_teams: Vec<Team> = if archive.teams.is_empty() {
+            vec![]
+        } else {
+            teams
+                .list_teams(ListTeamsParamsBuilder::new().build())
+                .try_collect()
+                .await?
+        };
+        for team in parents_first(&archive.teams) {
+            let existing = existing_teams
+                .iter()
+                .find(|t| t.name.is_some() && t.name == team.name);
+            let new_id = match existing {
+                Some(existing) => existing.id.clone(),
+                None => {
+                    let body = CreateTeam {
+                        team: remap(team, &ids)?,
+                    };
+                    teams.create_team(body).await?.id
+                }
+            };
+            record(&mut ids, &team.id, &new_id);
+        }
+
+        let users = self.client.users();
+        let existing_users: Vec<User> = users
+            .list_users(ListUsersParamsBuilder::new().build())
+            .try_collect()
+            .await?;
+        for user in &archive.users {
+            let existing = existing_users
+                .iter()
+                .find(|u| u.email.is_some() && u.email == user.email);
+            let new_id = match existing {
+                Some(existing) => existing.id.clone(),
+                None => {
+                    // Contact methods and notification rules are created separately.
+                    let mut user = remap(user, &ids)?;
+                    user.contact_methods = None;
+                    user.notification_rules = None;
+                    users
+                        .create_user(CreateUser { user }, &self.from_email)
+                        .await?
+                        .id
+                }
+            };
+            record(&mut ids, &user.id, &new_id);
+        }
+
+        let schedules = self.client.schedules();
+        let existing_schedules: Vec<Schedule> = schedules
+            .list_schedules(ListSchedulesParamsBuilder::new().build())
+            .try_collect()
+            .await?;
+        for schedule in &archive.schedules {
+            let existing = existing_schedules
+                .iter()
+                .find(|s| s.name.is_some() && s.name == schedule.name);
+            let new_id = match existing {
+                Some(existing) => existing.id.clone(),
+                None => {
+                    let body = CreateSchedule {
+                        schedule: remap(schedule, &ids)?,
+                    };
+                    schedules
+                        .create_schedule(CreateScheduleParamsBuilder::new().build(), body)
+                        .await?
+                        .id
+                }
+            };
+            record(&mut ids, &schedule.id, &new_id);
+        }
+
+        let escalation_policies = self
+            .client
+            .escalation_policies(Some(String::clone(&self.from_email)));
+        let existing_escalation_policies: Vec<EscalationPolicy> = escalation_policies
+            .list_escalation_policies(ListEscalationPoliciesParamsBuilder::new().build())
+            .try_collect()
+            .await?;
+        for escalation_policy in &archive.escalation_policies {
+            let existing = existing_escalation_policies
+                .iter()
+                .find(|e| e.name.is_some() && e.name == escalation_policy.name);
+            let new_id = match existing {
+                Some(existing) => existing.id.clone(),
+                None => {
+                    let mut escalation_policy_body: EscalationPolicy =
+                        remap(escalation_policy, &ids)?;
+                    // Services are linked when they are created.
+                    escalation_policy_body.services = None;
+                    escalation_policies
+                        .create_escalation_policy(CreateEscalationPolicy {
+                            escalation_policy: escalation_policy_body,
+                        })
+                        .await?
+                        .id
+                }
+            };
+            record(&mut ids, &escalation_policy.id, &new_id);
+        }
+
+        let services = self.client.services();
+        let existing_services: Vec<Service> = services
+            .list_services(ListServicesParamsBuilder::new().build())
+            .try_collect()
+            .await?;
+        for service_archive in &archive.services {
+            let existing = existing_services
+                .iter()
+                .find(|s| s.name.is_some() && s.name == service_archive.service.name);
+            if let Some(existing) = existing {
+                record(&mut ids, &service_archive.service.id, &existing.id);
+                continue;
+            }
+
+            let mut service: Service = remap(&service_archive.service, &ids)?;
+            service.integrations = None;
+            let created = services.create_service(CreateService { service }).await?;
+            record(&mut ids, &service_archive.service.id, &created.id);
+            let service_id = created.id.ok_or_else(|| Error::MissingIdError {
+                kind: String::from("service"),
+            })?;
+
+            for integration in &service_archive.integrations {
+                let body = CreateServiceIntegration {
+                    integration: remap(integration, &ids)?,
+                };
+                let created = services
+                    .create_service_integration(&service_id, body)
+                    .await?;
+                record(&mut ids, &integration.id, &created.id);
+            }
+
+            for event_rule in &service_archive.event_rules {
+                let body = CreateServiceEventRule {
+                    rule: remap(event_rule, &ids)?,
+                };
+                let created = services
+                    .create_service_event_rule(&service_id, body)
+                    .await?;
+                record(&mut ids, &event_rule.id, &created.id);
+            }
+        }
+
+        if let Some(slack_workspace_id) = &self.slack_workspace_id {
+            let slack_connections = self.client.slack_connections(slack_workspace_id);
+            for slack_connection in &archive.slack_connections {
+                let body = CreateConnection {
+                    slack_connection: remap(slack_connection, &ids)?,
+                };
+                let created = slack_connections.create_connection(body).await?;
+                record(&mut ids, &slack_connection.id, &created.id);
+            }
+        }
+
+        info!("Imported {} objects", ids.len());
+
+        Ok(ids)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_remap() {
+        let mut ids = IdMap::new();
+        ids.insert(String::from("POLDEP"), String::from("PNEWEP"));
+        ids.insert(String::from("POLDTEAM"), String::from("PNEWTEAM"));
+
+        let service = Service {
+            id: Some(String::from("POLDSVC")),
+            _self: Some(String::from("https://api.pagerduty.com/services/POLDSVC")),
+            name: Some(String::from("API")),
+            escalation_policy: Some(EscalationPolicy {
+                id: Some(String::from("POLDEP")),
+                _type: EscalationPolicyTypeEnum::ESCALATION_POLICY_REFERENCE,
+                ..Default::default()
+            }),
+            teams: Some(vec![Team {
+                id: Some(String::from("POLDTEAM")),
+                ..Default::default()
+            }]),
+            ..Default::default()
+        };
+
+        let remapped = remap(&service, &ids).unwrap();
+
+        assert_eq!(remapped.id, None);
+        assert_eq!(remapped._self, None);
+        assert_eq!(remapped.name, Some(String::from("API")));
+        assert_eq!(
+            remapped.escalation_policy.unwrap().id,
+            Some(String::from("PNEWEP"))
+        );
+        assert_eq!(
+            remapped.teams.unwrap()[0].id,
+            Some(String::from("PNEWTEAM"))
+        );
+    }
+
+    #[test]
+    fn test_remap_schedule_layers() {
+        let mut ids = IdMap::new();
+        ids.insert(String::from("POLDUSER"), String::from("PNEWUSER"));
+
+        let start = chrono::DateTime::parse_from_rfc3339("2022-01-01T09:00:00Z").unwrap();
+        let schedule = Schedule {
+            id: Some(String::from("POLDSCHED")),
+            name: Some(String::from("Primary")),
+            schedule_layers: Some(vec![ScheduleLayer {
+                id: Some(String::from("POLDLAYER")),
+                name: Some(String::from("Day")),
+                start,
+                rotation_virtual_start: start,
+                rotation_turn_length_seconds: 86400,
+                users: vec![ScheduleLayerUser {
+                    user: User {
+                        id: Some(String::from("POLDUSER")),
+                        _type: UserTypeEnum::USER_REFERENCE,
+                        ..Default::default()
+                    },
+                }],
+                ..Default::default()
+            }]),
+            ..Default::default()
+        };
+
+        let remapped = remap(&schedule, &ids).unwrap();
+        let layer = &remapped.schedule_layers.unwrap()[0];
+
+        assert_eq!(remapped.id, None);
+        assert_eq!(layer.id, None);
+        assert_eq!(layer.name, Some(String::from("Day")));
+        assert_eq!(layer.users[0].user.id, Some(String::from("PNEWUSER")));
+
+        let integration = Integration {
+            id: Some(String::from("POLDINTEG")),
+            vendor: Some(Vendor {
+                id: Some(String::from("PZD94QK")),
+                _type: String::from("vendor_reference"),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+
+        let remapped = remap(&integration, &ids).unwrap();
+
+        assert_eq!(remapped.id, None);
+        assert_eq!(remapped.vendor.unwrap().id, Some(String::from("PZD94QK")));
+    }
+
+    #[test]
+    fn test_check_capabilities() {
+        let archive = Archive {
+            services: vec![ServiceArchive {
+                service: Service {
+                    incident_urgency_rule: Some(IncidentUrgencyRule {
+                        _type: IncidentUrgencyRuleTypeEnum::USE_SUPPORT_HOURS,
+                        ..Default::default()
+                    }),
+                    ..Default::default()
+                },
+                ..Default::default()
+            }],
+            ..Default::default()
+        };
+
+        assert!(archive
+            .check_capabilities(&Capabilities::new(vec![String::from("urgencies")]))
+            .is_ok());
+        assert!(matches!(
+            archive.check_capabilities(&Capabilities::new(vec![String::from("teams")])),
+            Err(Error::MissingAbilityError { ability }) if ability == "urgencies"
+        ));
+    }
+
+    #[test]
+    fn test_parents_first() {
+        let team = |id: &str, parent: Option<&str>| Team {
+            id: Some(String::from(id)),
+            parent: parent.map(|parent| TeamReference {
+                id: Some(String::from(parent)),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        let teams = vec![
+            team("PDATABASE", Some("PSTORAGE")),
+            team("PSTORAGE", Some("PPLATFORM")),
+            team("PPLATFORM", None),
+        ];
+
+        let ordered: Vec<&str> = parents_first(&teams)
+            .into_iter()
+            .filter_map(|team| team.id.as_deref())
+            .collect();
+
+        assert_eq!(ordered, vec!["PPLATFORM", "PSTORAGE", "PDATABASE"]);
+    }
+
+    #[tokio::test]
+    async fn test_archive_writer() {
+        let path = std::env::temp_dir().join(format!(
+            "praiya_test_archive_writer_{}_{}.json",
+            std::process::id(),
+            Utc::now().timestamp_nanos_opt().unwrap_or_default()
+        ));
+        let mut writer = ArchiveWriter::create(&path).await.unwrap();
+        let teams = vec![
+            Ok(Team {
+                id: Some(String::from("PQ9K7I8")),
+                name: Some(String::from("Engineering")),
+                ..Default::default()
+            }),
+            Ok(Team {
+                id: Some(String::from("PQ9K7I9")),
+                name: Some(String::from("Operations")),
+                ..Default::default()
+            }),
+        ];
+        writer
+            .section("teams", futures_util::stream::iter(teams))
+            .await
+            .unwrap();
+        writer
+            .section(
+                "users",
+                futures_util::stream::iter(Vec::<Result<User, Error>>::new()),
+            )
+            .await
+            .unwrap();
+        writer.finish().await.unwrap();
+
+        let archive = Archive::read(&path).await.unwrap();
+        tokio::fs::remove_file(&path).await.unwrap();
+
+        assert_eq!(archive.version, ARCHIVE_VERSION);
+        assert_eq!(archive.teams.len(), 2);
+        assert_eq!(archive.teams[1].name, Some(String::from("Operations")));
+        assert!(archive.users.is_empty());
+    }
+}
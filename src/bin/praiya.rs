@@ -0,0 +1,604 @@
+//! The `praiya` command-line interface, built with the `cli` feature.
+//!
+//! The API token is read from `--token`, the `PAGERDUTY_TOKEN` environment variable, or a YAML
+//! config file (`--config`, defaulting to `~/.config/praiya/config.yaml`):
+//!
+//! ```yaml
+//! token: u+abcdefg
+//! from_email: oncall@example.com
+//! slack_workspace_id: T1234567
+//! ```
+
+use std::error::Error;
+use std::path::PathBuf;
+
+use clap::{Args, Parser, Subcommand, ValueEnum};
+use futures_util::{StreamExt, TryStreamExt};
+use serde::Serialize;
+use serde_derive::Deserialize;
+
+use praiya::api::incidents::ListIncidentsParamsBuilder;
+use praiya::api::on_calls::ListOnCallsParamsBuilder;
+use praiya::api::services::{GetServiceParamsBuilder, ListServicesParamsBuilder};
+use praiya::api::users::ListUsersParamsBuilder;
+use praiya::models::{
+    Incident, IncidentsidIncident, IncidentsidIncidentStatusEnum, Oncall, Service, UpdateIncident,
+    User,
+};
+use praiya::slack_models::{
+    SlackConnection, SlackConnectionNotifiationType, SlackConnectionUrgency, UpdateConnection,
+};
+use praiya::{ParamsBuilder, Praiya};
+
+#[derive(Parser)]
+#[command(
+    name = "praiya",
+    version,
+    about = "A command-line client for the PagerDuty API"
+)]
+struct Cli {
+    /// PagerDuty API token
+    #[arg(long, env = "PAGERDUTY_TOKEN", hide_env_values = true, global = true)]
+    token: Option<String>,
+
+    /// Path to a YAML config file
+    #[arg(long, global = true)]
+    config: Option<PathBuf>,
+
+    /// Output format
+    #[arg(long, short, value_enum, default_value_t = OutputFormat::Table, global = true)]
+    output: OutputFormat,
+
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum OutputFormat {
+    Json,
+    Table,
+    Csv,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// List and manage incidents
+    #[command(subcommand)]
+    Incidents(IncidentsCommand),
+    /// Query who is on call
+    #[command(subcommand)]
+    Oncall(OncallCommand),
+    /// List and inspect services
+    #[command(subcommand)]
+    Services(ServicesCommand),
+    /// List users
+    #[command(subcommand)]
+    Users(UsersCommand),
+    /// Manage the Slack integration
+    #[command(subcommand)]
+    Slack(SlackCommand),
+}
+
+#[derive(Subcommand)]
+enum IncidentsCommand {
+    /// List incidents
+    List {
+        /// Filter by status: triggered, acknowledged or resolved
+        #[arg(long)]
+        status: Vec<String>,
+        /// Filter by service id
+        #[arg(long)]
+        service: Vec<String>,
+        /// Filter by team id
+        #[arg(long)]
+        team: Vec<String>,
+        /// Filter by urgency: high or low
+        #[arg(long)]
+        urgency: Vec<String>,
+        /// Maximum number of incidents to return
+        #[arg(long)]
+        limit: Option<usize>,
+        /// Email address of the acting user, defaults to `from_email` in the config file
+        #[arg(long)]
+        from: Option<String>,
+    },
+    /// Acknowledge an incident
+    Ack(IncidentUpdateArgs),
+    /// Resolve an incident
+    Resolve(IncidentUpdateArgs),
+}
+
+#[derive(Args)]
+struct IncidentUpdateArgs {
+    /// Incident id
+    id: String,
+    /// Email address of the acting user, defaults to `from_email` in the config file
+    #[arg(long)]
+    from: Option<String>,
+}
+
+#[derive(Subcommand)]
+enum OncallCommand {
+    /// Show who is currently on call
+    Who {
+        /// Filter by schedule id
+        #[arg(long)]
+        schedule: Vec<String>,
+        /// Filter by escalation policy id
+        #[arg(long)]
+        escalation_policy: Vec<String>,
+    },
+}
+
+#[derive(Subcommand)]
+enum ServicesCommand {
+    /// List services
+    List {
+        /// Filter by team id
+        #[arg(long)]
+        team: Vec<String>,
+    },
+    /// Get a service
+    Get {
+        /// Service id
+        id: String,
+    },
+}
+
+#[derive(Subcommand)]
+enum UsersCommand {
+    /// List users
+    List {
+        /// Filter users by name or email
+        #[arg(long)]
+        query: Option<String>,
+    },
+}
+
+#[derive(Subcommand)]
+enum SlackCommand {
+    /// Manage Slack connections
+    #[command(subcommand)]
+    Connections(SlackConnectionsCommand),
+}
+
+#[derive(Subcommand)]
+enum SlackConnectionsCommand {
+    /// List Slack connections
+    List {
+        /// Slack workspace id, defaults to `slack_workspace_id` in the config file
+        #[arg(long)]
+        workspace: Option<String>,
+    },
+    /// Update a Slack connection
+    Update {
+        /// Slack connection id
+        id: String,
+        /// Slack workspace id, defaults to `slack_workspace_id` in the config file
+        #[arg(long)]
+        workspace: Option<String>,
+        #[arg(long)]
+        channel_id: Option<String>,
+        #[arg(long)]
+        channel_name: Option<String>,
+        /// responder or stakeholder
+        #[arg(long)]
+        notification_type: Option<String>,
+        /// high, low or null
+        #[arg(long)]
+        urgency: Option<String>,
+    },
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct Config {
+    token: Option<String>,
+    from_email: Option<String>,
+    slack_workspace_id: Option<String>,
+}
+
+impl Config {
+    fn load(path: Option<&PathBuf>) -> Result<Self, Box<dyn Error>> {
+        let path = match path {
+            Some(path) => PathBuf::clone(path),
+            None => match std::env::var_os("HOME") {
+                Some(home) => PathBuf::from(home).join(".config/praiya/config.yaml"),
+                None => return Ok(Self::default()),
+            },
+        };
+        match std::fs::read_to_string(&path) {
+            Ok(document) => Ok(serde_yaml::from_str(&document)?),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(e.into()),
+        }
+    }
+}
+
+fn required(
+    value: Option<String>,
+    fallback: &Option<String>,
+    name: &str,
+) -> Result<String, String> {
+    value.or_else(|| fallback.clone()).ok_or_else(|| {
+        format!(
+            "missing {}: pass it as an argument or set it in the config file",
+            name
+        )
+    })
+}
+
+/// A row of tabular output.
+trait Record {
+    const HEADERS: &'static [&'static str];
+
+    fn fields(&self) -> Vec<String>;
+}
+
+fn display<T: ToString>(value: &Option<T>) -> String {
+    value.as_ref().map(T::to_string).unwrap_or_default()
+}
+
+impl Record for Incident {
+    const HEADERS: &'static [&'static str] =
+        &["ID", "NUMBER", "STATUS", "URGENCY", "SERVICE", "TITLE"];
+
+    fn fields(&self) -> Vec<String> {
+        vec![
+            display(&self.id),
+            display(&self.incident_number),
+            display(&self.status),
+            display(&self.urgency),
+            display(&self.service.as_ref().and_then(|s| s.summary.clone())),
+            display(&self.title),
+        ]
+    }
+}
+
+impl Record for Oncall {
+    const HEADERS: &'static [&'static str] =
+        &["LEVEL", "USER", "SCHEDULE", "ESCALATION POLICY", "UNTIL"];
+
+    fn fields(&self) -> Vec<String> {
+        vec![
+            display(&self.escalation_level),
+            display(&self.user.as_ref().and_then(|u| u.summary.clone())),
+            display(&self.schedule.as_ref().and_then(|s| s.summary.clone())),
+            display(
+                &self
+                    .escalation_policy
+                    .as_ref()
+                    .and_then(|e| e.summary.clone()),
+            ),
+            display(&self.end),
+        ]
+    }
+}
+
+impl Record for Service {
+    const HEADERS: &'static [&'static str] = &["ID", "NAME", "STATUS", "ESCALATION POLICY"];
+
+    fn fields(&self) -> Vec<String> {
+        vec![
+            display(&self.id),
+            display(&self.name),
+            display(&self.status),
+            display(
+                &self
+                    .escalation_policy
+                    .as_ref()
+                    .and_then(|e| e.summary.clone()),
+            ),
+        ]
+    }
+}
+
+impl Record for User {
+    const HEADERS: &'static [&'static str] = &["ID", "NAME", "EMAIL", "ROLE", "TIME ZONE"];
+
+    fn fields(&self) -> Vec<String> {
+        vec![
+            display(&self.id),
+            display(&self.name),
+            display(&self.email),
+            display(&self.role),
+            display(&self.time_zone),
+        ]
+    }
+}
+
+impl Record for SlackConnection {
+    const HEADERS: &'static [&'static str] =
+        &["ID", "SOURCE", "CHANNEL", "NOTIFICATION TYPE", "URGENCY"];
+
+    fn fields(&self) -> Vec<String> {
+        vec![
+            display(&self.id),
+            display(&self.source_name),
+            display(&self.channel_name),
+            display(&self.notification_type),
+            display(&self.config.as_ref().and_then(|c| c.urgency)),
+        ]
+    }
+}
+
+fn render<T: Record + Serialize>(
+    records: &[T],
+    format: OutputFormat,
+) -> Result<String, Box<dyn Error>> {
+    let rows: Vec<Vec<String>> = records.iter().map(Record::fields).collect();
+    let output = match format {
+        OutputFormat::Json => serde_json::to_string_pretty(records)?,
+        OutputFormat::Csv => std::iter::once(T::HEADERS.iter().map(|h| h.to_string()).collect())
+            .chain(rows)
+            .map(|row: Vec<String>| {
+                row.iter()
+                    .map(|field| {
+                        if field.contains([',', '"', '\n', '\r']) {
+                            format!("\"{}\"", field.replace('"', "\"\""))
+                        } else {
+                            String::clone(field)
+                        }
+                    })
+                    .collect::<Vec<_>>()
+                    .join(",")
+            })
+            .collect::<Vec<_>>()
+            .join("\n"),
+        OutputFormat::Table => {
+            let mut widths: Vec<usize> = T::HEADERS.iter().map(|h| h.len()).collect();
+            for row in &rows {
+                for (width, field) in widths.iter_mut().zip(row) {
+                    *width = (*width).max(field.chars().count());
+                }
+            }
+            std::iter::once(T::HEADERS.iter().map(|h| h.to_string()).collect())
+                .chain(rows)
+                .map(|row: Vec<String>| {
+                    row.iter()
+                        .zip(&widths)
+                        .map(|(field, width)| format!("{:<width$}", field, width = width))
+                        .collect::<Vec<_>>()
+                        .join("  ")
+                        .trim_end()
+                        .to_string()
+                })
+                .collect::<Vec<_>>()
+                .join("\n")
+        }
+    };
+
+    Ok(output)
+}
+
+async fn update_incident_status(
+    pagerduty: &Praiya,
+    config: &Config,
+    args: IncidentUpdateArgs,
+    status: IncidentsidIncidentStatusEnum,
+) -> Result<Incident, Box<dyn Error>> {
+    let from = required(args.from, &config.from_email, "from email")?;
+    let body = UpdateIncident {
+        incident: IncidentsidIncident {
+            status: Some(status),
+            ..Default::default()
+        },
+    };
+
+    Ok(pagerduty
+        .incidents(&from)
+        .update_incident(&args.id, body)
+        .await?)
+}
+
+async fn run(cli: Cli) -> Result<String, Box<dyn Error>> {
+    let config = Config::load(cli.config.as_ref())?;
+    let token = required(cli.token, &config.token, "PagerDuty token")?;
+    let pagerduty = Praiya::new(&token);
+    let format = cli.output;
+
+    match cli.command {
+        Command::Incidents(IncidentsCommand::List {
+            status,
+            service,
+            team,
+            urgency,
+            limit,
+            from,
+        }) => {
+            let from = required(from, &config.from_email, "from email")?;
+            let params = {
+                let mut builder = ListIncidentsParamsBuilder::new();
+                builder.statuses(status.iter().map(String::as_str));
+                builder.service_ids(service.iter().map(String::as_str));
+                builder.team_ids(team.iter().map(String::as_str));
+                builder.urgencies(urgency.iter().map(String::as_str));
+                builder.build()
+            };
+            let incidents = pagerduty.incidents(&from);
+            let incidents: Vec<Incident> = incidents
+                .list_incidents(params)
+                .take(limit.unwrap_or(usize::MAX))
+                .try_collect()
+                .await?;
+            render(&incidents, format)
+        }
+        Command::Incidents(IncidentsCommand::Ack(args)) => {
+            let incident = update_incident_status(
+                &pagerduty,
+                &config,
+                args,
+                IncidentsidIncidentStatusEnum::ACKNOWLEDGED,
+            )
+            .await?;
+            render(&[incident], format)
+        }
+        Command::Incidents(IncidentsCommand::Resolve(args)) => {
+            let incident = update_incident_status(
+                &pagerduty,
+                &config,
+                args,
+                IncidentsidIncidentStatusEnum::RESOLVED,
+            )
+            .await?;
+            render(&[incident], format)
+        }
+        Command::Oncall(OncallCommand::Who {
+            schedule,
+            escalation_policy,
+        }) => {
+            let params = {
+                let mut builder = ListOnCallsParamsBuilder::new();
+                builder.schedule_ids(schedule.iter().map(String::as_str));
+                builder.escalation_policy_ids(escalation_policy.iter().map(String::as_str));
+                builder.build()
+            };
+            let on_calls = pagerduty.on_calls();
+            let on_calls: Vec<Oncall> = on_calls.list_on_calls(params).try_collect().await?;
+            render(&on_calls, format)
+        }
+        Command::Services(ServicesCommand::List { team }) => {
+            let params = {
+                let mut builder = ListServicesParamsBuilder::new();
+                builder.team_ids(team.iter().map(String::as_str));
+                builder.build()
+            };
+            let services = pagerduty.services();
+            let services: Vec<Service> = services.list_services(params).try_collect().await?;
+            render(&services, format)
+        }
+        Command::Services(ServicesCommand::Get { id }) => {
+            let service = pagerduty
+                .services()
+                .get_service(&id, GetServiceParamsBuilder::new().build())
+                .await?;
+            render(&[service], format)
+        }
+        Command::Users(UsersCommand::List { query }) => {
+            let params = {
+                let mut builder = ListUsersParamsBuilder::new();
+                if let Some(query) = &query {
+                    builder.query(query);
+                }
+                builder.build()
+            };
+            let users = pagerduty.users();
+            let users: Vec<User> = users.list_users(params).try_collect().await?;
+            render(&users, format)
+        }
+        Command::Slack(SlackCommand::Connections(SlackConnectionsCommand::List { workspace })) => {
+            let workspace = required(workspace, &config.slack_workspace_id, "Slack workspace id")?;
+            let slack_connections = pagerduty.slack_connections(&workspace);
+            let connections: Vec<SlackConnection> =
+                slack_connections.get_connections().try_collect().await?;
+            render(&connections, format)
+        }
+        Command::Slack(SlackCommand::Connections(SlackConnectionsCommand::Update {
+            id,
+            workspace,
+            channel_id,
+            channel_name,
+            notification_type,
+            urgency,
+        })) => {
+            let workspace = required(workspace, &config.slack_workspace_id, "Slack workspace id")?;
+            let slack_connections = pagerduty.slack_connections(&workspace);
+            let mut connection = slack_connections.get_connection(&id).await?;
+            if channel_id.is_some() {
+                connection.channel_id = channel_id;
+            }
+            if channel_name.is_some() {
+                connection.channel_name = channel_name;
+            }
+            if let Some(notification_type) = notification_type {
+                connection.notification_type = Some(
+                    notification_type
+                        .parse::<SlackConnectionNotifiationType>()
+                        .map_err(|_| format!("invalid notification type: {}", notification_type))?,
+                );
+            }
+            if let Some(urgency) = urgency {
+                connection
+                    .config
+                    .get_or_insert_with(Default::default)
+                    .urgency = Some(
+                    urgency
+                        .parse::<SlackConnectionUrgency>()
+                        .map_err(|_| format!("invalid urgency: {}", urgency))?,
+                );
+            }
+            connection.id = None;
+            let connection = slack_connections
+                .update_connection(
+                    &id,
+                    UpdateConnection {
+                        slack_connection: connection,
+                    },
+                )
+                .await?;
+            render(&[connection], format)
+        }
+    }
+}
+
+#[tokio::main]
+async fn main() {
+    match run(Cli::parse()).await {
+        Ok(output) => println!("{}", output),
+        Err(e) => {
+            eprintln!("error: {}", e);
+            std::process::exit(1);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn services() -> Vec<Service> {
+        vec![
+            Service {
+                id: Some(String::from("PIJ90N7")),
+                name: Some(String::from("API, \"v2\"")),
+                status: Some(praiya::models::ServiceStatusEnum::ACTIVE),
+                ..Default::default()
+            },
+            Service {
+                id: Some(String::from("PQ9K7I8")),
+                name: Some(String::from("Billing\r\nWorker")),
+                ..Default::default()
+            },
+        ]
+    }
+
+    #[test]
+    fn test_render_csv() {
+        let csv = render(&services(), OutputFormat::Csv).unwrap();
+
+        assert_eq!(
+            csv,
+            "ID,NAME,STATUS,ESCALATION POLICY\n\
+             PIJ90N7,\"API, \"\"v2\"\"\",active,\n\
+             PQ9K7I8,\"Billing\r\nWorker\",,"
+        );
+    }
+
+    #[test]
+    fn test_render_table() {
+        let table = render(&services()[..1], OutputFormat::Table).unwrap();
+
+        assert_eq!(
+            table,
+            "ID       NAME       STATUS  ESCALATION POLICY\n\
+             PIJ90N7  API, \"v2\"  active"
+        );
+    }
+
+    #[test]
+    fn test_render_json() {
+        let json = render(&services(), OutputFormat::Json).unwrap();
+        let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(value[1]["id"], "PQ9K7I8");
+    }
+}
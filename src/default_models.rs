@@ -2690,487 +2690,594 @@ pub struct CursorPagination {
     pub next_cursor: String,
 }
 
-/// The Email Contact Method of the User.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+/// A custom field value to set on an incident, identified by the field's id or name.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
-pub struct EmailContactMethod {
-    /// a URL at which the entity is uniquely displayed in the Web app
+pub struct CustomFieldsEditableFieldValue {
+    /// The ID of the field. Either `id` or `name` must be provided.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub html_url: Option<String>,
+    pub id: Option<String>,
+    /// The name of the field. Either `id` or `name` must be provided.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// The value of the field. Its JSON type depends on the field's `data_type` and `field_type`: a string, number or boolean for single value fields, an array of these for multi value fields, and `null` to unset the field.
+    #[serde(default)]
+    pub value: Value,
+}
+
+/// A custom field, which can be set on incidents to hold data such as the environment or the deployed version.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+pub struct CustomFieldsField {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    #[serde(rename = "type")]
+    pub _type: CustomFieldsFieldTypeEnum,
+    /// A short-form, server-generated string that provides succinct, important information about an object suitable for primary labeling of an entity in a client. In many cases, this will be identical to `name`, though it is not intended to be an identifier.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub summary: Option<String>,
     /// the API show URL at which the object is accessible
     #[serde(rename = "self")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub _self: Option<String>,
-    /// A short-form, server-generated string that provides succinct, important information about an object suitable for primary labeling of an entity in a client. In many cases, this will be identical to `name`, though it is not intended to be an identifier.
+    /// The name of the field. May include ASCII characters, specifically lowercase letters, digits, and underescores. Must be unique and cannot be changed once created.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub summary: Option<String>,
+    pub name: Option<String>,
+    /// The human-readable name of the field. Must be unique across an account.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub id: Option<String>,
-    #[serde(rename = "type")]
-    pub _type: EmailContactMethodTypeEnum,
-    /// The label (e.g., \"Work\", \"Mobile\", etc.).
-    #[serde(default = "EmailContactMethod::label_default")]
-    pub label: String,
-    /// The \"address\" to deliver to: email, phone number, etc., depending on the type.
-    #[serde(skip_serializing_if = "String::is_empty")]
-    pub address: String,
-    /// Send an abbreviated email message instead of the standard email output. Useful for email-to-SMS gateways and email based pagers.
+    pub display_name: Option<String>,
+    /// A description of the data this field contains.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub send_short_email: Option<bool>,
+    pub description: Option<String>,
+    /// The kind of data the custom field is allowed to contain. Cannot be changed once created.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub data_type: Option<CustomFieldsFieldDataTypeEnum>,
+    /// The type of data this field contains. In combination with the `data_type` field, this determines which values the field may hold. Fixed fields may only hold one of their field options. Cannot be changed once created.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub field_type: Option<CustomFieldsFieldFieldTypeEnum>,
+    /// The default value of the field, set on every new incident. Its JSON type depends on the field's `data_type` and `field_type`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_value: Option<Value>,
+    /// The options that a fixed field may hold.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub field_options: Option<Vec<CustomFieldsFieldOption>>,
+    /// The date/time the object was created at.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<DateTime<FixedOffset>>,
+    /// The date/time the object was last updated.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<DateTime<FixedOffset>>,
 }
 
-#[allow(clippy::derivable_impls)]
-impl Default for EmailContactMethod {
-    fn default() -> Self {
-        Self {
-            html_url: Default::default(),
-            _self: Default::default(),
-            summary: Default::default(),
-            id: Default::default(),
-            _type: Default::default(),
-            label: String::from("EmailContactMethod"),
-            address: Default::default(),
-            send_short_email: Default::default(),
+#[allow(non_camel_case_types)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize, Eq, Ord)]
+pub enum CustomFieldsFieldTypeEnum {
+    #[serde(rename = "field")]
+    FIELD,
+    #[serde(other)]
+    UNKNOWN,
+}
+
+impl ::std::fmt::Display for CustomFieldsFieldTypeEnum {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        match *self {
+            CustomFieldsFieldTypeEnum::FIELD => write!(f, "field"),
+            CustomFieldsFieldTypeEnum::UNKNOWN => write!(f, "unknown"),
         }
     }
 }
 
-impl EmailContactMethod {
-    #[allow(dead_code)]
-    fn label_default() -> String {
-        String::from("EmailContactMethod")
+impl ::std::str::FromStr for CustomFieldsFieldTypeEnum {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "field" => Ok(CustomFieldsFieldTypeEnum::FIELD),
+            x => Err(format!("Invalid enum type: {}", x)),
+        }
     }
-    #[allow(dead_code)]
-    fn _type_default() -> String {
-        String::from("email_contact_method")
+}
+
+impl ::std::convert::AsRef<str> for CustomFieldsFieldTypeEnum {
+    fn as_ref(&self) -> &str {
+        match self {
+            CustomFieldsFieldTypeEnum::FIELD => "field",
+            CustomFieldsFieldTypeEnum::UNKNOWN => "unknown",
+        }
     }
 }
 
+// Pick the first enum var as default. Please report this as a bug if it's not correct.
+impl std::default::Default for CustomFieldsFieldTypeEnum {
+    fn default() -> Self {
+        CustomFieldsFieldTypeEnum::FIELD
+    }
+}
+
+
 #[allow(non_camel_case_types)]
 #[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize, Eq, Ord)]
-pub enum EmailContactMethodTypeEnum {
-    #[serde(rename = "email_contact_method")]
-    EMAIL_CONTACT_METHOD,
+pub enum CustomFieldsFieldDataTypeEnum {
+    #[serde(rename = "string")]
+    STRING,
+    #[serde(rename = "integer")]
+    INTEGER,
+    #[serde(rename = "float")]
+    FLOAT,
+    #[serde(rename = "boolean")]
+    BOOLEAN,
+    #[serde(rename = "url")]
+    URL,
+    #[serde(rename = "datetime")]
+    DATETIME,
     #[serde(other)]
     UNKNOWN,
 }
 
-impl ::std::fmt::Display for EmailContactMethodTypeEnum {
+impl ::std::fmt::Display for CustomFieldsFieldDataTypeEnum {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         match *self {
-            EmailContactMethodTypeEnum::EMAIL_CONTACT_METHOD => write!(f, "email_contact_method"),
-            EmailContactMethodTypeEnum::UNKNOWN => write!(f, "unknown"),
+            CustomFieldsFieldDataTypeEnum::STRING => write!(f, "string"),
+            CustomFieldsFieldDataTypeEnum::INTEGER => write!(f, "integer"),
+            CustomFieldsFieldDataTypeEnum::FLOAT => write!(f, "float"),
+            CustomFieldsFieldDataTypeEnum::BOOLEAN => write!(f, "boolean"),
+            CustomFieldsFieldDataTypeEnum::URL => write!(f, "url"),
+            CustomFieldsFieldDataTypeEnum::DATETIME => write!(f, "datetime"),
+            CustomFieldsFieldDataTypeEnum::UNKNOWN => write!(f, "unknown"),
         }
     }
 }
 
-impl ::std::str::FromStr for EmailContactMethodTypeEnum {
+impl ::std::str::FromStr for CustomFieldsFieldDataTypeEnum {
     type Err = String;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         match s {
-            "email_contact_method" => Ok(EmailContactMethodTypeEnum::EMAIL_CONTACT_METHOD),
+            "string" => Ok(CustomFieldsFieldDataTypeEnum::STRING),
+            "integer" => Ok(CustomFieldsFieldDataTypeEnum::INTEGER),
+            "float" => Ok(CustomFieldsFieldDataTypeEnum::FLOAT),
+            "boolean" => Ok(CustomFieldsFieldDataTypeEnum::BOOLEAN),
+            "url" => Ok(CustomFieldsFieldDataTypeEnum::URL),
+            "datetime" => Ok(CustomFieldsFieldDataTypeEnum::DATETIME),
             x => Err(format!("Invalid enum type: {}", x)),
         }
     }
 }
 
-impl ::std::convert::AsRef<str> for EmailContactMethodTypeEnum {
+impl ::std::convert::AsRef<str> for CustomFieldsFieldDataTypeEnum {
     fn as_ref(&self) -> &str {
         match self {
-            EmailContactMethodTypeEnum::EMAIL_CONTACT_METHOD => "email_contact_method",
-            EmailContactMethodTypeEnum::UNKNOWN => "unknown",
+            CustomFieldsFieldDataTypeEnum::STRING => "string",
+            CustomFieldsFieldDataTypeEnum::INTEGER => "integer",
+            CustomFieldsFieldDataTypeEnum::FLOAT => "float",
+            CustomFieldsFieldDataTypeEnum::BOOLEAN => "boolean",
+            CustomFieldsFieldDataTypeEnum::URL => "url",
+            CustomFieldsFieldDataTypeEnum::DATETIME => "datetime",
+            CustomFieldsFieldDataTypeEnum::UNKNOWN => "unknown",
         }
     }
 }
 
 // Pick the first enum var as default. Please report this as a bug if it's not correct.
-impl std::default::Default for EmailContactMethodTypeEnum {
+impl std::default::Default for CustomFieldsFieldDataTypeEnum {
     fn default() -> Self {
-        EmailContactMethodTypeEnum::EMAIL_CONTACT_METHOD
+        CustomFieldsFieldDataTypeEnum::STRING
     }
 }
 
-#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
-#[allow(clippy::derive_partial_eq_without_eq)]
-pub struct EmailParser {
-    pub action: EmailParserActionEnum,
-    pub match_predicate: MatchPredicate,
-    /// Additional values that will be pulled in to the Incident object. Exactly one value extractor must have a `value_name` of `incident_key`.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub value_extractors: Option<Vec<EmailParserValueExtractors>>,
-}
 
 #[allow(non_camel_case_types)]
 #[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize, Eq, Ord)]
-pub enum EmailParserActionEnum {
-    #[serde(rename = "trigger")]
-    TRIGGER,
-    #[serde(rename = "resolve")]
-    RESOLVE,
+pub enum CustomFieldsFieldFieldTypeEnum {
+    #[serde(rename = "single_value")]
+    SINGLE_VALUE,
+    #[serde(rename = "single_value_fixed")]
+    SINGLE_VALUE_FIXED,
+    #[serde(rename = "multi_value")]
+    MULTI_VALUE,
+    #[serde(rename = "multi_value_fixed")]
+    MULTI_VALUE_FIXED,
     #[serde(other)]
     UNKNOWN,
 }
 
-impl ::std::fmt::Display for EmailParserActionEnum {
+impl ::std::fmt::Display for CustomFieldsFieldFieldTypeEnum {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         match *self {
-            EmailParserActionEnum::TRIGGER => write!(f, "trigger"),
-            EmailParserActionEnum::RESOLVE => write!(f, "resolve"),
-            EmailParserActionEnum::UNKNOWN => write!(f, "unknown"),
+            CustomFieldsFieldFieldTypeEnum::SINGLE_VALUE => write!(f, "single_value"),
+            CustomFieldsFieldFieldTypeEnum::SINGLE_VALUE_FIXED => write!(f, "single_value_fixed"),
+            CustomFieldsFieldFieldTypeEnum::MULTI_VALUE => write!(f, "multi_value"),
+            CustomFieldsFieldFieldTypeEnum::MULTI_VALUE_FIXED => write!(f, "multi_value_fixed"),
+            CustomFieldsFieldFieldTypeEnum::UNKNOWN => write!(f, "unknown"),
         }
     }
 }
 
-impl ::std::str::FromStr for EmailParserActionEnum {
+impl ::std::str::FromStr for CustomFieldsFieldFieldTypeEnum {
     type Err = String;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         match s {
-            "trigger" => Ok(EmailParserActionEnum::TRIGGER),
-            "resolve" => Ok(EmailParserActionEnum::RESOLVE),
+            "single_value" => Ok(CustomFieldsFieldFieldTypeEnum::SINGLE_VALUE),
+            "single_value_fixed" => Ok(CustomFieldsFieldFieldTypeEnum::SINGLE_VALUE_FIXED),
+            "multi_value" => Ok(CustomFieldsFieldFieldTypeEnum::MULTI_VALUE),
+            "multi_value_fixed" => Ok(CustomFieldsFieldFieldTypeEnum::MULTI_VALUE_FIXED),
             x => Err(format!("Invalid enum type: {}", x)),
         }
     }
 }
 
-impl ::std::convert::AsRef<str> for EmailParserActionEnum {
+impl ::std::convert::AsRef<str> for CustomFieldsFieldFieldTypeEnum {
     fn as_ref(&self) -> &str {
         match self {
-            EmailParserActionEnum::TRIGGER => "trigger",
-            EmailParserActionEnum::RESOLVE => "resolve",
-            EmailParserActionEnum::UNKNOWN => "unknown",
+            CustomFieldsFieldFieldTypeEnum::SINGLE_VALUE => "single_value",
+            CustomFieldsFieldFieldTypeEnum::SINGLE_VALUE_FIXED => "single_value_fixed",
+            CustomFieldsFieldFieldTypeEnum::MULTI_VALUE => "multi_value",
+            CustomFieldsFieldFieldTypeEnum::MULTI_VALUE_FIXED => "multi_value_fixed",
+            CustomFieldsFieldFieldTypeEnum::UNKNOWN => "unknown",
         }
     }
 }
 
 // Pick the first enum var as default. Please report this as a bug if it's not correct.
-impl std::default::Default for EmailParserActionEnum {
+impl std::default::Default for CustomFieldsFieldFieldTypeEnum {
     fn default() -> Self {
-        EmailParserActionEnum::TRIGGER
+        CustomFieldsFieldFieldTypeEnum::SINGLE_VALUE
     }
 }
 
+/// One of the values that a fixed custom field may hold.
 #[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
-pub struct EmailParserValueExtractors {
+pub struct CustomFieldsFieldOption {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
     #[serde(rename = "type")]
-    pub _type: EmailParserValueExtractorsTypeEnum,
-    pub part: EmailParserValueExtractorsPartEnum,
-    /// The field name to set in the Incident object. Exactly one must use the `value_name` of `incident_key`
-    #[serde(skip_serializing_if = "String::is_empty")]
-    pub value_name: String,
+    pub _type: CustomFieldsFieldOptionTypeEnum,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub regex: Option<String>,
+    pub data: Option<CustomFieldsFieldOptionData>,
+    /// The date/time the object was created at.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub starts_after: Option<String>,
+    pub created_at: Option<DateTime<FixedOffset>>,
+    /// The date/time the object was last updated.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub ends_with: Option<String>,
+    pub updated_at: Option<DateTime<FixedOffset>>,
 }
 
 #[allow(non_camel_case_types)]
 #[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize, Eq, Ord)]
-pub enum EmailParserValueExtractorsTypeEnum {
-    #[serde(rename = "entire")]
-    ENTIRE,
-    #[serde(rename = "regex")]
-    REGEX,
-    #[serde(rename = "between")]
-    BETWEEN,
+pub enum CustomFieldsFieldOptionTypeEnum {
+    #[serde(rename = "field_option")]
+    FIELD_OPTION,
     #[serde(other)]
     UNKNOWN,
 }
 
-impl ::std::fmt::Display for EmailParserValueExtractorsTypeEnum {
+impl ::std::fmt::Display for CustomFieldsFieldOptionTypeEnum {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         match *self {
-            EmailParserValueExtractorsTypeEnum::ENTIRE => write!(f, "entire"),
-            EmailParserValueExtractorsTypeEnum::REGEX => write!(f, "regex"),
-            EmailParserValueExtractorsTypeEnum::BETWEEN => write!(f, "between"),
-            EmailParserValueExtractorsTypeEnum::UNKNOWN => write!(f, "unknown"),
+            CustomFieldsFieldOptionTypeEnum::FIELD_OPTION => write!(f, "field_option"),
+            CustomFieldsFieldOptionTypeEnum::UNKNOWN => write!(f, "unknown"),
         }
     }
 }
 
-impl ::std::str::FromStr for EmailParserValueExtractorsTypeEnum {
+impl ::std::str::FromStr for CustomFieldsFieldOptionTypeEnum {
     type Err = String;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         match s {
-            "entire" => Ok(EmailParserValueExtractorsTypeEnum::ENTIRE),
-            "regex" => Ok(EmailParserValueExtractorsTypeEnum::REGEX),
-            "between" => Ok(EmailParserValueExtractorsTypeEnum::BETWEEN),
+            "field_option" => Ok(CustomFieldsFieldOptionTypeEnum::FIELD_OPTION),
             x => Err(format!("Invalid enum type: {}", x)),
         }
     }
 }
 
-impl ::std::convert::AsRef<str> for EmailParserValueExtractorsTypeEnum {
+impl ::std::convert::AsRef<str> for CustomFieldsFieldOptionTypeEnum {
     fn as_ref(&self) -> &str {
         match self {
-            EmailParserValueExtractorsTypeEnum::ENTIRE => "entire",
-            EmailParserValueExtractorsTypeEnum::REGEX => "regex",
-            EmailParserValueExtractorsTypeEnum::BETWEEN => "between",
-            EmailParserValueExtractorsTypeEnum::UNKNOWN => "unknown",
+            CustomFieldsFieldOptionTypeEnum::FIELD_OPTION => "field_option",
+            CustomFieldsFieldOptionTypeEnum::UNKNOWN => "unknown",
         }
     }
 }
 
 // Pick the first enum var as default. Please report this as a bug if it's not correct.
-impl std::default::Default for EmailParserValueExtractorsTypeEnum {
+impl std::default::Default for CustomFieldsFieldOptionTypeEnum {
     fn default() -> Self {
-        EmailParserValueExtractorsTypeEnum::ENTIRE
+        CustomFieldsFieldOptionTypeEnum::FIELD_OPTION
     }
 }
 
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+pub struct CustomFieldsFieldOptionData {
+    /// The kind of data represented by this option. Must match the data type of the field.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub data_type: Option<CustomFieldsFieldOptionDataDataTypeEnum>,
+    #[serde(default)]
+    pub value: Value,
+}
+
 #[allow(non_camel_case_types)]
 #[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize, Eq, Ord)]
-pub enum EmailParserValueExtractorsPartEnum {
-    #[serde(rename = "body")]
-    BODY,
-    #[serde(rename = "subject")]
-    SUBJECT,
-    #[serde(rename = "from_addresses")]
-    FROM_ADDRESSES,
+pub enum CustomFieldsFieldOptionDataDataTypeEnum {
+    #[serde(rename = "string")]
+    STRING,
     #[serde(other)]
     UNKNOWN,
 }
 
-impl ::std::fmt::Display for EmailParserValueExtractorsPartEnum {
+impl ::std::fmt::Display for CustomFieldsFieldOptionDataDataTypeEnum {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         match *self {
-            EmailParserValueExtractorsPartEnum::BODY => write!(f, "body"),
-            EmailParserValueExtractorsPartEnum::SUBJECT => write!(f, "subject"),
-            EmailParserValueExtractorsPartEnum::FROM_ADDRESSES => write!(f, "from_addresses"),
-            EmailParserValueExtractorsPartEnum::UNKNOWN => write!(f, "unknown"),
+            CustomFieldsFieldOptionDataDataTypeEnum::STRING => write!(f, "string"),
+            CustomFieldsFieldOptionDataDataTypeEnum::UNKNOWN => write!(f, "unknown"),
         }
     }
 }
 
-impl ::std::str::FromStr for EmailParserValueExtractorsPartEnum {
+impl ::std::str::FromStr for CustomFieldsFieldOptionDataDataTypeEnum {
     type Err = String;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         match s {
-            "body" => Ok(EmailParserValueExtractorsPartEnum::BODY),
-            "subject" => Ok(EmailParserValueExtractorsPartEnum::SUBJECT),
-            "from_addresses" => Ok(EmailParserValueExtractorsPartEnum::FROM_ADDRESSES),
+            "string" => Ok(CustomFieldsFieldOptionDataDataTypeEnum::STRING),
             x => Err(format!("Invalid enum type: {}", x)),
         }
     }
 }
 
-impl ::std::convert::AsRef<str> for EmailParserValueExtractorsPartEnum {
+impl ::std::convert::AsRef<str> for CustomFieldsFieldOptionDataDataTypeEnum {
     fn as_ref(&self) -> &str {
         match self {
-            EmailParserValueExtractorsPartEnum::BODY => "body",
-            EmailParserValueExtractorsPartEnum::SUBJECT => "subject",
-            EmailParserValueExtractorsPartEnum::FROM_ADDRESSES => "from_addresses",
-            EmailParserValueExtractorsPartEnum::UNKNOWN => "unknown",
+            CustomFieldsFieldOptionDataDataTypeEnum::STRING => "string",
+            CustomFieldsFieldOptionDataDataTypeEnum::UNKNOWN => "unknown",
         }
     }
 }
 
 // Pick the first enum var as default. Please report this as a bug if it's not correct.
-impl std::default::Default for EmailParserValueExtractorsPartEnum {
+impl std::default::Default for CustomFieldsFieldOptionDataDataTypeEnum {
     fn default() -> Self {
-        EmailParserValueExtractorsPartEnum::BODY
+        CustomFieldsFieldOptionDataDataTypeEnum::STRING
     }
 }
 
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+/// The value of a custom field on an incident.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
-pub struct EntityReference {
-    /// The label of the tag.
-    #[serde(default = "EntityReference::label_default")]
-    pub label: String,
-    /// a URL at which the entity is uniquely displayed in the Web app
+pub struct CustomFieldsFieldValue {
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub html_url: Option<String>,
-    /// the API show URL at which the object is accessible
-    #[serde(rename = "self")]
+    pub id: Option<String>,
+    #[serde(rename = "type")]
+    pub _type: CustomFieldsFieldValueTypeEnum,
+    /// The name of the field.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub _self: Option<String>,
-    /// A short-form, server-generated string that provides succinct, important information about an object suitable for primary labeling of an entity in a client. In many cases, this will be identical to `name`, though it is not intended to be an identifier.
+    pub name: Option<String>,
+    /// The human-readable name of the field.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub summary: Option<String>,
+    pub display_name: Option<String>,
+    /// A description of the data this field contains.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub id: Option<String>,
-    #[serde(rename = "type")]
-    pub _type: EntityReferenceTypeEnum,
-}
-
-#[allow(clippy::derivable_impls)]
-impl Default for EntityReference {
-    fn default() -> Self {
-        Self {
-            label: String::from("EntityReference"),
-            html_url: Default::default(),
-            _self: Default::default(),
-            summary: Default::default(),
-            id: Default::default(),
-            _type: Default::default(),
-        }
-    }
-}
-
-impl EntityReference {
-    #[allow(dead_code)]
-    fn label_default() -> String {
-        String::from("EntityReference")
-    }
-    #[allow(dead_code)]
-    fn _type_default() -> String {
-        String::from("entity_reference")
-    }
+    pub description: Option<String>,
+    /// The kind of data the custom field is allowed to contain.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub data_type: Option<CustomFieldsFieldValueDataTypeEnum>,
+    /// The type of data this field contains.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub field_type: Option<CustomFieldsFieldValueFieldTypeEnum>,
+    /// The default value of the field.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_value: Option<Value>,
+    /// The value of the field on the incident, or `None` when it is unset.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub value: Option<Value>,
 }
 
 #[allow(non_camel_case_types)]
 #[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize, Eq, Ord)]
-pub enum EntityReferenceTypeEnum {
-    #[serde(rename = "user_reference")]
-    USER_REFERENCE,
-    #[serde(rename = "team_reference")]
-    TEAM_REFERENCE,
-    #[serde(rename = "escalation_policy_reference")]
-    ESCALATION_POLICY_REFERENCE,
+pub enum CustomFieldsFieldValueTypeEnum {
+    #[serde(rename = "field_value")]
+    FIELD_VALUE,
     #[serde(other)]
     UNKNOWN,
 }
 
-impl ::std::fmt::Display for EntityReferenceTypeEnum {
+impl ::std::fmt::Display for CustomFieldsFieldValueTypeEnum {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         match *self {
-            EntityReferenceTypeEnum::USER_REFERENCE => write!(f, "user_reference"),
-            EntityReferenceTypeEnum::TEAM_REFERENCE => write!(f, "team_reference"),
-            EntityReferenceTypeEnum::ESCALATION_POLICY_REFERENCE => write!(f, "escalation_policy_reference"),
-            EntityReferenceTypeEnum::UNKNOWN => write!(f, "unknown"),
+            CustomFieldsFieldValueTypeEnum::FIELD_VALUE => write!(f, "field_value"),
+            CustomFieldsFieldValueTypeEnum::UNKNOWN => write!(f, "unknown"),
         }
     }
 }
 
-impl ::std::str::FromStr for EntityReferenceTypeEnum {
+impl ::std::str::FromStr for CustomFieldsFieldValueTypeEnum {
     type Err = String;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         match s {
-            "user_reference" => Ok(EntityReferenceTypeEnum::USER_REFERENCE),
-            "team_reference" => Ok(EntityReferenceTypeEnum::TEAM_REFERENCE),
-            "escalation_policy_reference" => Ok(EntityReferenceTypeEnum::ESCALATION_POLICY_REFERENCE),
+            "field_value" => Ok(CustomFieldsFieldValueTypeEnum::FIELD_VALUE),
             x => Err(format!("Invalid enum type: {}", x)),
         }
     }
 }
 
-impl ::std::convert::AsRef<str> for EntityReferenceTypeEnum {
+impl ::std::convert::AsRef<str> for CustomFieldsFieldValueTypeEnum {
     fn as_ref(&self) -> &str {
         match self {
-            EntityReferenceTypeEnum::USER_REFERENCE => "user_reference",
-            EntityReferenceTypeEnum::TEAM_REFERENCE => "team_reference",
-            EntityReferenceTypeEnum::ESCALATION_POLICY_REFERENCE => "escalation_policy_reference",
-            EntityReferenceTypeEnum::UNKNOWN => "unknown",
+            CustomFieldsFieldValueTypeEnum::FIELD_VALUE => "field_value",
+            CustomFieldsFieldValueTypeEnum::UNKNOWN => "unknown",
         }
     }
 }
 
 // Pick the first enum var as default. Please report this as a bug if it's not correct.
-impl std::default::Default for EntityReferenceTypeEnum {
+impl std::default::Default for CustomFieldsFieldValueTypeEnum {
     fn default() -> Self {
-        EntityReferenceTypeEnum::USER_REFERENCE
+        CustomFieldsFieldValueTypeEnum::FIELD_VALUE
     }
 }
 
-#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
-#[allow(clippy::derive_partial_eq_without_eq)]
-pub struct EscalateLogEntry {
-    #[serde(rename = "type")]
-    pub _type: EscalateLogEntryTypeEnum,
-    /// Time at which the log entry was created.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_at: Option<DateTime<FixedOffset>>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub channel: Option<Channel>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub agent: Option<AgentReference>,
-    /// Optional field containing a note, if one was included with the log entry.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub note: Option<String>,
-    /// Contexts to be included with the trigger such as links to graphs or images.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub contexts: Option<Vec<Context>>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub service: Option<Service>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub user: Option<User>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub incident: Option<Incident>,
-    /// Will consist of references unless included
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub teams: Option<Vec<Team>>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub event_details: Option<LogEntryEventDetails>,
-    /// An array of assigned Users for this log entry
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub assignees: Option<Vec<User>>,
+
+#[allow(non_camel_case_types)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize, Eq, Ord)]
+pub enum CustomFieldsFieldValueDataTypeEnum {
+    #[serde(rename = "string")]
+    STRING,
+    #[serde(rename = "integer")]
+    INTEGER,
+    #[serde(rename = "float")]
+    FLOAT,
+    #[serde(rename = "boolean")]
+    BOOLEAN,
+    #[serde(rename = "url")]
+    URL,
+    #[serde(rename = "datetime")]
+    DATETIME,
+    #[serde(other)]
+    UNKNOWN,
+}
+
+impl ::std::fmt::Display for CustomFieldsFieldValueDataTypeEnum {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        match *self {
+            CustomFieldsFieldValueDataTypeEnum::STRING => write!(f, "string"),
+            CustomFieldsFieldValueDataTypeEnum::INTEGER => write!(f, "integer"),
+            CustomFieldsFieldValueDataTypeEnum::FLOAT => write!(f, "float"),
+            CustomFieldsFieldValueDataTypeEnum::BOOLEAN => write!(f, "boolean"),
+            CustomFieldsFieldValueDataTypeEnum::URL => write!(f, "url"),
+            CustomFieldsFieldValueDataTypeEnum::DATETIME => write!(f, "datetime"),
+            CustomFieldsFieldValueDataTypeEnum::UNKNOWN => write!(f, "unknown"),
+        }
+    }
+}
+
+impl ::std::str::FromStr for CustomFieldsFieldValueDataTypeEnum {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "string" => Ok(CustomFieldsFieldValueDataTypeEnum::STRING),
+            "integer" => Ok(CustomFieldsFieldValueDataTypeEnum::INTEGER),
+            "float" => Ok(CustomFieldsFieldValueDataTypeEnum::FLOAT),
+            "boolean" => Ok(CustomFieldsFieldValueDataTypeEnum::BOOLEAN),
+            "url" => Ok(CustomFieldsFieldValueDataTypeEnum::URL),
+            "datetime" => Ok(CustomFieldsFieldValueDataTypeEnum::DATETIME),
+            x => Err(format!("Invalid enum type: {}", x)),
+        }
+    }
+}
+
+impl ::std::convert::AsRef<str> for CustomFieldsFieldValueDataTypeEnum {
+    fn as_ref(&self) -> &str {
+        match self {
+            CustomFieldsFieldValueDataTypeEnum::STRING => "string",
+            CustomFieldsFieldValueDataTypeEnum::INTEGER => "integer",
+            CustomFieldsFieldValueDataTypeEnum::FLOAT => "float",
+            CustomFieldsFieldValueDataTypeEnum::BOOLEAN => "boolean",
+            CustomFieldsFieldValueDataTypeEnum::URL => "url",
+            CustomFieldsFieldValueDataTypeEnum::DATETIME => "datetime",
+            CustomFieldsFieldValueDataTypeEnum::UNKNOWN => "unknown",
+        }
+    }
 }
 
+// Pick the first enum var as default. Please report this as a bug if it's not correct.
+impl std::default::Default for CustomFieldsFieldValueDataTypeEnum {
+    fn default() -> Self {
+        CustomFieldsFieldValueDataTypeEnum::STRING
+    }
+}
+
+
 #[allow(non_camel_case_types)]
 #[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize, Eq, Ord)]
-pub enum EscalateLogEntryTypeEnum {
-    #[serde(rename = "escalate_log_entry")]
-    ESCALATE_LOG_ENTRY,
+pub enum CustomFieldsFieldValueFieldTypeEnum {
+    #[serde(rename = "single_value")]
+    SINGLE_VALUE,
+    #[serde(rename = "single_value_fixed")]
+    SINGLE_VALUE_FIXED,
+    #[serde(rename = "multi_value")]
+    MULTI_VALUE,
+    #[serde(rename = "multi_value_fixed")]
+    MULTI_VALUE_FIXED,
     #[serde(other)]
     UNKNOWN,
 }
 
-impl ::std::fmt::Display for EscalateLogEntryTypeEnum {
+impl ::std::fmt::Display for CustomFieldsFieldValueFieldTypeEnum {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         match *self {
-            EscalateLogEntryTypeEnum::ESCALATE_LOG_ENTRY => write!(f, "escalate_log_entry"),
-            EscalateLogEntryTypeEnum::UNKNOWN => write!(f, "unknown"),
+            CustomFieldsFieldValueFieldTypeEnum::SINGLE_VALUE => write!(f, "single_value"),
+            CustomFieldsFieldValueFieldTypeEnum::SINGLE_VALUE_FIXED => write!(f, "single_value_fixed"),
+            CustomFieldsFieldValueFieldTypeEnum::MULTI_VALUE => write!(f, "multi_value"),
+            CustomFieldsFieldValueFieldTypeEnum::MULTI_VALUE_FIXED => write!(f, "multi_value_fixed"),
+            CustomFieldsFieldValueFieldTypeEnum::UNKNOWN => write!(f, "unknown"),
         }
     }
 }
 
-impl ::std::str::FromStr for EscalateLogEntryTypeEnum {
+impl ::std::str::FromStr for CustomFieldsFieldValueFieldTypeEnum {
     type Err = String;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         match s {
-            "escalate_log_entry" => Ok(EscalateLogEntryTypeEnum::ESCALATE_LOG_ENTRY),
+            "single_value" => Ok(CustomFieldsFieldValueFieldTypeEnum::SINGLE_VALUE),
+            "single_value_fixed" => Ok(CustomFieldsFieldValueFieldTypeEnum::SINGLE_VALUE_FIXED),
+            "multi_value" => Ok(CustomFieldsFieldValueFieldTypeEnum::MULTI_VALUE),
+            "multi_value_fixed" => Ok(CustomFieldsFieldValueFieldTypeEnum::MULTI_VALUE_FIXED),
             x => Err(format!("Invalid enum type: {}", x)),
         }
     }
 }
 
-impl ::std::convert::AsRef<str> for EscalateLogEntryTypeEnum {
+impl ::std::convert::AsRef<str> for CustomFieldsFieldValueFieldTypeEnum {
     fn as_ref(&self) -> &str {
         match self {
-            EscalateLogEntryTypeEnum::ESCALATE_LOG_ENTRY => "escalate_log_entry",
-            EscalateLogEntryTypeEnum::UNKNOWN => "unknown",
+            CustomFieldsFieldValueFieldTypeEnum::SINGLE_VALUE => "single_value",
+            CustomFieldsFieldValueFieldTypeEnum::SINGLE_VALUE_FIXED => "single_value_fixed",
+            CustomFieldsFieldValueFieldTypeEnum::MULTI_VALUE => "multi_value",
+            CustomFieldsFieldValueFieldTypeEnum::MULTI_VALUE_FIXED => "multi_value_fixed",
+            CustomFieldsFieldValueFieldTypeEnum::UNKNOWN => "unknown",
         }
     }
 }
 
 // Pick the first enum var as default. Please report this as a bug if it's not correct.
-impl std::default::Default for EscalateLogEntryTypeEnum {
+impl std::default::Default for CustomFieldsFieldValueFieldTypeEnum {
     fn default() -> Self {
-        EscalateLogEntryTypeEnum::ESCALATE_LOG_ENTRY
+        CustomFieldsFieldValueFieldTypeEnum::SINGLE_VALUE
     }
 }
 
 #[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
-pub struct CreateEscalationPolicy {
-    pub escalation_policy: EscalationPolicy,
+pub struct CreateCustomFieldsField {
+    pub field: CustomFieldsField,
 }
 
 #[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
-pub struct UpdateEscalationPolicy {
-    pub escalation_policy: EscalationPolicy,
+pub struct UpdateCustomFieldsField {
+    pub field: CustomFieldsField,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+pub struct CreateCustomFieldsFieldOption {
+    pub field_option: CustomFieldsFieldOption,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+pub struct UpdateCustomFieldsFieldOption {
+    pub field_option: CustomFieldsFieldOption,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+pub struct UpdateIncidentCustomFieldValues {
+    pub custom_fields: Vec<CustomFieldsEditableFieldValue>,
 }
 
+/// The Email Contact Method of the User.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
-pub struct EscalationPolicy {
-    /// The label of the tag.
-    #[serde(default = "EscalationPolicy::label_default")]
-    pub label: String,
+pub struct EmailContactMethod {
     /// a URL at which the entity is uniquely displayed in the Web app
     #[serde(skip_serializing_if = "Option::is_none")]
     pub html_url: Option<String>,
@@ -3183,267 +3290,279 @@ pub struct EscalationPolicy {
     pub summary: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub id: Option<String>,
-    /// The type of object being created.
     #[serde(rename = "type")]
-    pub _type: EscalationPolicyTypeEnum,
-    /// The name of the escalation policy.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub name: Option<String>,
-    /// Escalation policy description.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub description: Option<String>,
-    /// The number of times the escalation policy will repeat after reaching the end of its escalation.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub num_loops: Option<usize>,
-    /// Determines how on call handoff notifications will be sent for users on the escalation policy. Defaults to \"if_has_services\".
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub on_call_handoff_notifications: Option<EscalationPolicyOnCallHandoffNotificationsEnum>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub escalation_rules: Option<Vec<EscalationRule>>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub services: Option<Vec<Service>>,
-    /// Teams associated with the policy. Account must have the `teams` ability to use this parameter.
+    pub _type: EmailContactMethodTypeEnum,
+    /// The label (e.g., \"Work\", \"Mobile\", etc.).
+    #[serde(default = "EmailContactMethod::label_default")]
+    pub label: String,
+    /// The \"address\" to deliver to: email, phone number, etc., depending on the type.
+    #[serde(skip_serializing_if = "String::is_empty")]
+    pub address: String,
+    /// Send an abbreviated email message instead of the standard email output. Useful for email-to-SMS gateways and email based pagers.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub teams: Option<Vec<Team>>,
+    pub send_short_email: Option<bool>,
 }
 
 #[allow(clippy::derivable_impls)]
-impl Default for EscalationPolicy {
+impl Default for EmailContactMethod {
     fn default() -> Self {
         Self {
-            label: String::from("EscalationPolicy"),
             html_url: Default::default(),
             _self: Default::default(),
             summary: Default::default(),
             id: Default::default(),
             _type: Default::default(),
-            name: Default::default(),
-            description: Default::default(),
-            num_loops: Default::default(),
-            on_call_handoff_notifications: Default::default(),
-            escalation_rules: Default::default(),
-            services: Default::default(),
-            teams: Default::default(),
+            label: String::from("EmailContactMethod"),
+            address: Default::default(),
+            send_short_email: Default::default(),
         }
     }
 }
 
-impl EscalationPolicy {
+impl EmailContactMethod {
     #[allow(dead_code)]
     fn label_default() -> String {
-        String::from("EscalationPolicy")
+        String::from("EmailContactMethod")
     }
     #[allow(dead_code)]
     fn _type_default() -> String {
-        String::from("escalation_policy")
+        String::from("email_contact_method")
     }
 }
 
 #[allow(non_camel_case_types)]
 #[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize, Eq, Ord)]
-pub enum EscalationPolicyTypeEnum {
-    #[serde(rename = "escalation_policy")]
-    ESCALATION_POLICY,
-    #[serde(rename = "escalation_policy_reference")]
-    ESCALATION_POLICY_REFERENCE,
+pub enum EmailContactMethodTypeEnum {
+    #[serde(rename = "email_contact_method")]
+    EMAIL_CONTACT_METHOD,
     #[serde(other)]
     UNKNOWN,
 }
 
-impl ::std::fmt::Display for EscalationPolicyTypeEnum {
+impl ::std::fmt::Display for EmailContactMethodTypeEnum {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         match *self {
-            EscalationPolicyTypeEnum::ESCALATION_POLICY => write!(f, "escalation_policy"),
-            EscalationPolicyTypeEnum::ESCALATION_POLICY_REFERENCE => write!(f, "escalation_policy_reference"),
-            EscalationPolicyTypeEnum::UNKNOWN => write!(f, "unknown"),
+            EmailContactMethodTypeEnum::EMAIL_CONTACT_METHOD => write!(f, "email_contact_method"),
+            EmailContactMethodTypeEnum::UNKNOWN => write!(f, "unknown"),
         }
     }
 }
 
-impl ::std::str::FromStr for EscalationPolicyTypeEnum {
+impl ::std::str::FromStr for EmailContactMethodTypeEnum {
     type Err = String;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         match s {
-            "escalation_policy" => Ok(EscalationPolicyTypeEnum::ESCALATION_POLICY),
-            "escalation_policy_reference" => Ok(EscalationPolicyTypeEnum::ESCALATION_POLICY_REFERENCE),
+            "email_contact_method" => Ok(EmailContactMethodTypeEnum::EMAIL_CONTACT_METHOD),
             x => Err(format!("Invalid enum type: {}", x)),
         }
     }
 }
 
-impl ::std::convert::AsRef<str> for EscalationPolicyTypeEnum {
+impl ::std::convert::AsRef<str> for EmailContactMethodTypeEnum {
     fn as_ref(&self) -> &str {
         match self {
-            EscalationPolicyTypeEnum::ESCALATION_POLICY => "escalation_policy",
-            EscalationPolicyTypeEnum::ESCALATION_POLICY_REFERENCE => "escalation_policy_reference",
-            EscalationPolicyTypeEnum::UNKNOWN => "unknown",
+            EmailContactMethodTypeEnum::EMAIL_CONTACT_METHOD => "email_contact_method",
+            EmailContactMethodTypeEnum::UNKNOWN => "unknown",
         }
     }
 }
 
 // Pick the first enum var as default. Please report this as a bug if it's not correct.
-impl std::default::Default for EscalationPolicyTypeEnum {
+impl std::default::Default for EmailContactMethodTypeEnum {
     fn default() -> Self {
-        EscalationPolicyTypeEnum::ESCALATION_POLICY
+        EmailContactMethodTypeEnum::EMAIL_CONTACT_METHOD
     }
 }
 
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+pub struct EmailParser {
+    pub action: EmailParserActionEnum,
+    pub match_predicate: MatchPredicate,
+    /// Additional values that will be pulled in to the Incident object. Exactly one value extractor must have a `value_name` of `incident_key`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub value_extractors: Option<Vec<EmailParserValueExtractors>>,
+}
+
 #[allow(non_camel_case_types)]
 #[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize, Eq, Ord)]
-pub enum EscalationPolicyOnCallHandoffNotificationsEnum {
-    #[serde(rename = "if_has_services")]
-    IF_HAS_SERVICES,
-    #[serde(rename = "always")]
-    ALWAYS,
+pub enum EmailParserActionEnum {
+    #[serde(rename = "trigger")]
+    TRIGGER,
+    #[serde(rename = "resolve")]
+    RESOLVE,
     #[serde(other)]
     UNKNOWN,
 }
 
-impl ::std::fmt::Display for EscalationPolicyOnCallHandoffNotificationsEnum {
+impl ::std::fmt::Display for EmailParserActionEnum {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         match *self {
-            EscalationPolicyOnCallHandoffNotificationsEnum::IF_HAS_SERVICES => write!(f, "if_has_services"),
-            EscalationPolicyOnCallHandoffNotificationsEnum::ALWAYS => write!(f, "always"),
-            EscalationPolicyOnCallHandoffNotificationsEnum::UNKNOWN => write!(f, "unknown"),
+            EmailParserActionEnum::TRIGGER => write!(f, "trigger"),
+            EmailParserActionEnum::RESOLVE => write!(f, "resolve"),
+            EmailParserActionEnum::UNKNOWN => write!(f, "unknown"),
         }
     }
 }
 
-impl ::std::str::FromStr for EscalationPolicyOnCallHandoffNotificationsEnum {
+impl ::std::str::FromStr for EmailParserActionEnum {
     type Err = String;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         match s {
-            "if_has_services" => Ok(EscalationPolicyOnCallHandoffNotificationsEnum::IF_HAS_SERVICES),
-            "always" => Ok(EscalationPolicyOnCallHandoffNotificationsEnum::ALWAYS),
+            "trigger" => Ok(EmailParserActionEnum::TRIGGER),
+            "resolve" => Ok(EmailParserActionEnum::RESOLVE),
             x => Err(format!("Invalid enum type: {}", x)),
         }
     }
 }
 
-impl ::std::convert::AsRef<str> for EscalationPolicyOnCallHandoffNotificationsEnum {
+impl ::std::convert::AsRef<str> for EmailParserActionEnum {
     fn as_ref(&self) -> &str {
         match self {
-            EscalationPolicyOnCallHandoffNotificationsEnum::IF_HAS_SERVICES => "if_has_services",
-            EscalationPolicyOnCallHandoffNotificationsEnum::ALWAYS => "always",
-            EscalationPolicyOnCallHandoffNotificationsEnum::UNKNOWN => "unknown",
+            EmailParserActionEnum::TRIGGER => "trigger",
+            EmailParserActionEnum::RESOLVE => "resolve",
+            EmailParserActionEnum::UNKNOWN => "unknown",
         }
     }
 }
 
 // Pick the first enum var as default. Please report this as a bug if it's not correct.
-impl std::default::Default for EscalationPolicyOnCallHandoffNotificationsEnum {
+impl std::default::Default for EmailParserActionEnum {
     fn default() -> Self {
-        EscalationPolicyOnCallHandoffNotificationsEnum::IF_HAS_SERVICES
+        EmailParserActionEnum::TRIGGER
     }
 }
 
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
-pub struct EscalationPolicyReference {
-    /// The label of the tag.
-    #[serde(default = "EscalationPolicyReference::label_default")]
-    pub label: String,
-    /// a URL at which the entity is uniquely displayed in the Web app
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub html_url: Option<String>,
-    /// the API show URL at which the object is accessible
-    #[serde(rename = "self")]
+pub struct EmailParserValueExtractors {
+    #[serde(rename = "type")]
+    pub _type: EmailParserValueExtractorsTypeEnum,
+    pub part: EmailParserValueExtractorsPartEnum,
+    /// The field name to set in the Incident object. Exactly one must use the `value_name` of `incident_key`
+    #[serde(skip_serializing_if = "String::is_empty")]
+    pub value_name: String,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub _self: Option<String>,
-    /// A short-form, server-generated string that provides succinct, important information about an object suitable for primary labeling of an entity in a client. In many cases, this will be identical to `name`, though it is not intended to be an identifier.
+    pub regex: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub summary: Option<String>,
+    pub starts_after: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub id: Option<String>,
-    #[serde(rename = "type")]
-    pub _type: EscalationPolicyReferenceTypeEnum,
+    pub ends_with: Option<String>,
 }
 
-#[allow(clippy::derivable_impls)]
-impl Default for EscalationPolicyReference {
-    fn default() -> Self {
-        Self {
-            label: String::from("EscalationPolicyReference"),
-            html_url: Default::default(),
-            _self: Default::default(),
-            summary: Default::default(),
-            id: Default::default(),
-            _type: Default::default(),
+#[allow(non_camel_case_types)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize, Eq, Ord)]
+pub enum EmailParserValueExtractorsTypeEnum {
+    #[serde(rename = "entire")]
+    ENTIRE,
+    #[serde(rename = "regex")]
+    REGEX,
+    #[serde(rename = "between")]
+    BETWEEN,
+    #[serde(other)]
+    UNKNOWN,
+}
+
+impl ::std::fmt::Display for EmailParserValueExtractorsTypeEnum {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        match *self {
+            EmailParserValueExtractorsTypeEnum::ENTIRE => write!(f, "entire"),
+            EmailParserValueExtractorsTypeEnum::REGEX => write!(f, "regex"),
+            EmailParserValueExtractorsTypeEnum::BETWEEN => write!(f, "between"),
+            EmailParserValueExtractorsTypeEnum::UNKNOWN => write!(f, "unknown"),
         }
     }
 }
 
-impl EscalationPolicyReference {
-    #[allow(dead_code)]
-    fn label_default() -> String {
-        String::from("EscalationPolicyReference")
+impl ::std::str::FromStr for EmailParserValueExtractorsTypeEnum {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "entire" => Ok(EmailParserValueExtractorsTypeEnum::ENTIRE),
+            "regex" => Ok(EmailParserValueExtractorsTypeEnum::REGEX),
+            "between" => Ok(EmailParserValueExtractorsTypeEnum::BETWEEN),
+            x => Err(format!("Invalid enum type: {}", x)),
+        }
     }
-    #[allow(dead_code)]
-    fn _type_default() -> String {
-        String::from("escalation_policy_reference")
+}
+
+impl ::std::convert::AsRef<str> for EmailParserValueExtractorsTypeEnum {
+    fn as_ref(&self) -> &str {
+        match self {
+            EmailParserValueExtractorsTypeEnum::ENTIRE => "entire",
+            EmailParserValueExtractorsTypeEnum::REGEX => "regex",
+            EmailParserValueExtractorsTypeEnum::BETWEEN => "between",
+            EmailParserValueExtractorsTypeEnum::UNKNOWN => "unknown",
+        }
+    }
+}
+
+// Pick the first enum var as default. Please report this as a bug if it's not correct.
+impl std::default::Default for EmailParserValueExtractorsTypeEnum {
+    fn default() -> Self {
+        EmailParserValueExtractorsTypeEnum::ENTIRE
     }
 }
 
 #[allow(non_camel_case_types)]
 #[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize, Eq, Ord)]
-pub enum EscalationPolicyReferenceTypeEnum {
-    #[serde(rename = "escalation_policy_reference")]
-    ESCALATION_POLICY_REFERENCE,
+pub enum EmailParserValueExtractorsPartEnum {
+    #[serde(rename = "body")]
+    BODY,
+    #[serde(rename = "subject")]
+    SUBJECT,
+    #[serde(rename = "from_addresses")]
+    FROM_ADDRESSES,
     #[serde(other)]
     UNKNOWN,
 }
 
-impl ::std::fmt::Display for EscalationPolicyReferenceTypeEnum {
+impl ::std::fmt::Display for EmailParserValueExtractorsPartEnum {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         match *self {
-            EscalationPolicyReferenceTypeEnum::ESCALATION_POLICY_REFERENCE => write!(f, "escalation_policy_reference"),
-            EscalationPolicyReferenceTypeEnum::UNKNOWN => write!(f, "unknown"),
+            EmailParserValueExtractorsPartEnum::BODY => write!(f, "body"),
+            EmailParserValueExtractorsPartEnum::SUBJECT => write!(f, "subject"),
+            EmailParserValueExtractorsPartEnum::FROM_ADDRESSES => write!(f, "from_addresses"),
+            EmailParserValueExtractorsPartEnum::UNKNOWN => write!(f, "unknown"),
         }
     }
 }
 
-impl ::std::str::FromStr for EscalationPolicyReferenceTypeEnum {
+impl ::std::str::FromStr for EmailParserValueExtractorsPartEnum {
     type Err = String;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         match s {
-            "escalation_policy_reference" => Ok(EscalationPolicyReferenceTypeEnum::ESCALATION_POLICY_REFERENCE),
+            "body" => Ok(EmailParserValueExtractorsPartEnum::BODY),
+            "subject" => Ok(EmailParserValueExtractorsPartEnum::SUBJECT),
+            "from_addresses" => Ok(EmailParserValueExtractorsPartEnum::FROM_ADDRESSES),
             x => Err(format!("Invalid enum type: {}", x)),
         }
     }
 }
 
-impl ::std::convert::AsRef<str> for EscalationPolicyReferenceTypeEnum {
+impl ::std::convert::AsRef<str> for EmailParserValueExtractorsPartEnum {
     fn as_ref(&self) -> &str {
         match self {
-            EscalationPolicyReferenceTypeEnum::ESCALATION_POLICY_REFERENCE => "escalation_policy_reference",
-            EscalationPolicyReferenceTypeEnum::UNKNOWN => "unknown",
+            EmailParserValueExtractorsPartEnum::BODY => "body",
+            EmailParserValueExtractorsPartEnum::SUBJECT => "subject",
+            EmailParserValueExtractorsPartEnum::FROM_ADDRESSES => "from_addresses",
+            EmailParserValueExtractorsPartEnum::UNKNOWN => "unknown",
         }
     }
 }
 
 // Pick the first enum var as default. Please report this as a bug if it's not correct.
-impl std::default::Default for EscalationPolicyReferenceTypeEnum {
+impl std::default::Default for EmailParserValueExtractorsPartEnum {
     fn default() -> Self {
-        EscalationPolicyReferenceTypeEnum::ESCALATION_POLICY_REFERENCE
+        EmailParserValueExtractorsPartEnum::BODY
     }
 }
 
-#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
-#[allow(clippy::derive_partial_eq_without_eq)]
-pub struct EscalationRule {
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub id: Option<String>,
-    /// The number of minutes before an unacknowledged incident escalates away from this rule.
-    pub escalation_delay_in_minutes: isize,
-    /// The targets an incident should be assigned to upon reaching this rule.
-    pub targets: Vec<EscalationTargetReference>,
-}
-
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
-pub struct EscalationTargetReference {
+pub struct EntityReference {
     /// The label of the tag.
-    #[serde(default = "EscalationTargetReference::label_default")]
+    #[serde(default = "EntityReference::label_default")]
     pub label: String,
     /// a URL at which the entity is uniquely displayed in the Web app
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -3458,14 +3577,14 @@ pub struct EscalationTargetReference {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub id: Option<String>,
     #[serde(rename = "type")]
-    pub _type: EscalationTargetReferenceTypeEnum,
+    pub _type: EntityReferenceTypeEnum,
 }
 
 #[allow(clippy::derivable_impls)]
-impl Default for EscalationTargetReference {
+impl Default for EntityReference {
     fn default() -> Self {
         Self {
-            label: String::from("EscalationTargetReference"),
+            label: String::from("EntityReference"),
             html_url: Default::default(),
             _self: Default::default(),
             summary: Default::default(),
@@ -3475,2361 +3594,3239 @@ impl Default for EscalationTargetReference {
     }
 }
 
-impl EscalationTargetReference {
+impl EntityReference {
     #[allow(dead_code)]
     fn label_default() -> String {
-        String::from("EscalationTargetReference")
+        String::from("EntityReference")
     }
     #[allow(dead_code)]
     fn _type_default() -> String {
-        String::from("escalation_target_reference")
+        String::from("entity_reference")
     }
 }
 
 #[allow(non_camel_case_types)]
 #[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize, Eq, Ord)]
-pub enum EscalationTargetReferenceTypeEnum {
-    #[serde(rename = "user")]
-    USER,
-    #[serde(rename = "schedule")]
-    SCHEDULE,
+pub enum EntityReferenceTypeEnum {
     #[serde(rename = "user_reference")]
     USER_REFERENCE,
-    #[serde(rename = "schedule_reference")]
-    SCHEDULE_REFERENCE,
+    #[serde(rename = "team_reference")]
+    TEAM_REFERENCE,
+    #[serde(rename = "escalation_policy_reference")]
+    ESCALATION_POLICY_REFERENCE,
     #[serde(other)]
     UNKNOWN,
 }
 
-impl ::std::fmt::Display for EscalationTargetReferenceTypeEnum {
+impl ::std::fmt::Display for EntityReferenceTypeEnum {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         match *self {
-            EscalationTargetReferenceTypeEnum::USER => write!(f, "user"),
-            EscalationTargetReferenceTypeEnum::SCHEDULE => write!(f, "schedule"),
-            EscalationTargetReferenceTypeEnum::USER_REFERENCE => write!(f, "user_reference"),
-            EscalationTargetReferenceTypeEnum::SCHEDULE_REFERENCE => write!(f, "schedule_reference"),
-            EscalationTargetReferenceTypeEnum::UNKNOWN => write!(f, "unknown"),
+            EntityReferenceTypeEnum::USER_REFERENCE => write!(f, "user_reference"),
+            EntityReferenceTypeEnum::TEAM_REFERENCE => write!(f, "team_reference"),
+            EntityReferenceTypeEnum::ESCALATION_POLICY_REFERENCE => write!(f, "escalation_policy_reference"),
+            EntityReferenceTypeEnum::UNKNOWN => write!(f, "unknown"),
         }
     }
 }
 
-impl ::std::str::FromStr for EscalationTargetReferenceTypeEnum {
+impl ::std::str::FromStr for EntityReferenceTypeEnum {
     type Err = String;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         match s {
-            "user" => Ok(EscalationTargetReferenceTypeEnum::USER),
-            "schedule" => Ok(EscalationTargetReferenceTypeEnum::SCHEDULE),
-            "user_reference" => Ok(EscalationTargetReferenceTypeEnum::USER_REFERENCE),
-            "schedule_reference" => Ok(EscalationTargetReferenceTypeEnum::SCHEDULE_REFERENCE),
+            "user_reference" => Ok(EntityReferenceTypeEnum::USER_REFERENCE),
+            "team_reference" => Ok(EntityReferenceTypeEnum::TEAM_REFERENCE),
+            "escalation_policy_reference" => Ok(EntityReferenceTypeEnum::ESCALATION_POLICY_REFERENCE),
             x => Err(format!("Invalid enum type: {}", x)),
         }
     }
 }
 
-impl ::std::convert::AsRef<str> for EscalationTargetReferenceTypeEnum {
+impl ::std::convert::AsRef<str> for EntityReferenceTypeEnum {
     fn as_ref(&self) -> &str {
         match self {
-            EscalationTargetReferenceTypeEnum::USER => "user",
-            EscalationTargetReferenceTypeEnum::SCHEDULE => "schedule",
-            EscalationTargetReferenceTypeEnum::USER_REFERENCE => "user_reference",
-            EscalationTargetReferenceTypeEnum::SCHEDULE_REFERENCE => "schedule_reference",
-            EscalationTargetReferenceTypeEnum::UNKNOWN => "unknown",
+            EntityReferenceTypeEnum::USER_REFERENCE => "user_reference",
+            EntityReferenceTypeEnum::TEAM_REFERENCE => "team_reference",
+            EntityReferenceTypeEnum::ESCALATION_POLICY_REFERENCE => "escalation_policy_reference",
+            EntityReferenceTypeEnum::UNKNOWN => "unknown",
         }
     }
 }
 
 // Pick the first enum var as default. Please report this as a bug if it's not correct.
-impl std::default::Default for EscalationTargetReferenceTypeEnum {
+impl std::default::Default for EntityReferenceTypeEnum {
     fn default() -> Self {
-        EscalationTargetReferenceTypeEnum::USER
+        EntityReferenceTypeEnum::USER_REFERENCE
     }
 }
 
 #[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
-pub struct UpdateOrchestration {
-    pub orchestration: Orchestration,
-}
-
-#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
-#[allow(clippy::derive_partial_eq_without_eq)]
-pub struct PostOrchestration {
-    pub orchestration: Orchestration,
-}
-
-#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
-#[allow(clippy::derive_partial_eq_without_eq)]
-pub struct EventRule {
-    /// Position/index of the Event Rule in the Ruleset.  Starting from position 0 (the first rule), rules are evaluated one-by-one until a matching rule is found.
+pub struct EscalateLogEntry {
+    #[serde(rename = "type")]
+    pub _type: EscalateLogEntryTypeEnum,
+    /// Time at which the log entry was created.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub position: Option<isize>,
-    /// Indicates whether the Event Rule is the last Event Rule of the Ruleset that serves as a catch-all. It has limited functionality compared to other rules and always matches.
+    pub created_at: Option<DateTime<FixedOffset>>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub catch_all: Option<bool>,
-    /// When an event matches this rule, the actions that will be taken to change the resulting alert and incident.
+    pub channel: Option<Channel>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub actions: Option<Value>,
-}
-#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
-#[allow(clippy::derive_partial_eq_without_eq)]
-pub struct EventRuleActions {
-    /// Set the service ID of the target service for the resulting alert. You can find the service you want to route to by calling the services endpoint.
-    #[serde(rename = "route")]
+    pub agent: Option<AgentReference>,
+    /// Optional field containing a note, if one was included with the log entry.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub route: Option<HashMap<String, Value>>,
-}
-
-/// When an event matches this Event Rule, the actions that will be taken to change the resulting Alert and Incident.
-#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
-#[allow(clippy::derive_partial_eq_without_eq)]
-pub struct EventRuleActionsCommon {
+    pub note: Option<String>,
+    /// Contexts to be included with the trigger such as links to graphs or images.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub annotate: Option<EventRuleActionsCommonAnnotate>,
+    pub contexts: Option<Vec<Context>>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub event_action: Option<EventRuleActionsCommonEventAction>,
-    /// Dynamically extract values to set and modify new and existing PD-CEF fields.
+    pub service: Option<Service>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub extractions: Option<Vec<EventRuleActionsCommonExtractionsItems>>,
+    pub user: Option<User>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub priority: Option<EventRuleActionsCommonPriority>,
+    pub incident: Option<Incident>,
+    /// Will consist of references unless included
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub severity: Option<EventRuleActionsCommonSeverity>,
+    pub teams: Option<Vec<Team>>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub suppress: Option<EventRuleActionsCommonSuppress>,
+    pub event_details: Option<LogEntryEventDetails>,
+    /// An array of assigned Users for this log entry
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub suspend: Option<EventRuleActionsCommonSuspend>,
+    pub assignees: Option<Vec<User>>,
 }
 
-#[allow(non_camel_case_types, clippy::derive_partial_eq_without_eq)]
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
-#[serde(untagged)]
-pub enum EventRuleActionsCommonExtractionsItems {
-    ExtractionsItems0 { target: String, source: String, regex: String },
-    ExtractionsItems1 { target: String, template: String },
+#[allow(non_camel_case_types)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize, Eq, Ord)]
+pub enum EscalateLogEntryTypeEnum {
+    #[serde(rename = "escalate_log_entry")]
+    ESCALATE_LOG_ENTRY,
+    #[serde(other)]
+    UNKNOWN,
 }
 
-impl Display for EventRuleActionsCommonExtractionsItems {
-    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
-        match self {
-            EventRuleActionsCommonExtractionsItems::ExtractionsItems0 { target, source, regex } => write!(f, "ExtractionsItems0{{ {:?}, {:?}, {:?} }}", target, source, regex),
-            EventRuleActionsCommonExtractionsItems::ExtractionsItems1 { target, template } => write!(f, "ExtractionsItems1{{ {:?}, {:?} }}", target, template),
+impl ::std::fmt::Display for EscalateLogEntryTypeEnum {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        match *self {
+            EscalateLogEntryTypeEnum::ESCALATE_LOG_ENTRY => write!(f, "escalate_log_entry"),
+            EscalateLogEntryTypeEnum::UNKNOWN => write!(f, "unknown"),
         }
     }
 }
 
-impl std::default::Default for EventRuleActionsCommonExtractionsItems {
-    fn default() -> Self {
-        EventRuleActionsCommonExtractionsItems::ExtractionsItems0 { target: String::default(), source: String::default(), regex: String::default() }
-    }
-}
-
-/// Set a note on the resulting incident.
-#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
-#[allow(clippy::derive_partial_eq_without_eq)]
-pub struct EventRuleActionsCommonAnnotate {
-    /// The content of the note.
-    #[serde(skip_serializing_if = "String::is_empty")]
-    pub value: String,
-}
-
-/// Set whether the resulting alert status is trigger or resolve.
-#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
-#[allow(clippy::derive_partial_eq_without_eq)]
-pub struct EventRuleActionsCommonEventAction {
-    pub value: EventRuleActionsCommonEventActionValueEnum,
-}
-
-#[allow(non_camel_case_types)]
-#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize, Eq, Ord)]
-pub enum EventRuleActionsCommonEventActionValueEnum {
-    #[serde(rename = "trigger")]
-    TRIGGER,
-    #[serde(rename = "resolve")]
-    RESOLVE,
-    #[serde(other)]
-    UNKNOWN,
-}
-
-impl ::std::fmt::Display for EventRuleActionsCommonEventActionValueEnum {
-    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
-        match *self {
-            EventRuleActionsCommonEventActionValueEnum::TRIGGER => write!(f, "trigger"),
-            EventRuleActionsCommonEventActionValueEnum::RESOLVE => write!(f, "resolve"),
-            EventRuleActionsCommonEventActionValueEnum::UNKNOWN => write!(f, "unknown"),
-        }
-    }
-}
-
-impl ::std::str::FromStr for EventRuleActionsCommonEventActionValueEnum {
+impl ::std::str::FromStr for EscalateLogEntryTypeEnum {
     type Err = String;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         match s {
-            "trigger" => Ok(EventRuleActionsCommonEventActionValueEnum::TRIGGER),
-            "resolve" => Ok(EventRuleActionsCommonEventActionValueEnum::RESOLVE),
+            "escalate_log_entry" => Ok(EscalateLogEntryTypeEnum::ESCALATE_LOG_ENTRY),
             x => Err(format!("Invalid enum type: {}", x)),
         }
     }
 }
 
-impl ::std::convert::AsRef<str> for EventRuleActionsCommonEventActionValueEnum {
+impl ::std::convert::AsRef<str> for EscalateLogEntryTypeEnum {
     fn as_ref(&self) -> &str {
         match self {
-            EventRuleActionsCommonEventActionValueEnum::TRIGGER => "trigger",
-            EventRuleActionsCommonEventActionValueEnum::RESOLVE => "resolve",
-            EventRuleActionsCommonEventActionValueEnum::UNKNOWN => "unknown",
+            EscalateLogEntryTypeEnum::ESCALATE_LOG_ENTRY => "escalate_log_entry",
+            EscalateLogEntryTypeEnum::UNKNOWN => "unknown",
         }
     }
 }
 
 // Pick the first enum var as default. Please report this as a bug if it's not correct.
-impl std::default::Default for EventRuleActionsCommonEventActionValueEnum {
+impl std::default::Default for EscalateLogEntryTypeEnum {
     fn default() -> Self {
-        EventRuleActionsCommonEventActionValueEnum::TRIGGER
+        EscalateLogEntryTypeEnum::ESCALATE_LOG_ENTRY
     }
 }
 
-/// Set the priority ID for the resulting incident. You can find the priority you want by calling the priorities endpoint.
 #[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
-pub struct EventRuleActionsCommonPriority {
-    /// The priority ID.
-    #[serde(skip_serializing_if = "String::is_empty")]
-    pub value: String,
+pub struct CreateEscalationPolicy {
+    pub escalation_policy: EscalationPolicy,
 }
 
-/// Set the severity of the resulting alert.
 #[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
-pub struct EventRuleActionsCommonSeverity {
-    pub value: EventRuleActionsCommonSeverityValueEnum,
+pub struct UpdateEscalationPolicy {
+    pub escalation_policy: EscalationPolicy,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+pub struct EscalationPolicy {
+    /// The label of the tag.
+    #[serde(default = "EscalationPolicy::label_default")]
+    pub label: String,
+    /// a URL at which the entity is uniquely displayed in the Web app
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub html_url: Option<String>,
+    /// the API show URL at which the object is accessible
+    #[serde(rename = "self")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub _self: Option<String>,
+    /// A short-form, server-generated string that provides succinct, important information about an object suitable for primary labeling of an entity in a client. In many cases, this will be identical to `name`, though it is not intended to be an identifier.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub summary: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// The type of object being created.
+    #[serde(rename = "type")]
+    pub _type: EscalationPolicyTypeEnum,
+    /// The name of the escalation policy.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// Escalation policy description.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// The number of times the escalation policy will repeat after reaching the end of its escalation.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub num_loops: Option<usize>,
+    /// Determines how on call handoff notifications will be sent for users on the escalation policy. Defaults to \"if_has_services\".
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub on_call_handoff_notifications: Option<EscalationPolicyOnCallHandoffNotificationsEnum>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub escalation_rules: Option<Vec<EscalationRule>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub services: Option<Vec<Service>>,
+    /// Teams associated with the policy. Account must have the `teams` ability to use this parameter.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub teams: Option<Vec<Team>>,
+}
+
+#[allow(clippy::derivable_impls)]
+impl Default for EscalationPolicy {
+    fn default() -> Self {
+        Self {
+            label: String::from("EscalationPolicy"),
+            html_url: Default::default(),
+            _self: Default::default(),
+            summary: Default::default(),
+            id: Default::default(),
+            _type: Default::default(),
+            name: Default::default(),
+            description: Default::default(),
+            num_loops: Default::default(),
+            on_call_handoff_notifications: Default::default(),
+            escalation_rules: Default::default(),
+            services: Default::default(),
+            teams: Default::default(),
+        }
+    }
+}
+
+impl EscalationPolicy {
+    #[allow(dead_code)]
+    fn label_default() -> String {
+        String::from("EscalationPolicy")
+    }
+    #[allow(dead_code)]
+    fn _type_default() -> String {
+        String::from("escalation_policy")
+    }
 }
 
 #[allow(non_camel_case_types)]
 #[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize, Eq, Ord)]
-pub enum EventRuleActionsCommonSeverityValueEnum {
-    #[serde(rename = "info")]
-    INFO,
-    #[serde(rename = "warning")]
-    WARNING,
-    #[serde(rename = "error")]
-    ERROR,
-    #[serde(rename = "critical")]
-    CRITICAL,
+pub enum EscalationPolicyTypeEnum {
+    #[serde(rename = "escalation_policy")]
+    ESCALATION_POLICY,
+    #[serde(rename = "escalation_policy_reference")]
+    ESCALATION_POLICY_REFERENCE,
     #[serde(other)]
     UNKNOWN,
 }
 
-impl ::std::fmt::Display for EventRuleActionsCommonSeverityValueEnum {
+impl ::std::fmt::Display for EscalationPolicyTypeEnum {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         match *self {
-            EventRuleActionsCommonSeverityValueEnum::INFO => write!(f, "info"),
-            EventRuleActionsCommonSeverityValueEnum::WARNING => write!(f, "warning"),
-            EventRuleActionsCommonSeverityValueEnum::ERROR => write!(f, "error"),
-            EventRuleActionsCommonSeverityValueEnum::CRITICAL => write!(f, "critical"),
-            EventRuleActionsCommonSeverityValueEnum::UNKNOWN => write!(f, "unknown"),
+            EscalationPolicyTypeEnum::ESCALATION_POLICY => write!(f, "escalation_policy"),
+            EscalationPolicyTypeEnum::ESCALATION_POLICY_REFERENCE => write!(f, "escalation_policy_reference"),
+            EscalationPolicyTypeEnum::UNKNOWN => write!(f, "unknown"),
         }
     }
 }
 
-impl ::std::str::FromStr for EventRuleActionsCommonSeverityValueEnum {
+impl ::std::str::FromStr for EscalationPolicyTypeEnum {
     type Err = String;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         match s {
-            "info" => Ok(EventRuleActionsCommonSeverityValueEnum::INFO),
-            "warning" => Ok(EventRuleActionsCommonSeverityValueEnum::WARNING),
-            "error" => Ok(EventRuleActionsCommonSeverityValueEnum::ERROR),
-            "critical" => Ok(EventRuleActionsCommonSeverityValueEnum::CRITICAL),
+            "escalation_policy" => Ok(EscalationPolicyTypeEnum::ESCALATION_POLICY),
+            "escalation_policy_reference" => Ok(EscalationPolicyTypeEnum::ESCALATION_POLICY_REFERENCE),
             x => Err(format!("Invalid enum type: {}", x)),
         }
     }
 }
 
-impl ::std::convert::AsRef<str> for EventRuleActionsCommonSeverityValueEnum {
+impl ::std::convert::AsRef<str> for EscalationPolicyTypeEnum {
     fn as_ref(&self) -> &str {
         match self {
-            EventRuleActionsCommonSeverityValueEnum::INFO => "info",
-            EventRuleActionsCommonSeverityValueEnum::WARNING => "warning",
-            EventRuleActionsCommonSeverityValueEnum::ERROR => "error",
-            EventRuleActionsCommonSeverityValueEnum::CRITICAL => "critical",
-            EventRuleActionsCommonSeverityValueEnum::UNKNOWN => "unknown",
+            EscalationPolicyTypeEnum::ESCALATION_POLICY => "escalation_policy",
+            EscalationPolicyTypeEnum::ESCALATION_POLICY_REFERENCE => "escalation_policy_reference",
+            EscalationPolicyTypeEnum::UNKNOWN => "unknown",
         }
     }
 }
 
 // Pick the first enum var as default. Please report this as a bug if it's not correct.
-impl std::default::Default for EventRuleActionsCommonSeverityValueEnum {
+impl std::default::Default for EscalationPolicyTypeEnum {
     fn default() -> Self {
-        EventRuleActionsCommonSeverityValueEnum::INFO
+        EscalationPolicyTypeEnum::ESCALATION_POLICY
     }
 }
 
-/// Set whether the resulting alert is suppressed. Can optionally be used with a threshold where resulting alerts will be suppressed until the threshold is met in a window of time. If using a threshold the rule must also set a route action.
-#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
-#[allow(clippy::derive_partial_eq_without_eq)]
-pub struct EventRuleActionsCommonSuppress {
-    pub value: bool,
-    /// The number of occurences needed during the window of time to trigger the theshold.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub threshold_value: Option<isize>,
-    /// The time unit for the window of time.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub threshold_time_unit: Option<EventRuleActionsCommonSuppressThresholdTimeUnitEnum>,
-    /// The amount of time units for the window of time.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub threshold_time_amount: Option<isize>,
-}
-
 #[allow(non_camel_case_types)]
 #[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize, Eq, Ord)]
-pub enum EventRuleActionsCommonSuppressThresholdTimeUnitEnum {
-    #[serde(rename = "seconds")]
-    SECONDS,
-    #[serde(rename = "minutes")]
-    MINUTES,
-    #[serde(rename = "hours")]
-    HOURS,
+pub enum EscalationPolicyOnCallHandoffNotificationsEnum {
+    #[serde(rename = "if_has_services")]
+    IF_HAS_SERVICES,
+    #[serde(rename = "always")]
+    ALWAYS,
     #[serde(other)]
     UNKNOWN,
 }
 
-impl ::std::fmt::Display for EventRuleActionsCommonSuppressThresholdTimeUnitEnum {
+impl ::std::fmt::Display for EscalationPolicyOnCallHandoffNotificationsEnum {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         match *self {
-            EventRuleActionsCommonSuppressThresholdTimeUnitEnum::SECONDS => write!(f, "seconds"),
-            EventRuleActionsCommonSuppressThresholdTimeUnitEnum::MINUTES => write!(f, "minutes"),
-            EventRuleActionsCommonSuppressThresholdTimeUnitEnum::HOURS => write!(f, "hours"),
-            EventRuleActionsCommonSuppressThresholdTimeUnitEnum::UNKNOWN => write!(f, "unknown"),
+            EscalationPolicyOnCallHandoffNotificationsEnum::IF_HAS_SERVICES => write!(f, "if_has_services"),
+            EscalationPolicyOnCallHandoffNotificationsEnum::ALWAYS => write!(f, "always"),
+            EscalationPolicyOnCallHandoffNotificationsEnum::UNKNOWN => write!(f, "unknown"),
         }
     }
 }
 
-impl ::std::str::FromStr for EventRuleActionsCommonSuppressThresholdTimeUnitEnum {
+impl ::std::str::FromStr for EscalationPolicyOnCallHandoffNotificationsEnum {
     type Err = String;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         match s {
-            "seconds" => Ok(EventRuleActionsCommonSuppressThresholdTimeUnitEnum::SECONDS),
-            "minutes" => Ok(EventRuleActionsCommonSuppressThresholdTimeUnitEnum::MINUTES),
-            "hours" => Ok(EventRuleActionsCommonSuppressThresholdTimeUnitEnum::HOURS),
+            "if_has_services" => Ok(EscalationPolicyOnCallHandoffNotificationsEnum::IF_HAS_SERVICES),
+            "always" => Ok(EscalationPolicyOnCallHandoffNotificationsEnum::ALWAYS),
             x => Err(format!("Invalid enum type: {}", x)),
         }
     }
 }
 
-impl ::std::convert::AsRef<str> for EventRuleActionsCommonSuppressThresholdTimeUnitEnum {
+impl ::std::convert::AsRef<str> for EscalationPolicyOnCallHandoffNotificationsEnum {
     fn as_ref(&self) -> &str {
         match self {
-            EventRuleActionsCommonSuppressThresholdTimeUnitEnum::SECONDS => "seconds",
-            EventRuleActionsCommonSuppressThresholdTimeUnitEnum::MINUTES => "minutes",
-            EventRuleActionsCommonSuppressThresholdTimeUnitEnum::HOURS => "hours",
-            EventRuleActionsCommonSuppressThresholdTimeUnitEnum::UNKNOWN => "unknown",
+            EscalationPolicyOnCallHandoffNotificationsEnum::IF_HAS_SERVICES => "if_has_services",
+            EscalationPolicyOnCallHandoffNotificationsEnum::ALWAYS => "always",
+            EscalationPolicyOnCallHandoffNotificationsEnum::UNKNOWN => "unknown",
         }
     }
 }
 
 // Pick the first enum var as default. Please report this as a bug if it's not correct.
-impl std::default::Default for EventRuleActionsCommonSuppressThresholdTimeUnitEnum {
+impl std::default::Default for EscalationPolicyOnCallHandoffNotificationsEnum {
     fn default() -> Self {
-        EventRuleActionsCommonSuppressThresholdTimeUnitEnum::SECONDS
+        EscalationPolicyOnCallHandoffNotificationsEnum::IF_HAS_SERVICES
     }
 }
 
-/// Set the length of time to suspend the resulting alert before triggering. Rules with a suspend action must also set a route action, and cannot have a suppress with threshold action
-#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
-pub struct EventRuleActionsCommonSuspend {
-    /// The amount of time to suspend the alert in seconds.
-    pub value: isize,
-}
-
-/// Conditions evaluated to check if an event matches this Event Rule. Is always empty for the catch_all rule, though.
-#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
-#[allow(clippy::derive_partial_eq_without_eq)]
-pub struct EventRuleConditions {
-    /// Operator to combine sub-conditions.
-    pub operator: EventRuleConditionsOperatorEnum,
-    /// Array of sub-conditions.
-    pub subconditions: Vec<EventRuleConditionsSubconditions>,
+pub struct EscalationPolicyReference {
+    /// The label of the tag.
+    #[serde(default = "EscalationPolicyReference::label_default")]
+    pub label: String,
+    /// a URL at which the entity is uniquely displayed in the Web app
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub html_url: Option<String>,
+    /// the API show URL at which the object is accessible
+    #[serde(rename = "self")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub _self: Option<String>,
+    /// A short-form, server-generated string that provides succinct, important information about an object suitable for primary labeling of an entity in a client. In many cases, this will be identical to `name`, though it is not intended to be an identifier.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub summary: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    #[serde(rename = "type")]
+    pub _type: EscalationPolicyReferenceTypeEnum,
+}
+
+#[allow(clippy::derivable_impls)]
+impl Default for EscalationPolicyReference {
+    fn default() -> Self {
+        Self {
+            label: String::from("EscalationPolicyReference"),
+            html_url: Default::default(),
+            _self: Default::default(),
+            summary: Default::default(),
+            id: Default::default(),
+            _type: Default::default(),
+        }
+    }
+}
+
+impl EscalationPolicyReference {
+    #[allow(dead_code)]
+    fn label_default() -> String {
+        String::from("EscalationPolicyReference")
+    }
+    #[allow(dead_code)]
+    fn _type_default() -> String {
+        String::from("escalation_policy_reference")
+    }
 }
 
 #[allow(non_camel_case_types)]
 #[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize, Eq, Ord)]
-pub enum EventRuleConditionsOperatorEnum {
-    #[serde(rename = "and")]
-    AND,
-    #[serde(rename = "or")]
-    OR,
+pub enum EscalationPolicyReferenceTypeEnum {
+    #[serde(rename = "escalation_policy_reference")]
+    ESCALATION_POLICY_REFERENCE,
     #[serde(other)]
     UNKNOWN,
 }
 
-impl ::std::fmt::Display for EventRuleConditionsOperatorEnum {
+impl ::std::fmt::Display for EscalationPolicyReferenceTypeEnum {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         match *self {
-            EventRuleConditionsOperatorEnum::AND => write!(f, "and"),
-            EventRuleConditionsOperatorEnum::OR => write!(f, "or"),
-            EventRuleConditionsOperatorEnum::UNKNOWN => write!(f, "unknown"),
+            EscalationPolicyReferenceTypeEnum::ESCALATION_POLICY_REFERENCE => write!(f, "escalation_policy_reference"),
+            EscalationPolicyReferenceTypeEnum::UNKNOWN => write!(f, "unknown"),
         }
     }
 }
 
-impl ::std::str::FromStr for EventRuleConditionsOperatorEnum {
+impl ::std::str::FromStr for EscalationPolicyReferenceTypeEnum {
     type Err = String;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         match s {
-            "and" => Ok(EventRuleConditionsOperatorEnum::AND),
-            "or" => Ok(EventRuleConditionsOperatorEnum::OR),
+            "escalation_policy_reference" => Ok(EscalationPolicyReferenceTypeEnum::ESCALATION_POLICY_REFERENCE),
             x => Err(format!("Invalid enum type: {}", x)),
         }
     }
 }
 
-impl ::std::convert::AsRef<str> for EventRuleConditionsOperatorEnum {
+impl ::std::convert::AsRef<str> for EscalationPolicyReferenceTypeEnum {
     fn as_ref(&self) -> &str {
         match self {
-            EventRuleConditionsOperatorEnum::AND => "and",
-            EventRuleConditionsOperatorEnum::OR => "or",
-            EventRuleConditionsOperatorEnum::UNKNOWN => "unknown",
+            EscalationPolicyReferenceTypeEnum::ESCALATION_POLICY_REFERENCE => "escalation_policy_reference",
+            EscalationPolicyReferenceTypeEnum::UNKNOWN => "unknown",
         }
     }
 }
 
 // Pick the first enum var as default. Please report this as a bug if it's not correct.
-impl std::default::Default for EventRuleConditionsOperatorEnum {
+impl std::default::Default for EscalationPolicyReferenceTypeEnum {
     fn default() -> Self {
-        EventRuleConditionsOperatorEnum::AND
+        EscalationPolicyReferenceTypeEnum::ESCALATION_POLICY_REFERENCE
     }
 }
 
 #[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
-pub struct EventRuleConditionsParameters {
-    /// Path to a field in an event, in dot-notation.  For Event Rules on a serivce, this will have to be a PD-CEF field.
-    #[serde(skip_serializing_if = "String::is_empty")]
-    pub path: String,
-    /// Value to apply to the operator.
-    #[serde(skip_serializing_if = "String::is_empty")]
-    pub value: String,
-    /// Options to configure the operator.
+pub struct EscalationRule {
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub options: Option<HashMap<String, Value>>,
+    pub id: Option<String>,
+    /// The number of minutes before an unacknowledged incident escalates away from this rule.
+    pub escalation_delay_in_minutes: isize,
+    /// The targets an incident should be assigned to upon reaching this rule.
+    pub targets: Vec<EscalationTargetReference>,
 }
 
-#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
-pub struct EventRuleConditionsSubconditions {
-    /// The type of operator to apply.
-    pub operator: EventRuleConditionsSubconditionsOperatorEnum,
-    pub parameters: EventRuleConditionsParameters,
+pub struct EscalationTargetReference {
+    /// The label of the tag.
+    #[serde(default = "EscalationTargetReference::label_default")]
+    pub label: String,
+    /// a URL at which the entity is uniquely displayed in the Web app
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub html_url: Option<String>,
+    /// the API show URL at which the object is accessible
+    #[serde(rename = "self")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub _self: Option<String>,
+    /// A short-form, server-generated string that provides succinct, important information about an object suitable for primary labeling of an entity in a client. In many cases, this will be identical to `name`, though it is not intended to be an identifier.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub summary: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    #[serde(rename = "type")]
+    pub _type: EscalationTargetReferenceTypeEnum,
+}
+
+#[allow(clippy::derivable_impls)]
+impl Default for EscalationTargetReference {
+    fn default() -> Self {
+        Self {
+            label: String::from("EscalationTargetReference"),
+            html_url: Default::default(),
+            _self: Default::default(),
+            summary: Default::default(),
+            id: Default::default(),
+            _type: Default::default(),
+        }
+    }
+}
+
+impl EscalationTargetReference {
+    #[allow(dead_code)]
+    fn label_default() -> String {
+        String::from("EscalationTargetReference")
+    }
+    #[allow(dead_code)]
+    fn _type_default() -> String {
+        String::from("escalation_target_reference")
+    }
 }
 
 #[allow(non_camel_case_types)]
 #[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize, Eq, Ord)]
-pub enum EventRuleConditionsSubconditionsOperatorEnum {
-    #[serde(rename = "exists")]
-    EXISTS,
-    #[serde(rename = "nexists")]
-    NEXISTS,
-    #[serde(rename = "equals")]
-    EQUALS,
-    #[serde(rename = "nequals")]
-    NEQUALS,
-    #[serde(rename = "contains")]
-    CONTAINS,
-    #[serde(rename = "ncontains")]
-    NCONTAINS,
-    #[serde(rename = "matches")]
-    MATCHES,
-    #[serde(rename = "nmatches")]
-    NMATCHES,
+pub enum EscalationTargetReferenceTypeEnum {
+    #[serde(rename = "user")]
+    USER,
+    #[serde(rename = "schedule")]
+    SCHEDULE,
+    #[serde(rename = "user_reference")]
+    USER_REFERENCE,
+    #[serde(rename = "schedule_reference")]
+    SCHEDULE_REFERENCE,
     #[serde(other)]
     UNKNOWN,
 }
 
-impl ::std::fmt::Display for EventRuleConditionsSubconditionsOperatorEnum {
+impl ::std::fmt::Display for EscalationTargetReferenceTypeEnum {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         match *self {
-            EventRuleConditionsSubconditionsOperatorEnum::EXISTS => write!(f, "exists"),
-            EventRuleConditionsSubconditionsOperatorEnum::NEXISTS => write!(f, "nexists"),
-            EventRuleConditionsSubconditionsOperatorEnum::EQUALS => write!(f, "equals"),
-            EventRuleConditionsSubconditionsOperatorEnum::NEQUALS => write!(f, "nequals"),
-            EventRuleConditionsSubconditionsOperatorEnum::CONTAINS => write!(f, "contains"),
-            EventRuleConditionsSubconditionsOperatorEnum::NCONTAINS => write!(f, "ncontains"),
-            EventRuleConditionsSubconditionsOperatorEnum::MATCHES => write!(f, "matches"),
-            EventRuleConditionsSubconditionsOperatorEnum::NMATCHES => write!(f, "nmatches"),
-            EventRuleConditionsSubconditionsOperatorEnum::UNKNOWN => write!(f, "unknown"),
+            EscalationTargetReferenceTypeEnum::USER => write!(f, "user"),
+            EscalationTargetReferenceTypeEnum::SCHEDULE => write!(f, "schedule"),
+            EscalationTargetReferenceTypeEnum::USER_REFERENCE => write!(f, "user_reference"),
+            EscalationTargetReferenceTypeEnum::SCHEDULE_REFERENCE => write!(f, "schedule_reference"),
+            EscalationTargetReferenceTypeEnum::UNKNOWN => write!(f, "unknown"),
         }
     }
 }
 
-impl ::std::str::FromStr for EventRuleConditionsSubconditionsOperatorEnum {
+impl ::std::str::FromStr for EscalationTargetReferenceTypeEnum {
     type Err = String;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         match s {
-            "exists" => Ok(EventRuleConditionsSubconditionsOperatorEnum::EXISTS),
-            "nexists" => Ok(EventRuleConditionsSubconditionsOperatorEnum::NEXISTS),
-            "equals" => Ok(EventRuleConditionsSubconditionsOperatorEnum::EQUALS),
-            "nequals" => Ok(EventRuleConditionsSubconditionsOperatorEnum::NEQUALS),
-            "contains" => Ok(EventRuleConditionsSubconditionsOperatorEnum::CONTAINS),
-            "ncontains" => Ok(EventRuleConditionsSubconditionsOperatorEnum::NCONTAINS),
-            "matches" => Ok(EventRuleConditionsSubconditionsOperatorEnum::MATCHES),
-            "nmatches" => Ok(EventRuleConditionsSubconditionsOperatorEnum::NMATCHES),
+            "user" => Ok(EscalationTargetReferenceTypeEnum::USER),
+            "schedule" => Ok(EscalationTargetReferenceTypeEnum::SCHEDULE),
+            "user_reference" => Ok(EscalationTargetReferenceTypeEnum::USER_REFERENCE),
+            "schedule_reference" => Ok(EscalationTargetReferenceTypeEnum::SCHEDULE_REFERENCE),
             x => Err(format!("Invalid enum type: {}", x)),
         }
     }
 }
 
-impl ::std::convert::AsRef<str> for EventRuleConditionsSubconditionsOperatorEnum {
+impl ::std::convert::AsRef<str> for EscalationTargetReferenceTypeEnum {
     fn as_ref(&self) -> &str {
         match self {
-            EventRuleConditionsSubconditionsOperatorEnum::EXISTS => "exists",
-            EventRuleConditionsSubconditionsOperatorEnum::NEXISTS => "nexists",
-            EventRuleConditionsSubconditionsOperatorEnum::EQUALS => "equals",
-            EventRuleConditionsSubconditionsOperatorEnum::NEQUALS => "nequals",
-            EventRuleConditionsSubconditionsOperatorEnum::CONTAINS => "contains",
-            EventRuleConditionsSubconditionsOperatorEnum::NCONTAINS => "ncontains",
-            EventRuleConditionsSubconditionsOperatorEnum::MATCHES => "matches",
-            EventRuleConditionsSubconditionsOperatorEnum::NMATCHES => "nmatches",
-            EventRuleConditionsSubconditionsOperatorEnum::UNKNOWN => "unknown",
+            EscalationTargetReferenceTypeEnum::USER => "user",
+            EscalationTargetReferenceTypeEnum::SCHEDULE => "schedule",
+            EscalationTargetReferenceTypeEnum::USER_REFERENCE => "user_reference",
+            EscalationTargetReferenceTypeEnum::SCHEDULE_REFERENCE => "schedule_reference",
+            EscalationTargetReferenceTypeEnum::UNKNOWN => "unknown",
         }
     }
 }
 
 // Pick the first enum var as default. Please report this as a bug if it's not correct.
-impl std::default::Default for EventRuleConditionsSubconditionsOperatorEnum {
+impl std::default::Default for EscalationTargetReferenceTypeEnum {
     fn default() -> Self {
-        EventRuleConditionsSubconditionsOperatorEnum::EXISTS
+        EscalationTargetReferenceTypeEnum::USER
     }
 }
 
-/// The parameters for performing the operation to populate the
 #[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
-pub struct EventRuleParameters {
-    /// The value for the operation. For example, an RE2 regular expression for regex-type variables.
-    #[serde(skip_serializing_if = "String::is_empty")]
-    pub value: String,
-    /// Path to a field in an event, in dot-notation. For Event Rules on a Service, this will have to be a PD-CEF field.
-    #[serde(skip_serializing_if = "String::is_empty")]
-    pub path: String,
+pub struct UpdateOrchestration {
+    pub orchestration: Orchestration,
 }
 
-/// Time-based conditions for limiting when the rule is active.
 #[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
-pub struct EventRuleTimeFrame {
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub active_between: Option<EventRuleTimeFrameActiveBetween>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub scheduled_weekly: Option<EventRuleTimeFrameScheduledWeekly>,
+pub struct PostOrchestration {
+    pub orchestration: Orchestration,
 }
 
-/// A fixed window of time during which the rule is active.
 #[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
-pub struct EventRuleTimeFrameActiveBetween {
-    /// The start time in milliseconds.
-    pub start_time: isize,
-    /// End time in milliseconds.
-    pub end_time: isize,
+pub struct EventRule {
+    /// Position/index of the Event Rule in the Ruleset.  Starting from position 0 (the first rule), rules are evaluated one-by-one until a matching rule is found.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub position: Option<isize>,
+    /// Indicates whether the Event Rule is the last Event Rule of the Ruleset that serves as a catch-all. It has limited functionality compared to other rules and always matches.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub catch_all: Option<bool>,
+    /// When an event matches this rule, the actions that will be taken to change the resulting alert and incident.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub actions: Option<Value>,
+}
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+pub struct EventRuleActions {
+    /// Set the service ID of the target service for the resulting alert. You can find the service you want to route to by calling the services endpoint.
+    #[serde(rename = "route")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub route: Option<HashMap<String, Value>>,
 }
 
-/// A reccuring window of time based on the day of the week, during which the rule is active.
+/// When an event matches this Event Rule, the actions that will be taken to change the resulting Alert and Incident.
 #[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
-pub struct EventRuleTimeFrameScheduledWeekly {
-    /// The amount of milliseconds into the day at which the window starts.
-    pub start_time: isize,
-    /// The duration of the window in milliseconds.
-    pub duration: isize,
-    /// The timezone.
-    #[serde(skip_serializing_if = "String::is_empty")]
-    pub timezone: String,
-    /// An array of day values. Ex [1, 3, 5] is Monday, Wednesday, Friday.
-    pub weekdays: Vec<i32>,
+pub struct EventRuleActionsCommon {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub annotate: Option<EventRuleActionsCommonAnnotate>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub event_action: Option<EventRuleActionsCommonEventAction>,
+    /// Dynamically extract values to set and modify new and existing PD-CEF fields.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub extractions: Option<Vec<EventRuleActionsCommonExtractionsItems>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub priority: Option<EventRuleActionsCommonPriority>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub severity: Option<EventRuleActionsCommonSeverity>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub suppress: Option<EventRuleActionsCommonSuppress>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub suspend: Option<EventRuleActionsCommonSuspend>,
+}
+
+#[allow(non_camel_case_types, clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum EventRuleActionsCommonExtractionsItems {
+    ExtractionsItems0 { target: String, source: String, regex: String },
+    ExtractionsItems1 { target: String, template: String },
+}
+
+impl Display for EventRuleActionsCommonExtractionsItems {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            EventRuleActionsCommonExtractionsItems::ExtractionsItems0 { target, source, regex } => write!(f, "ExtractionsItems0{{ {:?}, {:?}, {:?} }}", target, source, regex),
+            EventRuleActionsCommonExtractionsItems::ExtractionsItems1 { target, template } => write!(f, "ExtractionsItems1{{ {:?}, {:?} }}", target, template),
+        }
+    }
+}
+
+impl std::default::Default for EventRuleActionsCommonExtractionsItems {
+    fn default() -> Self {
+        EventRuleActionsCommonExtractionsItems::ExtractionsItems0 { target: String::default(), source: String::default(), regex: String::default() }
+    }
 }
 
+/// Set a note on the resulting incident.
 #[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
-pub struct EventRuleVariables {
-    /// The type of operation to populate the variable.
-    #[serde(rename = "type")]
-    pub _type: EventRuleVariablesTypeEnum,
-    /// The name of the variable.
+pub struct EventRuleActionsCommonAnnotate {
+    /// The content of the note.
     #[serde(skip_serializing_if = "String::is_empty")]
-    pub name: String,
-    pub parameters: EventRuleParameters,
+    pub value: String,
+}
+
+/// Set whether the resulting alert status is trigger or resolve.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+pub struct EventRuleActionsCommonEventAction {
+    pub value: EventRuleActionsCommonEventActionValueEnum,
 }
 
 #[allow(non_camel_case_types)]
 #[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize, Eq, Ord)]
-pub enum EventRuleVariablesTypeEnum {
-    #[serde(rename = "regex")]
-    REGEX,
+pub enum EventRuleActionsCommonEventActionValueEnum {
+    #[serde(rename = "trigger")]
+    TRIGGER,
+    #[serde(rename = "resolve")]
+    RESOLVE,
     #[serde(other)]
     UNKNOWN,
 }
 
-impl ::std::fmt::Display for EventRuleVariablesTypeEnum {
+impl ::std::fmt::Display for EventRuleActionsCommonEventActionValueEnum {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         match *self {
-            EventRuleVariablesTypeEnum::REGEX => write!(f, "regex"),
-            EventRuleVariablesTypeEnum::UNKNOWN => write!(f, "unknown"),
+            EventRuleActionsCommonEventActionValueEnum::TRIGGER => write!(f, "trigger"),
+            EventRuleActionsCommonEventActionValueEnum::RESOLVE => write!(f, "resolve"),
+            EventRuleActionsCommonEventActionValueEnum::UNKNOWN => write!(f, "unknown"),
         }
     }
 }
 
-impl ::std::str::FromStr for EventRuleVariablesTypeEnum {
+impl ::std::str::FromStr for EventRuleActionsCommonEventActionValueEnum {
     type Err = String;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         match s {
-            "regex" => Ok(EventRuleVariablesTypeEnum::REGEX),
+            "trigger" => Ok(EventRuleActionsCommonEventActionValueEnum::TRIGGER),
+            "resolve" => Ok(EventRuleActionsCommonEventActionValueEnum::RESOLVE),
             x => Err(format!("Invalid enum type: {}", x)),
         }
     }
 }
 
-impl ::std::convert::AsRef<str> for EventRuleVariablesTypeEnum {
+impl ::std::convert::AsRef<str> for EventRuleActionsCommonEventActionValueEnum {
     fn as_ref(&self) -> &str {
         match self {
-            EventRuleVariablesTypeEnum::REGEX => "regex",
-            EventRuleVariablesTypeEnum::UNKNOWN => "unknown",
+            EventRuleActionsCommonEventActionValueEnum::TRIGGER => "trigger",
+            EventRuleActionsCommonEventActionValueEnum::RESOLVE => "resolve",
+            EventRuleActionsCommonEventActionValueEnum::UNKNOWN => "unknown",
         }
     }
 }
 
 // Pick the first enum var as default. Please report this as a bug if it's not correct.
-impl std::default::Default for EventRuleVariablesTypeEnum {
+impl std::default::Default for EventRuleActionsCommonEventActionValueEnum {
     fn default() -> Self {
-        EventRuleVariablesTypeEnum::REGEX
+        EventRuleActionsCommonEventActionValueEnum::TRIGGER
     }
 }
 
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+/// Set the priority ID for the resulting incident. You can find the priority you want by calling the priorities endpoint.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
-pub struct Extension {
-    /// The label of the tag.
-    #[serde(default = "Extension::label_default")]
-    pub label: String,
-    /// a URL at which the entity is uniquely displayed in the Web app
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub html_url: Option<String>,
-    /// the API show URL at which the object is accessible
-    #[serde(rename = "self")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub _self: Option<String>,
-    /// A short-form, server-generated string that provides succinct, important information about an object suitable for primary labeling of an entity in a client. In many cases, this will be identical to `name`, though it is not intended to be an identifier.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub summary: Option<String>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub id: Option<String>,
-    /// The name of the extension.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub name: Option<String>,
-    /// The type of object being created.
-    #[serde(rename = "type")]
-    pub _type: ExtensionTypeEnum,
-    /// The url of the extension.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub endpoint_url: Option<String>,
-    /// The objects for which the extension applies
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub extension_objects: Option<Vec<Service>>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub extension_schema: Option<ExtensionSchema>,
-    /// Whether or not this extension is temporarily disabled; for example, a webhook extension that is repeatedly rejected by the server.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub temporarily_disabled: Option<bool>,
-    /// The object that contains extension configuration values depending on the extension schema specification.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub config: Option<HashMap<String, Value>>,
-}
-
-#[allow(clippy::derivable_impls)]
-impl Default for Extension {
-    fn default() -> Self {
-        Self {
-            label: String::from("Extension"),
-            html_url: Default::default(),
-            _self: Default::default(),
-            summary: Default::default(),
-            id: Default::default(),
-            name: Default::default(),
-            _type: Default::default(),
-            endpoint_url: Default::default(),
-            extension_objects: Default::default(),
-            extension_schema: Default::default(),
-            temporarily_disabled: Default::default(),
-            config: Default::default(),
-        }
-    }
+pub struct EventRuleActionsCommonPriority {
+    /// The priority ID.
+    #[serde(skip_serializing_if = "String::is_empty")]
+    pub value: String,
 }
 
-impl Extension {
-    #[allow(dead_code)]
-    fn label_default() -> String {
-        String::from("Extension")
-    }
-    #[allow(dead_code)]
-    fn _type_default() -> String {
-        String::from("extension")
-    }
+/// Set the severity of the resulting alert.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+pub struct EventRuleActionsCommonSeverity {
+    pub value: EventRuleActionsCommonSeverityValueEnum,
 }
 
 #[allow(non_camel_case_types)]
 #[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize, Eq, Ord)]
-pub enum ExtensionTypeEnum {
-    #[serde(rename = "extension")]
-    EXTENSION,
-    #[serde(rename = "extension_reference")]
-    EXTENSION_REFERENCE,
+pub enum EventRuleActionsCommonSeverityValueEnum {
+    #[serde(rename = "info")]
+    INFO,
+    #[serde(rename = "warning")]
+    WARNING,
+    #[serde(rename = "error")]
+    ERROR,
+    #[serde(rename = "critical")]
+    CRITICAL,
     #[serde(other)]
     UNKNOWN,
 }
 
-impl ::std::fmt::Display for ExtensionTypeEnum {
+impl ::std::fmt::Display for EventRuleActionsCommonSeverityValueEnum {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         match *self {
-            ExtensionTypeEnum::EXTENSION => write!(f, "extension"),
-            ExtensionTypeEnum::EXTENSION_REFERENCE => write!(f, "extension_reference"),
-            ExtensionTypeEnum::UNKNOWN => write!(f, "unknown"),
+            EventRuleActionsCommonSeverityValueEnum::INFO => write!(f, "info"),
+            EventRuleActionsCommonSeverityValueEnum::WARNING => write!(f, "warning"),
+            EventRuleActionsCommonSeverityValueEnum::ERROR => write!(f, "error"),
+            EventRuleActionsCommonSeverityValueEnum::CRITICAL => write!(f, "critical"),
+            EventRuleActionsCommonSeverityValueEnum::UNKNOWN => write!(f, "unknown"),
         }
     }
 }
 
-impl ::std::str::FromStr for ExtensionTypeEnum {
+impl ::std::str::FromStr for EventRuleActionsCommonSeverityValueEnum {
     type Err = String;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         match s {
-            "extension" => Ok(ExtensionTypeEnum::EXTENSION),
-            "extension_reference" => Ok(ExtensionTypeEnum::EXTENSION_REFERENCE),
+            "info" => Ok(EventRuleActionsCommonSeverityValueEnum::INFO),
+            "warning" => Ok(EventRuleActionsCommonSeverityValueEnum::WARNING),
+            "error" => Ok(EventRuleActionsCommonSeverityValueEnum::ERROR),
+            "critical" => Ok(EventRuleActionsCommonSeverityValueEnum::CRITICAL),
             x => Err(format!("Invalid enum type: {}", x)),
         }
     }
 }
 
-impl ::std::convert::AsRef<str> for ExtensionTypeEnum {
+impl ::std::convert::AsRef<str> for EventRuleActionsCommonSeverityValueEnum {
     fn as_ref(&self) -> &str {
         match self {
-            ExtensionTypeEnum::EXTENSION => "extension",
-            ExtensionTypeEnum::EXTENSION_REFERENCE => "extension_reference",
-            ExtensionTypeEnum::UNKNOWN => "unknown",
+            EventRuleActionsCommonSeverityValueEnum::INFO => "info",
+            EventRuleActionsCommonSeverityValueEnum::WARNING => "warning",
+            EventRuleActionsCommonSeverityValueEnum::ERROR => "error",
+            EventRuleActionsCommonSeverityValueEnum::CRITICAL => "critical",
+            EventRuleActionsCommonSeverityValueEnum::UNKNOWN => "unknown",
         }
     }
 }
 
 // Pick the first enum var as default. Please report this as a bug if it's not correct.
-impl std::default::Default for ExtensionTypeEnum {
+impl std::default::Default for EventRuleActionsCommonSeverityValueEnum {
     fn default() -> Self {
-        ExtensionTypeEnum::EXTENSION
+        EventRuleActionsCommonSeverityValueEnum::INFO
     }
 }
 
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+/// Set whether the resulting alert is suppressed. Can optionally be used with a threshold where resulting alerts will be suppressed until the threshold is met in a window of time. If using a threshold the rule must also set a route action.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
-pub struct ExtensionReference {
-    /// The label of the tag.
-    #[serde(default = "ExtensionReference::label_default")]
-    pub label: String,
-    /// a URL at which the entity is uniquely displayed in the Web app
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub html_url: Option<String>,
-    /// the API show URL at which the object is accessible
-    #[serde(rename = "self")]
+pub struct EventRuleActionsCommonSuppress {
+    pub value: bool,
+    /// The number of occurences needed during the window of time to trigger the theshold.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub _self: Option<String>,
-    /// A short-form, server-generated string that provides succinct, important information about an object suitable for primary labeling of an entity in a client. In many cases, this will be identical to `name`, though it is not intended to be an identifier.
+    pub threshold_value: Option<isize>,
+    /// The time unit for the window of time.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub summary: Option<String>,
+    pub threshold_time_unit: Option<EventRuleActionsCommonSuppressThresholdTimeUnitEnum>,
+    /// The amount of time units for the window of time.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub id: Option<String>,
-    #[serde(rename = "type")]
-    pub _type: ExtensionReferenceTypeEnum,
-}
-
-#[allow(clippy::derivable_impls)]
-impl Default for ExtensionReference {
-    fn default() -> Self {
-        Self {
-            label: String::from("ExtensionReference"),
-            html_url: Default::default(),
-            _self: Default::default(),
-            summary: Default::default(),
-            id: Default::default(),
-            _type: Default::default(),
-        }
-    }
-}
-
-impl ExtensionReference {
-    #[allow(dead_code)]
-    fn label_default() -> String {
-        String::from("ExtensionReference")
-    }
-    #[allow(dead_code)]
-    fn _type_default() -> String {
-        String::from("extension_reference")
-    }
+    pub threshold_time_amount: Option<isize>,
 }
 
 #[allow(non_camel_case_types)]
 #[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize, Eq, Ord)]
-pub enum ExtensionReferenceTypeEnum {
-    #[serde(rename = "extension_reference")]
-    EXTENSION_REFERENCE,
+pub enum EventRuleActionsCommonSuppressThresholdTimeUnitEnum {
+    #[serde(rename = "seconds")]
+    SECONDS,
+    #[serde(rename = "minutes")]
+    MINUTES,
+    #[serde(rename = "hours")]
+    HOURS,
     #[serde(other)]
     UNKNOWN,
 }
 
-impl ::std::fmt::Display for ExtensionReferenceTypeEnum {
+impl ::std::fmt::Display for EventRuleActionsCommonSuppressThresholdTimeUnitEnum {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         match *self {
-            ExtensionReferenceTypeEnum::EXTENSION_REFERENCE => write!(f, "extension_reference"),
-            ExtensionReferenceTypeEnum::UNKNOWN => write!(f, "unknown"),
+            EventRuleActionsCommonSuppressThresholdTimeUnitEnum::SECONDS => write!(f, "seconds"),
+            EventRuleActionsCommonSuppressThresholdTimeUnitEnum::MINUTES => write!(f, "minutes"),
+            EventRuleActionsCommonSuppressThresholdTimeUnitEnum::HOURS => write!(f, "hours"),
+            EventRuleActionsCommonSuppressThresholdTimeUnitEnum::UNKNOWN => write!(f, "unknown"),
         }
     }
 }
 
-impl ::std::str::FromStr for ExtensionReferenceTypeEnum {
+impl ::std::str::FromStr for EventRuleActionsCommonSuppressThresholdTimeUnitEnum {
     type Err = String;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         match s {
-            "extension_reference" => Ok(ExtensionReferenceTypeEnum::EXTENSION_REFERENCE),
+            "seconds" => Ok(EventRuleActionsCommonSuppressThresholdTimeUnitEnum::SECONDS),
+            "minutes" => Ok(EventRuleActionsCommonSuppressThresholdTimeUnitEnum::MINUTES),
+            "hours" => Ok(EventRuleActionsCommonSuppressThresholdTimeUnitEnum::HOURS),
             x => Err(format!("Invalid enum type: {}", x)),
         }
     }
 }
 
-impl ::std::convert::AsRef<str> for ExtensionReferenceTypeEnum {
+impl ::std::convert::AsRef<str> for EventRuleActionsCommonSuppressThresholdTimeUnitEnum {
     fn as_ref(&self) -> &str {
         match self {
-            ExtensionReferenceTypeEnum::EXTENSION_REFERENCE => "extension_reference",
-            ExtensionReferenceTypeEnum::UNKNOWN => "unknown",
+            EventRuleActionsCommonSuppressThresholdTimeUnitEnum::SECONDS => "seconds",
+            EventRuleActionsCommonSuppressThresholdTimeUnitEnum::MINUTES => "minutes",
+            EventRuleActionsCommonSuppressThresholdTimeUnitEnum::HOURS => "hours",
+            EventRuleActionsCommonSuppressThresholdTimeUnitEnum::UNKNOWN => "unknown",
         }
     }
 }
 
 // Pick the first enum var as default. Please report this as a bug if it's not correct.
-impl std::default::Default for ExtensionReferenceTypeEnum {
+impl std::default::Default for EventRuleActionsCommonSuppressThresholdTimeUnitEnum {
     fn default() -> Self {
-        ExtensionReferenceTypeEnum::EXTENSION_REFERENCE
+        EventRuleActionsCommonSuppressThresholdTimeUnitEnum::SECONDS
     }
 }
 
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+/// Set the length of time to suspend the resulting alert before triggering. Rules with a suspend action must also set a route action, and cannot have a suppress with threshold action
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
-pub struct ExtensionSchema {
-    /// A small logo, 18-by-18 pixels.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub icon_url: Option<String>,
-    /// A large logo, 75 pixels high and no more than 300 pixels wide.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub logo_url: Option<String>,
-    /// Human friendly display label
-    #[serde(default = "ExtensionSchema::label_default")]
-    pub label: String,
-    /// Machine friendly display label
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub key: Option<String>,
-    /// The long description for the Extension
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub description: Option<String>,
-    /// A link to the extension's support guide
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub guide_url: Option<String>,
-    /// The types of PagerDuty incident events that will activate this Extension
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub send_types: Option<Vec<String>>,
-    /// The url that the webhook payload will be sent to for this Extension.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub url: Option<String>,
-}
-
-#[allow(clippy::derivable_impls)]
-impl Default for ExtensionSchema {
-    fn default() -> Self {
-        Self {
-            icon_url: Default::default(),
-            logo_url: Default::default(),
-            label: String::from("ExtensionSchema"),
-            key: Default::default(),
-            description: Default::default(),
-            guide_url: Default::default(),
-            send_types: Default::default(),
-            url: Default::default(),
-        }
-    }
-}
-
-impl ExtensionSchema {
-    #[allow(dead_code)]
-    fn label_default() -> String {
-        String::from("ExtensionSchema")
-    }
+pub struct EventRuleActionsCommonSuspend {
+    /// The amount of time to suspend the alert in seconds.
+    pub value: isize,
 }
 
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+/// Conditions evaluated to check if an event matches this Event Rule. Is always empty for the catch_all rule, though.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
-pub struct ExtensionSchemaReference {
-    /// The label of the tag.
-    #[serde(default = "ExtensionSchemaReference::label_default")]
-    pub label: String,
-    /// a URL at which the entity is uniquely displayed in the Web app
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub html_url: Option<String>,
-    /// the API show URL at which the object is accessible
-    #[serde(rename = "self")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub _self: Option<String>,
-    /// A short-form, server-generated string that provides succinct, important information about an object suitable for primary labeling of an entity in a client. In many cases, this will be identical to `name`, though it is not intended to be an identifier.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub summary: Option<String>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub id: Option<String>,
-    #[serde(rename = "type")]
-    pub _type: ExtensionSchemaReferenceTypeEnum,
-}
-
-#[allow(clippy::derivable_impls)]
-impl Default for ExtensionSchemaReference {
-    fn default() -> Self {
-        Self {
-            label: String::from("ExtensionSchemaReference"),
-            html_url: Default::default(),
-            _self: Default::default(),
-            summary: Default::default(),
-            id: Default::default(),
-            _type: Default::default(),
-        }
-    }
-}
-
-impl ExtensionSchemaReference {
-    #[allow(dead_code)]
-    fn label_default() -> String {
-        String::from("ExtensionSchemaReference")
-    }
-    #[allow(dead_code)]
-    fn _type_default() -> String {
-        String::from("extension_schema_reference")
-    }
+pub struct EventRuleConditions {
+    /// Operator to combine sub-conditions.
+    pub operator: EventRuleConditionsOperatorEnum,
+    /// Array of sub-conditions.
+    pub subconditions: Vec<EventRuleConditionsSubconditions>,
 }
 
 #[allow(non_camel_case_types)]
 #[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize, Eq, Ord)]
-pub enum ExtensionSchemaReferenceTypeEnum {
-    #[serde(rename = "extension_schema_reference")]
-    EXTENSION_SCHEMA_REFERENCE,
+pub enum EventRuleConditionsOperatorEnum {
+    #[serde(rename = "and")]
+    AND,
+    #[serde(rename = "or")]
+    OR,
     #[serde(other)]
     UNKNOWN,
 }
 
-impl ::std::fmt::Display for ExtensionSchemaReferenceTypeEnum {
+impl ::std::fmt::Display for EventRuleConditionsOperatorEnum {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         match *self {
-            ExtensionSchemaReferenceTypeEnum::EXTENSION_SCHEMA_REFERENCE => write!(f, "extension_schema_reference"),
-            ExtensionSchemaReferenceTypeEnum::UNKNOWN => write!(f, "unknown"),
+            EventRuleConditionsOperatorEnum::AND => write!(f, "and"),
+            EventRuleConditionsOperatorEnum::OR => write!(f, "or"),
+            EventRuleConditionsOperatorEnum::UNKNOWN => write!(f, "unknown"),
         }
     }
 }
 
-impl ::std::str::FromStr for ExtensionSchemaReferenceTypeEnum {
+impl ::std::str::FromStr for EventRuleConditionsOperatorEnum {
     type Err = String;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         match s {
-            "extension_schema_reference" => Ok(ExtensionSchemaReferenceTypeEnum::EXTENSION_SCHEMA_REFERENCE),
+            "and" => Ok(EventRuleConditionsOperatorEnum::AND),
+            "or" => Ok(EventRuleConditionsOperatorEnum::OR),
             x => Err(format!("Invalid enum type: {}", x)),
         }
     }
 }
 
-impl ::std::convert::AsRef<str> for ExtensionSchemaReferenceTypeEnum {
+impl ::std::convert::AsRef<str> for EventRuleConditionsOperatorEnum {
     fn as_ref(&self) -> &str {
         match self {
-            ExtensionSchemaReferenceTypeEnum::EXTENSION_SCHEMA_REFERENCE => "extension_schema_reference",
-            ExtensionSchemaReferenceTypeEnum::UNKNOWN => "unknown",
+            EventRuleConditionsOperatorEnum::AND => "and",
+            EventRuleConditionsOperatorEnum::OR => "or",
+            EventRuleConditionsOperatorEnum::UNKNOWN => "unknown",
         }
     }
 }
 
 // Pick the first enum var as default. Please report this as a bug if it's not correct.
-impl std::default::Default for ExtensionSchemaReferenceTypeEnum {
+impl std::default::Default for EventRuleConditionsOperatorEnum {
     fn default() -> Self {
-        ExtensionSchemaReferenceTypeEnum::EXTENSION_SCHEMA_REFERENCE
+        EventRuleConditionsOperatorEnum::AND
     }
 }
 
 #[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
-pub struct CreateExtension {
-    pub extension: Extension,
-}
-
-#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
-#[allow(clippy::derive_partial_eq_without_eq)]
-pub struct UpdateExtension {
-    pub extension: Extension,
+pub struct EventRuleConditionsParameters {
+    /// Path to a field in an event, in dot-notation.  For Event Rules on a serivce, this will have to be a PD-CEF field.
+    #[serde(skip_serializing_if = "String::is_empty")]
+    pub path: String,
+    /// Value to apply to the operator.
+    #[serde(skip_serializing_if = "String::is_empty")]
+    pub value: String,
+    /// Options to configure the operator.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub options: Option<HashMap<String, Value>>,
 }
 
-/// A rule for contacting the user for Handoff Notifications.
 #[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
-pub struct HandoffNotificationRule {
-    #[serde(skip_serializing_if = "String::is_empty")]
-    pub id: String,
-    /// The delay before firing the rule, in minutes.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub notify_advance_in_minutes: Option<usize>,
-    /// The type of handoff being created.
-    pub handoff_type: HandoffNotificationRuleHandoffTypeEnum,
-    pub contact_method: ContactMethod,
+pub struct EventRuleConditionsSubconditions {
+    /// The type of operator to apply.
+    pub operator: EventRuleConditionsSubconditionsOperatorEnum,
+    pub parameters: EventRuleConditionsParameters,
 }
 
 #[allow(non_camel_case_types)]
 #[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize, Eq, Ord)]
-pub enum HandoffNotificationRuleHandoffTypeEnum {
-    #[serde(rename = "both")]
-    BOTH,
-    #[serde(rename = "oncall")]
-    ONCALL,
-    #[serde(rename = "offcall")]
-    OFFCALL,
+pub enum EventRuleConditionsSubconditionsOperatorEnum {
+    #[serde(rename = "exists")]
+    EXISTS,
+    #[serde(rename = "nexists")]
+    NEXISTS,
+    #[serde(rename = "equals")]
+    EQUALS,
+    #[serde(rename = "nequals")]
+    NEQUALS,
+    #[serde(rename = "contains")]
+    CONTAINS,
+    #[serde(rename = "ncontains")]
+    NCONTAINS,
+    #[serde(rename = "matches")]
+    MATCHES,
+    #[serde(rename = "nmatches")]
+    NMATCHES,
     #[serde(other)]
     UNKNOWN,
 }
 
-impl ::std::fmt::Display for HandoffNotificationRuleHandoffTypeEnum {
+impl ::std::fmt::Display for EventRuleConditionsSubconditionsOperatorEnum {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         match *self {
-            HandoffNotificationRuleHandoffTypeEnum::BOTH => write!(f, "both"),
-            HandoffNotificationRuleHandoffTypeEnum::ONCALL => write!(f, "oncall"),
-            HandoffNotificationRuleHandoffTypeEnum::OFFCALL => write!(f, "offcall"),
-            HandoffNotificationRuleHandoffTypeEnum::UNKNOWN => write!(f, "unknown"),
+            EventRuleConditionsSubconditionsOperatorEnum::EXISTS => write!(f, "exists"),
+            EventRuleConditionsSubconditionsOperatorEnum::NEXISTS => write!(f, "nexists"),
+            EventRuleConditionsSubconditionsOperatorEnum::EQUALS => write!(f, "equals"),
+            EventRuleConditionsSubconditionsOperatorEnum::NEQUALS => write!(f, "nequals"),
+            EventRuleConditionsSubconditionsOperatorEnum::CONTAINS => write!(f, "contains"),
+            EventRuleConditionsSubconditionsOperatorEnum::NCONTAINS => write!(f, "ncontains"),
+            EventRuleConditionsSubconditionsOperatorEnum::MATCHES => write!(f, "matches"),
+            EventRuleConditionsSubconditionsOperatorEnum::NMATCHES => write!(f, "nmatches"),
+            EventRuleConditionsSubconditionsOperatorEnum::UNKNOWN => write!(f, "unknown"),
         }
     }
 }
 
-impl ::std::str::FromStr for HandoffNotificationRuleHandoffTypeEnum {
+impl ::std::str::FromStr for EventRuleConditionsSubconditionsOperatorEnum {
     type Err = String;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         match s {
-            "both" => Ok(HandoffNotificationRuleHandoffTypeEnum::BOTH),
-            "oncall" => Ok(HandoffNotificationRuleHandoffTypeEnum::ONCALL),
-            "offcall" => Ok(HandoffNotificationRuleHandoffTypeEnum::OFFCALL),
+            "exists" => Ok(EventRuleConditionsSubconditionsOperatorEnum::EXISTS),
+            "nexists" => Ok(EventRuleConditionsSubconditionsOperatorEnum::NEXISTS),
+            "equals" => Ok(EventRuleConditionsSubconditionsOperatorEnum::EQUALS),
+            "nequals" => Ok(EventRuleConditionsSubconditionsOperatorEnum::NEQUALS),
+            "contains" => Ok(EventRuleConditionsSubconditionsOperatorEnum::CONTAINS),
+            "ncontains" => Ok(EventRuleConditionsSubconditionsOperatorEnum::NCONTAINS),
+            "matches" => Ok(EventRuleConditionsSubconditionsOperatorEnum::MATCHES),
+            "nmatches" => Ok(EventRuleConditionsSubconditionsOperatorEnum::NMATCHES),
             x => Err(format!("Invalid enum type: {}", x)),
         }
     }
 }
 
-impl ::std::convert::AsRef<str> for HandoffNotificationRuleHandoffTypeEnum {
+impl ::std::convert::AsRef<str> for EventRuleConditionsSubconditionsOperatorEnum {
     fn as_ref(&self) -> &str {
         match self {
-            HandoffNotificationRuleHandoffTypeEnum::BOTH => "both",
-            HandoffNotificationRuleHandoffTypeEnum::ONCALL => "oncall",
-            HandoffNotificationRuleHandoffTypeEnum::OFFCALL => "offcall",
-            HandoffNotificationRuleHandoffTypeEnum::UNKNOWN => "unknown",
+            EventRuleConditionsSubconditionsOperatorEnum::EXISTS => "exists",
+            EventRuleConditionsSubconditionsOperatorEnum::NEXISTS => "nexists",
+            EventRuleConditionsSubconditionsOperatorEnum::EQUALS => "equals",
+            EventRuleConditionsSubconditionsOperatorEnum::NEQUALS => "nequals",
+            EventRuleConditionsSubconditionsOperatorEnum::CONTAINS => "contains",
+            EventRuleConditionsSubconditionsOperatorEnum::NCONTAINS => "ncontains",
+            EventRuleConditionsSubconditionsOperatorEnum::MATCHES => "matches",
+            EventRuleConditionsSubconditionsOperatorEnum::NMATCHES => "nmatches",
+            EventRuleConditionsSubconditionsOperatorEnum::UNKNOWN => "unknown",
         }
     }
 }
 
 // Pick the first enum var as default. Please report this as a bug if it's not correct.
-impl std::default::Default for HandoffNotificationRuleHandoffTypeEnum {
+impl std::default::Default for EventRuleConditionsSubconditionsOperatorEnum {
     fn default() -> Self {
-        HandoffNotificationRuleHandoffTypeEnum::BOTH
+        EventRuleConditionsSubconditionsOperatorEnum::EXISTS
     }
 }
 
+/// The parameters for performing the operation to populate the
 #[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
-pub struct UpdateIncidentAlerts {
-    /// An array of alerts, including the parameters to update for each alert.
-    pub alerts: Vec<Alert>,
+pub struct EventRuleParameters {
+    /// The value for the operation. For example, an RE2 regular expression for regex-type variables.
+    #[serde(skip_serializing_if = "String::is_empty")]
+    pub value: String,
+    /// Path to a field in an event, in dot-notation. For Event Rules on a Service, this will have to be a PD-CEF field.
+    #[serde(skip_serializing_if = "String::is_empty")]
+    pub path: String,
 }
 
-/// Tags to add to or remove from the entity.
+/// Time-based conditions for limiting when the rule is active.
 #[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
-pub struct CreateEntityTypeByIdChangeTags {
-    /// Array of tags and/or tag references to add to the entity. For elements with type `tag_reference`, the tag with the corresponding `id` is added to the entity. For elements with type `tag`, if there is an existing tag with the given label that tag is added to the entity. If there is no existing tag with that label and the user has permission to create tags, a new tag is created with that label and assigned to the entity. 
+pub struct EventRuleTimeFrame {
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub add: Option<Vec<TagsToAdd>>,
-    /// Array of tag references to remove from the entity.
+    pub active_between: Option<EventRuleTimeFrameActiveBetween>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub remove: Option<Vec<TagsToRemove_>>,
+    pub scheduled_weekly: Option<EventRuleTimeFrameScheduledWeekly>,
 }
 
+/// A fixed window of time during which the rule is active.
 #[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
-pub struct UpdateLogEntryChannel {
-    pub channel: LogEntriesidchannelChannel,
+pub struct EventRuleTimeFrameActiveBetween {
+    /// The start time in milliseconds.
+    pub start_time: isize,
+    /// End time in milliseconds.
+    pub end_time: isize,
 }
 
+/// A reccuring window of time based on the day of the week, during which the rule is active.
 #[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
-pub struct CreateUserContactMethod {
-    pub contact_method: CreateUserContactMethodContactMethodEnum,
+pub struct EventRuleTimeFrameScheduledWeekly {
+    /// The amount of milliseconds into the day at which the window starts.
+    pub start_time: isize,
+    /// The duration of the window in milliseconds.
+    pub duration: isize,
+    /// The timezone.
+    #[serde(skip_serializing_if = "String::is_empty")]
+    pub timezone: String,
+    /// An array of day values. Ex [1, 3, 5] is Monday, Wednesday, Friday.
+    pub weekdays: Vec<i32>,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+pub struct EventRuleVariables {
+    /// The type of operation to populate the variable.
+    #[serde(rename = "type")]
+    pub _type: EventRuleVariablesTypeEnum,
+    /// The name of the variable.
+    #[serde(skip_serializing_if = "String::is_empty")]
+    pub name: String,
+    pub parameters: EventRuleParameters,
 }
 
 #[allow(non_camel_case_types)]
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
-#[serde(untagged)]
-pub enum CreateUserContactMethodContactMethodEnum {
-    PHONE_CONTACT_METHOD(PhoneContactMethod),
-    PUSH_CONTACT_METHOD(PushContactMethod),
-    EMAIL_CONTACT_METHOD(EmailContactMethod),
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize, Eq, Ord)]
+pub enum EventRuleVariablesTypeEnum {
+    #[serde(rename = "regex")]
+    REGEX,
+    #[serde(other)]
+    UNKNOWN,
 }
 
-impl Display for CreateUserContactMethodContactMethodEnum {
-    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
-        match self {
-            CreateUserContactMethodContactMethodEnum::PHONE_CONTACT_METHOD(value) => write!(f, "{:?}", value),
-            CreateUserContactMethodContactMethodEnum::PUSH_CONTACT_METHOD(value) => write!(f, "{:?}", value),
-            CreateUserContactMethodContactMethodEnum::EMAIL_CONTACT_METHOD(value) => write!(f, "{:?}", value),
+impl ::std::fmt::Display for EventRuleVariablesTypeEnum {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        match *self {
+            EventRuleVariablesTypeEnum::REGEX => write!(f, "regex"),
+            EventRuleVariablesTypeEnum::UNKNOWN => write!(f, "unknown"),
         }
     }
 }
 
-impl std::default::Default for CreateUserContactMethodContactMethodEnum {
-    fn default() -> Self {
-        CreateUserContactMethodContactMethodEnum::PHONE_CONTACT_METHOD(PhoneContactMethod::default())
+impl ::std::str::FromStr for EventRuleVariablesTypeEnum {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "regex" => Ok(EventRuleVariablesTypeEnum::REGEX),
+            x => Err(format!("Invalid enum type: {}", x)),
+        }
     }
 }
 
-#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
-#[allow(clippy::derive_partial_eq_without_eq)]
-pub struct CreateServiceIntegration {
-    pub integration: Integration,
+impl ::std::convert::AsRef<str> for EventRuleVariablesTypeEnum {
+    fn as_ref(&self) -> &str {
+        match self {
+            EventRuleVariablesTypeEnum::REGEX => "regex",
+            EventRuleVariablesTypeEnum::UNKNOWN => "unknown",
+        }
+    }
 }
 
-#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
-#[allow(clippy::derive_partial_eq_without_eq)]
-pub struct MergeIncidents {
-    /// The source incidents that will be merged into the target incident and resolved.
-    pub source_incidents: Vec<IncidentReference>,
+// Pick the first enum var as default. Please report this as a bug if it's not correct.
+impl std::default::Default for EventRuleVariablesTypeEnum {
+    fn default() -> Self {
+        EventRuleVariablesTypeEnum::REGEX
+    }
 }
 
-#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
-pub struct CreateIncidentNote {
-    pub note: IncidentsidnotesNote,
+pub struct Extension {
+    /// The label of the tag.
+    #[serde(default = "Extension::label_default")]
+    pub label: String,
+    /// a URL at which the entity is uniquely displayed in the Web app
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub html_url: Option<String>,
+    /// the API show URL at which the object is accessible
+    #[serde(rename = "self")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub _self: Option<String>,
+    /// A short-form, server-generated string that provides succinct, important information about an object suitable for primary labeling of an entity in a client. In many cases, this will be identical to `name`, though it is not intended to be an identifier.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub summary: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// The name of the extension.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// The type of object being created.
+    #[serde(rename = "type")]
+    pub _type: ExtensionTypeEnum,
+    /// The url of the extension.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub endpoint_url: Option<String>,
+    /// The objects for which the extension applies
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub extension_objects: Option<Vec<Service>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub extension_schema: Option<ExtensionSchema>,
+    /// Whether or not this extension is temporarily disabled; for example, a webhook extension that is repeatedly rejected by the server.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub temporarily_disabled: Option<bool>,
+    /// The object that contains extension configuration values depending on the extension schema specification.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub config: Option<HashMap<String, Value>>,
 }
 
-#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
-#[allow(clippy::derive_partial_eq_without_eq)]
-pub struct CreateUserNotificationRule {
-    pub notification_rule: NotificationRule,
+#[allow(clippy::derivable_impls)]
+impl Default for Extension {
+    fn default() -> Self {
+        Self {
+            label: String::from("Extension"),
+            html_url: Default::default(),
+            _self: Default::default(),
+            summary: Default::default(),
+            id: Default::default(),
+            name: Default::default(),
+            _type: Default::default(),
+            endpoint_url: Default::default(),
+            extension_objects: Default::default(),
+            extension_schema: Default::default(),
+            temporarily_disabled: Default::default(),
+            config: Default::default(),
+        }
+    }
 }
 
-#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
-#[allow(clippy::derive_partial_eq_without_eq)]
-pub struct CreateTeamNotificationSubscriptions {
-    pub subscribables: Vec<NotificationSubscribable>,
+impl Extension {
+    #[allow(dead_code)]
+    fn label_default() -> String {
+        String::from("Extension")
+    }
+    #[allow(dead_code)]
+    fn _type_default() -> String {
+        String::from("extension")
+    }
 }
 
-#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
-#[allow(clippy::derive_partial_eq_without_eq)]
-pub struct CreateUserNotificationSubscriptions {
-    pub subscribables: Vec<NotificationSubscribable>,
+#[allow(non_camel_case_types)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize, Eq, Ord)]
+pub enum ExtensionTypeEnum {
+    #[serde(rename = "extension")]
+    EXTENSION,
+    #[serde(rename = "extension_reference")]
+    EXTENSION_REFERENCE,
+    #[serde(other)]
+    UNKNOWN,
 }
 
-#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
-#[allow(clippy::derive_partial_eq_without_eq)]
-pub struct CreateUserHandoffNotificationRule {
-    pub oncall_handoff_notification_rule: HandoffNotificationRule,
+impl ::std::fmt::Display for ExtensionTypeEnum {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        match *self {
+            ExtensionTypeEnum::EXTENSION => write!(f, "extension"),
+            ExtensionTypeEnum::EXTENSION_REFERENCE => write!(f, "extension_reference"),
+            ExtensionTypeEnum::UNKNOWN => write!(f, "unknown"),
+        }
+    }
 }
 
-#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
-#[allow(clippy::derive_partial_eq_without_eq)]
-pub struct CreateScheduleOverride {
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub overrides: Option<Vec<ModelOverride>>,
+impl ::std::str::FromStr for ExtensionTypeEnum {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "extension" => Ok(ExtensionTypeEnum::EXTENSION),
+            "extension_reference" => Ok(ExtensionTypeEnum::EXTENSION_REFERENCE),
+            x => Err(format!("Invalid enum type: {}", x)),
+        }
+    }
 }
 
-#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
-#[allow(clippy::derive_partial_eq_without_eq)]
-pub struct CreateIncidentResponderRequest {
-    /// The user id of the requester.
-    #[serde(skip_serializing_if = "String::is_empty")]
-    pub requester_id: String,
-    /// The message sent with the responder request.
-    #[serde(skip_serializing_if = "String::is_empty")]
-    pub message: String,
-    /// The array of targets the responder request is sent to.
-    pub responder_request_targets: Vec<ResponderRequestTargetReference>,
+impl ::std::convert::AsRef<str> for ExtensionTypeEnum {
+    fn as_ref(&self) -> &str {
+        match self {
+            ExtensionTypeEnum::EXTENSION => "extension",
+            ExtensionTypeEnum::EXTENSION_REFERENCE => "extension_reference",
+            ExtensionTypeEnum::UNKNOWN => "unknown",
+        }
+    }
 }
 
-#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
-#[allow(clippy::derive_partial_eq_without_eq)]
-pub struct CreateRulesetEventRule {
-    pub rule: EventRule,
+// Pick the first enum var as default. Please report this as a bug if it's not correct.
+impl std::default::Default for ExtensionTypeEnum {
+    fn default() -> Self {
+        ExtensionTypeEnum::EXTENSION
+    }
 }
 
-#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
-pub struct CreateServiceEventRule {
-    pub rule: ServiceEventRule,
+pub struct ExtensionReference {
+    /// The label of the tag.
+    #[serde(default = "ExtensionReference::label_default")]
+    pub label: String,
+    /// a URL at which the entity is uniquely displayed in the Web app
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub html_url: Option<String>,
+    /// the API show URL at which the object is accessible
+    #[serde(rename = "self")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub _self: Option<String>,
+    /// A short-form, server-generated string that provides succinct, important information about an object suitable for primary labeling of an entity in a client. In many cases, this will be identical to `name`, though it is not intended to be an identifier.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub summary: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    #[serde(rename = "type")]
+    pub _type: ExtensionReferenceTypeEnum,
 }
 
-#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
-#[allow(clippy::derive_partial_eq_without_eq)]
-pub struct CreateIncidentSnooze {
-    /// The number of seconds to snooze the incident for. After this number of seconds has elapsed, the incident will return to the \"triggered\" state.
-    pub duration: isize,
+#[allow(clippy::derivable_impls)]
+impl Default for ExtensionReference {
+    fn default() -> Self {
+        Self {
+            label: String::from("ExtensionReference"),
+            html_url: Default::default(),
+            _self: Default::default(),
+            summary: Default::default(),
+            id: Default::default(),
+            _type: Default::default(),
+        }
+    }
 }
 
-#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
-#[allow(clippy::derive_partial_eq_without_eq)]
-pub struct CreateUserStatusUpdateNotificationRule {
-    pub status_update_notification_rule: StatusUpdateNotificationRule,
+impl ExtensionReference {
+    #[allow(dead_code)]
+    fn label_default() -> String {
+        String::from("ExtensionReference")
+    }
+    #[allow(dead_code)]
+    fn _type_default() -> String {
+        String::from("extension_reference")
+    }
 }
 
-#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[allow(non_camel_case_types)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize, Eq, Ord)]
+pub enum ExtensionReferenceTypeEnum {
+    #[serde(rename = "extension_reference")]
+    EXTENSION_REFERENCE,
+    #[serde(other)]
+    UNKNOWN,
+}
+
+impl ::std::fmt::Display for ExtensionReferenceTypeEnum {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        match *self {
+            ExtensionReferenceTypeEnum::EXTENSION_REFERENCE => write!(f, "extension_reference"),
+            ExtensionReferenceTypeEnum::UNKNOWN => write!(f, "unknown"),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ExtensionReferenceTypeEnum {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "extension_reference" => Ok(ExtensionReferenceTypeEnum::EXTENSION_REFERENCE),
+            x => Err(format!("Invalid enum type: {}", x)),
+        }
+    }
+}
+
+impl ::std::convert::AsRef<str> for ExtensionReferenceTypeEnum {
+    fn as_ref(&self) -> &str {
+        match self {
+            ExtensionReferenceTypeEnum::EXTENSION_REFERENCE => "extension_reference",
+            ExtensionReferenceTypeEnum::UNKNOWN => "unknown",
+        }
+    }
+}
+
+// Pick the first enum var as default. Please report this as a bug if it's not correct.
+impl std::default::Default for ExtensionReferenceTypeEnum {
+    fn default() -> Self {
+        ExtensionReferenceTypeEnum::EXTENSION_REFERENCE
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
-pub struct CreateIncidentStatusUpdate {
-    /// The message to be posted as a status update.
-    #[serde(skip_serializing_if = "String::is_empty")]
-    pub message: String,
-    /// The subject to be sent for the custom html email status update. Required if sending custom html email.
+pub struct ExtensionSchema {
+    /// A small logo, 18-by-18 pixels.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub subject: Option<String>,
-    /// The html content to be sent for the custom html email status update. Required if sending custom html email.
+    pub icon_url: Option<String>,
+    /// A large logo, 75 pixels high and no more than 300 pixels wide.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub html_message: Option<String>,
+    pub logo_url: Option<String>,
+    /// Human friendly display label
+    #[serde(default = "ExtensionSchema::label_default")]
+    pub label: String,
+    /// Machine friendly display label
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub key: Option<String>,
+    /// The long description for the Extension
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// A link to the extension's support guide
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub guide_url: Option<String>,
+    /// The types of PagerDuty incident events that will activate this Extension
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub send_types: Option<Vec<String>>,
+    /// The url that the webhook payload will be sent to for this Extension.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
 }
 
-#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
-#[allow(clippy::derive_partial_eq_without_eq)]
-pub struct CreateBusinessServiceNotificationSubscribers {
-    pub subscribers: Vec<NotificationSubscriber>,
+#[allow(clippy::derivable_impls)]
+impl Default for ExtensionSchema {
+    fn default() -> Self {
+        Self {
+            icon_url: Default::default(),
+            logo_url: Default::default(),
+            label: String::from("ExtensionSchema"),
+            key: Default::default(),
+            description: Default::default(),
+            guide_url: Default::default(),
+            send_types: Default::default(),
+            url: Default::default(),
+        }
+    }
 }
 
-#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
-#[allow(clippy::derive_partial_eq_without_eq)]
-pub struct RemoveBusinessServiceNotificationSubscriber {
-    pub subscribers: Vec<NotificationSubscriber>,
+impl ExtensionSchema {
+    #[allow(dead_code)]
+    fn label_default() -> String {
+        String::from("ExtensionSchema")
+    }
 }
 
-#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
-pub struct Impact {
+pub struct ExtensionSchemaReference {
+    /// The label of the tag.
+    #[serde(default = "ExtensionSchemaReference::label_default")]
+    pub label: String,
+    /// a URL at which the entity is uniquely displayed in the Web app
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub id: Option<String>,
+    pub html_url: Option<String>,
+    /// the API show URL at which the object is accessible
+    #[serde(rename = "self")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub name: Option<String>,
-    /// The kind of object that has been impacted
-    #[serde(rename = "type")]
-    pub _type: ImpactTypeEnum,
-    /// The current impact status of the object
+    pub _self: Option<String>,
+    /// A short-form, server-generated string that provides succinct, important information about an object suitable for primary labeling of an entity in a client. In many cases, this will be identical to `name`, though it is not intended to be an identifier.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<ImpactStatusEnum>,
+    pub summary: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub additional_fields: Option<ImpactAdditionalFields>,
+    pub id: Option<String>,
+    #[serde(rename = "type")]
+    pub _type: ExtensionSchemaReferenceTypeEnum,
+}
+
+#[allow(clippy::derivable_impls)]
+impl Default for ExtensionSchemaReference {
+    fn default() -> Self {
+        Self {
+            label: String::from("ExtensionSchemaReference"),
+            html_url: Default::default(),
+            _self: Default::default(),
+            summary: Default::default(),
+            id: Default::default(),
+            _type: Default::default(),
+        }
+    }
+}
+
+impl ExtensionSchemaReference {
+    #[allow(dead_code)]
+    fn label_default() -> String {
+        String::from("ExtensionSchemaReference")
+    }
+    #[allow(dead_code)]
+    fn _type_default() -> String {
+        String::from("extension_schema_reference")
+    }
+}
+
+#[allow(non_camel_case_types)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize, Eq, Ord)]
+pub enum ExtensionSchemaReferenceTypeEnum {
+    #[serde(rename = "extension_schema_reference")]
+    EXTENSION_SCHEMA_REFERENCE,
+    #[serde(other)]
+    UNKNOWN,
+}
+
+impl ::std::fmt::Display for ExtensionSchemaReferenceTypeEnum {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        match *self {
+            ExtensionSchemaReferenceTypeEnum::EXTENSION_SCHEMA_REFERENCE => write!(f, "extension_schema_reference"),
+            ExtensionSchemaReferenceTypeEnum::UNKNOWN => write!(f, "unknown"),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ExtensionSchemaReferenceTypeEnum {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "extension_schema_reference" => Ok(ExtensionSchemaReferenceTypeEnum::EXTENSION_SCHEMA_REFERENCE),
+            x => Err(format!("Invalid enum type: {}", x)),
+        }
+    }
+}
+
+impl ::std::convert::AsRef<str> for ExtensionSchemaReferenceTypeEnum {
+    fn as_ref(&self) -> &str {
+        match self {
+            ExtensionSchemaReferenceTypeEnum::EXTENSION_SCHEMA_REFERENCE => "extension_schema_reference",
+            ExtensionSchemaReferenceTypeEnum::UNKNOWN => "unknown",
+        }
+    }
+}
+
+// Pick the first enum var as default. Please report this as a bug if it's not correct.
+impl std::default::Default for ExtensionSchemaReferenceTypeEnum {
+    fn default() -> Self {
+        ExtensionSchemaReferenceTypeEnum::EXTENSION_SCHEMA_REFERENCE
+    }
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+pub struct CreateExtension {
+    pub extension: Extension,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+pub struct UpdateExtension {
+    pub extension: Extension,
+}
+
+/// A rule for contacting the user for Handoff Notifications.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+pub struct HandoffNotificationRule {
+    #[serde(skip_serializing_if = "String::is_empty")]
+    pub id: String,
+    /// The delay before firing the rule, in minutes.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub notify_advance_in_minutes: Option<usize>,
+    /// The type of handoff being created.
+    pub handoff_type: HandoffNotificationRuleHandoffTypeEnum,
+    pub contact_method: ContactMethod,
+}
+
+#[allow(non_camel_case_types)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize, Eq, Ord)]
+pub enum HandoffNotificationRuleHandoffTypeEnum {
+    #[serde(rename = "both")]
+    BOTH,
+    #[serde(rename = "oncall")]
+    ONCALL,
+    #[serde(rename = "offcall")]
+    OFFCALL,
+    #[serde(other)]
+    UNKNOWN,
+}
+
+impl ::std::fmt::Display for HandoffNotificationRuleHandoffTypeEnum {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        match *self {
+            HandoffNotificationRuleHandoffTypeEnum::BOTH => write!(f, "both"),
+            HandoffNotificationRuleHandoffTypeEnum::ONCALL => write!(f, "oncall"),
+            HandoffNotificationRuleHandoffTypeEnum::OFFCALL => write!(f, "offcall"),
+            HandoffNotificationRuleHandoffTypeEnum::UNKNOWN => write!(f, "unknown"),
+        }
+    }
+}
+
+impl ::std::str::FromStr for HandoffNotificationRuleHandoffTypeEnum {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "both" => Ok(HandoffNotificationRuleHandoffTypeEnum::BOTH),
+            "oncall" => Ok(HandoffNotificationRuleHandoffTypeEnum::ONCALL),
+            "offcall" => Ok(HandoffNotificationRuleHandoffTypeEnum::OFFCALL),
+            x => Err(format!("Invalid enum type: {}", x)),
+        }
+    }
+}
+
+impl ::std::convert::AsRef<str> for HandoffNotificationRuleHandoffTypeEnum {
+    fn as_ref(&self) -> &str {
+        match self {
+            HandoffNotificationRuleHandoffTypeEnum::BOTH => "both",
+            HandoffNotificationRuleHandoffTypeEnum::ONCALL => "oncall",
+            HandoffNotificationRuleHandoffTypeEnum::OFFCALL => "offcall",
+            HandoffNotificationRuleHandoffTypeEnum::UNKNOWN => "unknown",
+        }
+    }
+}
+
+// Pick the first enum var as default. Please report this as a bug if it's not correct.
+impl std::default::Default for HandoffNotificationRuleHandoffTypeEnum {
+    fn default() -> Self {
+        HandoffNotificationRuleHandoffTypeEnum::BOTH
+    }
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+pub struct UpdateIncidentAlerts {
+    /// An array of alerts, including the parameters to update for each alert.
+    pub alerts: Vec<Alert>,
+}
+
+/// Tags to add to or remove from the entity.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+pub struct CreateEntityTypeByIdChangeTags {
+    /// Array of tags and/or tag references to add to the entity. For elements with type `tag_reference`, the tag with the corresponding `id` is added to the entity. For elements with type `tag`, if there is an existing tag with the given label that tag is added to the entity. If there is no existing tag with that label and the user has permission to create tags, a new tag is created with that label and assigned to the entity. 
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub add: Option<Vec<TagsToAdd>>,
+    /// Array of tag references to remove from the entity.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub remove: Option<Vec<TagsToRemove_>>,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+pub struct UpdateLogEntryChannel {
+    pub channel: LogEntriesidchannelChannel,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+pub struct CreateUserContactMethod {
+    pub contact_method: CreateUserContactMethodContactMethodEnum,
+}
+
+#[allow(non_camel_case_types)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum CreateUserContactMethodContactMethodEnum {
+    PHONE_CONTACT_METHOD(PhoneContactMethod),
+    PUSH_CONTACT_METHOD(PushContactMethod),
+    EMAIL_CONTACT_METHOD(EmailContactMethod),
+}
+
+impl Display for CreateUserContactMethodContactMethodEnum {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            CreateUserContactMethodContactMethodEnum::PHONE_CONTACT_METHOD(value) => write!(f, "{:?}", value),
+            CreateUserContactMethodContactMethodEnum::PUSH_CONTACT_METHOD(value) => write!(f, "{:?}", value),
+            CreateUserContactMethodContactMethodEnum::EMAIL_CONTACT_METHOD(value) => write!(f, "{:?}", value),
+        }
+    }
+}
+
+impl std::default::Default for CreateUserContactMethodContactMethodEnum {
+    fn default() -> Self {
+        CreateUserContactMethodContactMethodEnum::PHONE_CONTACT_METHOD(PhoneContactMethod::default())
+    }
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+pub struct CreateServiceIntegration {
+    pub integration: Integration,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+pub struct MergeIncidents {
+    /// The source incidents that will be merged into the target incident and resolved.
+    pub source_incidents: Vec<IncidentReference>,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+pub struct CreateIncidentNote {
+    pub note: IncidentsidnotesNote,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+pub struct CreateUserNotificationRule {
+    pub notification_rule: NotificationRule,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+pub struct CreateTeamNotificationSubscriptions {
+    pub subscribables: Vec<NotificationSubscribable>,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+pub struct CreateUserNotificationSubscriptions {
+    pub subscribables: Vec<NotificationSubscribable>,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+pub struct CreateUserHandoffNotificationRule {
+    pub oncall_handoff_notification_rule: HandoffNotificationRule,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+pub struct CreateScheduleOverride {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub overrides: Option<Vec<ModelOverride>>,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+pub struct CreateIncidentResponderRequest {
+    /// The user id of the requester.
+    #[serde(skip_serializing_if = "String::is_empty")]
+    pub requester_id: String,
+    /// The message sent with the responder request.
+    #[serde(skip_serializing_if = "String::is_empty")]
+    pub message: String,
+    /// The array of targets the responder request is sent to.
+    pub responder_request_targets: Vec<ResponderRequestTargetReference>,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+pub struct CreateRulesetEventRule {
+    pub rule: EventRule,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+pub struct CreateServiceEventRule {
+    pub rule: ServiceEventRule,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+pub struct CreateIncidentSnooze {
+    /// The number of seconds to snooze the incident for. After this number of seconds has elapsed, the incident will return to the \"triggered\" state.
+    pub duration: isize,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+pub struct CreateUserStatusUpdateNotificationRule {
+    pub status_update_notification_rule: StatusUpdateNotificationRule,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+pub struct CreateIncidentStatusUpdate {
+    /// The message to be posted as a status update.
+    #[serde(skip_serializing_if = "String::is_empty")]
+    pub message: String,
+    /// The subject to be sent for the custom html email status update. Required if sending custom html email.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub subject: Option<String>,
+    /// The html content to be sent for the custom html email status update. Required if sending custom html email.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub html_message: Option<String>,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+pub struct CreateBusinessServiceNotificationSubscribers {
+    pub subscribers: Vec<NotificationSubscriber>,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+pub struct RemoveBusinessServiceNotificationSubscriber {
+    pub subscribers: Vec<NotificationSubscriber>,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+pub struct Impact {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// The kind of object that has been impacted
+    #[serde(rename = "type")]
+    pub _type: ImpactTypeEnum,
+    /// The current impact status of the object
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<ImpactStatusEnum>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub additional_fields: Option<ImpactAdditionalFields>,
+}
+
+#[allow(non_camel_case_types)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize, Eq, Ord)]
+pub enum ImpactTypeEnum {
+    #[serde(rename = "business_service")]
+    BUSINESS_SERVICE,
+    #[serde(other)]
+    UNKNOWN,
+}
+
+impl ::std::fmt::Display for ImpactTypeEnum {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        match *self {
+            ImpactTypeEnum::BUSINESS_SERVICE => write!(f, "business_service"),
+            ImpactTypeEnum::UNKNOWN => write!(f, "unknown"),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ImpactTypeEnum {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "business_service" => Ok(ImpactTypeEnum::BUSINESS_SERVICE),
+            x => Err(format!("Invalid enum type: {}", x)),
+        }
+    }
+}
+
+impl ::std::convert::AsRef<str> for ImpactTypeEnum {
+    fn as_ref(&self) -> &str {
+        match self {
+            ImpactTypeEnum::BUSINESS_SERVICE => "business_service",
+            ImpactTypeEnum::UNKNOWN => "unknown",
+        }
+    }
+}
+
+// Pick the first enum var as default. Please report this as a bug if it's not correct.
+impl std::default::Default for ImpactTypeEnum {
+    fn default() -> Self {
+        ImpactTypeEnum::BUSINESS_SERVICE
+    }
+}
+
+#[allow(non_camel_case_types)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize, Eq, Ord)]
+pub enum ImpactStatusEnum {
+    #[serde(rename = "impacted")]
+    IMPACTED,
+    #[serde(rename = "not_impacted")]
+    NOT_IMPACTED,
+    #[serde(other)]
+    UNKNOWN,
+}
+
+impl ::std::fmt::Display for ImpactStatusEnum {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        match *self {
+            ImpactStatusEnum::IMPACTED => write!(f, "impacted"),
+            ImpactStatusEnum::NOT_IMPACTED => write!(f, "not_impacted"),
+            ImpactStatusEnum::UNKNOWN => write!(f, "unknown"),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ImpactStatusEnum {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "impacted" => Ok(ImpactStatusEnum::IMPACTED),
+            "not_impacted" => Ok(ImpactStatusEnum::NOT_IMPACTED),
+            x => Err(format!("Invalid enum type: {}", x)),
+        }
+    }
+}
+
+impl ::std::convert::AsRef<str> for ImpactStatusEnum {
+    fn as_ref(&self) -> &str {
+        match self {
+            ImpactStatusEnum::IMPACTED => "impacted",
+            ImpactStatusEnum::NOT_IMPACTED => "not_impacted",
+            ImpactStatusEnum::UNKNOWN => "unknown",
+        }
+    }
+}
+
+// Pick the first enum var as default. Please report this as a bug if it's not correct.
+impl std::default::Default for ImpactStatusEnum {
+    fn default() -> Self {
+        ImpactStatusEnum::IMPACTED
+    }
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+pub struct ImpactAdditionalFields {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub highest_impacting_priority: Option<ImpactAdditionalFieldsHighestImpactingPriority>,
+}
+
+/// Priority information for the highest priority level that is affecting the impacted object.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+pub struct ImpactAdditionalFieldsHighestImpactingPriority {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub order: Option<isize>,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+pub struct Impactor {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// The kind of object that is impacting
+    #[serde(rename = "type")]
+    pub _type: ImpactorTypeEnum,
+}
+
+#[allow(non_camel_case_types)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize, Eq, Ord)]
+pub enum ImpactorTypeEnum {
+    #[serde(rename = "incident")]
+    INCIDENT,
+    #[serde(other)]
+    UNKNOWN,
+}
+
+impl ::std::fmt::Display for ImpactorTypeEnum {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        match *self {
+            ImpactorTypeEnum::INCIDENT => write!(f, "incident"),
+            ImpactorTypeEnum::UNKNOWN => write!(f, "unknown"),
+        }
+    }
+}
+
+impl ::std::str::FromStr for ImpactorTypeEnum {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "incident" => Ok(ImpactorTypeEnum::INCIDENT),
+            x => Err(format!("Invalid enum type: {}", x)),
+        }
+    }
+}
+
+impl ::std::convert::AsRef<str> for ImpactorTypeEnum {
+    fn as_ref(&self) -> &str {
+        match self {
+            ImpactorTypeEnum::INCIDENT => "incident",
+            ImpactorTypeEnum::UNKNOWN => "unknown",
+        }
+    }
+}
+
+// Pick the first enum var as default. Please report this as a bug if it's not correct.
+impl std::default::Default for ImpactorTypeEnum {
+    fn default() -> Self {
+        ImpactorTypeEnum::INCIDENT
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+pub struct Incident {
+    /// The label of the tag.
+    #[serde(default = "Incident::label_default")]
+    pub label: String,
+    /// a URL at which the entity is uniquely displayed in the Web app
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub html_url: Option<String>,
+    /// the API show URL at which the object is accessible
+    #[serde(rename = "self")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub _self: Option<String>,
+    /// A string that determines the schema of the object. This must be the standard name for the entity, suffixed by `_reference` if the object is a reference.
+    #[serde(rename = "type")]
+    #[serde(default = "Incident::_type_default")]
+    pub _type: String,
+    /// A short-form, server-generated string that provides succinct, important information about an object suitable for primary labeling of an entity in a client. In many cases, this will be identical to `name`, though it is not intended to be an identifier.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub summary: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// The number of the incident. This is unique across your account.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub incident_number: Option<isize>,
+    /// The date/time the incident was first triggered.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<DateTime<FixedOffset>>,
+    /// The current status of the incident.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<IncidentStatusEnum>,
+    /// A succinct description of the nature, symptoms, cause, or effect of the incident.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    /// The list of pending_actions on the incident. A pending_action object contains a type of action which can be escalate, unacknowledge, resolve or urgency_change. A pending_action object contains at, the time at which the action will take place. An urgency_change pending_action will contain to, the urgency that the incident will change to.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pending_actions: Option<Vec<IncidentAction>>,
+    /// The incident's de-duplication key.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub incident_key: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub service: Option<Service>,
+    /// List of all assignments for this incident. This list will be empty if the `Incident.status` is `resolved`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub assignments: Option<Vec<Assignment>>,
+    /// How the current incident assignments were decided.  Note that `direct_assignment` incidents will not escalate up the attached `escalation_policy`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub assigned_via: Option<IncidentAssignedViaEnum>,
+    /// List of all acknowledgements for this incident. This list will be empty if the `Incident.status` is `resolved` or `triggered`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub acknowledgements: Option<Vec<Acknowledgement>>,
+    /// The time at which the status of the incident last changed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_status_change_at: Option<DateTime<FixedOffset>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_status_change_by: Option<AgentReference>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub first_trigger_log_entry: Option<LogEntryReference>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub escalation_policy: Option<EscalationPolicy>,
+    /// The teams involved in the incident’s lifecycle.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub teams: Option<Vec<Team>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub priority: Option<Priority>,
+    /// The current urgency of the incident.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub urgency: Option<IncidentUrgencyEnum>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub resolve_reason: Option<ResolveReason>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub alert_counts: Option<AlertCount>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub conference_bridge: Option<ConferenceBridge>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub body: Option<IncidentBody>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub occurrence: Option<IncidentOccurrence>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub incidents_responders: Option<Vec<IncidentsRespondersReference>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub responder_requests: Option<Vec<ResponderRequest>>,
+}
+
+#[allow(clippy::derivable_impls)]
+impl Default for Incident {
+    fn default() -> Self {
+        Self {
+            label: String::from("Incident"),
+            html_url: Default::default(),
+            _self: Default::default(),
+            _type: String::from("incident"),
+            summary: Default::default(),
+            id: Default::default(),
+            incident_number: Default::default(),
+            created_at: Default::default(),
+            status: Default::default(),
+            title: Default::default(),
+            pending_actions: Default::default(),
+            incident_key: Default::default(),
+            service: Default::default(),
+            assignments: Default::default(),
+            assigned_via: Default::default(),
+            acknowledgements: Default::default(),
+            last_status_change_at: Default::default(),
+            last_status_change_by: Default::default(),
+            first_trigger_log_entry: Default::default(),
+            escalation_policy: Default::default(),
+            teams: Default::default(),
+            priority: Default::default(),
+            urgency: Default::default(),
+            resolve_reason: Default::default(),
+            alert_counts: Default::default(),
+            conference_bridge: Default::default(),
+            body: Default::default(),
+            occurrence: Default::default(),
+            incidents_responders: Default::default(),
+            responder_requests: Default::default(),
+        }
+    }
+}
+
+impl Incident {
+    #[allow(dead_code)]
+    fn label_default() -> String {
+        String::from("Incident")
+    }
+    #[allow(dead_code)]
+    fn _type_default() -> String {
+        String::from("incident")
+    }
+}
+
+#[allow(non_camel_case_types)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize, Eq, Ord)]
+pub enum IncidentStatusEnum {
+    #[serde(rename = "triggered")]
+    TRIGGERED,
+    #[serde(rename = "acknowledged")]
+    ACKNOWLEDGED,
+    #[serde(rename = "resolved")]
+    RESOLVED,
+    #[serde(other)]
+    UNKNOWN,
+}
+
+impl ::std::fmt::Display for IncidentStatusEnum {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        match *self {
+            IncidentStatusEnum::TRIGGERED => write!(f, "triggered"),
+            IncidentStatusEnum::ACKNOWLEDGED => write!(f, "acknowledged"),
+            IncidentStatusEnum::RESOLVED => write!(f, "resolved"),
+            IncidentStatusEnum::UNKNOWN => write!(f, "unknown"),
+        }
+    }
+}
+
+impl ::std::str::FromStr for IncidentStatusEnum {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "triggered" => Ok(IncidentStatusEnum::TRIGGERED),
+            "acknowledged" => Ok(IncidentStatusEnum::ACKNOWLEDGED),
+            "resolved" => Ok(IncidentStatusEnum::RESOLVED),
+            x => Err(format!("Invalid enum type: {}", x)),
+        }
+    }
+}
+
+impl ::std::convert::AsRef<str> for IncidentStatusEnum {
+    fn as_ref(&self) -> &str {
+        match self {
+            IncidentStatusEnum::TRIGGERED => "triggered",
+            IncidentStatusEnum::ACKNOWLEDGED => "acknowledged",
+            IncidentStatusEnum::RESOLVED => "resolved",
+            IncidentStatusEnum::UNKNOWN => "unknown",
+        }
+    }
+}
+
+// Pick the first enum var as default. Please report this as a bug if it's not correct.
+impl std::default::Default for IncidentStatusEnum {
+    fn default() -> Self {
+        IncidentStatusEnum::TRIGGERED
+    }
+}
+
+#[allow(non_camel_case_types)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize, Eq, Ord)]
+pub enum IncidentAssignedViaEnum {
+    #[serde(rename = "escalation_policy")]
+    ESCALATION_POLICY,
+    #[serde(rename = "direct_assignment")]
+    DIRECT_ASSIGNMENT,
+    #[serde(other)]
+    UNKNOWN,
+}
+
+impl ::std::fmt::Display for IncidentAssignedViaEnum {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        match *self {
+            IncidentAssignedViaEnum::ESCALATION_POLICY => write!(f, "escalation_policy"),
+            IncidentAssignedViaEnum::DIRECT_ASSIGNMENT => write!(f, "direct_assignment"),
+            IncidentAssignedViaEnum::UNKNOWN => write!(f, "unknown"),
+        }
+    }
+}
+
+impl ::std::str::FromStr for IncidentAssignedViaEnum {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "escalation_policy" => Ok(IncidentAssignedViaEnum::ESCALATION_POLICY),
+            "direct_assignment" => Ok(IncidentAssignedViaEnum::DIRECT_ASSIGNMENT),
+            x => Err(format!("Invalid enum type: {}", x)),
+        }
+    }
+}
+
+impl ::std::convert::AsRef<str> for IncidentAssignedViaEnum {
+    fn as_ref(&self) -> &str {
+        match self {
+            IncidentAssignedViaEnum::ESCALATION_POLICY => "escalation_policy",
+            IncidentAssignedViaEnum::DIRECT_ASSIGNMENT => "direct_assignment",
+            IncidentAssignedViaEnum::UNKNOWN => "unknown",
+        }
+    }
+}
+
+// Pick the first enum var as default. Please report this as a bug if it's not correct.
+impl std::default::Default for IncidentAssignedViaEnum {
+    fn default() -> Self {
+        IncidentAssignedViaEnum::ESCALATION_POLICY
+    }
+}
+
+#[allow(non_camel_case_types)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize, Eq, Ord)]
+pub enum IncidentUrgencyEnum {
+    #[serde(rename = "high")]
+    HIGH,
+    #[serde(rename = "low")]
+    LOW,
+    #[serde(other)]
+    UNKNOWN,
+}
+
+impl ::std::fmt::Display for IncidentUrgencyEnum {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        match *self {
+            IncidentUrgencyEnum::HIGH => write!(f, "high"),
+            IncidentUrgencyEnum::LOW => write!(f, "low"),
+            IncidentUrgencyEnum::UNKNOWN => write!(f, "unknown"),
+        }
+    }
+}
+
+impl ::std::str::FromStr for IncidentUrgencyEnum {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "high" => Ok(IncidentUrgencyEnum::HIGH),
+            "low" => Ok(IncidentUrgencyEnum::LOW),
+            x => Err(format!("Invalid enum type: {}", x)),
+        }
+    }
+}
+
+impl ::std::convert::AsRef<str> for IncidentUrgencyEnum {
+    fn as_ref(&self) -> &str {
+        match self {
+            IncidentUrgencyEnum::HIGH => "high",
+            IncidentUrgencyEnum::LOW => "low",
+            IncidentUrgencyEnum::UNKNOWN => "unknown",
+        }
+    }
+}
+
+// Pick the first enum var as default. Please report this as a bug if it's not correct.
+impl std::default::Default for IncidentUrgencyEnum {
+    fn default() -> Self {
+        IncidentUrgencyEnum::HIGH
+    }
+}
+
+/// An incident action is a pending change to an incident that will automatically happen at some future time.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+pub struct IncidentAction {
+    #[serde(rename = "type")]
+    pub _type: IncidentActionTypeEnum,
+    pub at: DateTime<FixedOffset>,
 }
 
 #[allow(non_camel_case_types)]
 #[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize, Eq, Ord)]
-pub enum ImpactTypeEnum {
-    #[serde(rename = "business_service")]
-    BUSINESS_SERVICE,
+pub enum IncidentActionTypeEnum {
+    #[serde(rename = "unacknowledge")]
+    UNACKNOWLEDGE,
+    #[serde(rename = "escalate")]
+    ESCALATE,
+    #[serde(rename = "resolve")]
+    RESOLVE,
+    #[serde(rename = "urgency_change")]
+    URGENCY_CHANGE,
     #[serde(other)]
     UNKNOWN,
 }
 
-impl ::std::fmt::Display for ImpactTypeEnum {
+impl ::std::fmt::Display for IncidentActionTypeEnum {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         match *self {
-            ImpactTypeEnum::BUSINESS_SERVICE => write!(f, "business_service"),
-            ImpactTypeEnum::UNKNOWN => write!(f, "unknown"),
+            IncidentActionTypeEnum::UNACKNOWLEDGE => write!(f, "unacknowledge"),
+            IncidentActionTypeEnum::ESCALATE => write!(f, "escalate"),
+            IncidentActionTypeEnum::RESOLVE => write!(f, "resolve"),
+            IncidentActionTypeEnum::URGENCY_CHANGE => write!(f, "urgency_change"),
+            IncidentActionTypeEnum::UNKNOWN => write!(f, "unknown"),
         }
     }
 }
 
-impl ::std::str::FromStr for ImpactTypeEnum {
+impl ::std::str::FromStr for IncidentActionTypeEnum {
     type Err = String;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         match s {
-            "business_service" => Ok(ImpactTypeEnum::BUSINESS_SERVICE),
+            "unacknowledge" => Ok(IncidentActionTypeEnum::UNACKNOWLEDGE),
+            "escalate" => Ok(IncidentActionTypeEnum::ESCALATE),
+            "resolve" => Ok(IncidentActionTypeEnum::RESOLVE),
+            "urgency_change" => Ok(IncidentActionTypeEnum::URGENCY_CHANGE),
             x => Err(format!("Invalid enum type: {}", x)),
         }
     }
 }
 
-impl ::std::convert::AsRef<str> for ImpactTypeEnum {
+impl ::std::convert::AsRef<str> for IncidentActionTypeEnum {
     fn as_ref(&self) -> &str {
         match self {
-            ImpactTypeEnum::BUSINESS_SERVICE => "business_service",
-            ImpactTypeEnum::UNKNOWN => "unknown",
+            IncidentActionTypeEnum::UNACKNOWLEDGE => "unacknowledge",
+            IncidentActionTypeEnum::ESCALATE => "escalate",
+            IncidentActionTypeEnum::RESOLVE => "resolve",
+            IncidentActionTypeEnum::URGENCY_CHANGE => "urgency_change",
+            IncidentActionTypeEnum::UNKNOWN => "unknown",
         }
     }
 }
 
 // Pick the first enum var as default. Please report this as a bug if it's not correct.
-impl std::default::Default for ImpactTypeEnum {
+impl std::default::Default for IncidentActionTypeEnum {
     fn default() -> Self {
-        ImpactTypeEnum::BUSINESS_SERVICE
+        IncidentActionTypeEnum::UNACKNOWLEDGE
     }
 }
 
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+pub struct IncidentAddon {
+    /// The type of Add-on.
+    #[serde(rename = "type")]
+    pub _type: IncidentAddonTypeEnum,
+    /// The name of the Add-on.
+    #[serde(skip_serializing_if = "String::is_empty")]
+    pub name: String,
+    /// The source URL to display in a frame in the PagerDuty UI. HTTPS is required.
+    #[serde(skip_serializing_if = "String::is_empty")]
+    pub src: String,
+    /// The services this Add-on is associated with. If non-empty, the Add-on will appear only on incidents for those services. If empty, it will appear on incidents for all services. 
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub services: Option<Vec<Service>>,
+}
+
 #[allow(non_camel_case_types)]
 #[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize, Eq, Ord)]
-pub enum ImpactStatusEnum {
-    #[serde(rename = "impacted")]
-    IMPACTED,
-    #[serde(rename = "not_impacted")]
-    NOT_IMPACTED,
+pub enum IncidentAddonTypeEnum {
+    #[serde(rename = "full_page_addon")]
+    FULL_PAGE_ADDON,
+    #[serde(rename = "incident_show_addon")]
+    INCIDENT_SHOW_ADDON,
     #[serde(other)]
     UNKNOWN,
 }
 
-impl ::std::fmt::Display for ImpactStatusEnum {
+impl ::std::fmt::Display for IncidentAddonTypeEnum {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         match *self {
-            ImpactStatusEnum::IMPACTED => write!(f, "impacted"),
-            ImpactStatusEnum::NOT_IMPACTED => write!(f, "not_impacted"),
-            ImpactStatusEnum::UNKNOWN => write!(f, "unknown"),
+            IncidentAddonTypeEnum::FULL_PAGE_ADDON => write!(f, "full_page_addon"),
+            IncidentAddonTypeEnum::INCIDENT_SHOW_ADDON => write!(f, "incident_show_addon"),
+            IncidentAddonTypeEnum::UNKNOWN => write!(f, "unknown"),
         }
     }
 }
 
-impl ::std::str::FromStr for ImpactStatusEnum {
+impl ::std::str::FromStr for IncidentAddonTypeEnum {
     type Err = String;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         match s {
-            "impacted" => Ok(ImpactStatusEnum::IMPACTED),
-            "not_impacted" => Ok(ImpactStatusEnum::NOT_IMPACTED),
+            "full_page_addon" => Ok(IncidentAddonTypeEnum::FULL_PAGE_ADDON),
+            "incident_show_addon" => Ok(IncidentAddonTypeEnum::INCIDENT_SHOW_ADDON),
             x => Err(format!("Invalid enum type: {}", x)),
         }
     }
 }
 
-impl ::std::convert::AsRef<str> for ImpactStatusEnum {
+impl ::std::convert::AsRef<str> for IncidentAddonTypeEnum {
     fn as_ref(&self) -> &str {
         match self {
-            ImpactStatusEnum::IMPACTED => "impacted",
-            ImpactStatusEnum::NOT_IMPACTED => "not_impacted",
-            ImpactStatusEnum::UNKNOWN => "unknown",
+            IncidentAddonTypeEnum::FULL_PAGE_ADDON => "full_page_addon",
+            IncidentAddonTypeEnum::INCIDENT_SHOW_ADDON => "incident_show_addon",
+            IncidentAddonTypeEnum::UNKNOWN => "unknown",
         }
     }
 }
 
 // Pick the first enum var as default. Please report this as a bug if it's not correct.
-impl std::default::Default for ImpactStatusEnum {
+impl std::default::Default for IncidentAddonTypeEnum {
     fn default() -> Self {
-        ImpactStatusEnum::IMPACTED
+        IncidentAddonTypeEnum::FULL_PAGE_ADDON
     }
 }
 
 #[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
-pub struct ImpactAdditionalFields {
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub highest_impacting_priority: Option<ImpactAdditionalFieldsHighestImpactingPriority>,
-}
-
-/// Priority information for the highest priority level that is affecting the impacted object.
-#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
-#[allow(clippy::derive_partial_eq_without_eq)]
-pub struct ImpactAdditionalFieldsHighestImpactingPriority {
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub id: Option<String>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub order: Option<isize>,
-}
-
-#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
-#[allow(clippy::derive_partial_eq_without_eq)]
-pub struct Impactor {
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub id: Option<String>,
-    /// The kind of object that is impacting
+pub struct IncidentBody {
     #[serde(rename = "type")]
-    pub _type: ImpactorTypeEnum,
+    pub _type: IncidentBodyTypeEnum,
+    /// Additional incident details.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub details: Option<String>,
 }
 
 #[allow(non_camel_case_types)]
 #[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize, Eq, Ord)]
-pub enum ImpactorTypeEnum {
-    #[serde(rename = "incident")]
-    INCIDENT,
+pub enum IncidentBodyTypeEnum {
+    #[serde(rename = "incident_body")]
+    INCIDENT_BODY,
     #[serde(other)]
     UNKNOWN,
 }
 
-impl ::std::fmt::Display for ImpactorTypeEnum {
+impl ::std::fmt::Display for IncidentBodyTypeEnum {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         match *self {
-            ImpactorTypeEnum::INCIDENT => write!(f, "incident"),
-            ImpactorTypeEnum::UNKNOWN => write!(f, "unknown"),
+            IncidentBodyTypeEnum::INCIDENT_BODY => write!(f, "incident_body"),
+            IncidentBodyTypeEnum::UNKNOWN => write!(f, "unknown"),
         }
     }
 }
 
-impl ::std::str::FromStr for ImpactorTypeEnum {
+impl ::std::str::FromStr for IncidentBodyTypeEnum {
     type Err = String;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         match s {
-            "incident" => Ok(ImpactorTypeEnum::INCIDENT),
+            "incident_body" => Ok(IncidentBodyTypeEnum::INCIDENT_BODY),
             x => Err(format!("Invalid enum type: {}", x)),
         }
     }
 }
 
-impl ::std::convert::AsRef<str> for ImpactorTypeEnum {
+impl ::std::convert::AsRef<str> for IncidentBodyTypeEnum {
     fn as_ref(&self) -> &str {
         match self {
-            ImpactorTypeEnum::INCIDENT => "incident",
-            ImpactorTypeEnum::UNKNOWN => "unknown",
+            IncidentBodyTypeEnum::INCIDENT_BODY => "incident_body",
+            IncidentBodyTypeEnum::UNKNOWN => "unknown",
         }
     }
 }
 
 // Pick the first enum var as default. Please report this as a bug if it's not correct.
-impl std::default::Default for ImpactorTypeEnum {
+impl std::default::Default for IncidentBodyTypeEnum {
     fn default() -> Self {
-        ImpactorTypeEnum::INCIDENT
+        IncidentBodyTypeEnum::INCIDENT_BODY
     }
 }
 
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
-pub struct Incident {
-    /// The label of the tag.
-    #[serde(default = "Incident::label_default")]
-    pub label: String,
-    /// a URL at which the entity is uniquely displayed in the Web app
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub html_url: Option<String>,
-    /// the API show URL at which the object is accessible
-    #[serde(rename = "self")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub _self: Option<String>,
-    /// A string that determines the schema of the object. This must be the standard name for the entity, suffixed by `_reference` if the object is a reference.
-    #[serde(rename = "type")]
-    #[serde(default = "Incident::_type_default")]
-    pub _type: String,
-    /// A short-form, server-generated string that provides succinct, important information about an object suitable for primary labeling of an entity in a client. In many cases, this will be identical to `name`, though it is not intended to be an identifier.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub summary: Option<String>,
+pub struct IncidentNote {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub id: Option<String>,
-    /// The number of the incident. This is unique across your account.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub incident_number: Option<isize>,
-    /// The date/time the incident was first triggered.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_at: Option<DateTime<FixedOffset>>,
-    /// The current status of the incident.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<IncidentStatusEnum>,
-    /// A succinct description of the nature, symptoms, cause, or effect of the incident.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub title: Option<String>,
-    /// The list of pending_actions on the incident. A pending_action object contains a type of action which can be escalate, unacknowledge, resolve or urgency_change. A pending_action object contains at, the time at which the action will take place. An urgency_change pending_action will contain to, the urgency that the incident will change to.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub pending_actions: Option<Vec<IncidentAction>>,
-    /// The incident's de-duplication key.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub incident_key: Option<String>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub service: Option<Service>,
-    /// List of all assignments for this incident. This list will be empty if the `Incident.status` is `resolved`.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub assignments: Option<Vec<Assignment>>,
-    /// How the current incident assignments were decided.  Note that `direct_assignment` incidents will not escalate up the attached `escalation_policy`
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub assigned_via: Option<IncidentAssignedViaEnum>,
-    /// List of all acknowledgements for this incident. This list will be empty if the `Incident.status` is `resolved` or `triggered`.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub acknowledgements: Option<Vec<Acknowledgement>>,
-    /// The time at which the status of the incident last changed.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_status_change_at: Option<DateTime<FixedOffset>>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_status_change_by: Option<AgentReference>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub first_trigger_log_entry: Option<LogEntryReference>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub escalation_policy: Option<EscalationPolicy>,
-    /// The teams involved in the incident’s lifecycle.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub teams: Option<Vec<Team>>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub priority: Option<Priority>,
-    /// The current urgency of the incident.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub urgency: Option<IncidentUrgencyEnum>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub resolve_reason: Option<ResolveReason>,
+    pub user: Option<AllOfIncidentNoteUser>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub alert_counts: Option<AlertCount>,
+    pub channel: Option<IncidentNoteChannel>,
+    /// The note content
+    #[serde(skip_serializing_if = "String::is_empty")]
+    pub content: String,
+    /// The time at which the note was submitted
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub conference_bridge: Option<ConferenceBridge>,
+    pub created_at: Option<DateTime<FixedOffset>>,
+}
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+pub struct IncidentNoteUser {
+    #[serde(rename = "type")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub body: Option<IncidentBody>,
+    pub _type: Option<String>,
+}
+
+/// The means by which this Note was created. Has different formats depending on type.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+pub struct IncidentNoteChannel {
+    /// A string describing the source of the Note.
+    #[serde(skip_serializing_if = "String::is_empty")]
+    pub summary: String,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub occurrence: Option<IncidentOccurrence>,
+    pub id: Option<String>,
+    /// A string that determines the schema of the object
+    #[serde(rename = "type")]
+    #[serde(default = "IncidentNoteChannel::_type_default")]
+    pub _type: String,
+    /// The API show URL at which the object is accessible
+    #[serde(rename = "self")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub incidents_responders: Option<Vec<IncidentsRespondersReference>>,
+    pub _self: Option<String>,
+    /// a URL at which the entity is uniquely displayed in the Web app
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub responder_requests: Option<Vec<ResponderRequest>>,
+    pub html_url: Option<String>,
 }
 
 #[allow(clippy::derivable_impls)]
-impl Default for Incident {
+impl Default for IncidentNoteChannel {
     fn default() -> Self {
         Self {
-            label: String::from("Incident"),
-            html_url: Default::default(),
-            _self: Default::default(),
-            _type: String::from("incident"),
             summary: Default::default(),
             id: Default::default(),
-            incident_number: Default::default(),
-            created_at: Default::default(),
-            status: Default::default(),
-            title: Default::default(),
-            pending_actions: Default::default(),
-            incident_key: Default::default(),
-            service: Default::default(),
-            assignments: Default::default(),
-            assigned_via: Default::default(),
-            acknowledgements: Default::default(),
-            last_status_change_at: Default::default(),
-            last_status_change_by: Default::default(),
-            first_trigger_log_entry: Default::default(),
-            escalation_policy: Default::default(),
-            teams: Default::default(),
-            priority: Default::default(),
-            urgency: Default::default(),
-            resolve_reason: Default::default(),
-            alert_counts: Default::default(),
-            conference_bridge: Default::default(),
-            body: Default::default(),
-            occurrence: Default::default(),
-            incidents_responders: Default::default(),
-            responder_requests: Default::default(),
+            _type: String::from("incident_note_channel"),
+            _self: Default::default(),
+            html_url: Default::default(),
         }
     }
 }
 
-impl Incident {
-    #[allow(dead_code)]
-    fn label_default() -> String {
-        String::from("Incident")
-    }
+impl IncidentNoteChannel {
     #[allow(dead_code)]
     fn _type_default() -> String {
-        String::from("incident")
+        String::from("incident_note_channel")
     }
 }
 
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+pub struct IncidentOccurrence {
+    /// The ID of the Service referenced.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub count: Option<isize>,
+    /// The percentage that this kind of Incident has occurred on this Service over the given period of time.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub frequency: Option<f64>,
+    /// The classifcation of the Outlier Incident. The values can be one of [\"novel\", \"rare\",  \"frequent\", \"other\"]. \"novel\": It means this Incident hasn't occured. \"rare\": It means this Incident occurs with a low frequency. \"frequent\": It means this Incident occurs with a high frequency. \"other\": It means this Incident occurs with a medium frequency. 
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub category: Option<IncidentOccurrenceCategoryEnum>,
+    /// The start of the date/time range over which Outlier Incident was calculated.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub since: Option<DateTime<FixedOffset>>,
+    /// The end of the date/time range over which Outlier Incident was calculated.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub until: Option<DateTime<FixedOffset>>,
+}
+
 #[allow(non_camel_case_types)]
 #[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize, Eq, Ord)]
-pub enum IncidentStatusEnum {
-    #[serde(rename = "triggered")]
-    TRIGGERED,
-    #[serde(rename = "acknowledged")]
-    ACKNOWLEDGED,
-    #[serde(rename = "resolved")]
-    RESOLVED,
+pub enum IncidentOccurrenceCategoryEnum {
+    #[serde(rename = "novel")]
+    NOVEL,
+    #[serde(rename = "rare")]
+    RARE,
+    #[serde(rename = "frequent")]
+    FREQUENT,
+    #[serde(rename = "other")]
+    OTHER,
     #[serde(other)]
     UNKNOWN,
 }
 
-impl ::std::fmt::Display for IncidentStatusEnum {
+impl ::std::fmt::Display for IncidentOccurrenceCategoryEnum {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         match *self {
-            IncidentStatusEnum::TRIGGERED => write!(f, "triggered"),
-            IncidentStatusEnum::ACKNOWLEDGED => write!(f, "acknowledged"),
-            IncidentStatusEnum::RESOLVED => write!(f, "resolved"),
-            IncidentStatusEnum::UNKNOWN => write!(f, "unknown"),
+            IncidentOccurrenceCategoryEnum::NOVEL => write!(f, "novel"),
+            IncidentOccurrenceCategoryEnum::RARE => write!(f, "rare"),
+            IncidentOccurrenceCategoryEnum::FREQUENT => write!(f, "frequent"),
+            IncidentOccurrenceCategoryEnum::OTHER => write!(f, "other"),
+            IncidentOccurrenceCategoryEnum::UNKNOWN => write!(f, "unknown"),
         }
     }
 }
 
-impl ::std::str::FromStr for IncidentStatusEnum {
+impl ::std::str::FromStr for IncidentOccurrenceCategoryEnum {
     type Err = String;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         match s {
-            "triggered" => Ok(IncidentStatusEnum::TRIGGERED),
-            "acknowledged" => Ok(IncidentStatusEnum::ACKNOWLEDGED),
-            "resolved" => Ok(IncidentStatusEnum::RESOLVED),
+            "novel" => Ok(IncidentOccurrenceCategoryEnum::NOVEL),
+            "rare" => Ok(IncidentOccurrenceCategoryEnum::RARE),
+            "frequent" => Ok(IncidentOccurrenceCategoryEnum::FREQUENT),
+            "other" => Ok(IncidentOccurrenceCategoryEnum::OTHER),
             x => Err(format!("Invalid enum type: {}", x)),
         }
     }
 }
 
-impl ::std::convert::AsRef<str> for IncidentStatusEnum {
+impl ::std::convert::AsRef<str> for IncidentOccurrenceCategoryEnum {
     fn as_ref(&self) -> &str {
         match self {
-            IncidentStatusEnum::TRIGGERED => "triggered",
-            IncidentStatusEnum::ACKNOWLEDGED => "acknowledged",
-            IncidentStatusEnum::RESOLVED => "resolved",
-            IncidentStatusEnum::UNKNOWN => "unknown",
+            IncidentOccurrenceCategoryEnum::NOVEL => "novel",
+            IncidentOccurrenceCategoryEnum::RARE => "rare",
+            IncidentOccurrenceCategoryEnum::FREQUENT => "frequent",
+            IncidentOccurrenceCategoryEnum::OTHER => "other",
+            IncidentOccurrenceCategoryEnum::UNKNOWN => "unknown",
         }
     }
 }
 
 // Pick the first enum var as default. Please report this as a bug if it's not correct.
-impl std::default::Default for IncidentStatusEnum {
+impl std::default::Default for IncidentOccurrenceCategoryEnum {
     fn default() -> Self {
-        IncidentStatusEnum::TRIGGERED
+        IncidentOccurrenceCategoryEnum::NOVEL
     }
 }
 
-#[allow(non_camel_case_types)]
-#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize, Eq, Ord)]
-pub enum IncidentAssignedViaEnum {
-    #[serde(rename = "escalation_policy")]
-    ESCALATION_POLICY,
-    #[serde(rename = "direct_assignment")]
-    DIRECT_ASSIGNMENT,
-    #[serde(other)]
-    UNKNOWN,
-}
-
-impl ::std::fmt::Display for IncidentAssignedViaEnum {
-    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
-        match *self {
-            IncidentAssignedViaEnum::ESCALATION_POLICY => write!(f, "escalation_policy"),
-            IncidentAssignedViaEnum::DIRECT_ASSIGNMENT => write!(f, "direct_assignment"),
-            IncidentAssignedViaEnum::UNKNOWN => write!(f, "unknown"),
-        }
-    }
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+pub struct IncidentReference {
+    /// The label of the tag.
+    #[serde(default = "IncidentReference::label_default")]
+    pub label: String,
+    /// a URL at which the entity is uniquely displayed in the Web app
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub html_url: Option<String>,
+    /// the API show URL at which the object is accessible
+    #[serde(rename = "self")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub _self: Option<String>,
+    /// A short-form, server-generated string that provides succinct, important information about an object suitable for primary labeling of an entity in a client. In many cases, this will be identical to `name`, though it is not intended to be an identifier.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub summary: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    #[serde(rename = "type")]
+    pub _type: IncidentReferenceTypeEnum,
 }
 
-impl ::std::str::FromStr for IncidentAssignedViaEnum {
-    type Err = String;
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        match s {
-            "escalation_policy" => Ok(IncidentAssignedViaEnum::ESCALATION_POLICY),
-            "direct_assignment" => Ok(IncidentAssignedViaEnum::DIRECT_ASSIGNMENT),
-            x => Err(format!("Invalid enum type: {}", x)),
+#[allow(clippy::derivable_impls)]
+impl Default for IncidentReference {
+    fn default() -> Self {
+        Self {
+            label: String::from("IncidentReference"),
+            html_url: Default::default(),
+            _self: Default::default(),
+            summary: Default::default(),
+            id: Default::default(),
+            _type: Default::default(),
         }
     }
 }
 
-impl ::std::convert::AsRef<str> for IncidentAssignedViaEnum {
-    fn as_ref(&self) -> &str {
-        match self {
-            IncidentAssignedViaEnum::ESCALATION_POLICY => "escalation_policy",
-            IncidentAssignedViaEnum::DIRECT_ASSIGNMENT => "direct_assignment",
-            IncidentAssignedViaEnum::UNKNOWN => "unknown",
-        }
+impl IncidentReference {
+    #[allow(dead_code)]
+    fn label_default() -> String {
+        String::from("IncidentReference")
     }
-}
-
-// Pick the first enum var as default. Please report this as a bug if it's not correct.
-impl std::default::Default for IncidentAssignedViaEnum {
-    fn default() -> Self {
-        IncidentAssignedViaEnum::ESCALATION_POLICY
+    #[allow(dead_code)]
+    fn _type_default() -> String {
+        String::from("incident_reference")
     }
 }
 
 #[allow(non_camel_case_types)]
 #[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize, Eq, Ord)]
-pub enum IncidentUrgencyEnum {
-    #[serde(rename = "high")]
-    HIGH,
-    #[serde(rename = "low")]
-    LOW,
+pub enum IncidentReferenceTypeEnum {
+    #[serde(rename = "incident_reference")]
+    INCIDENT_REFERENCE,
     #[serde(other)]
     UNKNOWN,
 }
 
-impl ::std::fmt::Display for IncidentUrgencyEnum {
+impl ::std::fmt::Display for IncidentReferenceTypeEnum {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         match *self {
-            IncidentUrgencyEnum::HIGH => write!(f, "high"),
-            IncidentUrgencyEnum::LOW => write!(f, "low"),
-            IncidentUrgencyEnum::UNKNOWN => write!(f, "unknown"),
+            IncidentReferenceTypeEnum::INCIDENT_REFERENCE => write!(f, "incident_reference"),
+            IncidentReferenceTypeEnum::UNKNOWN => write!(f, "unknown"),
         }
     }
 }
 
-impl ::std::str::FromStr for IncidentUrgencyEnum {
+impl ::std::str::FromStr for IncidentReferenceTypeEnum {
     type Err = String;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         match s {
-            "high" => Ok(IncidentUrgencyEnum::HIGH),
-            "low" => Ok(IncidentUrgencyEnum::LOW),
+            "incident_reference" => Ok(IncidentReferenceTypeEnum::INCIDENT_REFERENCE),
             x => Err(format!("Invalid enum type: {}", x)),
         }
     }
 }
 
-impl ::std::convert::AsRef<str> for IncidentUrgencyEnum {
+impl ::std::convert::AsRef<str> for IncidentReferenceTypeEnum {
     fn as_ref(&self) -> &str {
         match self {
-            IncidentUrgencyEnum::HIGH => "high",
-            IncidentUrgencyEnum::LOW => "low",
-            IncidentUrgencyEnum::UNKNOWN => "unknown",
+            IncidentReferenceTypeEnum::INCIDENT_REFERENCE => "incident_reference",
+            IncidentReferenceTypeEnum::UNKNOWN => "unknown",
         }
     }
 }
 
 // Pick the first enum var as default. Please report this as a bug if it's not correct.
-impl std::default::Default for IncidentUrgencyEnum {
+impl std::default::Default for IncidentReferenceTypeEnum {
     fn default() -> Self {
-        IncidentUrgencyEnum::HIGH
+        IncidentReferenceTypeEnum::INCIDENT_REFERENCE
     }
 }
 
-/// An incident action is a pending change to an incident that will automatically happen at some future time.
 #[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
-pub struct IncidentAction {
+pub struct IncidentUrgencyRule {
+    /// The type of incident urgency: whether it's constant, or it's dependent on the support hours.
     #[serde(rename = "type")]
-    pub _type: IncidentActionTypeEnum,
-    pub at: DateTime<FixedOffset>,
+    pub _type: IncidentUrgencyRuleTypeEnum,
+    /// The incidents' urgency, if type is constant.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub urgency: Option<IncidentUrgencyRuleUrgencyEnum>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub during_support_hours: Option<IncidentUrgencyType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub outside_support_hours: Option<IncidentUrgencyType>,
 }
 
 #[allow(non_camel_case_types)]
 #[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize, Eq, Ord)]
-pub enum IncidentActionTypeEnum {
-    #[serde(rename = "unacknowledge")]
-    UNACKNOWLEDGE,
-    #[serde(rename = "escalate")]
-    ESCALATE,
-    #[serde(rename = "resolve")]
-    RESOLVE,
-    #[serde(rename = "urgency_change")]
-    URGENCY_CHANGE,
+pub enum IncidentUrgencyRuleTypeEnum {
+    #[serde(rename = "constant")]
+    CONSTANT,
+    #[serde(rename = "use_support_hours")]
+    USE_SUPPORT_HOURS,
     #[serde(other)]
     UNKNOWN,
 }
 
-impl ::std::fmt::Display for IncidentActionTypeEnum {
+impl ::std::fmt::Display for IncidentUrgencyRuleTypeEnum {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         match *self {
-            IncidentActionTypeEnum::UNACKNOWLEDGE => write!(f, "unacknowledge"),
-            IncidentActionTypeEnum::ESCALATE => write!(f, "escalate"),
-            IncidentActionTypeEnum::RESOLVE => write!(f, "resolve"),
-            IncidentActionTypeEnum::URGENCY_CHANGE => write!(f, "urgency_change"),
-            IncidentActionTypeEnum::UNKNOWN => write!(f, "unknown"),
+            IncidentUrgencyRuleTypeEnum::CONSTANT => write!(f, "constant"),
+            IncidentUrgencyRuleTypeEnum::USE_SUPPORT_HOURS => write!(f, "use_support_hours"),
+            IncidentUrgencyRuleTypeEnum::UNKNOWN => write!(f, "unknown"),
         }
     }
 }
 
-impl ::std::str::FromStr for IncidentActionTypeEnum {
+impl ::std::str::FromStr for IncidentUrgencyRuleTypeEnum {
     type Err = String;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         match s {
-            "unacknowledge" => Ok(IncidentActionTypeEnum::UNACKNOWLEDGE),
-            "escalate" => Ok(IncidentActionTypeEnum::ESCALATE),
-            "resolve" => Ok(IncidentActionTypeEnum::RESOLVE),
-            "urgency_change" => Ok(IncidentActionTypeEnum::URGENCY_CHANGE),
+            "constant" => Ok(IncidentUrgencyRuleTypeEnum::CONSTANT),
+            "use_support_hours" => Ok(IncidentUrgencyRuleTypeEnum::USE_SUPPORT_HOURS),
             x => Err(format!("Invalid enum type: {}", x)),
         }
     }
 }
 
-impl ::std::convert::AsRef<str> for IncidentActionTypeEnum {
+impl ::std::convert::AsRef<str> for IncidentUrgencyRuleTypeEnum {
     fn as_ref(&self) -> &str {
         match self {
-            IncidentActionTypeEnum::UNACKNOWLEDGE => "unacknowledge",
-            IncidentActionTypeEnum::ESCALATE => "escalate",
-            IncidentActionTypeEnum::RESOLVE => "resolve",
-            IncidentActionTypeEnum::URGENCY_CHANGE => "urgency_change",
-            IncidentActionTypeEnum::UNKNOWN => "unknown",
+            IncidentUrgencyRuleTypeEnum::CONSTANT => "constant",
+            IncidentUrgencyRuleTypeEnum::USE_SUPPORT_HOURS => "use_support_hours",
+            IncidentUrgencyRuleTypeEnum::UNKNOWN => "unknown",
         }
     }
 }
 
 // Pick the first enum var as default. Please report this as a bug if it's not correct.
-impl std::default::Default for IncidentActionTypeEnum {
+impl std::default::Default for IncidentUrgencyRuleTypeEnum {
     fn default() -> Self {
-        IncidentActionTypeEnum::UNACKNOWLEDGE
+        IncidentUrgencyRuleTypeEnum::CONSTANT
     }
 }
 
-#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
-#[allow(clippy::derive_partial_eq_without_eq)]
-pub struct IncidentAddon {
-    /// The type of Add-on.
-    #[serde(rename = "type")]
-    pub _type: IncidentAddonTypeEnum,
-    /// The name of the Add-on.
-    #[serde(skip_serializing_if = "String::is_empty")]
-    pub name: String,
-    /// The source URL to display in a frame in the PagerDuty UI. HTTPS is required.
-    #[serde(skip_serializing_if = "String::is_empty")]
-    pub src: String,
-    /// The services this Add-on is associated with. If non-empty, the Add-on will appear only on incidents for those services. If empty, it will appear on incidents for all services. 
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub services: Option<Vec<Service>>,
-}
-
 #[allow(non_camel_case_types)]
 #[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize, Eq, Ord)]
-pub enum IncidentAddonTypeEnum {
-    #[serde(rename = "full_page_addon")]
-    FULL_PAGE_ADDON,
-    #[serde(rename = "incident_show_addon")]
-    INCIDENT_SHOW_ADDON,
+pub enum IncidentUrgencyRuleUrgencyEnum {
+    #[serde(rename = "low")]
+    LOW,
+    #[serde(rename = "high")]
+    HIGH,
+    #[serde(rename = "severity_based")]
+    SEVERITY_BASED,
     #[serde(other)]
     UNKNOWN,
 }
 
-impl ::std::fmt::Display for IncidentAddonTypeEnum {
+impl ::std::fmt::Display for IncidentUrgencyRuleUrgencyEnum {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         match *self {
-            IncidentAddonTypeEnum::FULL_PAGE_ADDON => write!(f, "full_page_addon"),
-            IncidentAddonTypeEnum::INCIDENT_SHOW_ADDON => write!(f, "incident_show_addon"),
-            IncidentAddonTypeEnum::UNKNOWN => write!(f, "unknown"),
+            IncidentUrgencyRuleUrgencyEnum::LOW => write!(f, "low"),
+            IncidentUrgencyRuleUrgencyEnum::HIGH => write!(f, "high"),
+            IncidentUrgencyRuleUrgencyEnum::SEVERITY_BASED => write!(f, "severity_based"),
+            IncidentUrgencyRuleUrgencyEnum::UNKNOWN => write!(f, "unknown"),
         }
     }
 }
 
-impl ::std::str::FromStr for IncidentAddonTypeEnum {
+impl ::std::str::FromStr for IncidentUrgencyRuleUrgencyEnum {
     type Err = String;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         match s {
-            "full_page_addon" => Ok(IncidentAddonTypeEnum::FULL_PAGE_ADDON),
-            "incident_show_addon" => Ok(IncidentAddonTypeEnum::INCIDENT_SHOW_ADDON),
+            "low" => Ok(IncidentUrgencyRuleUrgencyEnum::LOW),
+            "high" => Ok(IncidentUrgencyRuleUrgencyEnum::HIGH),
+            "severity_based" => Ok(IncidentUrgencyRuleUrgencyEnum::SEVERITY_BASED),
             x => Err(format!("Invalid enum type: {}", x)),
         }
     }
 }
 
-impl ::std::convert::AsRef<str> for IncidentAddonTypeEnum {
+impl ::std::convert::AsRef<str> for IncidentUrgencyRuleUrgencyEnum {
     fn as_ref(&self) -> &str {
         match self {
-            IncidentAddonTypeEnum::FULL_PAGE_ADDON => "full_page_addon",
-            IncidentAddonTypeEnum::INCIDENT_SHOW_ADDON => "incident_show_addon",
-            IncidentAddonTypeEnum::UNKNOWN => "unknown",
+            IncidentUrgencyRuleUrgencyEnum::LOW => "low",
+            IncidentUrgencyRuleUrgencyEnum::HIGH => "high",
+            IncidentUrgencyRuleUrgencyEnum::SEVERITY_BASED => "severity_based",
+            IncidentUrgencyRuleUrgencyEnum::UNKNOWN => "unknown",
         }
     }
 }
 
 // Pick the first enum var as default. Please report this as a bug if it's not correct.
-impl std::default::Default for IncidentAddonTypeEnum {
+impl std::default::Default for IncidentUrgencyRuleUrgencyEnum {
     fn default() -> Self {
-        IncidentAddonTypeEnum::FULL_PAGE_ADDON
+        IncidentUrgencyRuleUrgencyEnum::LOW
     }
 }
 
 #[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
-pub struct IncidentBody {
+pub struct IncidentUrgencyType {
+    /// The type of incident urgency: whether it's constant, or it's dependent on the support hours.
     #[serde(rename = "type")]
-    pub _type: IncidentBodyTypeEnum,
-    /// Additional incident details.
+    pub _type: IncidentUrgencyTypeTypeEnum,
+    /// The incidents' urgency, if type is constant.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub details: Option<String>,
+    pub urgency: Option<IncidentUrgencyTypeUrgencyEnum>,
 }
 
 #[allow(non_camel_case_types)]
 #[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize, Eq, Ord)]
-pub enum IncidentBodyTypeEnum {
-    #[serde(rename = "incident_body")]
-    INCIDENT_BODY,
+pub enum IncidentUrgencyTypeTypeEnum {
+    #[serde(rename = "constant")]
+    CONSTANT,
+    #[serde(rename = "use_support_hours")]
+    USE_SUPPORT_HOURS,
     #[serde(other)]
     UNKNOWN,
 }
 
-impl ::std::fmt::Display for IncidentBodyTypeEnum {
+impl ::std::fmt::Display for IncidentUrgencyTypeTypeEnum {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         match *self {
-            IncidentBodyTypeEnum::INCIDENT_BODY => write!(f, "incident_body"),
-            IncidentBodyTypeEnum::UNKNOWN => write!(f, "unknown"),
+            IncidentUrgencyTypeTypeEnum::CONSTANT => write!(f, "constant"),
+            IncidentUrgencyTypeTypeEnum::USE_SUPPORT_HOURS => write!(f, "use_support_hours"),
+            IncidentUrgencyTypeTypeEnum::UNKNOWN => write!(f, "unknown"),
         }
     }
 }
 
-impl ::std::str::FromStr for IncidentBodyTypeEnum {
+impl ::std::str::FromStr for IncidentUrgencyTypeTypeEnum {
     type Err = String;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         match s {
-            "incident_body" => Ok(IncidentBodyTypeEnum::INCIDENT_BODY),
+            "constant" => Ok(IncidentUrgencyTypeTypeEnum::CONSTANT),
+            "use_support_hours" => Ok(IncidentUrgencyTypeTypeEnum::USE_SUPPORT_HOURS),
             x => Err(format!("Invalid enum type: {}", x)),
         }
     }
 }
 
-impl ::std::convert::AsRef<str> for IncidentBodyTypeEnum {
+impl ::std::convert::AsRef<str> for IncidentUrgencyTypeTypeEnum {
     fn as_ref(&self) -> &str {
         match self {
-            IncidentBodyTypeEnum::INCIDENT_BODY => "incident_body",
-            IncidentBodyTypeEnum::UNKNOWN => "unknown",
+            IncidentUrgencyTypeTypeEnum::CONSTANT => "constant",
+            IncidentUrgencyTypeTypeEnum::USE_SUPPORT_HOURS => "use_support_hours",
+            IncidentUrgencyTypeTypeEnum::UNKNOWN => "unknown",
         }
     }
 }
 
 // Pick the first enum var as default. Please report this as a bug if it's not correct.
-impl std::default::Default for IncidentBodyTypeEnum {
-    fn default() -> Self {
-        IncidentBodyTypeEnum::INCIDENT_BODY
-    }
-}
-
-#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
-#[allow(clippy::derive_partial_eq_without_eq)]
-pub struct IncidentNote {
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub id: Option<String>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub user: Option<AllOfIncidentNoteUser>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub channel: Option<IncidentNoteChannel>,
-    /// The note content
-    #[serde(skip_serializing_if = "String::is_empty")]
-    pub content: String,
-    /// The time at which the note was submitted
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_at: Option<DateTime<FixedOffset>>,
-}
-#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
-#[allow(clippy::derive_partial_eq_without_eq)]
-pub struct IncidentNoteUser {
-    #[serde(rename = "type")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub _type: Option<String>,
-}
-
-/// The means by which this Note was created. Has different formats depending on type.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
-#[allow(clippy::derive_partial_eq_without_eq)]
-pub struct IncidentNoteChannel {
-    /// A string describing the source of the Note.
-    #[serde(skip_serializing_if = "String::is_empty")]
-    pub summary: String,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub id: Option<String>,
-    /// A string that determines the schema of the object
-    #[serde(rename = "type")]
-    #[serde(default = "IncidentNoteChannel::_type_default")]
-    pub _type: String,
-    /// The API show URL at which the object is accessible
-    #[serde(rename = "self")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub _self: Option<String>,
-    /// a URL at which the entity is uniquely displayed in the Web app
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub html_url: Option<String>,
-}
-
-#[allow(clippy::derivable_impls)]
-impl Default for IncidentNoteChannel {
+impl std::default::Default for IncidentUrgencyTypeTypeEnum {
     fn default() -> Self {
-        Self {
-            summary: Default::default(),
-            id: Default::default(),
-            _type: String::from("incident_note_channel"),
-            _self: Default::default(),
-            html_url: Default::default(),
-        }
-    }
-}
-
-impl IncidentNoteChannel {
-    #[allow(dead_code)]
-    fn _type_default() -> String {
-        String::from("incident_note_channel")
+        IncidentUrgencyTypeTypeEnum::CONSTANT
     }
 }
 
-#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
-#[allow(clippy::derive_partial_eq_without_eq)]
-pub struct IncidentOccurrence {
-    /// The ID of the Service referenced.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub count: Option<isize>,
-    /// The percentage that this kind of Incident has occurred on this Service over the given period of time.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub frequency: Option<f64>,
-    /// The classifcation of the Outlier Incident. The values can be one of [\"novel\", \"rare\",  \"frequent\", \"other\"]. \"novel\": It means this Incident hasn't occured. \"rare\": It means this Incident occurs with a low frequency. \"frequent\": It means this Incident occurs with a high frequency. \"other\": It means this Incident occurs with a medium frequency. 
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub category: Option<IncidentOccurrenceCategoryEnum>,
-    /// The start of the date/time range over which Outlier Incident was calculated.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub since: Option<DateTime<FixedOffset>>,
-    /// The end of the date/time range over which Outlier Incident was calculated.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub until: Option<DateTime<FixedOffset>>,
-}
-
 #[allow(non_camel_case_types)]
 #[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize, Eq, Ord)]
-pub enum IncidentOccurrenceCategoryEnum {
-    #[serde(rename = "novel")]
-    NOVEL,
-    #[serde(rename = "rare")]
-    RARE,
-    #[serde(rename = "frequent")]
-    FREQUENT,
-    #[serde(rename = "other")]
-    OTHER,
+pub enum IncidentUrgencyTypeUrgencyEnum {
+    #[serde(rename = "low")]
+    LOW,
+    #[serde(rename = "high")]
+    HIGH,
+    #[serde(rename = "severity_based")]
+    SEVERITY_BASED,
     #[serde(other)]
     UNKNOWN,
 }
 
-impl ::std::fmt::Display for IncidentOccurrenceCategoryEnum {
+impl ::std::fmt::Display for IncidentUrgencyTypeUrgencyEnum {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         match *self {
-            IncidentOccurrenceCategoryEnum::NOVEL => write!(f, "novel"),
-            IncidentOccurrenceCategoryEnum::RARE => write!(f, "rare"),
-            IncidentOccurrenceCategoryEnum::FREQUENT => write!(f, "frequent"),
-            IncidentOccurrenceCategoryEnum::OTHER => write!(f, "other"),
-            IncidentOccurrenceCategoryEnum::UNKNOWN => write!(f, "unknown"),
+            IncidentUrgencyTypeUrgencyEnum::LOW => write!(f, "low"),
+            IncidentUrgencyTypeUrgencyEnum::HIGH => write!(f, "high"),
+            IncidentUrgencyTypeUrgencyEnum::SEVERITY_BASED => write!(f, "severity_based"),
+            IncidentUrgencyTypeUrgencyEnum::UNKNOWN => write!(f, "unknown"),
         }
     }
 }
 
-impl ::std::str::FromStr for IncidentOccurrenceCategoryEnum {
+impl ::std::str::FromStr for IncidentUrgencyTypeUrgencyEnum {
     type Err = String;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         match s {
-            "novel" => Ok(IncidentOccurrenceCategoryEnum::NOVEL),
-            "rare" => Ok(IncidentOccurrenceCategoryEnum::RARE),
-            "frequent" => Ok(IncidentOccurrenceCategoryEnum::FREQUENT),
-            "other" => Ok(IncidentOccurrenceCategoryEnum::OTHER),
+            "low" => Ok(IncidentUrgencyTypeUrgencyEnum::LOW),
+            "high" => Ok(IncidentUrgencyTypeUrgencyEnum::HIGH),
+            "severity_based" => Ok(IncidentUrgencyTypeUrgencyEnum::SEVERITY_BASED),
             x => Err(format!("Invalid enum type: {}", x)),
         }
     }
 }
 
-impl ::std::convert::AsRef<str> for IncidentOccurrenceCategoryEnum {
+impl ::std::convert::AsRef<str> for IncidentUrgencyTypeUrgencyEnum {
     fn as_ref(&self) -> &str {
         match self {
-            IncidentOccurrenceCategoryEnum::NOVEL => "novel",
-            IncidentOccurrenceCategoryEnum::RARE => "rare",
-            IncidentOccurrenceCategoryEnum::FREQUENT => "frequent",
-            IncidentOccurrenceCategoryEnum::OTHER => "other",
-            IncidentOccurrenceCategoryEnum::UNKNOWN => "unknown",
+            IncidentUrgencyTypeUrgencyEnum::LOW => "low",
+            IncidentUrgencyTypeUrgencyEnum::HIGH => "high",
+            IncidentUrgencyTypeUrgencyEnum::SEVERITY_BASED => "severity_based",
+            IncidentUrgencyTypeUrgencyEnum::UNKNOWN => "unknown",
         }
     }
 }
 
 // Pick the first enum var as default. Please report this as a bug if it's not correct.
-impl std::default::Default for IncidentOccurrenceCategoryEnum {
+impl std::default::Default for IncidentUrgencyTypeUrgencyEnum {
     fn default() -> Self {
-        IncidentOccurrenceCategoryEnum::NOVEL
+        IncidentUrgencyTypeUrgencyEnum::LOW
     }
 }
 
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+/// An Incident Workflow is a sequence of configurable Steps and associated Triggers that can execute automated Actions for a given Incident.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
-pub struct IncidentReference {
-    /// The label of the tag.
-    #[serde(default = "IncidentReference::label_default")]
-    pub label: String,
-    /// a URL at which the entity is uniquely displayed in the Web app
+pub struct IncidentWorkflow {
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub html_url: Option<String>,
+    pub id: Option<String>,
+    #[serde(rename = "type")]
+    pub _type: IncidentWorkflowTypeEnum,
+    /// A short-form, server-generated string that provides succinct, important information about an object suitable for primary labeling of an entity in a client. In many cases, this will be identical to `name`, though it is not intended to be an identifier.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub summary: Option<String>,
     /// the API show URL at which the object is accessible
     #[serde(rename = "self")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub _self: Option<String>,
-    /// A short-form, server-generated string that provides succinct, important information about an object suitable for primary labeling of an entity in a client. In many cases, this will be identical to `name`, though it is not intended to be an identifier.
+    /// a URL at which the entity is uniquely displayed in the Web app
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub summary: Option<String>,
+    pub html_url: Option<String>,
+    /// A descriptive name for the Incident Workflow.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub id: Option<String>,
-    #[serde(rename = "type")]
-    pub _type: IncidentReferenceTypeEnum,
-}
-
-#[allow(clippy::derivable_impls)]
-impl Default for IncidentReference {
-    fn default() -> Self {
-        Self {
-            label: String::from("IncidentReference"),
-            html_url: Default::default(),
-            _self: Default::default(),
-            summary: Default::default(),
-            id: Default::default(),
-            _type: Default::default(),
-        }
-    }
-}
-
-impl IncidentReference {
-    #[allow(dead_code)]
-    fn label_default() -> String {
-        String::from("IncidentReference")
-    }
-    #[allow(dead_code)]
-    fn _type_default() -> String {
-        String::from("incident_reference")
-    }
+    pub name: Option<String>,
+    /// A description of what the Incident Workflow does.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// The timestamp this Incident Workflow was created.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<DateTime<FixedOffset>>,
+    /// Indicates whether the Incident Workflow is enabled or not. Disabled workflows will not be triggered, and will not count toward the account's enabled workflow limit.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_enabled: Option<bool>,
+    /// The ordered list of steps that execute sequentially as part of the workflow.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub steps: Option<Vec<IncidentWorkflowStep>>,
+    /// If specified then workflow edit permissions will be scoped to members of this team.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub team: Option<Team>,
 }
 
 #[allow(non_camel_case_types)]
 #[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize, Eq, Ord)]
-pub enum IncidentReferenceTypeEnum {
-    #[serde(rename = "incident_reference")]
-    INCIDENT_REFERENCE,
+pub enum IncidentWorkflowTypeEnum {
+    #[serde(rename = "incident_workflow")]
+    INCIDENT_WORKFLOW,
     #[serde(other)]
     UNKNOWN,
 }
 
-impl ::std::fmt::Display for IncidentReferenceTypeEnum {
+impl ::std::fmt::Display for IncidentWorkflowTypeEnum {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         match *self {
-            IncidentReferenceTypeEnum::INCIDENT_REFERENCE => write!(f, "incident_reference"),
-            IncidentReferenceTypeEnum::UNKNOWN => write!(f, "unknown"),
+            IncidentWorkflowTypeEnum::INCIDENT_WORKFLOW => write!(f, "incident_workflow"),
+            IncidentWorkflowTypeEnum::UNKNOWN => write!(f, "unknown"),
         }
     }
 }
 
-impl ::std::str::FromStr for IncidentReferenceTypeEnum {
+impl ::std::str::FromStr for IncidentWorkflowTypeEnum {
     type Err = String;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         match s {
-            "incident_reference" => Ok(IncidentReferenceTypeEnum::INCIDENT_REFERENCE),
+            "incident_workflow" => Ok(IncidentWorkflowTypeEnum::INCIDENT_WORKFLOW),
             x => Err(format!("Invalid enum type: {}", x)),
         }
     }
 }
 
-impl ::std::convert::AsRef<str> for IncidentReferenceTypeEnum {
+impl ::std::convert::AsRef<str> for IncidentWorkflowTypeEnum {
     fn as_ref(&self) -> &str {
         match self {
-            IncidentReferenceTypeEnum::INCIDENT_REFERENCE => "incident_reference",
-            IncidentReferenceTypeEnum::UNKNOWN => "unknown",
+            IncidentWorkflowTypeEnum::INCIDENT_WORKFLOW => "incident_workflow",
+            IncidentWorkflowTypeEnum::UNKNOWN => "unknown",
         }
     }
 }
 
 // Pick the first enum var as default. Please report this as a bug if it's not correct.
-impl std::default::Default for IncidentReferenceTypeEnum {
+impl std::default::Default for IncidentWorkflowTypeEnum {
     fn default() -> Self {
-        IncidentReferenceTypeEnum::INCIDENT_REFERENCE
+        IncidentWorkflowTypeEnum::INCIDENT_WORKFLOW
     }
 }
 
+/// A single run of an Incident Workflow on an incident.
 #[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
-pub struct IncidentUrgencyRule {
-    /// The type of incident urgency: whether it's constant, or it's dependent on the support hours.
+pub struct IncidentWorkflowInstance {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
     #[serde(rename = "type")]
-    pub _type: IncidentUrgencyRuleTypeEnum,
-    /// The incidents' urgency, if type is constant.
+    pub _type: IncidentWorkflowInstanceTypeEnum,
+    /// A short-form, server-generated string that provides succinct, important information about an object suitable for primary labeling of an entity in a client. In many cases, this will be identical to `name`, though it is not intended to be an identifier.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub urgency: Option<IncidentUrgencyRuleUrgencyEnum>,
+    pub summary: Option<String>,
+    /// the API show URL at which the object is accessible
+    #[serde(rename = "self")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub during_support_hours: Option<IncidentUrgencyType>,
+    pub _self: Option<String>,
+    /// a URL at which the entity is uniquely displayed in the Web app
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub outside_support_hours: Option<IncidentUrgencyType>,
+    pub html_url: Option<String>,
+    /// The incident on which the workflow is started.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub incident: Option<Incident>,
 }
 
 #[allow(non_camel_case_types)]
 #[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize, Eq, Ord)]
-pub enum IncidentUrgencyRuleTypeEnum {
-    #[serde(rename = "constant")]
-    CONSTANT,
-    #[serde(rename = "use_support_hours")]
-    USE_SUPPORT_HOURS,
+pub enum IncidentWorkflowInstanceTypeEnum {
+    #[serde(rename = "incident_workflow_instance")]
+    INCIDENT_WORKFLOW_INSTANCE,
     #[serde(other)]
     UNKNOWN,
 }
 
-impl ::std::fmt::Display for IncidentUrgencyRuleTypeEnum {
+impl ::std::fmt::Display for IncidentWorkflowInstanceTypeEnum {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         match *self {
-            IncidentUrgencyRuleTypeEnum::CONSTANT => write!(f, "constant"),
-            IncidentUrgencyRuleTypeEnum::USE_SUPPORT_HOURS => write!(f, "use_support_hours"),
-            IncidentUrgencyRuleTypeEnum::UNKNOWN => write!(f, "unknown"),
+            IncidentWorkflowInstanceTypeEnum::INCIDENT_WORKFLOW_INSTANCE => write!(f, "incident_workflow_instance"),
+            IncidentWorkflowInstanceTypeEnum::UNKNOWN => write!(f, "unknown"),
         }
     }
 }
 
-impl ::std::str::FromStr for IncidentUrgencyRuleTypeEnum {
+impl ::std::str::FromStr for IncidentWorkflowInstanceTypeEnum {
     type Err = String;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         match s {
-            "constant" => Ok(IncidentUrgencyRuleTypeEnum::CONSTANT),
-            "use_support_hours" => Ok(IncidentUrgencyRuleTypeEnum::USE_SUPPORT_HOURS),
+            "incident_workflow_instance" => Ok(IncidentWorkflowInstanceTypeEnum::INCIDENT_WORKFLOW_INSTANCE),
             x => Err(format!("Invalid enum type: {}", x)),
         }
     }
 }
 
-impl ::std::convert::AsRef<str> for IncidentUrgencyRuleTypeEnum {
+impl ::std::convert::AsRef<str> for IncidentWorkflowInstanceTypeEnum {
     fn as_ref(&self) -> &str {
         match self {
-            IncidentUrgencyRuleTypeEnum::CONSTANT => "constant",
-            IncidentUrgencyRuleTypeEnum::USE_SUPPORT_HOURS => "use_support_hours",
-            IncidentUrgencyRuleTypeEnum::UNKNOWN => "unknown",
+            IncidentWorkflowInstanceTypeEnum::INCIDENT_WORKFLOW_INSTANCE => "incident_workflow_instance",
+            IncidentWorkflowInstanceTypeEnum::UNKNOWN => "unknown",
         }
     }
 }
 
 // Pick the first enum var as default. Please report this as a bug if it's not correct.
-impl std::default::Default for IncidentUrgencyRuleTypeEnum {
+impl std::default::Default for IncidentWorkflowInstanceTypeEnum {
     fn default() -> Self {
-        IncidentUrgencyRuleTypeEnum::CONSTANT
+        IncidentWorkflowInstanceTypeEnum::INCIDENT_WORKFLOW_INSTANCE
     }
 }
 
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+pub struct IncidentWorkflowStep {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    #[serde(rename = "type")]
+    pub _type: IncidentWorkflowStepTypeEnum,
+    /// A descriptive name for the Step.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// A description of the action performed by the Step.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub action_configuration: Option<IncidentWorkflowStepActionConfiguration>,
+}
+
 #[allow(non_camel_case_types)]
 #[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize, Eq, Ord)]
-pub enum IncidentUrgencyRuleUrgencyEnum {
-    #[serde(rename = "low")]
-    LOW,
-    #[serde(rename = "high")]
-    HIGH,
-    #[serde(rename = "severity_based")]
-    SEVERITY_BASED,
+pub enum IncidentWorkflowStepTypeEnum {
+    #[serde(rename = "step")]
+    STEP,
     #[serde(other)]
     UNKNOWN,
 }
 
-impl ::std::fmt::Display for IncidentUrgencyRuleUrgencyEnum {
+impl ::std::fmt::Display for IncidentWorkflowStepTypeEnum {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         match *self {
-            IncidentUrgencyRuleUrgencyEnum::LOW => write!(f, "low"),
-            IncidentUrgencyRuleUrgencyEnum::HIGH => write!(f, "high"),
-            IncidentUrgencyRuleUrgencyEnum::SEVERITY_BASED => write!(f, "severity_based"),
-            IncidentUrgencyRuleUrgencyEnum::UNKNOWN => write!(f, "unknown"),
+            IncidentWorkflowStepTypeEnum::STEP => write!(f, "step"),
+            IncidentWorkflowStepTypeEnum::UNKNOWN => write!(f, "unknown"),
         }
     }
 }
 
-impl ::std::str::FromStr for IncidentUrgencyRuleUrgencyEnum {
+impl ::std::str::FromStr for IncidentWorkflowStepTypeEnum {
     type Err = String;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         match s {
-            "low" => Ok(IncidentUrgencyRuleUrgencyEnum::LOW),
-            "high" => Ok(IncidentUrgencyRuleUrgencyEnum::HIGH),
-            "severity_based" => Ok(IncidentUrgencyRuleUrgencyEnum::SEVERITY_BASED),
+            "step" => Ok(IncidentWorkflowStepTypeEnum::STEP),
             x => Err(format!("Invalid enum type: {}", x)),
         }
     }
 }
 
-impl ::std::convert::AsRef<str> for IncidentUrgencyRuleUrgencyEnum {
+impl ::std::convert::AsRef<str> for IncidentWorkflowStepTypeEnum {
     fn as_ref(&self) -> &str {
         match self {
-            IncidentUrgencyRuleUrgencyEnum::LOW => "low",
-            IncidentUrgencyRuleUrgencyEnum::HIGH => "high",
-            IncidentUrgencyRuleUrgencyEnum::SEVERITY_BASED => "severity_based",
-            IncidentUrgencyRuleUrgencyEnum::UNKNOWN => "unknown",
+            IncidentWorkflowStepTypeEnum::STEP => "step",
+            IncidentWorkflowStepTypeEnum::UNKNOWN => "unknown",
         }
     }
 }
 
 // Pick the first enum var as default. Please report this as a bug if it's not correct.
-impl std::default::Default for IncidentUrgencyRuleUrgencyEnum {
+impl std::default::Default for IncidentWorkflowStepTypeEnum {
     fn default() -> Self {
-        IncidentUrgencyRuleUrgencyEnum::LOW
+        IncidentWorkflowStepTypeEnum::STEP
     }
 }
 
 #[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
-pub struct IncidentUrgencyType {
-    /// The type of incident urgency: whether it's constant, or it's dependent on the support hours.
+pub struct IncidentWorkflowStepActionConfiguration {
+    /// The identifier of the action to execute, e.g. `pagerduty.com:incident-workflows:send-status-update:1`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub action_id: Option<String>,
+    /// A description of the action.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub inputs: Option<Vec<IncidentWorkflowStepActionConfigurationInputs>>,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+pub struct IncidentWorkflowStepActionConfigurationInputs {
+    /// The name of the action input.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// The configured value of the action input.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub value: Option<String>,
+}
+
+/// A Trigger determines when an Incident Workflow is started, either manually on an incident or automatically when a condition is met.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+pub struct IncidentWorkflowTrigger {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
     #[serde(rename = "type")]
-    pub _type: IncidentUrgencyTypeTypeEnum,
-    /// The incidents' urgency, if type is constant.
+    pub _type: IncidentWorkflowTriggerTypeEnum,
+    /// A short-form, server-generated string that provides succinct, important information about an object suitable for primary labeling of an entity in a client. In many cases, this will be identical to `name`, though it is not intended to be an identifier.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub urgency: Option<IncidentUrgencyTypeUrgencyEnum>,
+    pub summary: Option<String>,
+    /// the API show URL at which the object is accessible
+    #[serde(rename = "self")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub _self: Option<String>,
+    /// a URL at which the entity is uniquely displayed in the Web app
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub html_url: Option<String>,
+    /// The type of the trigger: `manual` triggers are started from the incident, `conditional` triggers start automatically when their condition is met.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub trigger_type: Option<IncidentWorkflowTriggerTriggerTypeEnum>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub workflow: Option<IncidentWorkflow>,
+    /// The services this trigger applies to, unless `is_subscribed_to_all_services` is set.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub services: Option<Vec<Service>>,
+    /// A PCL condition string. Required for `conditional` triggers, and must be empty for `manual` triggers.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub condition: Option<String>,
+    /// The URL to start a manual trigger from.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub trigger_url: Option<String>,
+    /// Set to `true` if the trigger should be eligible for firing on all services.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_subscribed_to_all_services: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub permissions: Option<IncidentWorkflowTriggerPermissions>,
 }
 
 #[allow(non_camel_case_types)]
 #[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize, Eq, Ord)]
-pub enum IncidentUrgencyTypeTypeEnum {
-    #[serde(rename = "constant")]
-    CONSTANT,
-    #[serde(rename = "use_support_hours")]
-    USE_SUPPORT_HOURS,
+pub enum IncidentWorkflowTriggerTypeEnum {
+    #[serde(rename = "workflow_trigger")]
+    WORKFLOW_TRIGGER,
     #[serde(other)]
     UNKNOWN,
 }
 
-impl ::std::fmt::Display for IncidentUrgencyTypeTypeEnum {
+impl ::std::fmt::Display for IncidentWorkflowTriggerTypeEnum {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         match *self {
-            IncidentUrgencyTypeTypeEnum::CONSTANT => write!(f, "constant"),
-            IncidentUrgencyTypeTypeEnum::USE_SUPPORT_HOURS => write!(f, "use_support_hours"),
-            IncidentUrgencyTypeTypeEnum::UNKNOWN => write!(f, "unknown"),
+            IncidentWorkflowTriggerTypeEnum::WORKFLOW_TRIGGER => write!(f, "workflow_trigger"),
+            IncidentWorkflowTriggerTypeEnum::UNKNOWN => write!(f, "unknown"),
         }
     }
 }
 
-impl ::std::str::FromStr for IncidentUrgencyTypeTypeEnum {
+impl ::std::str::FromStr for IncidentWorkflowTriggerTypeEnum {
     type Err = String;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         match s {
-            "constant" => Ok(IncidentUrgencyTypeTypeEnum::CONSTANT),
-            "use_support_hours" => Ok(IncidentUrgencyTypeTypeEnum::USE_SUPPORT_HOURS),
+            "workflow_trigger" => Ok(IncidentWorkflowTriggerTypeEnum::WORKFLOW_TRIGGER),
             x => Err(format!("Invalid enum type: {}", x)),
         }
     }
 }
 
-impl ::std::convert::AsRef<str> for IncidentUrgencyTypeTypeEnum {
+impl ::std::convert::AsRef<str> for IncidentWorkflowTriggerTypeEnum {
     fn as_ref(&self) -> &str {
         match self {
-            IncidentUrgencyTypeTypeEnum::CONSTANT => "constant",
-            IncidentUrgencyTypeTypeEnum::USE_SUPPORT_HOURS => "use_support_hours",
-            IncidentUrgencyTypeTypeEnum::UNKNOWN => "unknown",
+            IncidentWorkflowTriggerTypeEnum::WORKFLOW_TRIGGER => "workflow_trigger",
+            IncidentWorkflowTriggerTypeEnum::UNKNOWN => "unknown",
         }
     }
 }
 
 // Pick the first enum var as default. Please report this as a bug if it's not correct.
-impl std::default::Default for IncidentUrgencyTypeTypeEnum {
+impl std::default::Default for IncidentWorkflowTriggerTypeEnum {
     fn default() -> Self {
-        IncidentUrgencyTypeTypeEnum::CONSTANT
+        IncidentWorkflowTriggerTypeEnum::WORKFLOW_TRIGGER
     }
 }
 
+
 #[allow(non_camel_case_types)]
 #[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize, Eq, Ord)]
-pub enum IncidentUrgencyTypeUrgencyEnum {
-    #[serde(rename = "low")]
-    LOW,
-    #[serde(rename = "high")]
-    HIGH,
-    #[serde(rename = "severity_based")]
-    SEVERITY_BASED,
+pub enum IncidentWorkflowTriggerTriggerTypeEnum {
+    #[serde(rename = "manual")]
+    MANUAL,
+    #[serde(rename = "conditional")]
+    CONDITIONAL,
     #[serde(other)]
     UNKNOWN,
 }
 
-impl ::std::fmt::Display for IncidentUrgencyTypeUrgencyEnum {
+impl ::std::fmt::Display for IncidentWorkflowTriggerTriggerTypeEnum {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         match *self {
-            IncidentUrgencyTypeUrgencyEnum::LOW => write!(f, "low"),
-            IncidentUrgencyTypeUrgencyEnum::HIGH => write!(f, "high"),
-            IncidentUrgencyTypeUrgencyEnum::SEVERITY_BASED => write!(f, "severity_based"),
-            IncidentUrgencyTypeUrgencyEnum::UNKNOWN => write!(f, "unknown"),
+            IncidentWorkflowTriggerTriggerTypeEnum::MANUAL => write!(f, "manual"),
+            IncidentWorkflowTriggerTriggerTypeEnum::CONDITIONAL => write!(f, "conditional"),
+            IncidentWorkflowTriggerTriggerTypeEnum::UNKNOWN => write!(f, "unknown"),
         }
     }
 }
 
-impl ::std::str::FromStr for IncidentUrgencyTypeUrgencyEnum {
+impl ::std::str::FromStr for IncidentWorkflowTriggerTriggerTypeEnum {
     type Err = String;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         match s {
-            "low" => Ok(IncidentUrgencyTypeUrgencyEnum::LOW),
-            "high" => Ok(IncidentUrgencyTypeUrgencyEnum::HIGH),
-            "severity_based" => Ok(IncidentUrgencyTypeUrgencyEnum::SEVERITY_BASED),
+            "manual" => Ok(IncidentWorkflowTriggerTriggerTypeEnum::MANUAL),
+            "conditional" => Ok(IncidentWorkflowTriggerTriggerTypeEnum::CONDITIONAL),
             x => Err(format!("Invalid enum type: {}", x)),
         }
     }
 }
 
-impl ::std::convert::AsRef<str> for IncidentUrgencyTypeUrgencyEnum {
+impl ::std::convert::AsRef<str> for IncidentWorkflowTriggerTriggerTypeEnum {
     fn as_ref(&self) -> &str {
         match self {
-            IncidentUrgencyTypeUrgencyEnum::LOW => "low",
-            IncidentUrgencyTypeUrgencyEnum::HIGH => "high",
-            IncidentUrgencyTypeUrgencyEnum::SEVERITY_BASED => "severity_based",
-            IncidentUrgencyTypeUrgencyEnum::UNKNOWN => "unknown",
+            IncidentWorkflowTriggerTriggerTypeEnum::MANUAL => "manual",
+            IncidentWorkflowTriggerTriggerTypeEnum::CONDITIONAL => "conditional",
+            IncidentWorkflowTriggerTriggerTypeEnum::UNKNOWN => "unknown",
         }
     }
 }
 
 // Pick the first enum var as default. Please report this as a bug if it's not correct.
-impl std::default::Default for IncidentUrgencyTypeUrgencyEnum {
+impl std::default::Default for IncidentWorkflowTriggerTriggerTypeEnum {
     fn default() -> Self {
-        IncidentUrgencyTypeUrgencyEnum::LOW
+        IncidentWorkflowTriggerTriggerTypeEnum::MANUAL
     }
 }
 
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+pub struct IncidentWorkflowTriggerPermissions {
+    /// If `true`, the trigger may only be started by members of the given team.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub restricted: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub team_id: Option<String>,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+pub struct CreateIncidentWorkflow {
+    pub incident_workflow: IncidentWorkflow,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+pub struct UpdateIncidentWorkflow {
+    pub incident_workflow: IncidentWorkflow,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+pub struct CreateIncidentWorkflowInstance {
+    pub incident_workflow_instance: IncidentWorkflowInstance,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+pub struct CreateIncidentWorkflowTrigger {
+    pub trigger: IncidentWorkflowTrigger,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+pub struct UpdateIncidentWorkflowTrigger {
+    pub trigger: IncidentWorkflowTrigger,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+pub struct AssociateServiceToIncidentWorkflowTrigger {
+    pub service: Service,
+}
+
 #[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 pub struct IncidentsAssignments {
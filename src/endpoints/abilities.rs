@@ -0,0 +1,203 @@
+//! Method, error and parameter types for the Abilities endpoint.
+//!
+//! An ability is a feature that the account's plan does or does not include, such as `teams` or
+//! `urgencies`. [Praiya::capabilities] fetches the abilities once per client and caches them, so
+//! that higher-level helpers can [require](Capabilities::require) an ability before making any
+//! changes.
+
+use std::collections::BTreeSet;
+use std::sync::PoisonError;
+
+use crate::errors::Error;
+use crate::{Praiya, SingleResponse};
+
+pub const API_ENDPOINT: &str = "https://api.pagerduty.com";
+
+/// A client for the PagerDuty abilities API
+pub struct AbilitiesClient {
+    pub(crate) api_endpoint: String,
+    pub(crate) client: Praiya,
+}
+
+impl Praiya {
+    pub fn abilities(&self) -> AbilitiesClient {
+        AbilitiesClient {
+            api_endpoint: std::env::var("PAGERDUTY_API_ENDPOINT")
+                .unwrap_or_else(|_| String::from(API_ENDPOINT)),
+            client: Praiya::clone(self),
+        }
+    }
+
+    /// ---
+    ///
+    /// # Account capabilities
+    ///
+    /// Praiya convenience method returning the abilities of the account. They are listed on
+    /// the first call and cached for the lifetime of this client and its clones.
+    ///
+    /// ---
+    pub async fn capabilities(&self) -> Result<Capabilities, Error> {
+        if let Some(capabilities) = self
+            .capabilities
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .as_ref()
+        {
+            return Ok(Capabilities::clone(capabilities));
+        }
+
+        let capabilities = Capabilities::new(self.abilities().list_abilities().await?);
+        *self
+            .capabilities
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner) = Some(Capabilities::clone(&capabilities));
+
+        Ok(capabilities)
+    }
+
+    /// Fail with [Error::MissingAbilityError] unless the account has the given ability.
+    pub async fn require_ability(&self, ability: &str) -> Result<(), Error> {
+        self.capabilities().await?.require(ability)
+    }
+}
+
+/// The abilities of an account, as returned by [Praiya::capabilities]
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Capabilities {
+    abilities: BTreeSet<String>,
+}
+
+impl Capabilities {
+    pub fn new(abilities: impl IntoIterator<Item = String>) -> Self {
+        Capabilities {
+            abilities: abilities.into_iter().collect(),
+        }
+    }
+
+    pub fn has(&self, ability: &str) -> bool {
+        self.abilities.contains(ability)
+    }
+
+    /// Fail with [Error::MissingAbilityError] unless the account has the given ability.
+    pub fn require(&self, ability: &str) -> Result<(), Error> {
+        if self.has(ability) {
+            Ok(())
+        } else {
+            Err(Error::MissingAbilityError {
+                ability: String::from(ability),
+            })
+        }
+    }
+
+    pub fn abilities(&self) -> impl Iterator<Item = &str> {
+        self.abilities.iter().map(String::as_str)
+    }
+}
+
+plural_response_type!(String, abilities, ListAbilities);
+
+impl AbilitiesClient {
+    /// ---
+    ///
+    /// # List abilities
+    ///
+    /// List all of your account's abilities, by name.
+    ///
+    /// "Abilities" describes your account's capabilities by feature name. For example
+    /// `"teams"`.
+    ///
+    /// An ability may be available to your account based on things like your pricing plan or
+    /// account state.
+    ///
+    /// ---
+    pub async fn list_abilities(&self) -> Result<Vec<String>, Error> {
+        let url = Praiya::parse_url(&self.api_endpoint, "/abilities", None)?;
+
+        let req = self.client.build_request(
+            url,
+            http::request::Builder::new().method(http::method::Method::GET),
+            hyper::Body::empty(),
+        );
+
+        self.client
+            .process_into_value::<_, ListAbilitiesResponse>(req)
+            .await
+    }
+
+    /// ---
+    ///
+    /// # Test an ability
+    ///
+    /// Test whether your account has a given ability. PagerDuty responds with `204` when it
+    /// does, and `402` when it does not, which is returned as `false`.
+    ///
+    /// ---
+    pub async fn test_ability(&self, id: &str) -> Result<bool, Error> {
+        let url = Praiya::parse_url(&self.api_endpoint, &format!("/abilities/{}", &id), None)?;
+
+        let req = self.client.build_request(
+            url,
+            http::request::Builder::new().method(http::method::Method::GET),
+            hyper::Body::empty(),
+        );
+
+        match self.client.process_into_unit(req).await {
+            Ok(()) => Ok(true),
+            Err(Error::PraiyaResponseServerError {
+                status_code: 402, ..
+            }) => Ok(false),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Capabilities;
+    use crate::errors::Error;
+
+    #[test]
+    fn test_capabilities() {
+        let capabilities =
+            Capabilities::new(vec![String::from("teams"), String::from("read_only_users")]);
+
+        assert!(capabilities.has("teams"));
+        assert!(capabilities.require("teams").is_ok());
+        assert!(matches!(
+            capabilities.require("urgencies"),
+            Err(Error::MissingAbilityError { ability }) if ability == "urgencies"
+        ));
+        assert_eq!(
+            capabilities.abilities().collect::<Vec<_>>(),
+            vec!["read_only_users", "teams"]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_list_abilities() {
+        let pagerduty = crate::Praiya::new("test");
+        let abilities = pagerduty.abilities().list_abilities().await.unwrap();
+
+        assert!(abilities.contains(&String::from("teams")));
+    }
+
+    #[tokio::test]
+    async fn test_test_ability() {
+        let pagerduty = crate::Praiya::new("test");
+        let has_teams = pagerduty.abilities().test_ability("teams").await.unwrap();
+
+        assert!(has_teams);
+    }
+
+    #[tokio::test]
+    async fn test_capabilities_cached() {
+        let pagerduty = crate::Praiya::new("test");
+        let capabilities = pagerduty.capabilities().await.unwrap();
+
+        assert!(capabilities.has("teams"));
+        assert_eq!(
+            pagerduty.clone().capabilities().await.unwrap(),
+            capabilities
+        );
+    }
+}
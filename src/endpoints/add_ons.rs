@@ -0,0 +1,250 @@
+//! Method, error and parameter types for the Add-ons endpoint.
+
+use futures_core::Stream;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+use crate::errors::Error;
+use crate::models::*;
+use crate::praiya::PraiyaCustomHeaders;
+use crate::{
+    BaseOption, BaseRequest, PaginatedResponse, PaginationQueryComponent, Praiya, SingleResponse,
+    SubSystem, DEFAULT_PAGERDUTY_API_LIMIT,
+};
+
+pub const API_ENDPOINT: &str = "https://api.pagerduty.com";
+
+/// A client for the PagerDuty add-ons API
+pub struct AddOnsClient {
+    pub(crate) api_endpoint: String,
+    pub(crate) client: Praiya,
+}
+
+impl Praiya {
+    pub fn add_ons(&self) -> AddOnsClient {
+        AddOnsClient {
+            api_endpoint: std::env::var("PAGERDUTY_API_ENDPOINT")
+                .unwrap_or_else(|_| String::from(API_ENDPOINT)),
+            client: Praiya::clone(self),
+        }
+    }
+}
+
+impl Addon {
+    /// An add-on displayed as a full page in the PagerDuty UI.
+    pub fn full_page(name: &str, src: &str) -> Self {
+        Addon {
+            _type: AddonTypeEnum::FULL_PAGE_ADDON,
+            name: Some(String::from(name)),
+            src: Some(String::from(src)),
+            ..Default::default()
+        }
+    }
+
+    /// An add-on displayed on the incident details page.
+    pub fn incident_show(name: &str, src: &str) -> Self {
+        Addon {
+            _type: AddonTypeEnum::INCIDENT_SHOW_ADDON,
+            name: Some(String::from(name)),
+            src: Some(String::from(src)),
+            ..Default::default()
+        }
+    }
+}
+
+single_response_type!(Addon, addon, CreateAddon);
+
+single_response_type!(Addon, addon, GetAddon);
+
+list_response_type!(ListAddons, addons, Addon);
+
+single_response_type!(Addon, addon, UpdateAddon);
+
+#[derive(praiya_macro::PraiyaParamsBuilder)]
+#[doc = "[AddOnsClient::list_addons]"]
+#[allow(dead_code)]
+struct ListAddons {
+    include: Vec<String>,
+    service_ids: Vec<String>,
+    filter: AddonTypeEnum,
+}
+
+impl AddOnsClient {
+    /// ---
+    ///
+    /// # Install an Add-on
+    ///
+    /// Install an Add-on for your account.
+    ///
+    /// Addon's are pieces of functionality that developers can write to insert new
+    /// functionality into PagerDuty's UI.
+    ///
+    /// ---
+    pub async fn create_addon(&self, body: CreateAddon) -> Result<Addon, Error> {
+        let url = Praiya::parse_url(&self.api_endpoint, "/addons", None)?;
+
+        let req = self.client.build_request(
+            url,
+            http::request::Builder::new().method(http::method::Method::POST),
+            Praiya::serialize_payload(body)?,
+        );
+
+        self.client
+            .process_into_value::<_, CreateAddonResponse>(req)
+            .await
+    }
+
+    /// ---
+    ///
+    /// # Delete an Add-on
+    ///
+    /// Remove an existing Add-on.
+    ///
+    /// ---
+    pub async fn delete_addon(&self, id: &str) -> Result<(), Error> {
+        let url = Praiya::parse_url(&self.api_endpoint, &format!("/addons/{}", &id), None)?;
+
+        let req = self.client.build_request(
+            url,
+            http::request::Builder::new().method(http::method::Method::DELETE),
+            hyper::Body::empty(),
+        );
+
+        self.client.process_into_unit(req).await
+    }
+
+    /// ---
+    ///
+    /// # Get an Add-on
+    ///
+    /// Get details about an existing Add-on.
+    ///
+    /// ---
+    pub async fn get_addon(&self, id: &str) -> Result<Addon, Error> {
+        let url = Praiya::parse_url(&self.api_endpoint, &format!("/addons/{}", &id), None)?;
+
+        let req = self.client.build_request(
+            url,
+            http::request::Builder::new().method(http::method::Method::GET),
+            hyper::Body::empty(),
+        );
+
+        self.client
+            .process_into_value::<_, GetAddonResponse>(req)
+            .await
+    }
+
+    /// ---
+    ///
+    /// # List installed Add-ons
+    ///
+    /// List all of the Add-ons installed on your account, optionally filtered by the services
+    /// they apply to or by their type.
+    ///
+    /// ---
+    pub fn list_addons(
+        &self,
+        query_params: ListAddonsParams,
+    ) -> impl Stream<Item = Result<Addon, Error>> + '_ {
+        self.client.list_request::<_, _, ListAddonsResponse>(
+            &self.api_endpoint,
+            "/addons",
+            query_params,
+            PraiyaCustomHeaders::None,
+        )
+    }
+
+    /// ---
+    ///
+    /// # Update an Add-on
+    ///
+    /// Update an existing Add-on.
+    ///
+    /// ---
+    pub async fn update_addon(&self, id: &str, body: UpdateAddon) -> Result<Addon, Error> {
+        let url = Praiya::parse_url(&self.api_endpoint, &format!("/addons/{}", &id), None)?;
+
+        let req = self.client.build_request(
+            url,
+            http::request::Builder::new().method(http::method::Method::PUT),
+            Praiya::serialize_payload(body)?,
+        );
+
+        self.client
+            .process_into_value::<_, UpdateAddonResponse>(req)
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::models::*;
+    use crate::praiya::ParamsBuilder;
+    use futures_util::TryStreamExt;
+
+    #[tokio::test]
+    async fn test_create_addon() {
+        let pagerduty = crate::Praiya::new("test");
+        let addon = pagerduty
+            .add_ons()
+            .create_addon(CreateAddon {
+                addon: Addon::full_page(
+                    "Internal Status Page",
+                    "https://intranet.example.com/status",
+                ),
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(addon.id, Some(String::from("PKX7F81")));
+    }
+
+    #[tokio::test]
+    async fn test_delete_addon() {
+        let pagerduty = crate::Praiya::new("test");
+        let unit = pagerduty.add_ons().delete_addon("PKX7F81").await.unwrap();
+
+        assert_eq!(unit, ());
+    }
+
+    #[tokio::test]
+    async fn test_get_addon() {
+        let pagerduty = crate::Praiya::new("test");
+        let addon = pagerduty.add_ons().get_addon("PKX7F81").await.unwrap();
+
+        assert_eq!(addon._type, AddonTypeEnum::FULL_PAGE_ADDON);
+    }
+
+    #[tokio::test]
+    async fn test_list_addons() {
+        let pagerduty = crate::Praiya::new("test");
+        let mut opts_builder = super::ListAddonsParamsBuilder::new();
+        opts_builder.filter(&AddonTypeEnum::FULL_PAGE_ADDON);
+        let opts = opts_builder.build();
+        let addon: Option<Addon> = pagerduty
+            .add_ons()
+            .list_addons(opts)
+            .try_next()
+            .await
+            .unwrap();
+
+        assert_eq!(addon.unwrap().id, Some(String::from("PKX7F81")));
+    }
+
+    #[tokio::test]
+    async fn test_update_addon() {
+        let pagerduty = crate::Praiya::new("test");
+        let addon = pagerduty
+            .add_ons()
+            .update_addon(
+                "PKX7F81",
+                UpdateAddon {
+                    addon: Addon::incident_show("Status", "https://intranet.example.com/status"),
+                },
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(addon.id, Some(String::from("PKX7F81")));
+    }
+}
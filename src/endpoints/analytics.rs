@@ -0,0 +1,366 @@
+//! Method, error and parameter types for the Analytics endpoint.
+//!
+//! Every analytics endpoint is a `POST` taking its filters as a JSON body. Raw incidents are
+//! paginated with a cursor: each page reports the `last` incident it contains, which is passed
+//! back as `starting_after` to fetch the next page.
+
+use chrono::{DateTime, Utc};
+use futures_core::Stream;
+use futures_util::stream;
+use futures_util::stream::{StreamExt, TryStreamExt};
+
+use crate::errors::Error;
+use crate::models::*;
+use crate::praiya::PraiyaCustomHeaders;
+use crate::{Praiya, SingleResponse};
+
+pub const API_ENDPOINT: &str = "https://api.pagerduty.com";
+
+/// The value of the early access header required by the analytics API.
+const ANALYTICS_EARLY_ACCESS: &str = "analytics-v2";
+
+/// A client for the PagerDuty analytics API
+pub struct AnalyticsClient {
+    pub(crate) api_endpoint: String,
+    pub(crate) client: Praiya,
+}
+
+impl Praiya {
+    pub fn analytics(&self) -> AnalyticsClient {
+        AnalyticsClient {
+            api_endpoint: std::env::var("PAGERDUTY_API_ENDPOINT")
+                .unwrap_or_else(|_| String::from(API_ENDPOINT)),
+            client: Praiya::clone(self),
+        }
+    }
+}
+
+impl AnalyticsModelFilters {
+    /// Filter incidents created from `start` up to, but excluding, `end`. The range may not
+    /// exceed one year.
+    pub fn created_between(start: DateTime<Utc>, end: DateTime<Utc>) -> Self {
+        AnalyticsModelFilters {
+            created_at_start: Some(start.to_rfc3339()),
+            created_at_end: Some(end.to_rfc3339()),
+            ..Default::default()
+        }
+    }
+
+    pub fn urgency(mut self, urgency: AnalyticsModelFiltersUrgencyEnum) -> Self {
+        self.urgency = Some(urgency);
+        self
+    }
+
+    /// Only include major incidents when `true`, or exclude them when `false`.
+    pub fn major(mut self, major: bool) -> Self {
+        self.major = Some(major);
+        self
+    }
+
+    pub fn team_ids(mut self, team_ids: Vec<String>) -> Self {
+        self.team_ids = Some(team_ids);
+        self
+    }
+
+    pub fn service_ids(mut self, service_ids: Vec<String>) -> Self {
+        self.service_ids = Some(service_ids);
+        self
+    }
+
+    pub fn priority_ids(mut self, priority_ids: Vec<String>) -> Self {
+        self.priority_ids = Some(priority_ids);
+        self
+    }
+
+    pub fn priority_names(mut self, priority_names: Vec<String>) -> Self {
+        self.priority_names = Some(priority_names);
+        self
+    }
+}
+
+impl From<AnalyticsModelFilters> for AnalyticsrawincidentsFilters {
+    fn from(filters: AnalyticsModelFilters) -> Self {
+        AnalyticsrawincidentsFilters {
+            created_at_start: filters.created_at_start,
+            created_at_end: filters.created_at_end,
+            urgency: filters.urgency.map(|urgency| urgency.to_string()),
+            major: filters.major,
+            team_ids: filters.team_ids,
+            service_ids: filters.service_ids,
+            priority_ids: filters.priority_ids,
+            priority_names: filters.priority_names,
+        }
+    }
+}
+
+impl GetAnalyticsMetricsIncidentsTeam {
+    /// The body of a metrics request, shared by the `all`, `services` and `teams` endpoints.
+    pub fn new(
+        filters: AnalyticsModelFilters,
+        aggregate_unit: Option<GetAnalyticsMetricsIncidentsTeamAggregateUnitEnum>,
+    ) -> Self {
+        GetAnalyticsMetricsIncidentsTeam {
+            filters: Some(filters),
+            time_zone: None,
+            aggregate_unit,
+        }
+    }
+
+    pub fn time_zone(mut self, time_zone: &chrono_tz::Tz) -> Self {
+        self.time_zone = Some(String::from(time_zone.name()));
+        self
+    }
+}
+
+impl GetAnalyticsIncidents {
+    /// The body of a raw incidents request.
+    pub fn new(filters: AnalyticsModelFilters) -> Self {
+        GetAnalyticsIncidents {
+            filters: Some(AnalyticsrawincidentsFilters::from(filters)),
+            ..Default::default()
+        }
+    }
+
+    pub fn time_zone(mut self, time_zone: &chrono_tz::Tz) -> Self {
+        self.time_zone = Some(String::from(time_zone.name()));
+        self
+    }
+}
+
+/// Aggregated incident metrics, along with the start of the period they cover when an
+/// `aggregate_unit` was requested.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AnalyticsIncidentMetricsRow {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub range_start: Option<String>,
+    #[serde(flatten)]
+    pub metrics: AnalyticsIncidentMetrics,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct GetAnalyticsMetricsIncidentsResponse {
+    pub filters: Option<AnalyticsModelFilters>,
+    pub aggregate_unit: Option<GetAnalyticsMetricsIncidentsTeamAggregateUnitEnum>,
+    pub time_zone: Option<String>,
+    pub data: Vec<AnalyticsIncidentMetricsRow>,
+}
+
+impl SingleResponse for GetAnalyticsMetricsIncidentsResponse {
+    type Inner = Vec<AnalyticsIncidentMetricsRow>;
+
+    fn inner(self) -> Self::Inner {
+        self.data
+    }
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct ListAnalyticsRawIncidentsResponse {
+    pub first: Option<String>,
+    pub last: Option<String>,
+    pub limit: Option<u16>,
+    pub more: Option<bool>,
+    pub starting_after: Option<String>,
+    pub ending_before: Option<String>,
+    pub time_zone: Option<String>,
+    pub data: Vec<AnalyticsRawIncident>,
+}
+
+impl AnalyticsClient {
+    async fn get_analytics_metrics_incidents(
+        &self,
+        path: &str,
+        body: GetAnalyticsMetricsIncidentsTeam,
+    ) -> Result<Vec<AnalyticsIncidentMetricsRow>, Error> {
+        let url = Praiya::parse_url(&self.api_endpoint, path, None)?;
+
+        let early_access: &str = PraiyaCustomHeaders::EarlyAccess(None).into();
+        let req = self.client.build_request(
+            url,
+            http::request::Builder::new()
+                .header(early_access, ANALYTICS_EARLY_ACCESS)
+                .method(http::method::Method::POST),
+            Praiya::serialize_payload(body)?,
+        );
+
+        self.client
+            .process_into_value::<_, GetAnalyticsMetricsIncidentsResponse>(req)
+            .await
+    }
+
+    /// ---
+    ///
+    /// # Get aggregated incident data
+    ///
+    /// Provides aggregated enriched metrics for incidents, over the whole period or per
+    /// `aggregate_unit`.
+    ///
+    /// ---
+    pub async fn get_analytics_metrics_incidents_all(
+        &self,
+        body: GetAnalyticsMetricsIncidentsTeam,
+    ) -> Result<Vec<AnalyticsIncidentMetricsRow>, Error> {
+        self.get_analytics_metrics_incidents("/analytics/metrics/incidents/all", body)
+            .await
+    }
+
+    /// ---
+    ///
+    /// # Get aggregated service data
+    ///
+    /// Provides aggregated metrics for incidents, grouped by service.
+    ///
+    /// ---
+    pub async fn get_analytics_metrics_incidents_services(
+        &self,
+        body: GetAnalyticsMetricsIncidentsTeam,
+    ) -> Result<Vec<AnalyticsIncidentMetricsRow>, Error> {
+        self.get_analytics_metrics_incidents("/analytics/metrics/incidents/services", body)
+            .await
+    }
+
+    /// ---
+    ///
+    /// # Get aggregated team data
+    ///
+    /// Provides aggregated metrics for incidents, grouped by team.
+    ///
+    /// ---
+    pub async fn get_analytics_metrics_incidents_teams(
+        &self,
+        body: GetAnalyticsMetricsIncidentsTeam,
+    ) -> Result<Vec<AnalyticsIncidentMetricsRow>, Error> {
+        self.get_analytics_metrics_incidents("/analytics/metrics/incidents/teams", body)
+            .await
+    }
+
+    async fn list_analytics_raw_incidents_page(
+        &self,
+        body: &GetAnalyticsIncidents,
+    ) -> Result<ListAnalyticsRawIncidentsResponse, Error> {
+        let url = Praiya::parse_url(&self.api_endpoint, "/analytics/raw/incidents", None)?;
+
+        let early_access: &str = PraiyaCustomHeaders::EarlyAccess(None).into();
+        let req = self.client.build_request(
+            url,
+            http::request::Builder::new()
+                .header(early_access, ANALYTICS_EARLY_ACCESS)
+                .method(http::method::Method::POST),
+            Praiya::serialize_payload(body)?,
+        );
+
+        let response = self.client.process_request(req).await?;
+        Praiya::decode_response(response).await
+    }
+
+    /// ---
+    ///
+    /// # Get raw data - multiple incidents
+    ///
+    /// Provides enriched incident data and metrics for multiple incidents. The stream follows
+    /// the `starting_after` cursor until the API reports no more results or the cursor stops
+    /// advancing; set `starting_after` on the body to resume from a known incident.
+    ///
+    /// ---
+    pub fn list_analytics_raw_incidents(
+        &self,
+        body: GetAnalyticsIncidents,
+    ) -> impl Stream<Item = Result<AnalyticsRawIncident, Error>> + '_ {
+        stream::try_unfold(Some(body), move |body| async move {
+            let mut body = match body {
+                Some(body) => body,
+                None => return Ok::<_, Error>(None),
+            };
+
+            let page = self.list_analytics_raw_incidents_page(&body).await?;
+            // A cursor that does not advance would request the same page forever.
+            let next = match (page.more, page.last) {
+                (Some(true), Some(last))
+                    if !page.data.is_empty()
+                        && body.starting_after.as_deref() != Some(last.as_str()) =>
+                {
+                    body.starting_after = Some(last);
+                    Some(body)
+                }
+                _ => None,
+            };
+
+            Ok(Some((stream::iter(page.data.into_iter().map(Ok)), next)))
+        })
+        .try_flatten()
+        .boxed()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures_util::TryStreamExt;
+
+    #[test]
+    fn test_analytics_filters() {
+        let start = DateTime::parse_from_rfc3339("2024-01-01T00:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        let end = DateTime::parse_from_rfc3339("2024-02-01T00:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        let filters = AnalyticsModelFilters::created_between(start, end)
+            .urgency(AnalyticsModelFiltersUrgencyEnum::HIGH)
+            .service_ids(vec![String::from("PQVUB8D")]);
+
+        let body = GetAnalyticsIncidents::new(filters.clone()).time_zone(&chrono_tz::Etc::UTC);
+        let json = serde_json::to_value(&body).unwrap();
+        assert_eq!(json["filters"]["urgency"], "high");
+        assert_eq!(json["filters"]["service_ids"][0], "PQVUB8D");
+        assert_eq!(json["time_zone"], "Etc/UTC");
+
+        let body = GetAnalyticsMetricsIncidentsTeam::new(
+            filters,
+            Some(GetAnalyticsMetricsIncidentsTeamAggregateUnitEnum::WEEK),
+        );
+        let json = serde_json::to_value(&body).unwrap();
+        assert_eq!(json["aggregate_unit"], "week");
+        assert_eq!(
+            json["filters"]["created_at_start"],
+            "2024-01-01T00:00:00+00:00"
+        );
+
+        let row: AnalyticsIncidentMetricsRow = serde_json::from_str(
+            r#"{"range_start": "2024-01-01T00:00:00", "total_incident_count": 12}"#,
+        )
+        .unwrap();
+        assert_eq!(row.metrics.total_incident_count, Some(12));
+    }
+
+    #[tokio::test]
+    async fn test_get_analytics_metrics_incidents_services() {
+        let pagerduty = crate::Praiya::new("test");
+        let body = GetAnalyticsMetricsIncidentsTeam::new(
+            AnalyticsModelFilters {
+                urgency: Some(AnalyticsModelFiltersUrgencyEnum::HIGH),
+                ..Default::default()
+            },
+            None,
+        );
+        let metrics = pagerduty
+            .analytics()
+            .get_analytics_metrics_incidents_services(body)
+            .await
+            .unwrap();
+
+        assert_eq!(metrics[0].metrics.service_id, Some(String::from("PQVUB8D")));
+    }
+
+    #[tokio::test]
+    async fn test_list_analytics_raw_incidents() {
+        let pagerduty = crate::Praiya::new("test");
+        let incident: Option<AnalyticsRawIncident> = pagerduty
+            .analytics()
+            .list_analytics_raw_incidents(GetAnalyticsIncidents::default())
+            .try_next()
+            .await
+            .unwrap();
+
+        assert_eq!(incident.unwrap().id, Some(String::from("PR05P5T")));
+    }
+}
@@ -0,0 +1,1543 @@
+//! Method, error and parameter types for the Automation Actions endpoint.
+
+use std::future::Future;
+use std::time::Duration;
+
+use chrono::{DateTime, FixedOffset};
+use futures_core::Stream;
+use futures_util::{stream, StreamExt};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+use crate::errors::Error;
+use crate::models::*;
+use crate::praiya::{PaginatedCursorPosition, PraiyaCustomHeaders};
+use crate::{
+    BaseOption, BaseRequest, PaginatedResponse, PaginationQueryComponent, Praiya, SingleResponse,
+    SubSystem, DEFAULT_PAGERDUTY_API_LIMIT,
+};
+
+pub const API_ENDPOINT: &str = "https://api.pagerduty.com";
+
+/// A client for the PagerDuty automation actions API
+pub struct AutomationActionsClient {
+    pub(crate) api_endpoint: String,
+    pub(crate) client: Praiya,
+}
+
+impl Praiya {
+    pub fn automation_actions(&self) -> AutomationActionsClient {
+        AutomationActionsClient {
+            api_endpoint: std::env::var("PAGERDUTY_API_ENDPOINT")
+                .unwrap_or_else(|_| String::from(API_ENDPOINT)),
+            client: Praiya::clone(self),
+        }
+    }
+}
+
+/// An Automation Action runs a script or a job against your infrastructure, on demand from an
+/// incident or as part of an Event Orchestration.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AutomationAction {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    #[serde(rename = "type")]
+    pub _type: AutomationActionTypeEnum,
+    /// A short-form, server-generated string that provides succinct, important information about an
+    /// object suitable for primary labeling of an entity in a client. In many cases, this will be
+    /// identical to `name`, though it is not intended to be an identifier.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub summary: Option<String>,
+    /// the API show URL at which the object is accessible
+    #[serde(rename = "self")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub _self: Option<String>,
+    /// a URL at which the entity is uniquely displayed in the Web app
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub html_url: Option<String>,
+    /// The name of the Automation Action.
+    pub name: String,
+    /// A description of what the Automation Action does.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// The kind of Automation Action. A `script` runs on a sidecar runner, a `process_automation`
+    /// runs a job on a Process Automation or Runbook Automation runner.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub action_type: Option<AutomationActionActionTypeEnum>,
+    /// Whether the Automation Action gathers diagnostics or remediates the incident.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub action_classification: Option<AutomationActionActionClassificationEnum>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub action_data_reference: Option<AutomationActionDataReference>,
+    /// The ID of the runner that executes the Automation Action.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub runner: Option<String>,
+    /// The type of the runner that executes the Automation Action.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub runner_type: Option<AutomationActionRunnerTypeEnum>,
+    /// The services associated with the Automation Action.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub services: Option<Vec<Service>>,
+    /// The teams associated with the Automation Action.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub teams: Option<Vec<Team>>,
+    /// The date/time the Automation Action was created at.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub creation_time: Option<DateTime<FixedOffset>>,
+    /// The date/time the Automation Action was last modified.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub modify_time: Option<DateTime<FixedOffset>>,
+    /// The date/time the Automation Action was last invoked.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_run: Option<DateTime<FixedOffset>>,
+    /// The user that last invoked the Automation Action.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_run_by: Option<User>,
+}
+
+#[allow(non_camel_case_types)]
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum AutomationActionTypeEnum {
+    #[default]
+    #[serde(rename = "action")]
+    ACTION,
+    #[serde(other)]
+    UNKNOWN,
+}
+
+impl AsRef<str> for AutomationActionTypeEnum {
+    fn as_ref(&self) -> &str {
+        match self {
+            AutomationActionTypeEnum::ACTION => "action",
+            AutomationActionTypeEnum::UNKNOWN => "unknown",
+        }
+    }
+}
+
+impl std::fmt::Display for AutomationActionTypeEnum {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str(self.as_ref())
+    }
+}
+
+#[allow(non_camel_case_types)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum AutomationActionActionTypeEnum {
+    #[serde(rename = "script")]
+    SCRIPT,
+    #[serde(rename = "process_automation")]
+    PROCESS_AUTOMATION,
+    #[serde(other)]
+    UNKNOWN,
+}
+
+impl AsRef<str> for AutomationActionActionTypeEnum {
+    fn as_ref(&self) -> &str {
+        match self {
+            AutomationActionActionTypeEnum::SCRIPT => "script",
+            AutomationActionActionTypeEnum::PROCESS_AUTOMATION => "process_automation",
+            AutomationActionActionTypeEnum::UNKNOWN => "unknown",
+        }
+    }
+}
+
+impl std::fmt::Display for AutomationActionActionTypeEnum {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str(self.as_ref())
+    }
+}
+
+#[allow(non_camel_case_types)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum AutomationActionActionClassificationEnum {
+    #[serde(rename = "diagnostic")]
+    DIAGNOSTIC,
+    #[serde(rename = "remediation")]
+    REMEDIATION,
+    #[serde(other)]
+    UNKNOWN,
+}
+
+impl AsRef<str> for AutomationActionActionClassificationEnum {
+    fn as_ref(&self) -> &str {
+        match self {
+            AutomationActionActionClassificationEnum::DIAGNOSTIC => "diagnostic",
+            AutomationActionActionClassificationEnum::REMEDIATION => "remediation",
+            AutomationActionActionClassificationEnum::UNKNOWN => "unknown",
+        }
+    }
+}
+
+impl std::fmt::Display for AutomationActionActionClassificationEnum {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str(self.as_ref())
+    }
+}
+
+#[allow(non_camel_case_types)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum AutomationActionRunnerTypeEnum {
+    #[serde(rename = "sidecar")]
+    SIDECAR,
+    #[serde(rename = "runbook")]
+    RUNBOOK,
+    #[serde(other)]
+    UNKNOWN,
+}
+
+impl AsRef<str> for AutomationActionRunnerTypeEnum {
+    fn as_ref(&self) -> &str {
+        match self {
+            AutomationActionRunnerTypeEnum::SIDECAR => "sidecar",
+            AutomationActionRunnerTypeEnum::RUNBOOK => "runbook",
+            AutomationActionRunnerTypeEnum::UNKNOWN => "unknown",
+        }
+    }
+}
+
+impl std::fmt::Display for AutomationActionRunnerTypeEnum {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str(self.as_ref())
+    }
+}
+
+/// The script or job run by an Automation Action.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AutomationActionDataReference {
+    /// The body of the script to run, for `script` actions.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub script: Option<String>,
+    /// The command that runs the script, for example `/usr/bin/python3`. Defaults to `/bin/sh`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub invocation_command: Option<String>,
+    /// The ID of the job to run, for `process_automation` actions.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub process_automation_job_id: Option<String>,
+    /// The arguments to pass to the job.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub process_automation_job_arguments: Option<String>,
+    /// The node filter restricting the nodes the job runs on.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub process_automation_node_filter: Option<String>,
+}
+
+/// A single run of an Automation Action.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AutomationActionsInvocation {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    #[serde(rename = "type")]
+    pub _type: AutomationActionsInvocationTypeEnum,
+    /// A short-form, server-generated string that provides succinct, important information about an
+    /// object suitable for primary labeling of an entity in a client. In many cases, this will be
+    /// identical to `name`, though it is not intended to be an identifier.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub summary: Option<String>,
+    /// the API show URL at which the object is accessible
+    #[serde(rename = "self")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub _self: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub action_snapshot: Option<AutomationActionsInvocationActionSnapshot>,
+    /// The ID of the runner that executes the invocation.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub runner_id: Option<String>,
+    /// The current state of the invocation. `aborted`, `completed` and `error` are final states.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub state: Option<AutomationActionsInvocationStateEnum>,
+    /// The time at which the invocation entered each of its states.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timing: Option<Vec<AutomationActionsInvocationTiming>>,
+    /// The duration of the invocation in milliseconds, once it has reached a final state.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub duration: Option<isize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<AutomationActionsInvocationMetadata>,
+}
+
+#[allow(non_camel_case_types)]
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum AutomationActionsInvocationTypeEnum {
+    #[default]
+    #[serde(rename = "invocation")]
+    INVOCATION,
+    #[serde(other)]
+    UNKNOWN,
+}
+
+impl AsRef<str> for AutomationActionsInvocationTypeEnum {
+    fn as_ref(&self) -> &str {
+        match self {
+            AutomationActionsInvocationTypeEnum::INVOCATION => "invocation",
+            AutomationActionsInvocationTypeEnum::UNKNOWN => "unknown",
+        }
+    }
+}
+
+impl std::fmt::Display for AutomationActionsInvocationTypeEnum {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str(self.as_ref())
+    }
+}
+
+#[allow(non_camel_case_types)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum AutomationActionsInvocationStateEnum {
+    #[serde(rename = "prepared")]
+    PREPARED,
+    #[serde(rename = "created")]
+    CREATED,
+    #[serde(rename = "sent")]
+    SENT,
+    #[serde(rename = "queued")]
+    QUEUED,
+    #[serde(rename = "running")]
+    RUNNING,
+    #[serde(rename = "aborted")]
+    ABORTED,
+    #[serde(rename = "completed")]
+    COMPLETED,
+    #[serde(rename = "error")]
+    ERROR,
+    #[serde(other)]
+    UNKNOWN,
+}
+
+impl AsRef<str> for AutomationActionsInvocationStateEnum {
+    fn as_ref(&self) -> &str {
+        match self {
+            AutomationActionsInvocationStateEnum::PREPARED => "prepared",
+            AutomationActionsInvocationStateEnum::CREATED => "created",
+            AutomationActionsInvocationStateEnum::SENT => "sent",
+            AutomationActionsInvocationStateEnum::QUEUED => "queued",
+            AutomationActionsInvocationStateEnum::RUNNING => "running",
+            AutomationActionsInvocationStateEnum::ABORTED => "aborted",
+            AutomationActionsInvocationStateEnum::COMPLETED => "completed",
+            AutomationActionsInvocationStateEnum::ERROR => "error",
+            AutomationActionsInvocationStateEnum::UNKNOWN => "unknown",
+        }
+    }
+}
+
+impl std::fmt::Display for AutomationActionsInvocationStateEnum {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str(self.as_ref())
+    }
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AutomationActionsInvocationActionSnapshot {
+    /// The name of the Automation Action at the time of the invocation.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// The description of the Automation Action at the time of the invocation.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// The kind of Automation Action at the time of the invocation.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub action_type: Option<String>,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AutomationActionsInvocationMetadata {
+    /// The ID of the incident the Automation Action runs on.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub incident_id: Option<String>,
+    /// The agent that invoked the Automation Action.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub agent: Option<AgentReference>,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AutomationActionsInvocationTiming {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub state: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time: Option<DateTime<FixedOffset>>,
+}
+
+/// A runner executes Automation Actions, either as a sidecar in your infrastructure or through
+/// Runbook Automation.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AutomationActionsRunner {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    #[serde(rename = "type")]
+    pub _type: AutomationActionsRunnerTypeEnum,
+    /// A short-form, server-generated string that provides succinct, important information about an
+    /// object suitable for primary labeling of an entity in a client. In many cases, this will be
+    /// identical to `name`, though it is not intended to be an identifier.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub summary: Option<String>,
+    /// the API show URL at which the object is accessible
+    #[serde(rename = "self")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub _self: Option<String>,
+    /// a URL at which the entity is uniquely displayed in the Web app
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub html_url: Option<String>,
+    /// The name of the runner.
+    pub name: String,
+    /// A description of the runner.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// A `sidecar` runner is installed in your infrastructure, a `runbook` runner connects to a
+    /// Process Automation or Runbook Automation instance.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub runner_type: Option<AutomationActionsRunnerRunnerTypeEnum>,
+    /// Whether the runner has been configured and has connected to PagerDuty.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<AutomationActionsRunnerStatusEnum>,
+    /// The subdomain of the Runbook Automation instance, for `runbook` runners.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub runbook_base_uri: Option<String>,
+    /// The API key of the Runbook Automation instance, for `runbook` runners. Write only.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub runbook_api_key: Option<String>,
+    /// The teams associated with the runner.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub teams: Option<Vec<Team>>,
+    /// The date/time the runner was created at.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub creation_time: Option<DateTime<FixedOffset>>,
+    /// The date/time the runner last connected to PagerDuty.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_seen: Option<DateTime<FixedOffset>>,
+}
+
+#[allow(non_camel_case_types)]
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum AutomationActionsRunnerTypeEnum {
+    #[default]
+    #[serde(rename = "runner")]
+    RUNNER,
+    #[serde(other)]
+    UNKNOWN,
+}
+
+impl AsRef<str> for AutomationActionsRunnerTypeEnum {
+    fn as_ref(&self) -> &str {
+        match self {
+            AutomationActionsRunnerTypeEnum::RUNNER => "runner",
+            AutomationActionsRunnerTypeEnum::UNKNOWN => "unknown",
+        }
+    }
+}
+
+impl std::fmt::Display for AutomationActionsRunnerTypeEnum {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str(self.as_ref())
+    }
+}
+
+#[allow(non_camel_case_types)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum AutomationActionsRunnerRunnerTypeEnum {
+    #[serde(rename = "sidecar")]
+    SIDECAR,
+    #[serde(rename = "runbook")]
+    RUNBOOK,
+    #[serde(other)]
+    UNKNOWN,
+}
+
+impl AsRef<str> for AutomationActionsRunnerRunnerTypeEnum {
+    fn as_ref(&self) -> &str {
+        match self {
+            AutomationActionsRunnerRunnerTypeEnum::SIDECAR => "sidecar",
+            AutomationActionsRunnerRunnerTypeEnum::RUNBOOK => "runbook",
+            AutomationActionsRunnerRunnerTypeEnum::UNKNOWN => "unknown",
+        }
+    }
+}
+
+impl std::fmt::Display for AutomationActionsRunnerRunnerTypeEnum {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str(self.as_ref())
+    }
+}
+
+#[allow(non_camel_case_types)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum AutomationActionsRunnerStatusEnum {
+    #[serde(rename = "Configured")]
+    CONFIGURED,
+    #[serde(rename = "NotConfigured")]
+    NOTCONFIGURED,
+    #[serde(other)]
+    UNKNOWN,
+}
+
+impl AsRef<str> for AutomationActionsRunnerStatusEnum {
+    fn as_ref(&self) -> &str {
+        match self {
+            AutomationActionsRunnerStatusEnum::CONFIGURED => "Configured",
+            AutomationActionsRunnerStatusEnum::NOTCONFIGURED => "NotConfigured",
+            AutomationActionsRunnerStatusEnum::UNKNOWN => "unknown",
+        }
+    }
+}
+
+impl std::fmt::Display for AutomationActionsRunnerStatusEnum {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str(self.as_ref())
+    }
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CreateAutomationAction {
+    pub action: AutomationAction,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub struct UpdateAutomationAction {
+    pub action: AutomationAction,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CreateAutomationActionInvocation {
+    pub invocation: CreateAutomationActionInvocationInvocation,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CreateAutomationActionInvocationInvocation {
+    pub metadata: AutomationActionsInvocationMetadata,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CreateAutomationActionServiceAssociation {
+    pub service: Service,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CreateAutomationActionTeamAssociation {
+    pub team: Team,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CreateAutomationActionsRunner {
+    pub runner: AutomationActionsRunner,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub struct UpdateAutomationActionsRunner {
+    pub runner: AutomationActionsRunner,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CreateAutomationActionsRunnerTeamAssociation {
+    pub team: Team,
+}
+
+impl AutomationActionsInvocationStateEnum {
+    /// Whether the invocation has stopped running, and its state will no longer change.
+    pub fn is_terminal(&self) -> bool {
+        matches!(
+            self,
+            AutomationActionsInvocationStateEnum::ABORTED
+                | AutomationActionsInvocationStateEnum::COMPLETED
+                | AutomationActionsInvocationStateEnum::ERROR
+        )
+    }
+}
+
+impl CreateAutomationActionInvocation {
+    /// Invoke the action on the incident with the given id.
+    pub fn incident(incident_id: &str) -> Self {
+        CreateAutomationActionInvocation {
+            invocation: CreateAutomationActionInvocationInvocation {
+                metadata: AutomationActionsInvocationMetadata {
+                    incident_id: Some(String::from(incident_id)),
+                    agent: None,
+                },
+            },
+        }
+    }
+}
+
+single_response_type!(Service, service, AutomationActionService);
+
+single_response_type!(Team, team, AutomationActionTeam);
+
+single_response_type!(AutomationAction, action, CreateAutomationAction);
+
+single_response_type!(
+    AutomationActionsInvocation,
+    invocation,
+    CreateAutomationActionInvocation
+);
+
+single_response_type!(
+    AutomationActionsRunner,
+    runner,
+    CreateAutomationActionsRunner
+);
+
+single_response_type!(AutomationAction, action, GetAutomationAction);
+
+single_response_type!(
+    AutomationActionsInvocation,
+    invocation,
+    GetAutomationActionsInvocation
+);
+
+single_response_type!(AutomationActionsRunner, runner, GetAutomationActionsRunner);
+
+plural_response_type!(Service, services, ListAutomationActionServices);
+
+plural_response_type!(Team, teams, ListAutomationActionTeams);
+
+plural_response_type!(
+    AutomationActionsInvocation,
+    invocations,
+    ListAutomationActionsInvocations
+);
+
+single_response_type!(AutomationAction, action, UpdateAutomationAction);
+
+single_response_type!(
+    AutomationActionsRunner,
+    runner,
+    UpdateAutomationActionsRunner
+);
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct ListAutomationActionsResponse {
+    pub next_cursor: Option<String>,
+    pub limit: Option<usize>,
+    pub actions: Vec<AutomationAction>,
+}
+
+impl PaginatedResponse<PaginatedCursorPosition> for ListAutomationActionsResponse {
+    type Inner = Vec<AutomationAction>;
+    type Cursor = Option<String>;
+
+    fn get_pos(&self) -> Self::Cursor {
+        Option::clone(&self.next_cursor)
+    }
+
+    fn get_limit(&self) -> usize {
+        self.limit.unwrap_or(DEFAULT_PAGERDUTY_API_LIMIT)
+    }
+
+    fn inner(self) -> Self::Inner {
+        self.actions
+    }
+
+    fn has_more(&self) -> bool {
+        self.next_cursor.is_some()
+    }
+
+    fn to_cursor(&self) -> PaginatedCursorPosition {
+        PaginatedCursorPosition {
+            cursor: self.get_pos(),
+            has_more: self.has_more(),
+            limit: self.get_limit(),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct ListAutomationActionsRunnersResponse {
+    pub next_cursor: Option<String>,
+    pub limit: Option<usize>,
+    pub runners: Vec<AutomationActionsRunner>,
+}
+
+impl PaginatedResponse<PaginatedCursorPosition> for ListAutomationActionsRunnersResponse {
+    type Inner = Vec<AutomationActionsRunner>;
+    type Cursor = Option<String>;
+
+    fn get_pos(&self) -> Self::Cursor {
+        Option::clone(&self.next_cursor)
+    }
+
+    fn get_limit(&self) -> usize {
+        self.limit.unwrap_or(DEFAULT_PAGERDUTY_API_LIMIT)
+    }
+
+    fn inner(self) -> Self::Inner {
+        self.runners
+    }
+
+    fn has_more(&self) -> bool {
+        self.next_cursor.is_some()
+    }
+
+    fn to_cursor(&self) -> PaginatedCursorPosition {
+        PaginatedCursorPosition {
+            cursor: self.get_pos(),
+            has_more: self.has_more(),
+            limit: self.get_limit(),
+        }
+    }
+}
+
+#[derive(praiya_macro::PraiyaParamsBuilder)]
+#[doc = "[AutomationActionsClient::list_automation_actions]"]
+#[allow(dead_code)]
+struct ListAutomationActions {
+    name: String,
+    runner_id: String,
+    classification: AutomationActionActionClassificationEnum,
+    team_id: String,
+    service_id: String,
+}
+
+#[derive(praiya_macro::PraiyaParamsBuilder)]
+#[doc = "[AutomationActionsClient::list_automation_actions_invocations]"]
+#[allow(dead_code)]
+struct ListAutomationActionsInvocations {
+    action_id: String,
+    incident_id: String,
+    invocation_state: AutomationActionsInvocationStateEnum,
+    not_invocation_state: AutomationActionsInvocationStateEnum,
+}
+
+#[derive(praiya_macro::PraiyaParamsBuilder)]
+#[doc = "[AutomationActionsClient::list_automation_actions_runners]"]
+#[allow(dead_code)]
+struct ListAutomationActionsRunners {
+    name: String,
+    include: Vec<String>,
+}
+
+// Call `fetch` every `poll_interval`, yielding the invocation each time its state changes, until
+// it reaches a final state or `max_polls` is exhausted.
+fn poll_invocation<'a, F, Fut>(
+    id: String,
+    fetch: F,
+    poll_interval: Duration,
+    max_polls: Option<usize>,
+) -> impl Stream<Item = Result<AutomationActionsInvocation, Error>> + 'a
+where
+    F: FnMut() -> Fut + 'a,
+    Fut: Future<Output = Result<AutomationActionsInvocation, Error>> + 'a,
+{
+    // The state is the last state yielded, or `None` before the first poll.
+    stream::try_unfold(
+        (fetch, None, 0),
+        move |(mut fetch, last, mut polls): (
+            F,
+            Option<Option<AutomationActionsInvocationStateEnum>>,
+            usize,
+        )| {
+            let id = String::clone(&id);
+            async move {
+                if let Some(Some(state)) = &last {
+                    if state.is_terminal() {
+                        return Ok(None);
+                    }
+                }
+
+                loop {
+                    if matches!(max_polls, Some(max) if polls >= max) {
+                        return Err(Error::InvocationTimeoutError { id, polls });
+                    }
+                    if polls > 0 {
+                        tokio::time::sleep(poll_interval).await;
+                    }
+
+                    let invocation = fetch().await?;
+                    polls += 1;
+                    if last.as_ref() != Some(&invocation.state) {
+                        let state = Option::clone(&invocation.state);
+                        return Ok(Some((invocation, (fetch, Some(state), polls))));
+                    }
+                }
+            }
+        },
+    )
+}
+
+impl AutomationActionsClient {
+    /// ---
+    ///
+    /// # Associate an Automation Action with a service
+    ///
+    /// Associate an Automation Action with a service, so that it can be invoked on the
+    /// service's incidents.
+    ///
+    /// ---
+    pub async fn associate_automation_action_with_service(
+        &self,
+        id: &str,
+        service_id: &str,
+    ) -> Result<Service, Error> {
+        let url = Praiya::parse_url(
+            &self.api_endpoint,
+            &format!("/automation_actions/actions/{}/services", &id),
+            None,
+        )?;
+
+        let body = CreateAutomationActionServiceAssociation {
+            service: Service {
+                id: Some(String::from(service_id)),
+                _type: ServiceTypeEnum::SERVICE_REFERENCE,
+                ..Default::default()
+            },
+        };
+        let req = self.client.build_request(
+            url,
+            http::request::Builder::new().method(http::method::Method::POST),
+            Praiya::serialize_payload(body)?,
+        );
+
+        self.client
+            .process_into_value::<_, AutomationActionServiceResponse>(req)
+            .await
+    }
+
+    /// ---
+    ///
+    /// # Associate an Automation Action with a team
+    ///
+    /// Associate an Automation Action with a team, whose members may then manage and invoke it.
+    ///
+    /// ---
+    pub async fn associate_automation_action_with_team(
+        &self,
+        id: &str,
+        team_id: &str,
+    ) -> Result<Team, Error> {
+        let url = Praiya::parse_url(
+            &self.api_endpoint,
+            &format!("/automation_actions/actions/{}/teams", &id),
+            None,
+        )?;
+
+        let body = CreateAutomationActionTeamAssociation {
+            team: Team {
+                id: Some(String::from(team_id)),
+                _type: TeamTypeEnum::TEAM_REFERENCE,
+                ..Default::default()
+            },
+        };
+        let req = self.client.build_request(
+            url,
+            http::request::Builder::new().method(http::method::Method::POST),
+            Praiya::serialize_payload(body)?,
+        );
+
+        self.client
+            .process_into_value::<_, AutomationActionTeamResponse>(req)
+            .await
+    }
+
+    /// ---
+    ///
+    /// # Associate a runner with a team
+    ///
+    /// Associate a runner with a team, whose members may then manage it.
+    ///
+    /// ---
+    pub async fn associate_automation_actions_runner_with_team(
+        &self,
+        id: &str,
+        team_id: &str,
+    ) -> Result<Team, Error> {
+        let url = Praiya::parse_url(
+            &self.api_endpoint,
+            &format!("/automation_actions/runners/{}/teams", &id),
+            None,
+        )?;
+
+        let body = CreateAutomationActionsRunnerTeamAssociation {
+            team: Team {
+                id: Some(String::from(team_id)),
+                _type: TeamTypeEnum::TEAM_REFERENCE,
+                ..Default::default()
+            },
+        };
+        let req = self.client.build_request(
+            url,
+            http::request::Builder::new().method(http::method::Method::POST),
+            Praiya::serialize_payload(body)?,
+        );
+
+        self.client
+            .process_into_value::<_, AutomationActionTeamResponse>(req)
+            .await
+    }
+
+    /// ---
+    ///
+    /// # Create an Automation Action
+    ///
+    /// Create a script or process automation Automation Action.
+    ///
+    /// ---
+    pub async fn create_automation_action(
+        &self,
+        body: CreateAutomationAction,
+    ) -> Result<AutomationAction, Error> {
+        let url = Praiya::parse_url(&self.api_endpoint, "/automation_actions/actions", None)?;
+
+        let req = self.client.build_request(
+            url,
+            http::request::Builder::new().method(http::method::Method::POST),
+            Praiya::serialize_payload(body)?,
+        );
+
+        self.client
+            .process_into_value::<_, CreateAutomationActionResponse>(req)
+            .await
+    }
+
+    /// ---
+    ///
+    /// # Invoke an Automation Action
+    ///
+    /// Invoke an Automation Action on an incident. The invocation runs asynchronously, use
+    /// [stream_automation_actions_invocation](AutomationActionsClient::stream_automation_actions_invocation)
+    /// to follow it until it completes.
+    ///
+    /// ---
+    pub async fn create_automation_action_invocation(
+        &self,
+        id: &str,
+        body: CreateAutomationActionInvocation,
+    ) -> Result<AutomationActionsInvocation, Error> {
+        let url = Praiya::parse_url(
+            &self.api_endpoint,
+            &format!("/automation_actions/actions/{}/invocations", &id),
+            None,
+        )?;
+
+        let req = self.client.build_request(
+            url,
+            http::request::Builder::new().method(http::method::Method::POST),
+            Praiya::serialize_payload(body)?,
+        );
+
+        self.client
+            .process_into_value::<_, CreateAutomationActionInvocationResponse>(req)
+            .await
+    }
+
+    /// ---
+    ///
+    /// # Create a runner
+    ///
+    /// Create a sidecar or Runbook Automation runner.
+    ///
+    /// ---
+    pub async fn create_automation_actions_runner(
+        &self,
+        body: CreateAutomationActionsRunner,
+    ) -> Result<AutomationActionsRunner, Error> {
+        let url = Praiya::parse_url(&self.api_endpoint, "/automation_actions/runners", None)?;
+
+        let req = self.client.build_request(
+            url,
+            http::request::Builder::new().method(http::method::Method::POST),
+            Praiya::serialize_payload(body)?,
+        );
+
+        self.client
+            .process_into_value::<_, CreateAutomationActionsRunnerResponse>(req)
+            .await
+    }
+
+    /// ---
+    ///
+    /// # Delete an Automation Action
+    ///
+    /// Delete an existing Automation Action.
+    ///
+    /// ---
+    pub async fn delete_automation_action(&self, id: &str) -> Result<(), Error> {
+        let url = Praiya::parse_url(
+            &self.api_endpoint,
+            &format!("/automation_actions/actions/{}", &id),
+            None,
+        )?;
+
+        let req = self.client.build_request(
+            url,
+            http::request::Builder::new().method(http::method::Method::DELETE),
+            hyper::Body::empty(),
+        );
+
+        self.client.process_into_unit(req).await
+    }
+
+    /// ---
+    ///
+    /// # Delete a runner
+    ///
+    /// Delete an existing runner. Runners with associated Automation Actions cannot be deleted.
+    ///
+    /// ---
+    pub async fn delete_automation_actions_runner(&self, id: &str) -> Result<(), Error> {
+        let url = Praiya::parse_url(
+            &self.api_endpoint,
+            &format!("/automation_actions/runners/{}", &id),
+            None,
+        )?;
+
+        let req = self.client.build_request(
+            url,
+            http::request::Builder::new().method(http::method::Method::DELETE),
+            hyper::Body::empty(),
+        );
+
+        self.client.process_into_unit(req).await
+    }
+
+    /// ---
+    ///
+    /// # Disassociate an Automation Action from a service
+    ///
+    /// Remove the association between an Automation Action and a service.
+    ///
+    /// ---
+    pub async fn disassociate_automation_action_from_service(
+        &self,
+        id: &str,
+        service_id: &str,
+    ) -> Result<(), Error> {
+        let url = Praiya::parse_url(
+            &self.api_endpoint,
+            &format!(
+                "/automation_actions/actions/{}/services/{}",
+                &id, &service_id
+            ),
+            None,
+        )?;
+
+        let req = self.client.build_request(
+            url,
+            http::request::Builder::new().method(http::method::Method::DELETE),
+            hyper::Body::empty(),
+        );
+
+        self.client.process_into_unit(req).await
+    }
+
+    /// ---
+    ///
+    /// # Disassociate an Automation Action from a team
+    ///
+    /// Remove the association between an Automation Action and a team.
+    ///
+    /// ---
+    pub async fn disassociate_automation_action_from_team(
+        &self,
+        id: &str,
+        team_id: &str,
+    ) -> Result<(), Error> {
+        let url = Praiya::parse_url(
+            &self.api_endpoint,
+            &format!("/automation_actions/actions/{}/teams/{}", &id, &team_id),
+            None,
+        )?;
+
+        let req = self.client.build_request(
+            url,
+            http::request::Builder::new().method(http::method::Method::DELETE),
+            hyper::Body::empty(),
+        );
+
+        self.client.process_into_unit(req).await
+    }
+
+    /// ---
+    ///
+    /// # Disassociate a runner from a team
+    ///
+    /// Remove the association between a runner and a team.
+    ///
+    /// ---
+    pub async fn disassociate_automation_actions_runner_from_team(
+        &self,
+        id: &str,
+        team_id: &str,
+    ) -> Result<(), Error> {
+        let url = Praiya::parse_url(
+            &self.api_endpoint,
+            &format!("/automation_actions/runners/{}/teams/{}", &id, &team_id),
+            None,
+        )?;
+
+        let req = self.client.build_request(
+            url,
+            http::request::Builder::new().method(http::method::Method::DELETE),
+            hyper::Body::empty(),
+        );
+
+        self.client.process_into_unit(req).await
+    }
+
+    /// ---
+    ///
+    /// # Get an Automation Action
+    ///
+    /// Get an existing Automation Action.
+    ///
+    /// ---
+    pub async fn get_automation_action(&self, id: &str) -> Result<AutomationAction, Error> {
+        let url = Praiya::parse_url(
+            &self.api_endpoint,
+            &format!("/automation_actions/actions/{}", &id),
+            None,
+        )?;
+
+        let req = self.client.build_request(
+            url,
+            http::request::Builder::new().method(http::method::Method::GET),
+            hyper::Body::empty(),
+        );
+
+        self.client
+            .process_into_value::<_, GetAutomationActionResponse>(req)
+            .await
+    }
+
+    /// ---
+    ///
+    /// # Get an invocation
+    ///
+    /// Get an invocation of an Automation Action, along with its current state.
+    ///
+    /// ---
+    pub async fn get_automation_actions_invocation(
+        &self,
+        id: &str,
+    ) -> Result<AutomationActionsInvocation, Error> {
+        let url = Praiya::parse_url(
+            &self.api_endpoint,
+            &format!("/automation_actions/invocations/{}", &id),
+            None,
+        )?;
+
+        let req = self.client.build_request(
+            url,
+            http::request::Builder::new().method(http::method::Method::GET),
+            hyper::Body::empty(),
+        );
+
+        self.client
+            .process_into_value::<_, GetAutomationActionsInvocationResponse>(req)
+            .await
+    }
+
+    /// ---
+    ///
+    /// # Get a runner
+    ///
+    /// Get an existing runner.
+    ///
+    /// ---
+    pub async fn get_automation_actions_runner(
+        &self,
+        id: &str,
+    ) -> Result<AutomationActionsRunner, Error> {
+        let url = Praiya::parse_url(
+            &self.api_endpoint,
+            &format!("/automation_actions/runners/{}", &id),
+            None,
+        )?;
+
+        let req = self.client.build_request(
+            url,
+            http::request::Builder::new().method(http::method::Method::GET),
+            hyper::Body::empty(),
+        );
+
+        self.client
+            .process_into_value::<_, GetAutomationActionsRunnerResponse>(req)
+            .await
+    }
+
+    /// ---
+    ///
+    /// # List the services of an Automation Action
+    ///
+    /// List the services associated with an Automation Action.
+    ///
+    /// ---
+    pub async fn list_automation_action_services(&self, id: &str) -> Result<Vec<Service>, Error> {
+        let url = Praiya::parse_url(
+            &self.api_endpoint,
+            &format!("/automation_actions/actions/{}/services", &id),
+            None,
+        )?;
+
+        let req = self.client.build_request(
+            url,
+            http::request::Builder::new().method(http::method::Method::GET),
+            hyper::Body::empty(),
+        );
+
+        self.client
+            .process_into_value::<_, ListAutomationActionServicesResponse>(req)
+            .await
+    }
+
+    /// ---
+    ///
+    /// # List the teams of an Automation Action
+    ///
+    /// List the teams associated with an Automation Action.
+    ///
+    /// ---
+    pub async fn list_automation_action_teams(&self, id: &str) -> Result<Vec<Team>, Error> {
+        let url = Praiya::parse_url(
+            &self.api_endpoint,
+            &format!("/automation_actions/actions/{}/teams", &id),
+            None,
+        )?;
+
+        let req = self.client.build_request(
+            url,
+            http::request::Builder::new().method(http::method::Method::GET),
+            hyper::Body::empty(),
+        );
+
+        self.client
+            .process_into_value::<_, ListAutomationActionTeamsResponse>(req)
+            .await
+    }
+
+    /// ---
+    ///
+    /// # List Automation Actions
+    ///
+    /// List Automation Actions, optionally filtered by name, runner, classification, team or
+    /// service.
+    ///
+    /// ---
+    pub fn list_automation_actions(
+        &self,
+        query_params: ListAutomationActionsParams,
+    ) -> impl Stream<Item = Result<AutomationAction, Error>> + '_ {
+        let base_request = BaseRequest {
+            host: String::from(&self.api_endpoint),
+            method: http::Method::GET,
+            options: std::sync::Arc::new(query_params),
+            path: String::from("/automation_actions/actions"),
+            headers: std::collections::HashMap::new(),
+        };
+
+        self.client.process_into_paginated_stream::<AutomationAction, ListAutomationActionsResponse, PaginatedCursorPosition, crate::praiya::PaginationCursorQueryComponent>(
+            base_request,
+            std::sync::Arc::new(crate::praiya::PaginationCursorQueryComponent {
+                cursor: None,
+                limit: DEFAULT_PAGERDUTY_API_LIMIT,
+            }),
+        )
+        .boxed()
+    }
+
+    /// ---
+    ///
+    /// # List invocations
+    ///
+    /// List invocations of Automation Actions, optionally filtered by action, incident or
+    /// state.
+    ///
+    /// ---
+    pub async fn list_automation_actions_invocations(
+        &self,
+        query_params: ListAutomationActionsInvocationsParams,
+    ) -> Result<Vec<AutomationActionsInvocation>, Error> {
+        let url = Praiya::parse_url(
+            &self.api_endpoint,
+            "/automation_actions/invocations",
+            Some(&query_params.qs),
+        )?;
+
+        let req = self.client.build_request(
+            url,
+            http::request::Builder::new().method(http::method::Method::GET),
+            hyper::Body::empty(),
+        );
+
+        self.client
+            .process_into_value::<_, ListAutomationActionsInvocationsResponse>(req)
+            .await
+    }
+
+    /// ---
+    ///
+    /// # List the teams of a runner
+    ///
+    /// List the teams associated with a runner.
+    ///
+    /// ---
+    pub async fn list_automation_actions_runner_teams(&self, id: &str) -> Result<Vec<Team>, Error> {
+        let url = Praiya::parse_url(
+            &self.api_endpoint,
+            &format!("/automation_actions/runners/{}/teams", &id),
+            None,
+        )?;
+
+        let req = self.client.build_request(
+            url,
+            http::request::Builder::new().method(http::method::Method::GET),
+            hyper::Body::empty(),
+        );
+
+        self.client
+            .process_into_value::<_, ListAutomationActionTeamsResponse>(req)
+            .await
+    }
+
+    /// ---
+    ///
+    /// # List runners
+    ///
+    /// List runners, optionally filtered by name. Include `associated_actions` to embed the
+    /// actions run by each runner.
+    ///
+    /// ---
+    pub fn list_automation_actions_runners(
+        &self,
+        query_params: ListAutomationActionsRunnersParams,
+    ) -> impl Stream<Item = Result<AutomationActionsRunner, Error>> + '_ {
+        let base_request = BaseRequest {
+            host: String::from(&self.api_endpoint),
+            method: http::Method::GET,
+            options: std::sync::Arc::new(query_params),
+            path: String::from("/automation_actions/runners"),
+            headers: std::collections::HashMap::new(),
+        };
+
+        self.client.process_into_paginated_stream::<AutomationActionsRunner, ListAutomationActionsRunnersResponse, PaginatedCursorPosition, crate::praiya::PaginationCursorQueryComponent>(
+            base_request,
+            std::sync::Arc::new(crate::praiya::PaginationCursorQueryComponent {
+                cursor: None,
+                limit: DEFAULT_PAGERDUTY_API_LIMIT,
+            }),
+        )
+        .boxed()
+    }
+
+    /// ---
+    ///
+    /// # Follow an invocation
+    ///
+    /// Poll an invocation every `poll_interval`, yielding it each time its state changes. The
+    /// stream ends once the invocation is aborted, completed or has failed.
+    ///
+    /// With `max_polls`, the stream fails with [Error::InvocationTimeoutError] if the invocation
+    /// has not reached one of these final states after that many polls.
+    ///
+    /// ---
+    pub fn stream_automation_actions_invocation(
+        &self,
+        id: &str,
+        poll_interval: Duration,
+        max_polls: Option<usize>,
+    ) -> impl Stream<Item = Result<AutomationActionsInvocation, Error>> + '_ {
+        let invocation_id = String::from(id);
+        let fetch = move || {
+            let id = String::clone(&invocation_id);
+            async move { self.get_automation_actions_invocation(&id).await }
+        };
+
+        poll_invocation(String::from(id), fetch, poll_interval, max_polls).boxed()
+    }
+
+    /// ---
+    ///
+    /// # Update an Automation Action
+    ///
+    /// Update an existing Automation Action.
+    ///
+    /// ---
+    pub async fn update_automation_action(
+        &self,
+        id: &str,
+        body: UpdateAutomationAction,
+    ) -> Result<AutomationAction, Error> {
+        let url = Praiya::parse_url(
+            &self.api_endpoint,
+            &format!("/automation_actions/actions/{}", &id),
+            None,
+        )?;
+
+        let req = self.client.build_request(
+            url,
+            http::request::Builder::new().method(http::method::Method::PUT),
+            Praiya::serialize_payload(body)?,
+        );
+
+        self.client
+            .process_into_value::<_, UpdateAutomationActionResponse>(req)
+            .await
+    }
+
+    /// ---
+    ///
+    /// # Update a runner
+    ///
+    /// Update an existing runner.
+    ///
+    /// ---
+    pub async fn update_automation_actions_runner(
+        &self,
+        id: &str,
+        body: UpdateAutomationActionsRunner,
+    ) -> Result<AutomationActionsRunner, Error> {
+        let url = Praiya::parse_url(
+            &self.api_endpoint,
+            &format!("/automation_actions/runners/{}", &id),
+            None,
+        )?;
+
+        let req = self.client.build_request(
+            url,
+            http::request::Builder::new().method(http::method::Method::PUT),
+            Praiya::serialize_payload(body)?,
+        );
+
+        self.client
+            .process_into_value::<_, UpdateAutomationActionsRunnerResponse>(req)
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::praiya::ParamsBuilder;
+    use futures_util::TryStreamExt;
+
+    #[test]
+    fn test_create_automation_action_invocation_body() {
+        let json =
+            serde_json::to_value(CreateAutomationActionInvocation::incident("PT4KHLK")).unwrap();
+
+        assert_eq!(json["invocation"]["metadata"]["incident_id"], "PT4KHLK");
+
+        let invocation: AutomationActionsInvocation = serde_json::from_str(
+            r#"{"id":"01DA2MLYN0J5EFC1LKWXUKDDKT","type":"invocation","state":"completed","duration":1254}"#,
+        )
+        .unwrap();
+        assert!(invocation.state.unwrap().is_terminal());
+        assert!(!AutomationActionsInvocationStateEnum::QUEUED.is_terminal());
+    }
+
+    #[tokio::test]
+    async fn test_poll_invocation() {
+        let invocation = |state: AutomationActionsInvocationStateEnum| {
+            Ok(AutomationActionsInvocation {
+                id: Some(String::from("01DF4OBNYKW8FD1ZS3N6F5F2Y4")),
+                state: Some(state),
+                ..Default::default()
+            })
+        };
+        let polls = std::cell::Cell::new(0);
+        let mut states = vec![
+            AutomationActionsInvocationStateEnum::QUEUED,
+            AutomationActionsInvocationStateEnum::QUEUED,
+            AutomationActionsInvocationStateEnum::RUNNING,
+            AutomationActionsInvocationStateEnum::COMPLETED,
+            AutomationActionsInvocationStateEnum::COMPLETED,
+        ]
+        .into_iter();
+        let fetch = || {
+            polls.set(polls.get() + 1);
+            std::future::ready(invocation(states.next().unwrap()))
+        };
+
+        let yielded: Vec<AutomationActionsInvocation> = poll_invocation(
+            String::from("01DF4OBNYKW8FD1ZS3N6F5F2Y4"),
+            fetch,
+            Duration::ZERO,
+            Some(10),
+        )
+        .try_collect()
+        .await
+        .unwrap();
+
+        assert_eq!(
+            yielded
+                .into_iter()
+                .map(|invocation| invocation.state.unwrap())
+                .collect::<Vec<_>>(),
+            vec![
+                AutomationActionsInvocationStateEnum::QUEUED,
+                AutomationActionsInvocationStateEnum::RUNNING,
+                AutomationActionsInvocationStateEnum::COMPLETED,
+            ]
+        );
+        assert_eq!(polls.get(), 4);
+
+        let fetch =
+            || std::future::ready(invocation(AutomationActionsInvocationStateEnum::RUNNING));
+        let results: Vec<Result<AutomationActionsInvocation, Error>> = poll_invocation(
+            String::from("01DF4OBNYKW8FD1ZS3N6F5F2Y4"),
+            fetch,
+            Duration::ZERO,
+            Some(3),
+        )
+        .collect()
+        .await;
+
+        assert_eq!(results.len(), 2);
+        assert!(results[0].is_ok());
+        assert!(matches!(
+            &results[1],
+            Err(Error::InvocationTimeoutError { polls: 3, .. })
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_create_automation_action_invocation() {
+        let pagerduty = crate::Praiya::new("test");
+        let invocation = pagerduty
+            .automation_actions()
+            .create_automation_action_invocation(
+                "01DA2MLYN0J5EFC1LKWXUKDDKT",
+                CreateAutomationActionInvocation::incident("PT4KHLK"),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(
+            invocation.id,
+            Some(String::from("01DF4OBNYKW8FD1ZS3N6F5F2Y4"))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_list_automation_actions() {
+        let pagerduty = crate::Praiya::new("test");
+        let mut opts_builder = ListAutomationActionsParamsBuilder::new();
+        opts_builder.classification(&AutomationActionActionClassificationEnum::REMEDIATION);
+        let opts = opts_builder.build();
+        let automation_actions = pagerduty.automation_actions();
+        let action: Option<AutomationAction> = automation_actions
+            .list_automation_actions(opts)
+            .try_next()
+            .await
+            .unwrap();
+
+        assert_eq!(
+            action.unwrap().id,
+            Some(String::from("01DA2MLYN0J5EFC1LKWXUKDDKT"))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_list_automation_actions_runners() {
+        let pagerduty = crate::Praiya::new("test");
+        let mut opts_builder = ListAutomationActionsRunnersParamsBuilder::new();
+        opts_builder.name("sidecar");
+        let opts = opts_builder.build();
+        let automation_actions = pagerduty.automation_actions();
+        let runner: Option<AutomationActionsRunner> = automation_actions
+            .list_automation_actions_runners(opts)
+            .try_next()
+            .await
+            .unwrap();
+
+        assert_eq!(
+            runner.unwrap().id,
+            Some(String::from("01DF4O9T1MDPYOUT7SUX9EXZ4R"))
+        );
+    }
+}
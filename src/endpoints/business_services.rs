@@ -0,0 +1,220 @@
+//! Method, error and parameter types for the Business Services endpoint.
+//!
+//! Only the business service impact methods are covered so far. They are in early access, and
+//! send the `business-impact-early-access` header.
+
+use futures_core::Stream;
+use futures_util::StreamExt;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+use crate::errors::Error;
+use crate::models::*;
+use crate::praiya::{NoopParams, PaginatedCursorPosition, PraiyaCustomHeaders};
+use crate::{
+    BaseOption, BaseRequest, PaginatedResponse, PaginationQueryComponent, Praiya, SingleResponse,
+    SubSystem, DEFAULT_PAGERDUTY_API_LIMIT,
+};
+
+pub const API_ENDPOINT: &str = "https://api.pagerduty.com";
+
+const BUSINESS_IMPACT_EARLY_ACCESS: &str = "business-impact-early-access";
+
+/// A client for the PagerDuty business services API
+pub struct BusinessServicesClient {
+    pub(crate) api_endpoint: String,
+    pub(crate) client: Praiya,
+}
+
+impl Praiya {
+    pub fn business_services(&self) -> BusinessServicesClient {
+        BusinessServicesClient {
+            api_endpoint: std::env::var("PAGERDUTY_API_ENDPOINT")
+                .unwrap_or_else(|_| String::from(API_ENDPOINT)),
+            client: Praiya::clone(self),
+        }
+    }
+}
+
+/// Additional fields requested through the `additional_fields[]` parameter
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ServiceImpactsAdditionalFields {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub total_impacted_count: Option<u64>,
+}
+
+/// The impact status of business services, most impacted first
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ServiceImpacts {
+    pub services: Vec<Impact>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub additional_fields: Option<ServiceImpactsAdditionalFields>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub more: Option<bool>,
+}
+
+impl SingleResponse for ServiceImpacts {
+    type Inner = ServiceImpacts;
+
+    fn inner(self) -> Self::Inner {
+        self
+    }
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct ListBusinessServiceImpactorsResponse {
+    pub next_cursor: Option<String>,
+    pub limit: Option<usize>,
+    pub impactors: Vec<Impactor>,
+}
+
+impl PaginatedResponse<PaginatedCursorPosition> for ListBusinessServiceImpactorsResponse {
+    type Inner = Vec<Impactor>;
+    type Cursor = Option<String>;
+
+    fn get_pos(&self) -> Self::Cursor {
+        Option::clone(&self.next_cursor)
+    }
+
+    fn get_limit(&self) -> usize {
+        self.limit.unwrap_or(DEFAULT_PAGERDUTY_API_LIMIT)
+    }
+
+    fn inner(self) -> Self::Inner {
+        self.impactors
+    }
+
+    fn has_more(&self) -> bool {
+        self.next_cursor.is_some()
+    }
+
+    fn to_cursor(&self) -> PaginatedCursorPosition {
+        PaginatedCursorPosition {
+            cursor: self.get_pos(),
+            has_more: self.has_more(),
+            limit: self.get_limit(),
+        }
+    }
+}
+
+#[derive(praiya_macro::PraiyaParamsBuilder)]
+#[doc = "[BusinessServicesClient::get_business_service_impacts]"]
+#[allow(dead_code)]
+struct GetBusinessServiceImpacts {
+    ids: String,
+    additional_fields: Vec<String>,
+}
+
+impl BusinessServicesClient {
+    /// ---
+    ///
+    /// # Get the impact status of business services
+    ///
+    /// Retrieve the top-level business services sorted by highest impact, along with their
+    /// impact status. Pass a comma-separated list of `ids` to only include those business
+    /// services, and `additional_fields[]=total_impacted_count` for the number of impacted
+    /// business services.
+    ///
+    /// ---
+    pub async fn get_business_service_impacts(
+        &self,
+        query_params: GetBusinessServiceImpactsParams,
+    ) -> Result<ServiceImpacts, Error> {
+        let url = Praiya::parse_url(
+            &self.api_endpoint,
+            "/business_services/impacts",
+            Some(&query_params.qs),
+        )?;
+
+        let early_access: &str = PraiyaCustomHeaders::EarlyAccess(None).into();
+        let req = self.client.build_request(
+            url,
+            http::request::Builder::new()
+                .header(early_access, BUSINESS_IMPACT_EARLY_ACCESS)
+                .method(http::method::Method::GET),
+            hyper::Body::empty(),
+        );
+
+        self.client
+            .process_into_value::<_, ServiceImpacts>(req)
+            .await
+    }
+
+    /// ---
+    ///
+    /// # List the impactors of a business service
+    ///
+    /// Retrieve the incidents directly impacting the given business service, or impacting it
+    /// through one of its dependencies.
+    ///
+    /// ---
+    pub fn list_business_service_impactors(
+        &self,
+        id: &str,
+    ) -> impl Stream<Item = Result<Impactor, Error>> + '_ {
+        let mut header_map = std::collections::HashMap::new();
+        let early_access: &str = PraiyaCustomHeaders::EarlyAccess(None).into();
+        header_map.insert(
+            String::from(early_access),
+            String::from(BUSINESS_IMPACT_EARLY_ACCESS),
+        );
+
+        let base_request = BaseRequest {
+            host: String::from(&self.api_endpoint),
+            method: http::Method::GET,
+            options: std::sync::Arc::new(NoopParams {}),
+            path: format!("/business_services/{}/impactors", &id),
+            headers: header_map,
+        };
+
+        self.client.process_into_paginated_stream::<Impactor, ListBusinessServiceImpactorsResponse, PaginatedCursorPosition, crate::praiya::PaginationCursorQueryComponent>(
+            base_request,
+            std::sync::Arc::new(crate::praiya::PaginationCursorQueryComponent {
+                cursor: None,
+                limit: DEFAULT_PAGERDUTY_API_LIMIT,
+            }),
+        )
+        .boxed()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::models::*;
+    use crate::praiya::ParamsBuilder;
+    use futures_util::TryStreamExt;
+
+    #[tokio::test]
+    async fn test_get_business_service_impacts() {
+        let pagerduty = crate::Praiya::new("test");
+        let mut opts_builder = super::GetBusinessServiceImpactsParamsBuilder::new();
+        opts_builder.additional_fields(vec!["total_impacted_count"]);
+        let opts = opts_builder.build();
+        let impacts = pagerduty
+            .business_services()
+            .get_business_service_impacts(opts)
+            .await
+            .unwrap();
+
+        assert_eq!(impacts.services[0].status, Some(ImpactStatusEnum::IMPACTED));
+        assert_eq!(
+            impacts.additional_fields.unwrap().total_impacted_count,
+            Some(1)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_list_business_service_impactors() {
+        let pagerduty = crate::Praiya::new("test");
+        let business_services = pagerduty.business_services();
+        let impactor: Option<Impactor> = business_services
+            .list_business_service_impactors("PD1234")
+            .try_next()
+            .await
+            .unwrap();
+
+        assert_eq!(impactor.unwrap()._type, ImpactorTypeEnum::INCIDENT);
+    }
+}
@@ -0,0 +1,477 @@
+//! Method, error and parameter types for the incident Custom Fields endpoint.
+
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::errors::Error;
+use crate::models::*;
+use crate::{
+    BaseOption, BaseRequest, PaginatedResponse, PaginationQueryComponent, Praiya, SingleResponse,
+    SubSystem, DEFAULT_PAGERDUTY_API_LIMIT,
+};
+
+pub const API_ENDPOINT: &str = "https://api.pagerduty.com";
+
+/// A client for the PagerDuty incident custom fields API
+pub struct CustomFieldsClient {
+    pub(crate) api_endpoint: String,
+    pub(crate) client: Praiya,
+}
+
+impl Praiya {
+    pub fn custom_fields(&self) -> CustomFieldsClient {
+        CustomFieldsClient {
+            api_endpoint: std::env::var("PAGERDUTY_API_ENDPOINT")
+                .unwrap_or_else(|_| String::from(API_ENDPOINT)),
+            client: Praiya::clone(self),
+        }
+    }
+}
+
+impl UpdateIncidentCustomFieldValues {
+    /// Set the value of the field with the given name. A `Value::Null` unsets the field.
+    pub fn set(mut self, name: &str, value: impl Into<Value>) -> Self {
+        self.custom_fields.push(CustomFieldsEditableFieldValue {
+            id: None,
+            name: Some(String::from(name)),
+            value: value.into(),
+        });
+        self
+    }
+}
+
+single_response_type!(CustomFieldsField, field, CreateCustomField);
+
+single_response_type!(
+    CustomFieldsFieldOption,
+    field_option,
+    CreateCustomFieldOption
+);
+
+single_response_type!(CustomFieldsField, field, GetCustomField);
+
+plural_response_type!(
+    CustomFieldsFieldValue,
+    custom_fields,
+    GetIncidentCustomFieldValues
+);
+
+plural_response_type!(CustomFieldsField, fields, ListCustomFields);
+
+plural_response_type!(
+    CustomFieldsFieldOption,
+    field_options,
+    ListCustomFieldOptions
+);
+
+single_response_type!(CustomFieldsField, field, UpdateCustomField);
+
+single_response_type!(
+    CustomFieldsFieldOption,
+    field_option,
+    UpdateCustomFieldOption
+);
+
+#[derive(praiya_macro::PraiyaParamsBuilder)]
+#[doc = "[CustomFieldsClient::get_custom_field]"]
+#[allow(dead_code)]
+struct GetCustomField {
+    include: Vec<String>,
+}
+
+#[derive(praiya_macro::PraiyaParamsBuilder)]
+#[doc = "[CustomFieldsClient::list_custom_fields]"]
+#[allow(dead_code)]
+struct ListCustomFields {
+    include: Vec<String>,
+}
+
+impl CustomFieldsClient {
+    /// ---
+    ///
+    /// # Create a Field
+    ///
+    /// Creates a new custom field, which can then be set on every incident of the account.
+    ///
+    /// ---
+    pub async fn create_custom_field(
+        &self,
+        body: CreateCustomFieldsField,
+    ) -> Result<CustomFieldsField, Error> {
+        let url = Praiya::parse_url(&self.api_endpoint, "/incidents/custom_fields", None)?;
+
+        let req = self.client.build_request(
+            url,
+            http::request::Builder::new().method(http::method::Method::POST),
+            Praiya::serialize_payload(body)?,
+        );
+
+        self.client
+            .process_into_value::<_, CreateCustomFieldResponse>(req)
+            .await
+    }
+
+    /// ---
+    ///
+    /// # Create a Field Option
+    ///
+    /// Create a new option for a fixed custom field.
+    ///
+    /// ---
+    pub async fn create_custom_field_option(
+        &self,
+        field_id: &str,
+        body: CreateCustomFieldsFieldOption,
+    ) -> Result<CustomFieldsFieldOption, Error> {
+        let url = Praiya::parse_url(
+            &self.api_endpoint,
+            &format!("/incidents/custom_fields/{}/field_options", &field_id),
+            None,
+        )?;
+
+        let req = self.client.build_request(
+            url,
+            http::request::Builder::new().method(http::method::Method::POST),
+            Praiya::serialize_payload(body)?,
+        );
+
+        self.client
+            .process_into_value::<_, CreateCustomFieldOptionResponse>(req)
+            .await
+    }
+
+    /// ---
+    ///
+    /// # Delete a Field
+    ///
+    /// Delete a custom field, along with its values on every incident.
+    ///
+    /// ---
+    pub async fn delete_custom_field(&self, field_id: &str) -> Result<(), Error> {
+        let url = Praiya::parse_url(
+            &self.api_endpoint,
+            &format!("/incidents/custom_fields/{}", &field_id),
+            None,
+        )?;
+
+        let req = self.client.build_request(
+            url,
+            http::request::Builder::new().method(http::method::Method::DELETE),
+            hyper::Body::empty(),
+        );
+
+        self.client.process_into_unit(req).await
+    }
+
+    /// ---
+    ///
+    /// # Delete a Field Option
+    ///
+    /// Delete an option of a fixed custom field.
+    ///
+    /// ---
+    pub async fn delete_custom_field_option(
+        &self,
+        field_id: &str,
+        field_option_id: &str,
+    ) -> Result<(), Error> {
+        let url = Praiya::parse_url(
+            &self.api_endpoint,
+            &format!(
+                "/incidents/custom_fields/{}/field_options/{}",
+                &field_id, &field_option_id
+            ),
+            None,
+        )?;
+
+        let req = self.client.build_request(
+            url,
+            http::request::Builder::new().method(http::method::Method::DELETE),
+            hyper::Body::empty(),
+        );
+
+        self.client.process_into_unit(req).await
+    }
+
+    /// ---
+    ///
+    /// # Get a Field
+    ///
+    /// Show detailed information about a custom field. Include `field_options` to embed the
+    /// options of a fixed field.
+    ///
+    /// ---
+    pub async fn get_custom_field(
+        &self,
+        field_id: &str,
+        query_params: GetCustomFieldParams,
+    ) -> Result<CustomFieldsField, Error> {
+        let url = Praiya::parse_url(
+            &self.api_endpoint,
+            &format!("/incidents/custom_fields/{}", &field_id),
+            Some(&query_params.qs),
+        )?;
+
+        let req = self.client.build_request(
+            url,
+            http::request::Builder::new().method(http::method::Method::GET),
+            hyper::Body::empty(),
+        );
+
+        self.client
+            .process_into_value::<_, GetCustomFieldResponse>(req)
+            .await
+    }
+
+    /// ---
+    ///
+    /// # Get Custom Field Values
+    ///
+    /// Get the values of every custom field on an incident.
+    ///
+    /// ---
+    pub async fn get_incident_custom_field_values(
+        &self,
+        incident_id: &str,
+    ) -> Result<Vec<CustomFieldsFieldValue>, Error> {
+        let url = Praiya::parse_url(
+            &self.api_endpoint,
+            &format!("/incidents/{}/custom_fields/values", &incident_id),
+            None,
+        )?;
+
+        let req = self.client.build_request(
+            url,
+            http::request::Builder::new().method(http::method::Method::GET),
+            hyper::Body::empty(),
+        );
+
+        self.client
+            .process_into_value::<_, GetIncidentCustomFieldValuesResponse>(req)
+            .await
+    }
+
+    /// ---
+    ///
+    /// # List Fields
+    ///
+    /// List the custom fields of the account. Include `field_options` to embed the options of
+    /// fixed fields.
+    ///
+    /// ---
+    pub async fn list_custom_fields(
+        &self,
+        query_params: ListCustomFieldsParams,
+    ) -> Result<Vec<CustomFieldsField>, Error> {
+        let url = Praiya::parse_url(
+            &self.api_endpoint,
+            "/incidents/custom_fields",
+            Some(&query_params.qs),
+        )?;
+
+        let req = self.client.build_request(
+            url,
+            http::request::Builder::new().method(http::method::Method::GET),
+            hyper::Body::empty(),
+        );
+
+        self.client
+            .process_into_value::<_, ListCustomFieldsResponse>(req)
+            .await
+    }
+
+    /// ---
+    ///
+    /// # List Field Options
+    ///
+    /// List the options of a fixed custom field.
+    ///
+    /// ---
+    pub async fn list_custom_field_options(
+        &self,
+        field_id: &str,
+    ) -> Result<Vec<CustomFieldsFieldOption>, Error> {
+        let url = Praiya::parse_url(
+            &self.api_endpoint,
+            &format!("/incidents/custom_fields/{}/field_options", &field_id),
+            None,
+        )?;
+
+        let req = self.client.build_request(
+            url,
+            http::request::Builder::new().method(http::method::Method::GET),
+            hyper::Body::empty(),
+        );
+
+        self.client
+            .process_into_value::<_, ListCustomFieldOptionsResponse>(req)
+            .await
+    }
+
+    /// ---
+    ///
+    /// # Update a Field
+    ///
+    /// Update a custom field. Its name, data type and field type cannot be changed.
+    ///
+    /// ---
+    pub async fn update_custom_field(
+        &self,
+        field_id: &str,
+        body: UpdateCustomFieldsField,
+    ) -> Result<CustomFieldsField, Error> {
+        let url = Praiya::parse_url(
+            &self.api_endpoint,
+            &format!("/incidents/custom_fields/{}", &field_id),
+            None,
+        )?;
+
+        let req = self.client.build_request(
+            url,
+            http::request::Builder::new().method(http::method::Method::PUT),
+            Praiya::serialize_payload(body)?,
+        );
+
+        self.client
+            .process_into_value::<_, UpdateCustomFieldResponse>(req)
+            .await
+    }
+
+    /// ---
+    ///
+    /// # Update a Field Option
+    ///
+    /// Update an option of a fixed custom field.
+    ///
+    /// ---
+    pub async fn update_custom_field_option(
+        &self,
+        field_id: &str,
+        field_option_id: &str,
+        body: UpdateCustomFieldsFieldOption,
+    ) -> Result<CustomFieldsFieldOption, Error> {
+        let url = Praiya::parse_url(
+            &self.api_endpoint,
+            &format!(
+                "/incidents/custom_fields/{}/field_options/{}",
+                &field_id, &field_option_id
+            ),
+            None,
+        )?;
+
+        let req = self.client.build_request(
+            url,
+            http::request::Builder::new().method(http::method::Method::PUT),
+            Praiya::serialize_payload(body)?,
+        );
+
+        self.client
+            .process_into_value::<_, UpdateCustomFieldOptionResponse>(req)
+            .await
+    }
+
+    /// ---
+    ///
+    /// # Update Custom Field Values
+    ///
+    /// Set custom field values on an incident. Fields that are left out keep their value.
+    /// Returns the values of every custom field on the incident.
+    ///
+    /// ---
+    pub async fn update_incident_custom_field_values(
+        &self,
+        incident_id: &str,
+        body: UpdateIncidentCustomFieldValues,
+    ) -> Result<Vec<CustomFieldsFieldValue>, Error> {
+        let url = Praiya::parse_url(
+            &self.api_endpoint,
+            &format!("/incidents/{}/custom_fields/values", &incident_id),
+            None,
+        )?;
+
+        let req = self.client.build_request(
+            url,
+            http::request::Builder::new().method(http::method::Method::PUT),
+            Praiya::serialize_payload(body)?,
+        );
+
+        self.client
+            .process_into_value::<_, GetIncidentCustomFieldValuesResponse>(req)
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::models::*;
+    use crate::praiya::ParamsBuilder;
+
+    #[test]
+    fn test_update_incident_custom_field_values_body() {
+        let body = UpdateIncidentCustomFieldValues::default()
+            .set("deploy_version", "v1.4.2")
+            .set("canary", true)
+            .set("regions", vec!["eu-west-1", "us-east-1"])
+            .set("rollback_of", serde_json::Value::Null);
+        let json = serde_json::to_value(&body).unwrap();
+
+        assert_eq!(json["custom_fields"][0]["name"], "deploy_version");
+        assert_eq!(json["custom_fields"][0]["value"], "v1.4.2");
+        assert_eq!(json["custom_fields"][1]["value"], true);
+        assert_eq!(json["custom_fields"][2]["value"][1], "us-east-1");
+        assert!(json["custom_fields"][3]["value"].is_null());
+
+        let value: CustomFieldsFieldValue = serde_json::from_str(
+            r#"{"id":"PT4KHEE","type":"field_value","name":"canary","data_type":"boolean","field_type":"single_value","value":null}"#,
+        )
+        .unwrap();
+        assert_eq!(
+            value.data_type,
+            Some(CustomFieldsFieldValueDataTypeEnum::BOOLEAN)
+        );
+        assert_eq!(value.value, None);
+    }
+
+    #[tokio::test]
+    async fn test_get_incident_custom_field_values() {
+        let pagerduty = crate::Praiya::new("test");
+        let values = pagerduty
+            .custom_fields()
+            .get_incident_custom_field_values("PT4KHLK")
+            .await
+            .unwrap();
+
+        assert_eq!(values[0].name, Some(String::from("environment")));
+    }
+
+    #[tokio::test]
+    async fn test_list_custom_fields() {
+        let pagerduty = crate::Praiya::new("test");
+        let mut opts_builder = super::ListCustomFieldsParamsBuilder::new();
+        opts_builder.include(vec!["field_options"]);
+        let opts = opts_builder.build();
+        let fields = pagerduty
+            .custom_fields()
+            .list_custom_fields(opts)
+            .await
+            .unwrap();
+
+        assert_eq!(fields[0].id, Some(String::from("PT4KHEE")));
+    }
+
+    #[tokio::test]
+    async fn test_update_incident_custom_field_values() {
+        let pagerduty = crate::Praiya::new("test");
+        let values = pagerduty
+            .custom_fields()
+            .update_incident_custom_field_values(
+                "PT4KHLK",
+                UpdateIncidentCustomFieldValues::default().set("environment", "production"),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(values[0].value, Some(serde_json::json!("production")));
+    }
+}
@@ -0,0 +1,885 @@
+//! Method, error and parameter types for the Event Orchestrations endpoint.
+
+use futures_core::Stream;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+use crate::errors::Error;
+use crate::models::*;
+use crate::praiya::PraiyaCustomHeaders;
+use crate::{
+    BaseOption, BaseRequest, PaginatedResponse, PaginationQueryComponent, Praiya, SingleResponse,
+    SubSystem, DEFAULT_PAGERDUTY_API_LIMIT,
+};
+
+pub const API_ENDPOINT: &str = "https://api.pagerduty.com";
+
+/// A client for the PagerDuty event orchestrations API
+pub struct EventOrchestrationsClient {
+    pub(crate) api_endpoint: String,
+    pub(crate) client: Praiya,
+}
+
+impl Praiya {
+    pub fn event_orchestrations(&self) -> EventOrchestrationsClient {
+        EventOrchestrationsClient {
+            api_endpoint: std::env::var("PAGERDUTY_API_ENDPOINT")
+                .unwrap_or_else(|_| String::from(API_ENDPOINT)),
+            client: Praiya::clone(self),
+        }
+    }
+}
+
+/// An integration of a global orchestration, whose routing key sends events to it
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub struct OrchestrationIntegration {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// Name of the integration.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub label: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parameters: Option<OrchestrationParameters>,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CreateOrchestrationIntegration {
+    pub integration: OrchestrationIntegration,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub struct UpdateOrchestrationIntegration {
+    pub integration: OrchestrationIntegration,
+}
+
+/// Move an integration from another global orchestration to this one
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MigrateOrchestrationIntegration {
+    /// The type of the source of the integration, `orchestration`.
+    pub source_type: String,
+    /// The id of the global orchestration currently owning the integration.
+    pub source_id: String,
+    pub integration_id: String,
+}
+
+/// Whether the orchestration rules of a service are evaluated, rather than its event rules
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ServiceOrchestrationActiveStatus {
+    pub active: bool,
+}
+
+impl SingleResponse for ServiceOrchestrationActiveStatus {
+    type Inner = ServiceOrchestrationActiveStatus;
+
+    fn inner(self) -> Self::Inner {
+        self
+    }
+}
+
+single_response_type!(
+    OrchestrationIntegration,
+    integration,
+    CreateOrchestrationIntegration
+);
+
+single_response_type!(Orchestration, orchestration, CreateOrchestration);
+
+single_response_type!(
+    OrchestrationIntegration,
+    integration,
+    GetOrchestrationIntegration
+);
+
+single_response_type!(Orchestration, orchestration, GetOrchestration);
+
+single_response_type!(
+    OrchestrationRouterOrchestrationPath,
+    orchestration_path,
+    GetOrchPathRouter
+);
+
+single_response_type!(
+    ServiceOrchestrationOrchestrationPath,
+    orchestration_path,
+    GetOrchPathService
+);
+
+single_response_type!(
+    OrchestrationUnroutedOrchestrationPath,
+    orchestration_path,
+    GetOrchPathUnrouted
+);
+
+list_response_type!(ListOrchestrations, orchestrations, Orchestration);
+
+plural_response_type!(
+    OrchestrationIntegration,
+    integrations,
+    ListOrchestrationIntegrations
+);
+
+plural_response_type!(
+    OrchestrationIntegration,
+    integrations,
+    MigrateOrchestrationIntegration
+);
+
+single_response_type!(
+    OrchestrationIntegration,
+    integration,
+    UpdateOrchestrationIntegration
+);
+
+single_response_type!(Orchestration, orchestration, UpdateOrchestration);
+
+single_response_type!(
+    OrchestrationRouterOrchestrationPath,
+    orchestration_path,
+    UpdateOrchPathRouter
+);
+
+single_response_type!(
+    ServiceOrchestrationOrchestrationPath,
+    orchestration_path,
+    UpdateOrchPathService
+);
+
+single_response_type!(
+    OrchestrationUnroutedOrchestrationPath,
+    orchestration_path,
+    UpdateOrchPathUnrouted
+);
+
+#[derive(praiya_macro::PraiyaParamsBuilder)]
+#[doc = "[EventOrchestrationsClient::get_orch_path_service]"]
+#[allow(dead_code)]
+struct GetOrchPathService {
+    include: Vec<String>,
+}
+
+#[derive(praiya_macro::PraiyaParamsBuilder)]
+#[doc = "[EventOrchestrationsClient::list_event_orchestrations]"]
+#[allow(dead_code)]
+struct ListEventOrchestrations {
+    sort_by: String,
+}
+
+impl EventOrchestrationsClient {
+    /// ---
+    ///
+    /// # Create an Orchestration
+    ///
+    /// Create a Global Orchestration.
+    ///
+    /// Global Event Orchestrations allow you define a set of Global Rules and Router Rules, so
+    /// that all of your ingested Events will be processed and routed to the correct Service.
+    ///
+    /// ---
+    pub async fn create_orchestration(
+        &self,
+        body: PostOrchestration,
+    ) -> Result<Orchestration, Error> {
+        let url = Praiya::parse_url(&self.api_endpoint, "/event_orchestrations", None)?;
+
+        let req = self.client.build_request(
+            url,
+            http::request::Builder::new().method(http::method::Method::POST),
+            Praiya::serialize_payload(body)?,
+        );
+
+        self.client
+            .process_into_value::<_, CreateOrchestrationResponse>(req)
+            .await
+    }
+
+    /// ---
+    ///
+    /// # Create an Integration for an Orchestration
+    ///
+    /// Create an Integration for a Global Orchestration. Its routing key can be used to send
+    /// events to the Orchestration.
+    ///
+    /// ---
+    pub async fn create_orchestration_integration(
+        &self,
+        id: &str,
+        body: CreateOrchestrationIntegration,
+    ) -> Result<OrchestrationIntegration, Error> {
+        let url = Praiya::parse_url(
+            &self.api_endpoint,
+            &format!("/event_orchestrations/{}/integrations", &id),
+            None,
+        )?;
+
+        let req = self.client.build_request(
+            url,
+            http::request::Builder::new().method(http::method::Method::POST),
+            Praiya::serialize_payload(body)?,
+        );
+
+        self.client
+            .process_into_value::<_, CreateOrchestrationIntegrationResponse>(req)
+            .await
+    }
+
+    /// ---
+    ///
+    /// # Delete an Orchestration
+    ///
+    /// Delete a Global Orchestration. Once deleted, events sent to its routing keys are dropped.
+    ///
+    /// ---
+    pub async fn delete_orchestration(&self, id: &str) -> Result<(), Error> {
+        let url = Praiya::parse_url(
+            &self.api_endpoint,
+            &format!("/event_orchestrations/{}", &id),
+            None,
+        )?;
+
+        let req = self.client.build_request(
+            url,
+            http::request::Builder::new().method(http::method::Method::DELETE),
+            hyper::Body::empty(),
+        );
+
+        self.client.process_into_unit(req).await
+    }
+
+    /// ---
+    ///
+    /// # Delete an Integration for an Orchestration
+    ///
+    /// Delete an Integration and its associated routing key. Events sent to the routing key are
+    /// dropped.
+    ///
+    /// ---
+    pub async fn delete_orchestration_integration(
+        &self,
+        id: &str,
+        integration_id: &str,
+    ) -> Result<(), Error> {
+        let url = Praiya::parse_url(
+            &self.api_endpoint,
+            &format!(
+                "/event_orchestrations/{}/integrations/{}",
+                &id, &integration_id
+            ),
+            None,
+        )?;
+
+        let req = self.client.build_request(
+            url,
+            http::request::Builder::new().method(http::method::Method::DELETE),
+            hyper::Body::empty(),
+        );
+
+        self.client.process_into_unit(req).await
+    }
+
+    /// ---
+    ///
+    /// # Get the Service Orchestration active status
+    ///
+    /// Get whether a Service's Orchestration Rules are evaluated, rather than its Event Rules.
+    ///
+    /// ---
+    pub async fn get_orch_active_status(
+        &self,
+        service_id: &str,
+    ) -> Result<ServiceOrchestrationActiveStatus, Error> {
+        let url = Praiya::parse_url(
+            &self.api_endpoint,
+            &format!("/event_orchestrations/services/{}/active", &service_id),
+            None,
+        )?;
+
+        let req = self.client.build_request(
+            url,
+            http::request::Builder::new().method(http::method::Method::GET),
+            hyper::Body::empty(),
+        );
+
+        self.client
+            .process_into_value::<_, ServiceOrchestrationActiveStatus>(req)
+            .await
+    }
+
+    /// ---
+    ///
+    /// # Get the Router for a Global Orchestration
+    ///
+    /// Get a Global Orchestration's Routing Rules, which determine the Service each event is
+    /// routed to.
+    ///
+    /// ---
+    pub async fn get_orch_path_router(
+        &self,
+        id: &str,
+    ) -> Result<OrchestrationRouterOrchestrationPath, Error> {
+        let url = Praiya::parse_url(
+            &self.api_endpoint,
+            &format!("/event_orchestrations/{}/router", &id),
+            None,
+        )?;
+
+        let req = self.client.build_request(
+            url,
+            http::request::Builder::new().method(http::method::Method::GET),
+            hyper::Body::empty(),
+        );
+
+        self.client
+            .process_into_value::<_, GetOrchPathRouterResponse>(req)
+            .await
+    }
+
+    /// ---
+    ///
+    /// # Get the Service Orchestration for a Service
+    ///
+    /// Get a Service's Orchestration, the set of rules applied to events routed to the Service.
+    ///
+    /// ---
+    pub async fn get_orch_path_service(
+        &self,
+        service_id: &str,
+        query_params: GetOrchPathServiceParams,
+    ) -> Result<ServiceOrchestrationOrchestrationPath, Error> {
+        let url = Praiya::parse_url(
+            &self.api_endpoint,
+            &format!("/event_orchestrations/services/{}", &service_id),
+            Some(&query_params.qs),
+        )?;
+
+        let req = self.client.build_request(
+            url,
+            http::request::Builder::new().method(http::method::Method::GET),
+            hyper::Body::empty(),
+        );
+
+        self.client
+            .process_into_value::<_, GetOrchPathServiceResponse>(req)
+            .await
+    }
+
+    /// ---
+    ///
+    /// # Get the Unrouted Orchestration for a Global Orchestration
+    ///
+    /// Get the rules applied to events that do not match any of a Global Orchestration's
+    /// Routing Rules.
+    ///
+    /// ---
+    pub async fn get_orch_path_unrouted(
+        &self,
+        id: &str,
+    ) -> Result<OrchestrationUnroutedOrchestrationPath, Error> {
+        let url = Praiya::parse_url(
+            &self.api_endpoint,
+            &format!("/event_orchestrations/{}/unrouted", &id),
+            None,
+        )?;
+
+        let req = self.client.build_request(
+            url,
+            http::request::Builder::new().method(http::method::Method::GET),
+            hyper::Body::empty(),
+        );
+
+        self.client
+            .process_into_value::<_, GetOrchPathUnroutedResponse>(req)
+            .await
+    }
+
+    /// ---
+    ///
+    /// # Get an Orchestration
+    ///
+    /// Get a Global Orchestration.
+    ///
+    /// ---
+    pub async fn get_orchestration(&self, id: &str) -> Result<Orchestration, Error> {
+        let url = Praiya::parse_url(
+            &self.api_endpoint,
+            &format!("/event_orchestrations/{}", &id),
+            None,
+        )?;
+
+        let req = self.client.build_request(
+            url,
+            http::request::Builder::new().method(http::method::Method::GET),
+            hyper::Body::empty(),
+        );
+
+        self.client
+            .process_into_value::<_, GetOrchestrationResponse>(req)
+            .await
+    }
+
+    /// ---
+    ///
+    /// # Get an Integration for an Orchestration
+    ///
+    /// Get an Integration of a Global Orchestration.
+    ///
+    /// ---
+    pub async fn get_orchestration_integration(
+        &self,
+        id: &str,
+        integration_id: &str,
+    ) -> Result<OrchestrationIntegration, Error> {
+        let url = Praiya::parse_url(
+            &self.api_endpoint,
+            &format!(
+                "/event_orchestrations/{}/integrations/{}",
+                &id, &integration_id
+            ),
+            None,
+        )?;
+
+        let req = self.client.build_request(
+            url,
+            http::request::Builder::new().method(http::method::Method::GET),
+            hyper::Body::empty(),
+        );
+
+        self.client
+            .process_into_value::<_, GetOrchestrationIntegrationResponse>(req)
+            .await
+    }
+
+    /// ---
+    ///
+    /// # List Event Orchestrations
+    ///
+    /// List all Global Event Orchestrations on an Account.
+    ///
+    /// ---
+    pub fn list_event_orchestrations(
+        &self,
+        query_params: ListEventOrchestrationsParams,
+    ) -> impl Stream<Item = Result<Orchestration, Error>> + '_ {
+        self.client
+            .list_request::<_, _, ListOrchestrationsResponse>(
+                &self.api_endpoint,
+                "/event_orchestrations",
+                query_params,
+                PraiyaCustomHeaders::None,
+            )
+    }
+
+    /// ---
+    ///
+    /// # List Integrations for an Orchestration
+    ///
+    /// List the Integrations of a Global Orchestration.
+    ///
+    /// ---
+    pub async fn list_orchestration_integrations(
+        &self,
+        id: &str,
+    ) -> Result<Vec<OrchestrationIntegration>, Error> {
+        let url = Praiya::parse_url(
+            &self.api_endpoint,
+            &format!("/event_orchestrations/{}/integrations", &id),
+            None,
+        )?;
+
+        let req = self.client.build_request(
+            url,
+            http::request::Builder::new().method(http::method::Method::GET),
+            hyper::Body::empty(),
+        );
+
+        self.client
+            .process_into_value::<_, ListOrchestrationIntegrationsResponse>(req)
+            .await
+    }
+
+    /// ---
+    ///
+    /// # Migrate an Integration from one Orchestration to another
+    ///
+    /// Move an Integration and its routing key from one Global Orchestration to another,
+    /// returning the Integrations of the destination Orchestration.
+    ///
+    /// ---
+    pub async fn migrate_orchestration_integration(
+        &self,
+        id: &str,
+        body: MigrateOrchestrationIntegration,
+    ) -> Result<Vec<OrchestrationIntegration>, Error> {
+        let url = Praiya::parse_url(
+            &self.api_endpoint,
+            &format!("/event_orchestrations/{}/integrations/migration", &id),
+            None,
+        )?;
+
+        let req = self.client.build_request(
+            url,
+            http::request::Builder::new().method(http::method::Method::POST),
+            Praiya::serialize_payload(body)?,
+        );
+
+        self.client
+            .process_into_value::<_, MigrateOrchestrationIntegrationResponse>(req)
+            .await
+    }
+
+    /// ---
+    ///
+    /// # Update the Service Orchestration active status
+    ///
+    /// Set whether a Service's Orchestration Rules are evaluated, rather than its Event Rules.
+    ///
+    /// ---
+    pub async fn update_orch_active_status(
+        &self,
+        service_id: &str,
+        body: ServiceOrchestrationActiveStatus,
+    ) -> Result<ServiceOrchestrationActiveStatus, Error> {
+        let url = Praiya::parse_url(
+            &self.api_endpoint,
+            &format!("/event_orchestrations/services/{}/active", &service_id),
+            None,
+        )?;
+
+        let req = self.client.build_request(
+            url,
+            http::request::Builder::new().method(http::method::Method::PUT),
+            Praiya::serialize_payload(body)?,
+        );
+
+        self.client
+            .process_into_value::<_, ServiceOrchestrationActiveStatus>(req)
+            .await
+    }
+
+    /// ---
+    ///
+    /// # Update the Router for a Global Orchestration
+    ///
+    /// Update a Global Orchestration's Routing Rules.
+    ///
+    /// ---
+    pub async fn update_orch_path_router(
+        &self,
+        id: &str,
+        body: UpdateOrchPathRouter,
+    ) -> Result<OrchestrationRouterOrchestrationPath, Error> {
+        let url = Praiya::parse_url(
+            &self.api_endpoint,
+            &format!("/event_orchestrations/{}/router", &id),
+            None,
+        )?;
+
+        let req = self.client.build_request(
+            url,
+            http::request::Builder::new().method(http::method::Method::PUT),
+            Praiya::serialize_payload(body)?,
+        );
+
+        self.client
+            .process_into_value::<_, UpdateOrchPathRouterResponse>(req)
+            .await
+    }
+
+    /// ---
+    ///
+    /// # Update the Service Orchestration for a Service
+    ///
+    /// Update a Service's Orchestration.
+    ///
+    /// ---
+    pub async fn update_orch_path_service(
+        &self,
+        service_id: &str,
+        body: UpdateOrchPathService,
+    ) -> Result<ServiceOrchestrationOrchestrationPath, Error> {
+        let url = Praiya::parse_url(
+            &self.api_endpoint,
+            &format!("/event_orchestrations/services/{}", &service_id),
+            None,
+        )?;
+
+        let req = self.client.build_request(
+            url,
+            http::request::Builder::new().method(http::method::Method::PUT),
+            Praiya::serialize_payload(body)?,
+        );
+
+        self.client
+            .process_into_value::<_, UpdateOrchPathServiceResponse>(req)
+            .await
+    }
+
+    /// ---
+    ///
+    /// # Update the Unrouted Orchestration for a Global Orchestration
+    ///
+    /// Update the rules applied to events that do not match any of a Global Orchestration's
+    /// Routing Rules.
+    ///
+    /// ---
+    pub async fn update_orch_path_unrouted(
+        &self,
+        id: &str,
+        body: UpdateOrchPathUnrouted,
+    ) -> Result<OrchestrationUnroutedOrchestrationPath, Error> {
+        let url = Praiya::parse_url(
+            &self.api_endpoint,
+            &format!("/event_orchestrations/{}/unrouted", &id),
+            None,
+        )?;
+
+        let req = self.client.build_request(
+            url,
+            http::request::Builder::new().method(http::method::Method::PUT),
+            Praiya::serialize_payload(body)?,
+        );
+
+        self.client
+            .process_into_value::<_, UpdateOrchPathUnroutedResponse>(req)
+            .await
+    }
+
+    /// ---
+    ///
+    /// # Update an Orchestration
+    ///
+    /// Update a Global Orchestration.
+    ///
+    /// ---
+    pub async fn update_orchestration(
+        &self,
+        id: &str,
+        body: UpdateOrchestration,
+    ) -> Result<Orchestration, Error> {
+        let url = Praiya::parse_url(
+            &self.api_endpoint,
+            &format!("/event_orchestrations/{}", &id),
+            None,
+        )?;
+
+        let req = self.client.build_request(
+            url,
+            http::request::Builder::new().method(http::method::Method::PUT),
+            Praiya::serialize_payload(body)?,
+        );
+
+        self.client
+            .process_into_value::<_, UpdateOrchestrationResponse>(req)
+            .await
+    }
+
+    /// ---
+    ///
+    /// # Update an Integration for an Orchestration
+    ///
+    /// Update the label of an Integration of a Global Orchestration.
+    ///
+    /// ---
+    pub async fn update_orchestration_integration(
+        &self,
+        id: &str,
+        integration_id: &str,
+        body: UpdateOrchestrationIntegration,
+    ) -> Result<OrchestrationIntegration, Error> {
+        let url = Praiya::parse_url(
+            &self.api_endpoint,
+            &format!(
+                "/event_orchestrations/{}/integrations/{}",
+                &id, &integration_id
+            ),
+            None,
+        )?;
+
+        let req = self.client.build_request(
+            url,
+            http::request::Builder::new().method(http::method::Method::PUT),
+            Praiya::serialize_payload(body)?,
+        );
+
+        self.client
+            .process_into_value::<_, UpdateOrchestrationIntegrationResponse>(req)
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::praiya::ParamsBuilder;
+    use futures_util::TryStreamExt;
+
+    #[tokio::test]
+    async fn test_create_orchestration() {
+        let pagerduty = crate::Praiya::new("test");
+        let post_orchestration = PostOrchestration {
+            orchestration: Orchestration {
+                name: Some(String::from("Shopping Cart Orchestration")),
+                ..Default::default()
+            },
+        };
+        let orchestration = pagerduty
+            .event_orchestrations()
+            .create_orchestration(post_orchestration)
+            .await
+            .unwrap();
+
+        assert_eq!(orchestration.id, Some(String::from("b02e973d")));
+    }
+
+    #[tokio::test]
+    async fn test_create_orchestration_integration() {
+        let pagerduty = crate::Praiya::new("test");
+        let create_integration = CreateOrchestrationIntegration {
+            integration: OrchestrationIntegration {
+                label: Some(String::from("Shopping Cart Integration")),
+                ..Default::default()
+            },
+        };
+        let integration = pagerduty
+            .event_orchestrations()
+            .create_orchestration_integration("b02e973d", create_integration)
+            .await
+            .unwrap();
+
+        assert!(integration.id.is_some());
+    }
+
+    #[tokio::test]
+    async fn test_delete_orchestration() {
+        let pagerduty = crate::Praiya::new("test");
+        let unit = pagerduty
+            .event_orchestrations()
+            .delete_orchestration("b02e973d")
+            .await
+            .unwrap();
+
+        assert_eq!(unit, ());
+    }
+
+    #[tokio::test]
+    async fn test_get_orch_active_status() {
+        let pagerduty = crate::Praiya::new("test");
+        let status = pagerduty
+            .event_orchestrations()
+            .get_orch_active_status("PC2D9ML")
+            .await
+            .unwrap();
+
+        assert!(status.active);
+    }
+
+    #[tokio::test]
+    async fn test_get_orch_path_router() {
+        let pagerduty = crate::Praiya::new("test");
+        let router = pagerduty
+            .event_orchestrations()
+            .get_orch_path_router("b02e973d")
+            .await
+            .unwrap();
+
+        assert_eq!(router._type, String::from("router"));
+    }
+
+    #[tokio::test]
+    async fn test_get_orch_path_service() {
+        let pagerduty = crate::Praiya::new("test");
+        let mut opts_builder = super::GetOrchPathServiceParamsBuilder::new();
+        opts_builder.include(vec!["migrated_metadata"]);
+        let opts = opts_builder.build();
+
+        let service_orchestration = pagerduty
+            .event_orchestrations()
+            .get_orch_path_service("PC2D9ML", opts)
+            .await
+            .unwrap();
+
+        assert!(service_orchestration.sets.is_some());
+    }
+
+    #[tokio::test]
+    async fn test_get_orch_path_unrouted() {
+        let pagerduty = crate::Praiya::new("test");
+        let unrouted = pagerduty
+            .event_orchestrations()
+            .get_orch_path_unrouted("b02e973d")
+            .await
+            .unwrap();
+
+        assert!(unrouted.sets.is_some());
+    }
+
+    #[tokio::test]
+    async fn test_get_orchestration() {
+        let pagerduty = crate::Praiya::new("test");
+        let orchestration = pagerduty
+            .event_orchestrations()
+            .get_orchestration("b02e973d")
+            .await
+            .unwrap();
+
+        assert_eq!(orchestration.id, Some(String::from("b02e973d")));
+    }
+
+    #[tokio::test]
+    async fn test_list_event_orchestrations() {
+        let pagerduty = crate::Praiya::new("test");
+        let mut opts_builder = super::ListEventOrchestrationsParamsBuilder::new();
+        opts_builder.sort_by("name:asc");
+        let opts = opts_builder.build();
+
+        let orchestration: Option<Orchestration> = pagerduty
+            .event_orchestrations()
+            .list_event_orchestrations(opts)
+            .try_next()
+            .await
+            .unwrap();
+
+        assert_eq!(orchestration.unwrap().id, Some(String::from("b02e973d")));
+    }
+
+    #[tokio::test]
+    async fn test_list_orchestration_integrations() {
+        let pagerduty = crate::Praiya::new("test");
+        let integrations = pagerduty
+            .event_orchestrations()
+            .list_orchestration_integrations("b02e973d")
+            .await
+            .unwrap();
+
+        assert!(!integrations.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_update_orch_active_status() {
+        let pagerduty = crate::Praiya::new("test");
+        let status = pagerduty
+            .event_orchestrations()
+            .update_orch_active_status("PC2D9ML", ServiceOrchestrationActiveStatus { active: true })
+            .await
+            .unwrap();
+
+        assert!(status.active);
+    }
+
+    #[tokio::test]
+    async fn test_update_orchestration() {
+        let pagerduty = crate::Praiya::new("test");
+        let update_orchestration = UpdateOrchestration {
+            orchestration: Orchestration {
+                description: Some(String::from("Send shopping cart alerts to the right team")),
+                ..Default::default()
+            },
+        };
+        let orchestration = pagerduty
+            .event_orchestrations()
+            .update_orchestration("b02e973d", update_orchestration)
+            .await
+            .unwrap();
+
+        assert_eq!(orchestration.id, Some(String::from("b02e973d")));
+    }
+}
@@ -0,0 +1,159 @@
+//! Method, error and parameter types for the Extension Schemas endpoint.
+//!
+//! Extension schemas are returned as [ExtensionSchemaDefinition], which keeps the `id` and
+//! reference fields that the generated [ExtensionSchema] model omits, so that a schema can be
+//! referenced when creating an [extension](crate::endpoints::extensions).
+
+use futures_core::Stream;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+use crate::errors::Error;
+use crate::models::*;
+use crate::praiya::{NoopParams, PraiyaCustomHeaders};
+use crate::{
+    BaseOption, BaseRequest, PaginatedResponse, PaginationQueryComponent, Praiya, SingleResponse,
+    SubSystem, DEFAULT_PAGERDUTY_API_LIMIT,
+};
+
+pub const API_ENDPOINT: &str = "https://api.pagerduty.com";
+
+/// A client for the PagerDuty extension schemas API
+pub struct ExtensionSchemasClient {
+    pub(crate) api_endpoint: String,
+    pub(crate) client: Praiya,
+}
+
+impl Praiya {
+    pub fn extension_schemas(&self) -> ExtensionSchemasClient {
+        ExtensionSchemasClient {
+            api_endpoint: std::env::var("PAGERDUTY_API_ENDPOINT")
+                .unwrap_or_else(|_| String::from(API_ENDPOINT)),
+            client: Praiya::clone(self),
+        }
+    }
+}
+
+/// An extension schema, along with the reference fields of the API object
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ExtensionSchemaDefinition {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// `extension_schema`, or `extension_schema_reference` when referencing a schema.
+    #[serde(rename = "type", default, skip_serializing_if = "Option::is_none")]
+    pub _type: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub summary: Option<String>,
+    #[serde(rename = "self", default, skip_serializing_if = "Option::is_none")]
+    pub _self: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub html_url: Option<String>,
+    #[serde(flatten)]
+    pub schema: ExtensionSchema,
+}
+
+impl ExtensionSchemaDefinition {
+    /// A reference to the extension schema with the given id.
+    pub fn reference(id: &str) -> Self {
+        ExtensionSchemaDefinition {
+            id: Some(String::from(id)),
+            _type: Some(String::from("extension_schema_reference")),
+            ..Default::default()
+        }
+    }
+}
+
+single_response_type!(
+    ExtensionSchemaDefinition,
+    extension_schema,
+    GetExtensionSchema
+);
+
+list_response_type!(
+    ListExtensionSchemas,
+    extension_schemas,
+    ExtensionSchemaDefinition
+);
+
+impl ExtensionSchemasClient {
+    /// ---
+    ///
+    /// # Get an extension vendor
+    ///
+    /// Get details about one specific extension vendor.
+    ///
+    /// A PagerDuty extension vendor represents a specific type of outbound extension such as
+    /// Generic Webhook, Slack, ServiceNow.
+    ///
+    /// ---
+    pub async fn get_extension_schema(&self, id: &str) -> Result<ExtensionSchemaDefinition, Error> {
+        let url = Praiya::parse_url(
+            &self.api_endpoint,
+            &format!("/extension_schemas/{}", &id),
+            None,
+        )?;
+
+        let req = self.client.build_request(
+            url,
+            http::request::Builder::new().method(http::method::Method::GET),
+            hyper::Body::empty(),
+        );
+
+        self.client
+            .process_into_value::<_, GetExtensionSchemaResponse>(req)
+            .await
+    }
+
+    /// ---
+    ///
+    /// # List extension schemas
+    ///
+    /// List all extension schemas.
+    ///
+    /// ---
+    pub fn list_extension_schemas(
+        &self,
+    ) -> impl Stream<Item = Result<ExtensionSchemaDefinition, Error>> + '_ {
+        self.client
+            .list_request::<_, _, ListExtensionSchemasResponse>(
+                &self.api_endpoint,
+                "/extension_schemas",
+                NoopParams {},
+                PraiyaCustomHeaders::None,
+            )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ExtensionSchemaDefinition;
+    use futures_util::TryStreamExt;
+
+    #[tokio::test]
+    async fn test_get_extension_schema() {
+        let pagerduty = crate::Praiya::new("test");
+        let extension_schema = pagerduty
+            .extension_schemas()
+            .get_extension_schema("PJFWPEP")
+            .await
+            .unwrap();
+
+        assert_eq!(extension_schema.id, Some(String::from("PJFWPEP")));
+    }
+
+    #[tokio::test]
+    async fn test_list_extension_schemas() {
+        let pagerduty = crate::Praiya::new("test");
+        let extension_schema: Option<ExtensionSchemaDefinition> = pagerduty
+            .extension_schemas()
+            .list_extension_schemas()
+            .try_next()
+            .await
+            .unwrap();
+
+        assert_eq!(
+            extension_schema.unwrap().schema.key,
+            Some(String::from("generic_v2_webhook"))
+        );
+    }
+}
@@ -0,0 +1,384 @@
+//! Method, error and parameter types for the Extensions endpoint.
+//!
+//! The generated [ExtensionSchema] model has no `id`, so an [Extension] cannot reference its
+//! schema. Extensions are therefore sent and returned as [ExtensionDefinition], which carries the
+//! schema as an [ExtensionSchemaDefinition] next to the generated model.
+
+use std::collections::HashMap;
+
+use futures_core::Stream;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::endpoints::extension_schemas::ExtensionSchemaDefinition;
+use crate::errors::Error;
+use crate::models::*;
+use crate::praiya::PraiyaCustomHeaders;
+use crate::{
+    BaseOption, BaseRequest, PaginatedResponse, PaginationQueryComponent, Praiya, SingleResponse,
+    SubSystem, DEFAULT_PAGERDUTY_API_LIMIT,
+};
+
+pub const API_ENDPOINT: &str = "https://api.pagerduty.com";
+
+/// A client for the PagerDuty extensions API
+pub struct ExtensionsClient {
+    pub(crate) api_endpoint: String,
+    pub(crate) client: Praiya,
+}
+
+impl Praiya {
+    pub fn extensions(&self) -> ExtensionsClient {
+        ExtensionsClient {
+            api_endpoint: std::env::var("PAGERDUTY_API_ENDPOINT")
+                .unwrap_or_else(|_| String::from(API_ENDPOINT)),
+            client: Praiya::clone(self),
+        }
+    }
+}
+
+/// An extension along with its schema. Leave `extension.extension_schema` unset and use
+/// `extension_schema` instead.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ExtensionDefinition {
+    #[serde(flatten)]
+    pub extension: Extension,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub extension_schema: Option<ExtensionSchemaDefinition>,
+}
+
+impl ExtensionDefinition {
+    /// An extension of the given schema, e.g. a generic webhook (v2), applying to the given
+    /// services.
+    pub fn new(name: &str, extension_schema_id: &str, service_ids: &[&str]) -> Self {
+        ExtensionDefinition {
+            extension: Extension {
+                name: Some(String::from(name)),
+                _type: ExtensionTypeEnum::EXTENSION,
+                extension_objects: Some(
+                    service_ids
+                        .iter()
+                        .map(|id| Service {
+                            id: Some(String::from(*id)),
+                            _type: ServiceTypeEnum::SERVICE_REFERENCE,
+                            ..Default::default()
+                        })
+                        .collect(),
+                ),
+                ..Default::default()
+            },
+            extension_schema: Some(ExtensionSchemaDefinition::reference(extension_schema_id)),
+        }
+    }
+
+    pub fn endpoint_url(mut self, endpoint_url: &str) -> Self {
+        self.extension.endpoint_url = Some(String::from(endpoint_url));
+        self
+    }
+
+    /// Set a configuration value, as specified by the extension schema.
+    pub fn config(mut self, key: &str, value: Value) -> Self {
+        self.extension
+            .config
+            .get_or_insert_with(HashMap::new)
+            .insert(String::from(key), value);
+        self
+    }
+}
+
+impl From<Extension> for ExtensionDefinition {
+    fn from(extension: Extension) -> Self {
+        ExtensionDefinition {
+            extension,
+            extension_schema: None,
+        }
+    }
+}
+
+/// The body of [ExtensionsClient::create_extension]
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CreateExtensionDefinition {
+    pub extension: ExtensionDefinition,
+}
+
+impl From<CreateExtension> for CreateExtensionDefinition {
+    fn from(body: CreateExtension) -> Self {
+        CreateExtensionDefinition {
+            extension: ExtensionDefinition::from(body.extension),
+        }
+    }
+}
+
+/// The body of [ExtensionsClient::update_extension]
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub struct UpdateExtensionDefinition {
+    pub extension: ExtensionDefinition,
+}
+
+impl From<UpdateExtension> for UpdateExtensionDefinition {
+    fn from(body: UpdateExtension) -> Self {
+        UpdateExtensionDefinition {
+            extension: ExtensionDefinition::from(body.extension),
+        }
+    }
+}
+
+single_response_type!(ExtensionDefinition, extension, CreateExtension);
+
+single_response_type!(ExtensionDefinition, extension, EnableExtension);
+
+single_response_type!(ExtensionDefinition, extension, GetExtension);
+
+list_response_type!(ListExtensions, extensions, ExtensionDefinition);
+
+single_response_type!(ExtensionDefinition, extension, UpdateExtension);
+
+#[derive(praiya_macro::PraiyaParamsBuilder)]
+#[doc = "[ExtensionsClient::get_extension]"]
+#[allow(dead_code)]
+struct GetExtension {
+    include: Vec<String>,
+}
+
+#[derive(praiya_macro::PraiyaParamsBuilder)]
+#[doc = "[ExtensionsClient::list_extensions]"]
+#[allow(dead_code)]
+struct ListExtensions {
+    query: String,
+    extension_object_id: String,
+    extension_schema_id: String,
+    include: Vec<String>,
+}
+
+impl ExtensionsClient {
+    /// ---
+    ///
+    /// # Create an extension
+    ///
+    /// Create a new Extension.
+    ///
+    /// Extensions are representations of Extension Schema objects that are attached to
+    /// Services.
+    ///
+    /// ---
+    pub async fn create_extension(
+        &self,
+        body: CreateExtensionDefinition,
+    ) -> Result<ExtensionDefinition, Error> {
+        let url = Praiya::parse_url(&self.api_endpoint, "/extensions", None)?;
+
+        let req = self.client.build_request(
+            url,
+            http::request::Builder::new().method(http::method::Method::POST),
+            Praiya::serialize_payload(body)?,
+        );
+
+        self.client
+            .process_into_value::<_, CreateExtensionResponse>(req)
+            .await
+    }
+
+    /// ---
+    ///
+    /// # Delete an extension
+    ///
+    /// Delete an existing extension. Once the extension is deleted, it will not be accessible
+    /// from the web UI and new incidents won't be able to be created for this extension.
+    ///
+    /// ---
+    pub async fn delete_extension(&self, id: &str) -> Result<(), Error> {
+        let url = Praiya::parse_url(&self.api_endpoint, &format!("/extensions/{}", &id), None)?;
+
+        let req = self.client.build_request(
+            url,
+            http::request::Builder::new().method(http::method::Method::DELETE),
+            hyper::Body::empty(),
+        );
+
+        self.client.process_into_unit(req).await
+    }
+
+    /// ---
+    ///
+    /// # Enable an extension
+    ///
+    /// Enable an extension that is temporarily disabled, e.g. a webhook extension that was
+    /// repeatedly rejected by the server.
+    ///
+    /// ---
+    pub async fn enable_extension(&self, id: &str) -> Result<ExtensionDefinition, Error> {
+        let url = Praiya::parse_url(
+            &self.api_endpoint,
+            &format!("/extensions/{}/enable", &id),
+            None,
+        )?;
+
+        let req = self.client.build_request(
+            url,
+            http::request::Builder::new().method(http::method::Method::POST),
+            hyper::Body::empty(),
+        );
+
+        self.client
+            .process_into_value::<_, EnableExtensionResponse>(req)
+            .await
+    }
+
+    /// ---
+    ///
+    /// # Get an extension
+    ///
+    /// Get details about an existing extension.
+    ///
+    /// ---
+    pub async fn get_extension(
+        &self,
+        id: &str,
+        query_params: GetExtensionParams,
+    ) -> Result<ExtensionDefinition, Error> {
+        let url = Praiya::parse_url(
+            &self.api_endpoint,
+            &format!("/extensions/{}", &id),
+            Some(&query_params.qs),
+        )?;
+
+        let req = self.client.build_request(
+            url,
+            http::request::Builder::new().method(http::method::Method::GET),
+            hyper::Body::empty(),
+        );
+
+        self.client
+            .process_into_value::<_, GetExtensionResponse>(req)
+            .await
+    }
+
+    /// ---
+    ///
+    /// # List extensions
+    ///
+    /// List existing extensions, optionally filtered by the object they apply to or by their
+    /// schema.
+    ///
+    /// ---
+    pub fn list_extensions(
+        &self,
+        query_params: ListExtensionsParams,
+    ) -> impl Stream<Item = Result<ExtensionDefinition, Error>> + '_ {
+        self.client.list_request::<_, _, ListExtensionsResponse>(
+            &self.api_endpoint,
+            "/extensions",
+            query_params,
+            PraiyaCustomHeaders::None,
+        )
+    }
+
+    /// ---
+    ///
+    /// # Update an extension
+    ///
+    /// Update an existing extension.
+    ///
+    /// ---
+    pub async fn update_extension(
+        &self,
+        id: &str,
+        body: UpdateExtensionDefinition,
+    ) -> Result<ExtensionDefinition, Error> {
+        let url = Praiya::parse_url(&self.api_endpoint, &format!("/extensions/{}", &id), None)?;
+
+        let req = self.client.build_request(
+            url,
+            http::request::Builder::new().method(http::method::Method::PUT),
+            Praiya::serialize_payload(body)?,
+        );
+
+        self.client
+            .process_into_value::<_, UpdateExtensionResponse>(req)
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{CreateExtensionDefinition, ExtensionDefinition};
+    use crate::praiya::ParamsBuilder;
+    use futures_util::TryStreamExt;
+
+    fn webhook() -> ExtensionDefinition {
+        ExtensionDefinition::new("My Webhook", "PJFWPEP", &["PIJ90N7"])
+            .endpoint_url("https://example.com/receive_a_pagerduty_webhook")
+            .config("notify_types", serde_json::json!({"resolve": true}))
+    }
+
+    #[test]
+    fn test_extension_definition() {
+        let json = serde_json::to_value(CreateExtensionDefinition {
+            extension: webhook(),
+        })
+        .unwrap();
+
+        assert_eq!(json["extension"]["type"], "extension");
+        assert_eq!(json["extension"]["extension_schema"]["id"], "PJFWPEP");
+        assert_eq!(
+            json["extension"]["extension_schema"]["type"],
+            "extension_schema_reference"
+        );
+        assert_eq!(json["extension"]["extension_objects"][0]["id"], "PIJ90N7");
+        assert_eq!(json["extension"]["config"]["notify_types"]["resolve"], true);
+
+        let extension: ExtensionDefinition =
+            serde_json::from_value(json["extension"].clone()).unwrap();
+        assert!(extension.extension.extension_schema.is_none());
+        assert_eq!(
+            extension.extension_schema.unwrap().id,
+            Some(String::from("PJFWPEP"))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_create_extension() {
+        let pagerduty = crate::Praiya::new("test");
+        let extension = pagerduty
+            .extensions()
+            .create_extension(CreateExtensionDefinition {
+                extension: webhook(),
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(extension.extension.id, Some(String::from("PPGPXHO")));
+    }
+
+    #[tokio::test]
+    async fn test_enable_extension() {
+        let pagerduty = crate::Praiya::new("test");
+        let extension = pagerduty
+            .extensions()
+            .enable_extension("PPGPXHO")
+            .await
+            .unwrap();
+
+        assert_eq!(extension.extension.temporarily_disabled, Some(false));
+    }
+
+    #[tokio::test]
+    async fn test_list_extensions() {
+        let pagerduty = crate::Praiya::new("test");
+        let mut opts_builder = super::ListExtensionsParamsBuilder::new();
+        opts_builder.extension_schema_id("PJFWPEP");
+        let opts = opts_builder.build();
+        let extension: Option<ExtensionDefinition> = pagerduty
+            .extensions()
+            .list_extensions(opts)
+            .try_next()
+            .await
+            .unwrap();
+
+        assert_eq!(
+            extension.unwrap().extension.id,
+            Some(String::from("PPGPXHO"))
+        );
+    }
+}
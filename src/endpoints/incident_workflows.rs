@@ -0,0 +1,577 @@
+//! Method, error and parameter types for the Incident Workflows endpoint.
+
+use futures_core::Stream;
+use futures_util::StreamExt;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+use crate::errors::Error;
+use crate::models::*;
+use crate::praiya::{PaginatedCursorPosition, PraiyaCustomHeaders};
+use crate::{
+    BaseOption, BaseRequest, PaginatedResponse, PaginationQueryComponent, Praiya, SingleResponse,
+    SubSystem, DEFAULT_PAGERDUTY_API_LIMIT,
+};
+
+pub const API_ENDPOINT: &str = "https://api.pagerduty.com";
+
+/// A client for the PagerDuty incident workflows API
+pub struct IncidentWorkflowsClient {
+    pub(crate) api_endpoint: String,
+    pub(crate) client: Praiya,
+}
+
+impl Praiya {
+    pub fn incident_workflows(&self) -> IncidentWorkflowsClient {
+        IncidentWorkflowsClient {
+            api_endpoint: std::env::var("PAGERDUTY_API_ENDPOINT")
+                .unwrap_or_else(|_| String::from(API_ENDPOINT)),
+            client: Praiya::clone(self),
+        }
+    }
+}
+
+impl CreateIncidentWorkflowInstance {
+    /// Start the workflow on the incident with the given id.
+    pub fn incident(incident_id: &str) -> Self {
+        CreateIncidentWorkflowInstance {
+            incident_workflow_instance: IncidentWorkflowInstance {
+                incident: Some(Incident {
+                    id: Some(String::from(incident_id)),
+                    _type: String::from("incident_reference"),
+                    ..Default::default()
+                }),
+                ..Default::default()
+            },
+        }
+    }
+}
+
+single_response_type!(IncidentWorkflow, incident_workflow, CreateIncidentWorkflow);
+
+single_response_type!(
+    IncidentWorkflowInstance,
+    incident_workflow_instance,
+    CreateIncidentWorkflowInstance
+);
+
+single_response_type!(
+    IncidentWorkflowTrigger,
+    trigger,
+    CreateIncidentWorkflowTrigger
+);
+
+single_response_type!(IncidentWorkflow, incident_workflow, GetIncidentWorkflow);
+
+single_response_type!(IncidentWorkflowTrigger, trigger, GetIncidentWorkflowTrigger);
+
+list_response_type!(ListIncidentWorkflows, incident_workflows, IncidentWorkflow);
+
+single_response_type!(IncidentWorkflow, incident_workflow, UpdateIncidentWorkflow);
+
+single_response_type!(
+    IncidentWorkflowTrigger,
+    trigger,
+    UpdateIncidentWorkflowTrigger
+);
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct ListIncidentWorkflowTriggersResponse {
+    pub next_cursor: Option<String>,
+    pub limit: Option<usize>,
+    pub triggers: Vec<IncidentWorkflowTrigger>,
+}
+
+impl PaginatedResponse<PaginatedCursorPosition> for ListIncidentWorkflowTriggersResponse {
+    type Inner = Vec<IncidentWorkflowTrigger>;
+    type Cursor = Option<String>;
+
+    fn get_pos(&self) -> Self::Cursor {
+        Option::clone(&self.next_cursor)
+    }
+
+    fn get_limit(&self) -> usize {
+        self.limit.unwrap_or(DEFAULT_PAGERDUTY_API_LIMIT)
+    }
+
+    fn inner(self) -> Self::Inner {
+        self.triggers
+    }
+
+    fn has_more(&self) -> bool {
+        self.next_cursor.is_some()
+    }
+
+    fn to_cursor(&self) -> PaginatedCursorPosition {
+        PaginatedCursorPosition {
+            cursor: self.get_pos(),
+            has_more: self.has_more(),
+            limit: self.get_limit(),
+        }
+    }
+}
+
+#[derive(praiya_macro::PraiyaParamsBuilder)]
+#[doc = "[IncidentWorkflowsClient::list_incident_workflows]"]
+#[allow(dead_code)]
+struct ListIncidentWorkflows {
+    query: String,
+    include: Vec<String>,
+}
+
+#[derive(praiya_macro::PraiyaParamsBuilder)]
+#[doc = "[IncidentWorkflowsClient::list_incident_workflow_triggers]"]
+#[allow(dead_code)]
+struct ListIncidentWorkflowTriggers {
+    incident_id: String,
+    incident_workflow_id: String,
+    service_id: String,
+    trigger_type: IncidentWorkflowTriggerTriggerTypeEnum,
+    workflow_name_contains: String,
+    is_subscribed_to_all_services: bool,
+}
+
+impl IncidentWorkflowsClient {
+    /// ---
+    ///
+    /// # Add a service to a trigger
+    ///
+    /// Associate a service with an existing incident workflow trigger.
+    ///
+    /// ---
+    pub async fn associate_service_to_incident_workflow_trigger(
+        &self,
+        id: &str,
+        service_id: &str,
+    ) -> Result<IncidentWorkflowTrigger, Error> {
+        let url = Praiya::parse_url(
+            &self.api_endpoint,
+            &format!("/incident_workflows/triggers/{}/services", &id),
+            None,
+        )?;
+
+        let body = AssociateServiceToIncidentWorkflowTrigger {
+            service: Service {
+                id: Some(String::from(service_id)),
+                _type: ServiceTypeEnum::SERVICE_REFERENCE,
+                ..Default::default()
+            },
+        };
+        let req = self.client.build_request(
+            url,
+            http::request::Builder::new().method(http::method::Method::POST),
+            Praiya::serialize_payload(body)?,
+        );
+
+        self.client
+            .process_into_value::<_, CreateIncidentWorkflowTriggerResponse>(req)
+            .await
+    }
+
+    /// ---
+    ///
+    /// # Create an Incident Workflow
+    ///
+    /// Create a new Incident Workflow.
+    ///
+    /// An Incident Workflow is a sequence of configurable Steps and associated Triggers that can
+    /// execute automated Actions for a given Incident.
+    ///
+    /// ---
+    pub async fn create_incident_workflow(
+        &self,
+        body: CreateIncidentWorkflow,
+    ) -> Result<IncidentWorkflow, Error> {
+        let url = Praiya::parse_url(&self.api_endpoint, "/incident_workflows", None)?;
+
+        let req = self.client.build_request(
+            url,
+            http::request::Builder::new().method(http::method::Method::POST),
+            Praiya::serialize_payload(body)?,
+        );
+
+        self.client
+            .process_into_value::<_, CreateIncidentWorkflowResponse>(req)
+            .await
+    }
+
+    /// ---
+    ///
+    /// # Start an Incident Workflow Instance
+    ///
+    /// Start an instance of an incident workflow, sometimes referred to as running a workflow
+    /// on an incident.
+    ///
+    /// ---
+    pub async fn create_incident_workflow_instance(
+        &self,
+        id: &str,
+        body: CreateIncidentWorkflowInstance,
+    ) -> Result<IncidentWorkflowInstance, Error> {
+        let url = Praiya::parse_url(
+            &self.api_endpoint,
+            &format!("/incident_workflows/{}/instances", &id),
+            None,
+        )?;
+
+        let req = self.client.build_request(
+            url,
+            http::request::Builder::new().method(http::method::Method::POST),
+            Praiya::serialize_payload(body)?,
+        );
+
+        self.client
+            .process_into_value::<_, CreateIncidentWorkflowInstanceResponse>(req)
+            .await
+    }
+
+    /// ---
+    ///
+    /// # Create a Trigger
+    ///
+    /// Create a new incident workflow trigger, which starts its workflow manually or when its
+    /// condition is met.
+    ///
+    /// ---
+    pub async fn create_incident_workflow_trigger(
+        &self,
+        body: CreateIncidentWorkflowTrigger,
+    ) -> Result<IncidentWorkflowTrigger, Error> {
+        let url = Praiya::parse_url(&self.api_endpoint, "/incident_workflows/triggers", None)?;
+
+        let req = self.client.build_request(
+            url,
+            http::request::Builder::new().method(http::method::Method::POST),
+            Praiya::serialize_payload(body)?,
+        );
+
+        self.client
+            .process_into_value::<_, CreateIncidentWorkflowTriggerResponse>(req)
+            .await
+    }
+
+    /// ---
+    ///
+    /// # Delete an Incident Workflow
+    ///
+    /// Delete an existing Incident Workflow.
+    ///
+    /// ---
+    pub async fn delete_incident_workflow(&self, id: &str) -> Result<(), Error> {
+        let url = Praiya::parse_url(
+            &self.api_endpoint,
+            &format!("/incident_workflows/{}", &id),
+            None,
+        )?;
+
+        let req = self.client.build_request(
+            url,
+            http::request::Builder::new().method(http::method::Method::DELETE),
+            hyper::Body::empty(),
+        );
+
+        self.client.process_into_unit(req).await
+    }
+
+    /// ---
+    ///
+    /// # Delete a Trigger
+    ///
+    /// Delete an existing incident workflow trigger.
+    ///
+    /// ---
+    pub async fn delete_incident_workflow_trigger(&self, id: &str) -> Result<(), Error> {
+        let url = Praiya::parse_url(
+            &self.api_endpoint,
+            &format!("/incident_workflows/triggers/{}", &id),
+            None,
+        )?;
+
+        let req = self.client.build_request(
+            url,
+            http::request::Builder::new().method(http::method::Method::DELETE),
+            hyper::Body::empty(),
+        );
+
+        self.client.process_into_unit(req).await
+    }
+
+    /// ---
+    ///
+    /// # Remove a service from a trigger
+    ///
+    /// Remove an existing service from an incident workflow trigger.
+    ///
+    /// ---
+    pub async fn delete_service_from_incident_workflow_trigger(
+        &self,
+        id: &str,
+        service_id: &str,
+    ) -> Result<IncidentWorkflowTrigger, Error> {
+        let url = Praiya::parse_url(
+            &self.api_endpoint,
+            &format!(
+                "/incident_workflows/triggers/{}/services/{}",
+                &id, &service_id
+            ),
+            None,
+        )?;
+
+        let req = self.client.build_request(
+            url,
+            http::request::Builder::new().method(http::method::Method::DELETE),
+            hyper::Body::empty(),
+        );
+
+        self.client
+            .process_into_value::<_, GetIncidentWorkflowTriggerResponse>(req)
+            .await
+    }
+
+    /// ---
+    ///
+    /// # Get an Incident Workflow
+    ///
+    /// Get an existing Incident Workflow, along with its steps.
+    ///
+    /// ---
+    pub async fn get_incident_workflow(&self, id: &str) -> Result<IncidentWorkflow, Error> {
+        let url = Praiya::parse_url(
+            &self.api_endpoint,
+            &format!("/incident_workflows/{}", &id),
+            None,
+        )?;
+
+        let req = self.client.build_request(
+            url,
+            http::request::Builder::new().method(http::method::Method::GET),
+            hyper::Body::empty(),
+        );
+
+        self.client
+            .process_into_value::<_, GetIncidentWorkflowResponse>(req)
+            .await
+    }
+
+    /// ---
+    ///
+    /// # Get a Trigger
+    ///
+    /// Retrieve an existing incident workflow trigger.
+    ///
+    /// ---
+    pub async fn get_incident_workflow_trigger(
+        &self,
+        id: &str,
+    ) -> Result<IncidentWorkflowTrigger, Error> {
+        let url = Praiya::parse_url(
+            &self.api_endpoint,
+            &format!("/incident_workflows/triggers/{}", &id),
+            None,
+        )?;
+
+        let req = self.client.build_request(
+            url,
+            http::request::Builder::new().method(http::method::Method::GET),
+            hyper::Body::empty(),
+        );
+
+        self.client
+            .process_into_value::<_, GetIncidentWorkflowTriggerResponse>(req)
+            .await
+    }
+
+    /// ---
+    ///
+    /// # List Incident Workflows
+    ///
+    /// List existing Incident Workflows. Include `steps` or `team` to embed them.
+    ///
+    /// ---
+    pub fn list_incident_workflows(
+        &self,
+        query_params: ListIncidentWorkflowsParams,
+    ) -> impl Stream<Item = Result<IncidentWorkflow, Error>> + '_ {
+        self.client
+            .list_request::<_, _, ListIncidentWorkflowsResponse>(
+                &self.api_endpoint,
+                "/incident_workflows",
+                query_params,
+                PraiyaCustomHeaders::None,
+            )
+    }
+
+    /// ---
+    ///
+    /// # List Triggers
+    ///
+    /// List existing incident workflow triggers, optionally filtered by incident, service,
+    /// workflow or trigger type. Filtering by incident lists the manual triggers that can be
+    /// started on it.
+    ///
+    /// ---
+    pub fn list_incident_workflow_triggers(
+        &self,
+        query_params: ListIncidentWorkflowTriggersParams,
+    ) -> impl Stream<Item = Result<IncidentWorkflowTrigger, Error>> + '_ {
+        let base_request = BaseRequest {
+            host: String::from(&self.api_endpoint),
+            method: http::Method::GET,
+            options: std::sync::Arc::new(query_params),
+            path: String::from("/incident_workflows/triggers"),
+            headers: std::collections::HashMap::new(),
+        };
+
+        self.client.process_into_paginated_stream::<IncidentWorkflowTrigger, ListIncidentWorkflowTriggersResponse, PaginatedCursorPosition, crate::praiya::PaginationCursorQueryComponent>(
+            base_request,
+            std::sync::Arc::new(crate::praiya::PaginationCursorQueryComponent {
+                cursor: None,
+                limit: DEFAULT_PAGERDUTY_API_LIMIT,
+            }),
+        )
+        .boxed()
+    }
+
+    /// ---
+    ///
+    /// # Update an Incident Workflow
+    ///
+    /// Update an existing Incident Workflow. The steps given replace the existing steps.
+    ///
+    /// ---
+    pub async fn update_incident_workflow(
+        &self,
+        id: &str,
+        body: UpdateIncidentWorkflow,
+    ) -> Result<IncidentWorkflow, Error> {
+        let url = Praiya::parse_url(
+            &self.api_endpoint,
+            &format!("/incident_workflows/{}", &id),
+            None,
+        )?;
+
+        let req = self.client.build_request(
+            url,
+            http::request::Builder::new().method(http::method::Method::PUT),
+            Praiya::serialize_payload(body)?,
+        );
+
+        self.client
+            .process_into_value::<_, UpdateIncidentWorkflowResponse>(req)
+            .await
+    }
+
+    /// ---
+    ///
+    /// # Update a Trigger
+    ///
+    /// Update an existing incident workflow trigger.
+    ///
+    /// ---
+    pub async fn update_incident_workflow_trigger(
+        &self,
+        id: &str,
+        body: UpdateIncidentWorkflowTrigger,
+    ) -> Result<IncidentWorkflowTrigger, Error> {
+        let url = Praiya::parse_url(
+            &self.api_endpoint,
+            &format!("/incident_workflows/triggers/{}", &id),
+            None,
+        )?;
+
+        let req = self.client.build_request(
+            url,
+            http::request::Builder::new().method(http::method::Method::PUT),
+            Praiya::serialize_payload(body)?,
+        );
+
+        self.client
+            .process_into_value::<_, UpdateIncidentWorkflowTriggerResponse>(req)
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::models::*;
+    use crate::praiya::ParamsBuilder;
+    use futures_util::TryStreamExt;
+
+    #[test]
+    fn test_create_incident_workflow_instance_body() {
+        let json =
+            serde_json::to_value(CreateIncidentWorkflowInstance::incident("PT4KHLK")).unwrap();
+
+        assert_eq!(
+            json["incident_workflow_instance"]["incident"]["id"],
+            "PT4KHLK"
+        );
+        assert_eq!(
+            json["incident_workflow_instance"]["incident"]["type"],
+            "incident_reference"
+        );
+        assert_eq!(
+            json["incident_workflow_instance"]["type"],
+            "incident_workflow_instance"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_create_incident_workflow_instance() {
+        let pagerduty = crate::Praiya::new("test");
+        let instance = pagerduty
+            .incident_workflows()
+            .create_incident_workflow_instance(
+                "PSFEVL7",
+                CreateIncidentWorkflowInstance::incident("PT4KHLK"),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(instance.id, Some(String::from("P3SNKQS")));
+    }
+
+    #[tokio::test]
+    async fn test_get_incident_workflow() {
+        let pagerduty = crate::Praiya::new("test");
+        let incident_workflow = pagerduty
+            .incident_workflows()
+            .get_incident_workflow("PSFEVL7")
+            .await
+            .unwrap();
+
+        assert_eq!(incident_workflow.id, Some(String::from("PSFEVL7")));
+    }
+
+    #[tokio::test]
+    async fn test_list_incident_workflows() {
+        let pagerduty = crate::Praiya::new("test");
+        let mut opts_builder = super::ListIncidentWorkflowsParamsBuilder::new();
+        opts_builder.include(vec!["steps"]);
+        let opts = opts_builder.build();
+        let incident_workflows = pagerduty.incident_workflows();
+        let incident_workflow: Option<IncidentWorkflow> = incident_workflows
+            .list_incident_workflows(opts)
+            .try_next()
+            .await
+            .unwrap();
+
+        assert_eq!(incident_workflow.unwrap().id, Some(String::from("PSFEVL7")));
+    }
+
+    #[tokio::test]
+    async fn test_list_incident_workflow_triggers() {
+        let pagerduty = crate::Praiya::new("test");
+        let mut opts_builder = super::ListIncidentWorkflowTriggersParamsBuilder::new();
+        opts_builder.trigger_type(&IncidentWorkflowTriggerTriggerTypeEnum::MANUAL);
+        let opts = opts_builder.build();
+        let incident_workflows = pagerduty.incident_workflows();
+        let trigger: Option<IncidentWorkflowTrigger> = incident_workflows
+            .list_incident_workflow_triggers(opts)
+            .try_next()
+            .await
+            .unwrap();
+
+        assert_eq!(trigger.unwrap().id, Some(String::from("PR2P3RW")));
+    }
+}
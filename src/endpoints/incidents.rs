@@ -1,10 +1,13 @@
 //! Method, error and parameter types for the Incidents endpoint.
 
+use std::future::Future;
+
 use futures_core::Stream;
 use http::header::FROM;
 use serde::de::DeserializeOwned;
 use serde::Serialize;
 
+use crate::endpoints::log_entries::TypedLogEntry;
 use crate::errors::Error;
 use crate::models::*;
 use crate::praiya::{NoopParams, PraiyaCustomHeaders};
@@ -88,7 +91,7 @@ plural_response_type!(RelatedIncidents, related_incidents, GetRelatedIncidents);
 
 list_response_type!(ListIncidentAlerts, alerts, Alert);
 
-list_response_type!(ListIncidentLogEntries, log_entries, LogEntry);
+list_response_type!(ListIncidentLogEntries, log_entries, TypedLogEntry);
 
 list_response_type!(ListIncidentNote, notes, IncidentNote);
 
@@ -110,6 +113,83 @@ plural_response_type!(Alert, alerts, UpdateIncidentAlerts);
 
 plural_response_type!(Incident, incidents, UpdateIncidents);
 
+/// Maximum number of incidents sent in a single bulk update request.
+pub const INCIDENT_BATCH_LIMIT: usize = 250;
+
+/// The outcome of a bulk incident operation for a single incident.
+#[derive(Debug)]
+pub struct BulkIncidentResult<T = Incident> {
+    pub id: String,
+    pub result: Result<T, Error>,
+}
+
+// Call `send` with the updates for each batch of at most [INCIDENT_BATCH_LIMIT] ids, matching the
+// returned incidents back to their ids. A failed batch reports its status and app code against
+// every id in the batch.
+async fn update_in_batches<F, S, Fut>(
+    ids: &[&str],
+    update: F,
+    mut send: S,
+) -> Vec<BulkIncidentResult>
+where
+    F: Fn(&str) -> IncidentsIncidents,
+    S: FnMut(UpdateIncidents) -> Fut,
+    Fut: Future<Output = Result<Vec<Incident>, Error>>,
+{
+    let mut results = Vec::with_capacity(ids.len());
+    for batch in ids.chunks(INCIDENT_BATCH_LIMIT) {
+        let body = UpdateIncidents {
+            incidents: batch.iter().map(|id| update(id)).collect(),
+        };
+        match send(body).await {
+            Ok(mut incidents) => {
+                for id in batch {
+                    let position = incidents
+                        .iter()
+                        .position(|incident| incident.id.as_deref() == Some(*id));
+                    let result = match position {
+                        Some(position) => Ok(incidents.swap_remove(position)),
+                        None => Err(Error::IncidentBulkUpdateError {
+                            id: String::from(*id),
+                            status_code: None,
+                            app_code: None,
+                            message: String::from("incident missing from the response"),
+                        }),
+                    };
+                    results.push(BulkIncidentResult {
+                        id: String::from(*id),
+                        result,
+                    });
+                }
+            }
+            Err(e) => {
+                let (status_code, app_code) = match e {
+                    Error::PraiyaResponseServerError {
+                        status_code,
+                        app_code,
+                        ..
+                    } => (Some(status_code), Some(app_code)),
+                    _ => (None, None),
+                };
+                let message = e.to_string();
+                for id in batch {
+                    results.push(BulkIncidentResult {
+                        id: String::from(*id),
+                        result: Err(Error::IncidentBulkUpdateError {
+                            id: String::from(*id),
+                            status_code,
+                            app_code,
+                            message: String::clone(&message),
+                        }),
+                    });
+                }
+            }
+        }
+    }
+
+    results
+}
+
 #[derive(praiya_macro::PraiyaParamsBuilder)]
 #[doc = "[IncidentsClient::list_incident_alerts]"]
 #[allow(dead_code)]
@@ -548,7 +628,7 @@ impl IncidentsClient {
         &self,
         id: &str,
         query_params: ListIncidentLogEntriesParams,
-    ) -> impl Stream<Item = Result<LogEntry, Error>> + '_ {
+    ) -> impl Stream<Item = Result<TypedLogEntry, Error>> + '_ {
         self.client
             .list_request::<_, _, ListIncidentLogEntriesResponse>(
                 &self.api_endpoint,
@@ -817,12 +897,364 @@ impl IncidentsClient {
             .process_into_value::<_, UpdateIncidentsResponse>(req)
             .await
     }
+
+    fn incident_update(id: &str) -> IncidentsIncidents {
+        IncidentsIncidents {
+            id: String::from(id),
+            _type: IncidentsIncidentsTypeEnum::INCIDENT_REFERENCE,
+            ..Default::default()
+        }
+    }
+
+    fn user_assignments(user_ids: &[&str]) -> Vec<IncidentsAssignments> {
+        user_ids
+            .iter()
+            .map(|user_id| IncidentsAssignments {
+                assignee: Some(User {
+                    id: Some(String::from(*user_id)),
+                    _type: UserTypeEnum::USER_REFERENCE,
+                    ..Default::default()
+                }),
+            })
+            .collect()
+    }
+
+    fn priority_reference(priority_id: &str) -> Priority {
+        Priority {
+            id: Some(String::from(priority_id)),
+            _type: String::from("priority_reference"),
+            ..Default::default()
+        }
+    }
+
+    async fn update_incident_with(
+        &self,
+        id: &str,
+        incident: IncidentsidIncident,
+    ) -> Result<Incident, Error> {
+        self.update_incident(
+            id,
+            UpdateIncident {
+                incident: IncidentsidIncident {
+                    _type: IncidentsidIncidentTypeEnum::INCIDENT_REFERENCE,
+                    ..incident
+                },
+            },
+        )
+        .await
+    }
+
+    /// Send `update_incidents` requests in batches of [INCIDENT_BATCH_LIMIT], reporting the
+    /// outcome for each incident id in the order given.
+    async fn bulk_update_incidents<F>(&self, ids: &[&str], update: F) -> Vec<BulkIncidentResult>
+    where
+        F: Fn(&str) -> IncidentsIncidents,
+    {
+        update_in_batches(ids, update, |body| self.update_incidents(body)).await
+    }
+
+    /// ---
+    ///
+    /// # Acknowledge an incident
+    ///
+    /// Praiya convenience method over [IncidentsClient::update_incident].
+    ///
+    /// ---
+    pub async fn acknowledge(&self, id: &str) -> Result<Incident, Error> {
+        let incident = IncidentsidIncident {
+            status: Some(IncidentsidIncidentStatusEnum::ACKNOWLEDGED),
+            ..Default::default()
+        };
+
+        self.update_incident_with(id, incident).await
+    }
+
+    /// ---
+    ///
+    /// # Acknowledge incidents
+    ///
+    /// Praiya convenience method over [IncidentsClient::update_incidents], sent in batches of
+    /// [INCIDENT_BATCH_LIMIT].
+    ///
+    /// ---
+    pub async fn acknowledge_incidents(&self, ids: &[&str]) -> Vec<BulkIncidentResult> {
+        self.bulk_update_incidents(ids, |id| IncidentsIncidents {
+            status: Some(IncidentsIncidentsStatusEnum::ACKNOWLEDGED),
+            ..Self::incident_update(id)
+        })
+        .await
+    }
+
+    /// ---
+    ///
+    /// # Resolve an incident
+    ///
+    /// Praiya convenience method over [IncidentsClient::update_incident], with an optional
+    /// resolution note.
+    ///
+    /// ---
+    pub async fn resolve(&self, id: &str, resolution: Option<&str>) -> Result<Incident, Error> {
+        let incident = IncidentsidIncident {
+            status: Some(IncidentsidIncidentStatusEnum::RESOLVED),
+            resolution: resolution.map(String::from),
+            ..Default::default()
+        };
+
+        self.update_incident_with(id, incident).await
+    }
+
+    /// ---
+    ///
+    /// # Resolve incidents
+    ///
+    /// Praiya convenience method over [IncidentsClient::update_incidents], sent in batches of
+    /// [INCIDENT_BATCH_LIMIT].
+    ///
+    /// ---
+    pub async fn resolve_incidents(
+        &self,
+        ids: &[&str],
+        resolution: Option<&str>,
+    ) -> Vec<BulkIncidentResult> {
+        self.bulk_update_incidents(ids, |id| IncidentsIncidents {
+            status: Some(IncidentsIncidentsStatusEnum::RESOLVED),
+            resolution: resolution.map(String::from),
+            ..Self::incident_update(id)
+        })
+        .await
+    }
+
+    /// ---
+    ///
+    /// # Reassign an incident
+    ///
+    /// Praiya convenience method over [IncidentsClient::update_incident], replacing the
+    /// assignees of the incident with the given users.
+    ///
+    /// ---
+    pub async fn reassign(&self, id: &str, user_ids: &[&str]) -> Result<Incident, Error> {
+        let incident = IncidentsidIncident {
+            assignments: Some(Self::user_assignments(user_ids)),
+            ..Default::default()
+        };
+
+        self.update_incident_with(id, incident).await
+    }
+
+    /// ---
+    ///
+    /// # Reassign incidents
+    ///
+    /// Praiya convenience method over [IncidentsClient::update_incidents], sent in batches of
+    /// [INCIDENT_BATCH_LIMIT].
+    ///
+    /// ---
+    pub async fn reassign_incidents(
+        &self,
+        ids: &[&str],
+        user_ids: &[&str],
+    ) -> Vec<BulkIncidentResult> {
+        self.bulk_update_incidents(ids, |id| IncidentsIncidents {
+            assignments: Some(Self::user_assignments(user_ids)),
+            ..Self::incident_update(id)
+        })
+        .await
+    }
+
+    /// ---
+    ///
+    /// # Escalate an incident
+    ///
+    /// Praiya convenience method over [IncidentsClient::update_incident], escalating the
+    /// incident to the given level of its escalation policy.
+    ///
+    /// ---
+    pub async fn escalate_to_level(&self, id: &str, level: isize) -> Result<Incident, Error> {
+        let incident = IncidentsidIncident {
+            escalation_level: Some(level),
+            ..Default::default()
+        };
+
+        self.update_incident_with(id, incident).await
+    }
+
+    /// ---
+    ///
+    /// # Escalate incidents
+    ///
+    /// Praiya convenience method over [IncidentsClient::update_incidents], sent in batches of
+    /// [INCIDENT_BATCH_LIMIT].
+    ///
+    /// ---
+    pub async fn escalate_incidents_to_level(
+        &self,
+        ids: &[&str],
+        level: isize,
+    ) -> Vec<BulkIncidentResult> {
+        self.bulk_update_incidents(ids, |id| IncidentsIncidents {
+            escalation_level: Some(level),
+            ..Self::incident_update(id)
+        })
+        .await
+    }
+
+    /// ---
+    ///
+    /// # Set the priority of an incident
+    ///
+    /// Praiya convenience method over [IncidentsClient::update_incident].
+    ///
+    /// ---
+    pub async fn set_priority(&self, id: &str, priority_id: &str) -> Result<Incident, Error> {
+        let incident = IncidentsidIncident {
+            priority: Some(Self::priority_reference(priority_id)),
+            ..Default::default()
+        };
+
+        self.update_incident_with(id, incident).await
+    }
+
+    /// ---
+    ///
+    /// # Set the priority of incidents
+    ///
+    /// Praiya convenience method over [IncidentsClient::update_incidents], sent in batches of
+    /// [INCIDENT_BATCH_LIMIT].
+    ///
+    /// ---
+    pub async fn set_incidents_priority(
+        &self,
+        ids: &[&str],
+        priority_id: &str,
+    ) -> Vec<BulkIncidentResult> {
+        self.bulk_update_incidents(ids, |id| IncidentsIncidents {
+            priority: Some(Self::priority_reference(priority_id)),
+            ..Self::incident_update(id)
+        })
+        .await
+    }
+
+    /// ---
+    ///
+    /// # Set the urgency of an incident
+    ///
+    /// Praiya convenience method over [IncidentsClient::update_incident]. Fails with
+    /// [Error::MissingAbilityError] when the account does not have the `urgencies` ability.
+    ///
+    /// ---
+    pub async fn set_urgency(
+        &self,
+        id: &str,
+        urgency: IncidentsidIncidentUrgencyEnum,
+    ) -> Result<Incident, Error> {
+        self.client.require_ability("urgencies").await?;
+
+        let incident = IncidentsidIncident {
+            urgency: Some(urgency),
+            ..Default::default()
+        };
+
+        self.update_incident_with(id, incident).await
+    }
+
+    /// ---
+    ///
+    /// # Set the urgency of incidents
+    ///
+    /// Praiya note: the bulk incidents endpoint does not accept an urgency, so each incident is
+    /// updated in turn through [IncidentsClient::update_incident].
+    ///
+    /// ---
+    pub async fn set_incidents_urgency(
+        &self,
+        ids: &[&str],
+        urgency: IncidentsidIncidentUrgencyEnum,
+    ) -> Vec<BulkIncidentResult> {
+        let mut results = Vec::with_capacity(ids.len());
+        for id in ids {
+            results.push(BulkIncidentResult {
+                id: String::from(*id),
+                result: self.set_urgency(id, urgency.clone()).await,
+            });
+        }
+
+        results
+    }
+
+    /// ---
+    ///
+    /// # Add responders to an incident
+    ///
+    /// Praiya convenience method over [IncidentsClient::create_incident_responder_request],
+    /// requesting the given users and escalation policies to respond.
+    ///
+    /// ---
+    pub async fn add_responders(
+        &self,
+        id: &str,
+        requester_id: &str,
+        message: &str,
+        user_ids: &[&str],
+        escalation_policy_ids: &[&str],
+    ) -> Result<ResponderRequest, Error> {
+        let targets =
+            user_ids
+                .iter()
+                .map(|user_id| (user_id, "user_reference"))
+                .chain(escalation_policy_ids.iter().map(|escalation_policy_id| {
+                    (escalation_policy_id, "escalation_policy_reference")
+                }))
+                .map(|(target_id, target_type)| ResponderRequestTargetReference {
+                    id: Some(String::from(*target_id)),
+                    _type: String::from(target_type),
+                    ..Default::default()
+                })
+                .collect();
+        let body = CreateIncidentResponderRequest {
+            requester_id: String::from(requester_id),
+            message: String::from(message),
+            responder_request_targets: targets,
+        };
+
+        self.create_incident_responder_request(id, body).await
+    }
+
+    /// ---
+    ///
+    /// # Add responders to incidents
+    ///
+    /// Praiya note: responder requests are created per incident, so each incident is handled in
+    /// turn through [IncidentsClient::add_responders].
+    ///
+    /// ---
+    pub async fn add_incidents_responders(
+        &self,
+        ids: &[&str],
+        requester_id: &str,
+        message: &str,
+        user_ids: &[&str],
+        escalation_policy_ids: &[&str],
+    ) -> Vec<BulkIncidentResult<ResponderRequest>> {
+        let mut results = Vec::with_capacity(ids.len());
+        for id in ids {
+            results.push(BulkIncidentResult {
+                id: String::from(*id),
+                result: self
+                    .add_responders(id, requester_id, message, user_ids, escalation_policy_ids)
+                    .await,
+            });
+        }
+
+        results
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use std::collections::HashMap;
 
+    use super::{update_in_batches, IncidentsClient, INCIDENT_BATCH_LIMIT};
+    use crate::endpoints::log_entries::TypedLogEntry;
     use crate::models::*;
     use crate::praiya::ParamsBuilder;
     use futures_util::TryStreamExt;
@@ -1095,7 +1527,7 @@ mod tests {
         opts_builder.include(vec![]);
         let opts = opts_builder.build();
 
-        let log_entry: Option<LogEntry> = pagerduty
+        let log_entry: Option<TypedLogEntry> = pagerduty
             .incidents("from@example.com")
             .list_incident_log_entries("PT4KHLK", opts)
             .try_next()
@@ -1103,7 +1535,7 @@ mod tests {
             .unwrap();
 
         assert_eq!(
-            log_entry.unwrap().id.as_ref().unwrap(),
+            log_entry.unwrap().common().id.as_ref().unwrap(),
             &String::from("Q02JTSNZWHSEKV")
         );
     }
@@ -1328,4 +1760,169 @@ mod tests {
 
         assert_eq!(incidents[0].id, Some(String::from("PT4KHLK")));
     }
+
+    #[tokio::test]
+    async fn test_acknowledge() {
+        let pagerduty = crate::Praiya::new("test");
+        let incident = pagerduty
+            .incidents("from@example.com")
+            .acknowledge("PT4KHLK")
+            .await
+            .unwrap();
+
+        assert_eq!(incident.id, Some(String::from("PT4KHLK")));
+    }
+
+    #[tokio::test]
+    async fn test_resolve_incidents() {
+        let pagerduty = crate::Praiya::new("test");
+        let results = pagerduty
+            .incidents("from@example.com")
+            .resolve_incidents(&["PT4KHLK"], Some("Fixed"))
+            .await;
+
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].id, "PT4KHLK");
+        assert_eq!(
+            results[0].result.as_ref().unwrap().id,
+            Some(String::from("PT4KHLK"))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_reassign() {
+        let pagerduty = crate::Praiya::new("test");
+        let incident = pagerduty
+            .incidents("from@example.com")
+            .reassign("PT4KHLK", &["PXPGF42"])
+            .await
+            .unwrap();
+
+        assert_eq!(incident.id, Some(String::from("PT4KHLK")));
+    }
+
+    #[tokio::test]
+    async fn test_escalate_to_level() {
+        let pagerduty = crate::Praiya::new("test");
+        let incident = pagerduty
+            .incidents("from@example.com")
+            .escalate_to_level("PT4KHLK", 2)
+            .await
+            .unwrap();
+
+        assert_eq!(incident.id, Some(String::from("PT4KHLK")));
+    }
+
+    #[tokio::test]
+    async fn test_set_priority() {
+        let pagerduty = crate::Praiya::new("test");
+        let incident = pagerduty
+            .incidents("from@example.com")
+            .set_priority("PT4KHLK", "P53ZZH5")
+            .await
+            .unwrap();
+
+        assert_eq!(incident.id, Some(String::from("PT4KHLK")));
+    }
+
+    #[tokio::test]
+    async fn test_add_responders() {
+        let pagerduty = crate::Praiya::new("test");
+        let responder_request = pagerduty
+            .incidents("from@example.com")
+            .add_responders(
+                "PT4KHLK",
+                "PL1JMK5",
+                "Please help with the database outage",
+                &["PXPGF42"],
+                &[],
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(
+            responder_request.incident.unwrap().id,
+            Some(String::from("PT4KHLK"))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_update_in_batches() {
+        let ids: Vec<String> = (0..251).map(|n| format!("P{:06}", n)).collect();
+        let ids: Vec<&str> = ids.iter().map(String::as_str).collect();
+        let mut batches = vec![];
+        let results = update_in_batches(&ids, IncidentsClient::incident_update, |body| {
+            batches.push(body.incidents.len());
+            // Respond out of order to check results are matched back to their ids.
+            let incidents = body
+                .incidents
+                .into_iter()
+                .rev()
+                .map(|incident| Incident {
+                    id: Some(incident.id),
+                    ..Default::default()
+                })
+                .collect();
+            std::future::ready(Ok(incidents))
+        })
+        .await;
+
+        assert_eq!(batches, vec![INCIDENT_BATCH_LIMIT, 1]);
+        assert_eq!(results.len(), 251);
+        for (result, id) in results.iter().zip(&ids) {
+            assert_eq!(result.id, *id);
+            assert_eq!(result.result.as_ref().unwrap().id.as_deref(), Some(*id));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_update_in_batches_missing_incident() {
+        let results = update_in_batches(
+            &["PT4KHLK", "PQMF62U"],
+            IncidentsClient::incident_update,
+            |_| {
+                std::future::ready(Ok(vec![Incident {
+                    id: Some(String::from("PQMF62U")),
+                    ..Default::default()
+                }]))
+            },
+        )
+        .await;
+
+        assert_eq!(results.len(), 2);
+        assert!(matches!(
+            &results[0].result,
+            Err(crate::errors::Error::IncidentBulkUpdateError { id, status_code: None, .. })
+                if id == "PT4KHLK"
+        ));
+        assert!(results[1].result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_update_in_batches_failed_batch() {
+        let results = update_in_batches(
+            &["PT4KHLK", "PQMF62U"],
+            IncidentsClient::incident_update,
+            |_| {
+                std::future::ready(Err(crate::errors::Error::PraiyaResponseServerError {
+                    status_code: 400,
+                    app_code: 2001,
+                    message: String::from("Invalid Input Provided"),
+                }))
+            },
+        )
+        .await;
+
+        assert_eq!(results.len(), 2);
+        for result in &results {
+            assert!(matches!(
+                &result.result,
+                Err(crate::errors::Error::IncidentBulkUpdateError {
+                    status_code: Some(400),
+                    app_code: Some(2001),
+                    ..
+                })
+            ));
+        }
+    }
 }
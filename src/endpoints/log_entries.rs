@@ -0,0 +1,846 @@
+//! Method, error and parameter types for the Log Entries endpoint.
+//!
+//! Log entries are decoded into [TypedLogEntry], an enum with a variant per log entry `type`
+//! carrying the fields specific to it. The generated [LogEntry] and [Channel] models only keep a
+//! handful of the fields PagerDuty sends, so the shared [LogEntryCommon] fields also include
+//! typed views of the `channel` and `event_details` payloads.
+
+use std::collections::HashMap;
+
+use chrono::{DateTime, FixedOffset};
+
+use futures_core::Stream;
+use http::header::FROM;
+use serde::de::DeserializeOwned;
+use serde::ser::Error as SerError;
+use serde::{Deserializer, Serialize, Serializer};
+use serde_json::Value;
+
+use crate::errors::Error;
+use crate::models::*;
+use crate::praiya::PraiyaCustomHeaders;
+use crate::{
+    BaseOption, BaseRequest, PaginatedResponse, PaginationQueryComponent, Praiya, SingleResponse,
+    SubSystem, DEFAULT_PAGERDUTY_API_LIMIT,
+};
+
+pub const API_ENDPOINT: &str = "https://api.pagerduty.com";
+
+/// A client for the PagerDuty log entries API
+pub struct LogEntriesClient {
+    pub(crate) api_endpoint: String,
+    pub(crate) client: Praiya,
+}
+
+impl Praiya {
+    pub fn log_entries(&self) -> LogEntriesClient {
+        LogEntriesClient {
+            api_endpoint: std::env::var("PAGERDUTY_API_ENDPOINT")
+                .unwrap_or_else(|_| String::from(API_ENDPOINT)),
+            client: Praiya::clone(self),
+        }
+    }
+}
+
+/// The channel through which a log entry's action was performed, e.g. the integration that
+/// triggered an incident or the contact method used to notify a user.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum LogEntryChannel {
+    /// An event sent through the Events API or a generic API integration.
+    Api {
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        summary: Option<String>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        service_key: Option<String>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        description: Option<String>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        incident_key: Option<String>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        client: Option<String>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        client_url: Option<String>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        details: Option<Value>,
+    },
+    /// An email sent to an email integration.
+    Email {
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        summary: Option<String>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        to: Option<String>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        from: Option<String>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        subject: Option<String>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        body: Option<String>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        body_content_type: Option<String>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        raw_url: Option<String>,
+    },
+    /// An incident created by hand in the web application.
+    WebTrigger {
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        summary: Option<String>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        subject: Option<String>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        details: Option<Value>,
+    },
+    /// An event sent by a Nagios integration.
+    Nagios {
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        summary: Option<String>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        host: Option<String>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        service: Option<String>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        state: Option<String>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        details: Option<Value>,
+    },
+    /// A notification delivered to a user.
+    Notification {
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        summary: Option<String>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        notification: Option<Box<Notification>>,
+    },
+    /// An action performed through the web application.
+    Website {
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        summary: Option<String>,
+    },
+    /// An action performed through the mobile application.
+    Mobile {
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        summary: Option<String>,
+    },
+    /// An action performed by replying to an SMS.
+    Sms {
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        summary: Option<String>,
+    },
+    /// An action performed over a phone call.
+    Phone {
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        summary: Option<String>,
+    },
+    /// An action performed through the Slack integration.
+    Slack {
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        summary: Option<String>,
+    },
+    /// An action performed by adding a note.
+    Note {
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        summary: Option<String>,
+    },
+    /// An action performed by PagerDuty itself, e.g. an escalation or auto-resolution.
+    Auto {
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        summary: Option<String>,
+    },
+    /// An action caused by an acknowledgement or resolve timeout.
+    Timeout {
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        summary: Option<String>,
+    },
+    /// Any channel type not covered above, or a known channel whose fields could not be
+    /// decoded.
+    #[serde(other)]
+    Other,
+}
+
+impl LogEntryChannel {
+    /// The `type` of the channel, e.g. `api` or `email`. Channel types without a dedicated
+    /// variant are reported as `other`.
+    pub fn channel_type(&self) -> &'static str {
+        match self {
+            LogEntryChannel::Api { .. } => "api",
+            LogEntryChannel::Email { .. } => "email",
+            LogEntryChannel::WebTrigger { .. } => "web_trigger",
+            LogEntryChannel::Nagios { .. } => "nagios",
+            LogEntryChannel::Notification { .. } => "notification",
+            LogEntryChannel::Website { .. } => "website",
+            LogEntryChannel::Mobile { .. } => "mobile",
+            LogEntryChannel::Sms { .. } => "sms",
+            LogEntryChannel::Phone { .. } => "phone",
+            LogEntryChannel::Slack { .. } => "slack",
+            LogEntryChannel::Note { .. } => "note",
+            LogEntryChannel::Auto { .. } => "auto",
+            LogEntryChannel::Timeout { .. } => "timeout",
+            LogEntryChannel::Other => "other",
+        }
+    }
+
+    /// The human-readable summary of the channel, if PagerDuty provided one.
+    pub fn summary(&self) -> Option<&str> {
+        match self {
+            LogEntryChannel::Api { summary, .. }
+            | LogEntryChannel::Email { summary, .. }
+            | LogEntryChannel::WebTrigger { summary, .. }
+            | LogEntryChannel::Nagios { summary, .. }
+            | LogEntryChannel::Notification { summary, .. }
+            | LogEntryChannel::Website { summary }
+            | LogEntryChannel::Mobile { summary }
+            | LogEntryChannel::Sms { summary }
+            | LogEntryChannel::Phone { summary }
+            | LogEntryChannel::Slack { summary }
+            | LogEntryChannel::Note { summary }
+            | LogEntryChannel::Auto { summary }
+            | LogEntryChannel::Timeout { summary } => summary.as_deref(),
+            LogEntryChannel::Other => None,
+        }
+    }
+}
+
+// A channel payload that does not match its variant is kept as `Other`, rather than failing the
+// whole log entry, and with it a page of `list_log_entries`.
+fn deserialize_channel<'de, D>(deserializer: D) -> Result<Option<LogEntryChannel>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    Ok(
+        <Option<Value> as serde::Deserialize>::deserialize(deserializer)?
+            .map(|value| serde_json::from_value(value).unwrap_or(LogEntryChannel::Other)),
+    )
+}
+
+/// Additional details of the event that caused a log entry, as found in `event_details`.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LogEntryEventDetail {
+    /// The description of the triggering event.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// Any remaining fields of the payload.
+    #[serde(flatten)]
+    pub other: HashMap<String, Value>,
+}
+
+/// Fields shared by every kind of log entry.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LogEntryCommon {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// A short-form, server-generated string that provides succinct, important information
+    /// about the log entry.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub summary: Option<String>,
+    /// The API show URL at which the log entry is accessible.
+    #[serde(rename = "self", default, skip_serializing_if = "Option::is_none")]
+    pub _self: Option<String>,
+    /// A URL at which the log entry is uniquely displayed in the web app.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub html_url: Option<String>,
+    /// Time at which the log entry was created.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<DateTime<FixedOffset>>,
+    #[serde(
+        default,
+        deserialize_with = "deserialize_channel",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub channel: Option<LogEntryChannel>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub agent: Option<AgentReference>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub service: Option<Box<Service>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub incident: Option<Box<Incident>>,
+    /// Will consist of references unless included.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub teams: Option<Vec<Team>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub contexts: Option<Vec<Context>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub event_details: Option<LogEntryEventDetail>,
+}
+
+/// Declares [TypedLogEntry] along with a private, internally tagged mirror used to decode and
+/// encode the variants listing their fields, so that unknown log entry types can still fall back
+/// to [TypedLogEntry::Other]. Variants after the `;` wrap the generated model of their type.
+macro_rules! typed_log_entry {
+    (
+        $(
+            $(#[$doc:meta])*
+            $variant:ident = $tag:literal {
+                $( $(#[$field_doc:meta])* $field:ident : $ty:ty ),* $(,)?
+            }
+        ),* $(,)?
+        ;
+        $(
+            $(#[$model_doc:meta])*
+            $model_variant:ident = $model_tag:literal ($model:ty)
+        ),* $(,)?
+    ) => {
+        /// A log entry, decoded into a variant according to its `type`.
+        #[derive(Debug, Clone, PartialEq)]
+        pub enum TypedLogEntry {
+            $(
+                $(#[$doc])*
+                $variant {
+                    common: LogEntryCommon,
+                    $( $(#[$field_doc])* $field: Option<$ty>, )*
+                },
+            )*
+            $(
+                $(#[$model_doc])*
+                $model_variant {
+                    common: LogEntryCommon,
+                    log_entry: Box<$model>,
+                },
+            )*
+            /// A log entry type without a dedicated variant, or a known type whose fields could
+            /// not be decoded.
+            Other {
+                log_entry_type: String,
+                common: LogEntryCommon,
+            },
+        }
+
+        #[derive(Serialize, Deserialize)]
+        #[serde(tag = "type")]
+        enum TaggedLogEntry {
+            $(
+                #[serde(rename = $tag)]
+                $variant {
+                    #[serde(flatten)]
+                    common: LogEntryCommon,
+                    $(
+                        #[serde(default, skip_serializing_if = "Option::is_none")]
+                        $field: Option<$ty>,
+                    )*
+                },
+            )*
+        }
+
+        impl TypedLogEntry {
+            /// The `type` of the log entry, e.g. `trigger_log_entry`.
+            pub fn log_entry_type(&self) -> &str {
+                match self {
+                    $( TypedLogEntry::$variant { .. } => $tag, )*
+                    $( TypedLogEntry::$model_variant { .. } => $model_tag, )*
+                    TypedLogEntry::Other { log_entry_type, .. } => log_entry_type,
+                }
+            }
+
+            /// The fields shared by every kind of log entry.
+            pub fn common(&self) -> &LogEntryCommon {
+                match self {
+                    $( TypedLogEntry::$variant { common, .. } => common, )*
+                    $( TypedLogEntry::$model_variant { common, .. } => common, )*
+                    TypedLogEntry::Other { common, .. } => common,
+                }
+            }
+
+            fn from_value(
+                log_entry_type: &str,
+                value: Value,
+            ) -> Result<Option<Self>, serde_json::Error> {
+                match log_entry_type {
+                    $( $tag )|* => serde_json::from_value::<TaggedLogEntry>(value)
+                        .map(|tagged| Some(TypedLogEntry::from(tagged))),
+                    $(
+                        $model_tag => Ok(Some(TypedLogEntry::$model_variant {
+                            common: serde_json::from_value(Value::clone(&value))?,
+                            log_entry: Box::new(serde_json::from_value(value)?),
+                        })),
+                    )*
+                    _ => Ok(None),
+                }
+            }
+
+            fn to_value(&self) -> Result<Value, serde_json::Error> {
+                match self {
+                    $(
+                        TypedLogEntry::$variant { common, $( $field, )* } => {
+                            serde_json::to_value(TaggedLogEntry::$variant {
+                                common: LogEntryCommon::clone(common),
+                                $( $field: $field.clone(), )*
+                            })
+                        }
+                    )*
+                    $(
+                        TypedLogEntry::$model_variant { common, log_entry } => {
+                            merge_log_entry(serde_json::to_value(log_entry)?, $model_tag, common)
+                        }
+                    )*
+                    TypedLogEntry::Other {
+                        log_entry_type,
+                        common,
+                    } => merge_log_entry(Value::Object(Default::default()), log_entry_type, common),
+                }
+            }
+        }
+
+        impl From<TaggedLogEntry> for TypedLogEntry {
+            fn from(tagged: TaggedLogEntry) -> Self {
+                match tagged {
+                    $(
+                        TaggedLogEntry::$variant { common, $( $field, )* } => {
+                            TypedLogEntry::$variant { common, $( $field, )* }
+                        }
+                    )*
+                }
+            }
+        }
+    };
+}
+
+// The shared fields take precedence over those of a generated model, as they carry the typed
+// `channel` and `event_details`.
+fn merge_log_entry(
+    mut value: Value,
+    log_entry_type: &str,
+    common: &LogEntryCommon,
+) -> Result<Value, serde_json::Error> {
+    if let (Some(object), Value::Object(shared)) =
+        (value.as_object_mut(), serde_json::to_value(common)?)
+    {
+        object.extend(shared);
+        object.insert(String::from("type"), Value::from(log_entry_type));
+    }
+
+    Ok(value)
+}
+
+// Delegate, acknowledge, annotate and the urgency and priority changes have no generated model,
+// so their specific fields are listed here.
+typed_log_entry! {
+    /// An incident was triggered.
+    Trigger = "trigger_log_entry" {},
+    /// An incident was acknowledged.
+    Acknowledge = "acknowledge_log_entry" {
+        /// Seconds after which the acknowledgement times out.
+        acknowledgement_timeout: u64,
+    },
+    /// An acknowledgement timed out or was undone.
+    Unacknowledge = "unacknowledge_log_entry" {},
+    /// An incident was delegated to another escalation policy.
+    Delegate = "delegate_log_entry" {
+        /// The users the incident was assigned to.
+        assignees: Vec<User>,
+    },
+    /// An incident reached the end of its escalation path.
+    ExhaustEscalationPath = "exhaust_escalation_path_log_entry" {},
+    /// An incident's escalation path was repeated.
+    RepeatEscalationPath = "repeat_escalation_path_log_entry" {},
+    /// An incident was resolved.
+    Resolve = "resolve_log_entry" {},
+    /// A note was added to an incident.
+    Annotate = "annotate_log_entry" {
+        /// The content of the note.
+        note: String,
+    },
+    /// An event was not turned into an incident because the service's trigger limit was
+    /// reached.
+    ReachTriggerLimit = "reach_trigger_limit_log_entry" {},
+    /// An incident was not reassigned because the acknowledgement limit was reached.
+    ReachAckLimit = "reach_ack_limit_log_entry" {},
+    /// The urgency of an incident changed.
+    UrgencyChange = "urgency_change_log_entry" {
+        /// The urgency of the incident after the change, `high` or `low`.
+        urgency: String,
+        /// The urgency of the incident before the change.
+        previous_urgency: String,
+    },
+    /// The priority of an incident changed.
+    PriorityChange = "priority_change_log_entry" {
+        /// The priority of the incident after the change, or none if it was removed.
+        priority: Box<Priority>,
+        /// The priority of the incident before the change.
+        previous_priority: Box<Priority>,
+    },
+    /// A reference to a log entry, as embedded in other resources.
+    Reference = "log_entry_reference" {};
+
+    /// An incident was assigned.
+    Assign = "assign_log_entry" (AssignLogEntry),
+    /// An incident was escalated.
+    Escalate = "escalate_log_entry" (EscalateLogEntry),
+    /// A user was notified.
+    Notify = "notify_log_entry" (NotifyLogEntry),
+    /// An incident was snoozed.
+    Snooze = "snooze_log_entry" (SnoozeLogEntry),
+}
+
+impl<'de> serde::Deserialize<'de> for TypedLogEntry {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        use serde::de::Error as DeError;
+
+        let value = Value::deserialize(deserializer)?;
+        let log_entry_type = value
+            .get("type")
+            .and_then(Value::as_str)
+            .map(String::from)
+            .ok_or_else(|| D::Error::missing_field("type"))?;
+
+        // A known type whose fields do not decode is kept as `Other`, rather than failing a whole
+        // page of `list_log_entries`.
+        match TypedLogEntry::from_value(&log_entry_type, Value::clone(&value)) {
+            Ok(Some(log_entry)) => Ok(log_entry),
+            Ok(None) | Err(_) => Ok(TypedLogEntry::Other {
+                log_entry_type,
+                common: serde_json::from_value(value).map_err(D::Error::custom)?,
+            }),
+        }
+    }
+}
+
+impl Serialize for TypedLogEntry {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.to_value()
+            .map_err(S::Error::custom)?
+            .serialize(serializer)
+    }
+}
+
+#[derive(praiya_macro::PraiyaParamsBuilder)]
+#[doc = "[LogEntriesClient::get_log_entry]"]
+#[allow(dead_code)]
+struct GetLogEntry {
+    time_zone: chrono_tz::Tz,
+    include: Vec<String>,
+}
+
+single_response_type!(TypedLogEntry, log_entry, GetLogEntry);
+
+#[derive(praiya_macro::PraiyaParamsBuilder)]
+#[doc = "[LogEntriesClient::list_log_entries]"]
+#[allow(dead_code)]
+struct ListLogEntries {
+    since: chrono::DateTime<chrono::Utc>,
+    until: chrono::DateTime<chrono::Utc>,
+    time_zone: chrono_tz::Tz,
+    team_ids: Vec<String>,
+    is_overview: bool,
+    include: Vec<String>,
+}
+
+list_response_type!(ListLogEntries, log_entries, TypedLogEntry);
+
+impl LogEntriesClient {
+    /// ---
+    ///
+    /// # Get a log entry
+    ///
+    /// Get details for a specific incident log entry. This method provides additional
+    /// information you can use to get at raw event data.
+    ///
+    /// ---
+    pub async fn get_log_entry(
+        &self,
+        id: &str,
+        query_params: GetLogEntryParams,
+    ) -> Result<TypedLogEntry, Error> {
+        let url = Praiya::parse_url(
+            &self.api_endpoint,
+            &format!("/log_entries/{}", &id),
+            Some(&query_params.qs),
+        )?;
+
+        let req = self.client.build_request(
+            url,
+            http::request::Builder::new().method(http::method::Method::GET),
+            hyper::Body::empty(),
+        );
+
+        self.client
+            .process_into_value::<_, GetLogEntryResponse>(req)
+            .await
+    }
+
+    /// ---
+    ///
+    /// # List log entries
+    ///
+    /// List all of the incident log entries across the entire account.
+    ///
+    /// ---
+    pub fn list_log_entries(
+        &self,
+        query_params: ListLogEntriesParams,
+    ) -> impl Stream<Item = Result<TypedLogEntry, Error>> + '_ {
+        self.client.list_request::<_, _, ListLogEntriesResponse>(
+            &self.api_endpoint,
+            "/log_entries",
+            query_params,
+            PraiyaCustomHeaders::None,
+        )
+    }
+
+    /// ---
+    ///
+    /// # Update log entry channel information
+    ///
+    /// Update an existing incident log entry channel. Only `web_trigger` and `mobile`
+    /// channels can be updated, and the channel type must match the present value.
+    ///
+    /// ---
+    pub async fn update_log_entry_channel(
+        &self,
+        id: &str,
+        body: UpdateLogEntryChannel,
+        from_email: &str,
+    ) -> Result<(), Error> {
+        let url = Praiya::parse_url(
+            &self.api_endpoint,
+            &format!("/log_entries/{}/channel", &id),
+            None,
+        )?;
+
+        let req = self.client.build_request(
+            url,
+            http::request::Builder::new()
+                .header(FROM, from_email)
+                .method(http::method::Method::PUT),
+            Praiya::serialize_payload(body)?,
+        );
+
+        self.client.process_into_unit(req).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{LogEntryChannel, TypedLogEntry};
+    use crate::models::*;
+    use crate::praiya::ParamsBuilder;
+    use futures_util::TryStreamExt;
+
+    #[test]
+    fn test_typed_log_entry() {
+        let log_entries: Vec<TypedLogEntry> = serde_json::from_str(
+            r#"[
+                {
+                    "id": "Q02JTSNZWHSEKV",
+                    "type": "trigger_log_entry",
+                    "summary": "Triggered through the API",
+                    "created_at": "2015-11-07T00:14:20Z",
+                    "channel": {
+                        "type": "api",
+                        "summary": "Server is on fire",
+                        "service_key": "e93facc04764012d7bfb002500d5d1a6",
+                        "incident_key": "srv01/HTTP"
+                    },
+                    "event_details": {
+                        "description": "Server is on fire",
+                        "severity": "critical"
+                    }
+                },
+                {
+                    "id": "Q1R2DLCB21K7NP",
+                    "type": "assign_log_entry",
+                    "created_at": "2015-11-07T00:14:21Z",
+                    "assignees": [{"id": "PXPGF42", "type": "user_reference"}]
+                },
+                {
+                    "id": "Q3S7IX2SF6Z5KH",
+                    "type": "link_log_entry",
+                    "created_at": "2015-11-07T00:15:00Z"
+                },
+                {
+                    "id": "Q1DDO6Y2DMA1FP",
+                    "type": "resolve_log_entry",
+                    "created_at": "2015-11-07T00:16:00Z",
+                    "channel": {"type": "email", "summary": "Resolved by email", "to": ["a@example.com"]}
+                },
+                {
+                    "id": "Q2K2KWBL8FWUUY",
+                    "type": "trigger_log_entry",
+                    "created_at": "2015-11-07T00:17:00Z",
+                    "channel": {"type": "web_trigger", "details": {"cause": "disk full"}}
+                }
+            ]"#,
+        )
+        .unwrap();
+
+        match &log_entries[0] {
+            TypedLogEntry::Trigger { common } => {
+                assert_eq!(common.id, Some(String::from("Q02JTSNZWHSEKV")));
+                assert_eq!(
+                    common.channel.as_ref().and_then(LogEntryChannel::summary),
+                    Some("Server is on fire")
+                );
+                let event_details = common.event_details.as_ref().unwrap();
+                assert_eq!(
+                    event_details.description,
+                    Some(String::from("Server is on fire"))
+                );
+                assert!(event_details.other.contains_key("severity"));
+            }
+            other => panic!("unexpected log entry {:?}", other),
+        }
+        match &log_entries[1] {
+            TypedLogEntry::Assign { log_entry, .. } => {
+                assert_eq!(
+                    log_entry.assignees.as_ref().unwrap()[0].id,
+                    Some(String::from("PXPGF42"))
+                );
+            }
+            other => panic!("unexpected log entry {:?}", other),
+        }
+        assert_eq!(log_entries[2].log_entry_type(), "link_log_entry");
+        assert_eq!(
+            log_entries[2].common().id,
+            Some(String::from("Q3S7IX2SF6Z5KH"))
+        );
+
+        assert_eq!(
+            log_entries[3].common().channel,
+            Some(LogEntryChannel::Other)
+        );
+        match &log_entries[4].common().channel {
+            Some(LogEntryChannel::WebTrigger { details, .. }) => {
+                assert_eq!(details, &Some(serde_json::json!({"cause": "disk full"})));
+            }
+            other => panic!("unexpected channel {:?}", other),
+        }
+
+        let roundtrip: Vec<TypedLogEntry> =
+            serde_json::from_value(serde_json::to_value(&log_entries).unwrap()).unwrap();
+        assert_eq!(roundtrip, log_entries);
+    }
+
+    #[test]
+    fn test_typed_log_entry_variants() {
+        let log_entries: Vec<TypedLogEntry> = serde_json::from_str(
+            r#"[
+                {
+                    "id": "Q0ZNH7CK6CQNPW",
+                    "type": "notify_log_entry",
+                    "created_at": "2015-11-07T00:14:22Z",
+                    "channel": {"type": "sms", "summary": "SMS sent"},
+                    "user": {"id": "PXPGF42", "type": "user_reference"}
+                },
+                {
+                    "id": "Q2JTOY4IJ7VQTG",
+                    "type": "urgency_change_log_entry",
+                    "created_at": "2015-11-07T00:14:23Z",
+                    "urgency": "low",
+                    "previous_urgency": "high"
+                },
+                {
+                    "id": "Q3OBVF3EFV2J5E",
+                    "type": "priority_change_log_entry",
+                    "created_at": "2015-11-07T00:14:24Z",
+                    "priority": {"id": "P53ZZH5", "type": "priority_reference", "summary": "P2"}
+                },
+                {
+                    "id": "Q1VXUZX1BTOG1E",
+                    "type": "acknowledge_log_entry",
+                    "created_at": "2015-11-07T00:14:25Z",
+                    "acknowledgement_timeout": "soon"
+                }
+            ]"#,
+        )
+        .unwrap();
+
+        match &log_entries[0] {
+            TypedLogEntry::Notify { common, log_entry } => {
+                assert_eq!(
+                    common.channel.as_ref().and_then(LogEntryChannel::summary),
+                    Some("SMS sent")
+                );
+                assert_eq!(
+                    log_entry.user.as_ref().unwrap().id,
+                    Some(String::from("PXPGF42"))
+                );
+            }
+            other => panic!("unexpected log entry {:?}", other),
+        }
+        match &log_entries[1] {
+            TypedLogEntry::UrgencyChange {
+                urgency,
+                previous_urgency,
+                ..
+            } => {
+                assert_eq!(urgency.as_deref(), Some("low"));
+                assert_eq!(previous_urgency.as_deref(), Some("high"));
+            }
+            other => panic!("unexpected log entry {:?}", other),
+        }
+        match &log_entries[2] {
+            TypedLogEntry::PriorityChange { priority, .. } => {
+                assert_eq!(priority.as_ref().unwrap().summary, Some(String::from("P2")));
+            }
+            other => panic!("unexpected log entry {:?}", other),
+        }
+        assert!(matches!(
+            &log_entries[3],
+            TypedLogEntry::Other { log_entry_type, .. } if log_entry_type == "acknowledge_log_entry"
+        ));
+
+        let value = serde_json::to_value(&log_entries[0]).unwrap();
+        assert_eq!(value["type"], "notify_log_entry");
+        assert_eq!(value["id"], "Q0ZNH7CK6CQNPW");
+        assert_eq!(value["channel"]["summary"], "SMS sent");
+
+        // The undecodable field of the fallback entry is not kept.
+        let roundtrip: Vec<TypedLogEntry> =
+            serde_json::from_value(serde_json::to_value(&log_entries[..3]).unwrap()).unwrap();
+        assert_eq!(roundtrip, log_entries[..3]);
+    }
+
+    #[tokio::test]
+    async fn test_get_log_entry() {
+        let pagerduty = crate::Praiya::new("test");
+        let mut opts_builder = super::GetLogEntryParamsBuilder::new();
+        let opts = opts_builder.build();
+        let log_entry = pagerduty
+            .log_entries()
+            .get_log_entry("Q02JTSNZWHSEKV", opts)
+            .await
+            .unwrap();
+
+        assert_eq!(log_entry.common().id, Some(String::from("Q02JTSNZWHSEKV")));
+    }
+
+    #[tokio::test]
+    async fn test_list_log_entries() {
+        let pagerduty = crate::Praiya::new("test");
+        let mut opts_builder = super::ListLogEntriesParamsBuilder::new();
+        opts_builder.is_overview(true);
+        let opts = opts_builder.build();
+        let log_entry: Option<super::TypedLogEntry> = pagerduty
+            .log_entries()
+            .list_log_entries(opts)
+            .try_next()
+            .await
+            .unwrap();
+
+        assert_eq!(
+            log_entry.unwrap().common().id,
+            Some(String::from("Q02JTSNZWHSEKV"))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_update_log_entry_channel() {
+        let pagerduty = crate::Praiya::new("test");
+        let update = UpdateLogEntryChannel {
+            channel: LogEntriesidchannelChannel {
+                details: String::from("New channel details"),
+                _type: LogEntriesidchannelChannelTypeEnum::WEB_TRIGGER,
+            },
+        };
+        let unit = pagerduty
+            .log_entries()
+            .update_log_entry_channel("Q02JTSNZWHSEKV", update, "user@example.com")
+            .await
+            .unwrap();
+
+        assert_eq!(unit, ());
+    }
+}
@@ -3,10 +3,31 @@
  * Generated by: https://github.com/swagger-api/swagger-codegen.git
  */
 
+pub mod abilities;
+pub mod add_ons;
+pub mod analytics;
+pub mod automation_actions;
+pub mod business_services;
+pub mod custom_fields;
 pub mod escalation_policies;
+pub mod event_orchestrations;
+pub mod extension_schemas;
+pub mod extensions;
+pub mod incident_workflows;
 pub mod incidents;
+pub mod log_entries;
+pub mod notifications;
 pub mod on_calls;
+pub mod paused_incident_reports;
+pub mod priorities;
+pub mod response_plays;
+pub mod rulesets;
 pub mod schedules;
 pub mod services;
 pub mod slack_connections;
+pub mod status_dashboards;
+pub mod tags;
+pub mod teams;
 pub mod users;
+pub mod vendors;
+pub mod webhook_subscriptions;
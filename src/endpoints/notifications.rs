@@ -0,0 +1,99 @@
+//! Method, error and parameter types for the Notifications endpoint.
+
+use futures_core::Stream;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+use crate::errors::Error;
+use crate::models::*;
+use crate::praiya::PraiyaCustomHeaders;
+use crate::{
+    BaseOption, BaseRequest, PaginatedResponse, PaginationQueryComponent, Praiya, SubSystem,
+    DEFAULT_PAGERDUTY_API_LIMIT,
+};
+
+pub const API_ENDPOINT: &str = "https://api.pagerduty.com";
+
+/// A client for the PagerDuty notifications API
+pub struct NotificationsClient {
+    pub(crate) api_endpoint: String,
+    pub(crate) client: Praiya,
+}
+
+impl Praiya {
+    pub fn notifications(&self) -> NotificationsClient {
+        NotificationsClient {
+            api_endpoint: std::env::var("PAGERDUTY_API_ENDPOINT")
+                .unwrap_or_else(|_| String::from(API_ENDPOINT)),
+            client: Praiya::clone(self),
+        }
+    }
+}
+
+#[derive(praiya_macro::PraiyaParamsBuilder)]
+#[doc = "[NotificationsClient::list_notifications]"]
+#[allow(dead_code)]
+struct ListNotifications {
+    time_zone: chrono_tz::Tz,
+    since: chrono::DateTime<chrono::Utc>,
+    until: chrono::DateTime<chrono::Utc>,
+    filter: NotificationTypeEnum,
+    include: Vec<String>,
+}
+
+list_response_type!(ListNotifications, notifications, Notification);
+
+impl NotificationsClient {
+    /// ---
+    ///
+    /// # List notifications
+    ///
+    /// List notifications for a given time range, optionally filtered by type (sms, email,
+    /// phone, or push). Both `since` and `until` are required, and the range may not exceed
+    /// three months.
+    ///
+    /// Pass `include[]=users` to embed the notified users, which provides their time zones to
+    /// the [NotificationAggregator](crate::notification_stats::NotificationAggregator).
+    ///
+    /// ---
+    pub fn list_notifications(
+        &self,
+        query_params: ListNotificationsParams,
+    ) -> impl Stream<Item = Result<Notification, Error>> + '_ {
+        self.client.list_request::<_, _, ListNotificationsResponse>(
+            &self.api_endpoint,
+            "/notifications",
+            query_params,
+            PraiyaCustomHeaders::None,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::models::*;
+    use crate::praiya::ParamsBuilder;
+    use futures_util::TryStreamExt;
+
+    #[tokio::test]
+    async fn test_list_notifications() {
+        let pagerduty = crate::Praiya::new("test");
+        let mut opts_builder = super::ListNotificationsParamsBuilder::new();
+        let now = chrono::Utc::now();
+        let since = now - chrono::Duration::days(1);
+        opts_builder.since(&since);
+        opts_builder.until(&now);
+        opts_builder.filter(&NotificationTypeEnum::SMS_NOTIFICATION);
+        opts_builder.include(vec!["users"]);
+        let opts = opts_builder.build();
+
+        let notification: Option<Notification> = pagerduty
+            .notifications()
+            .list_notifications(opts)
+            .try_next()
+            .await
+            .unwrap();
+
+        assert_eq!(notification.unwrap().id, Some(String::from("PWL7QXS")));
+    }
+}
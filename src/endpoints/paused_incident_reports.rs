@@ -0,0 +1,229 @@
+//! Method, error and parameter types for the Paused Incident Reports endpoint.
+//!
+//! Paused incident reports are in early access, and send the `paused-incident-reports` header.
+//! They are only available to accounts with the AIOps add-on.
+
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+use crate::errors::Error;
+use crate::models::*;
+use crate::praiya::PraiyaCustomHeaders;
+use crate::{
+    BaseOption, BaseRequest, PaginatedResponse, PaginationQueryComponent, Praiya, SingleResponse,
+    SubSystem, DEFAULT_PAGERDUTY_API_LIMIT,
+};
+
+pub const API_ENDPOINT: &str = "https://api.pagerduty.com";
+
+const PAUSED_INCIDENT_REPORTS_EARLY_ACCESS: &str = "paused-incident-reports";
+
+/// A client for the PagerDuty paused incident reports API
+pub struct PausedIncidentReportsClient {
+    pub(crate) api_endpoint: String,
+    pub(crate) client: Praiya,
+}
+
+impl Praiya {
+    pub fn paused_incident_reports(&self) -> PausedIncidentReportsClient {
+        PausedIncidentReportsClient {
+            api_endpoint: std::env::var("PAGERDUTY_API_ENDPOINT")
+                .unwrap_or_else(|_| String::from(API_ENDPOINT)),
+            client: Praiya::clone(self),
+        }
+    }
+}
+
+/// What paused the alerts counted in a report
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PausedIncidentSuspendedBy {
+    /// Alerts paused by auto-pause incident notifications on the service.
+    AutoPause,
+    /// Alerts paused by an event rule.
+    EventRules,
+}
+
+impl std::fmt::Display for PausedIncidentSuspendedBy {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            PausedIncidentSuspendedBy::AutoPause => write!(f, "auto_pause"),
+            PausedIncidentSuspendedBy::EventRules => write!(f, "event_rules"),
+        }
+    }
+}
+
+impl PausedIncidentReportingCounts {
+    /// The share of paused alerts that resolved on their own, without triggering an incident.
+    /// Returns `None` when no alerts were paused in the reported window.
+    pub fn transient_ratio(&self) -> Option<f64> {
+        match (self.paused_count, self.resolved_after_pause_count) {
+            (Some(paused), Some(resolved)) if paused > 0.0 => Some(resolved / paused),
+            _ => None,
+        }
+    }
+}
+
+// Both reports are wrapped in the same key.
+single_response_type!(
+    PausedIncidentReportingAlerts,
+    paused_incident_reporting_counts,
+    GetPausedIncidentReportAlerts
+);
+
+single_response_type!(
+    PausedIncidentReportingCounts,
+    paused_incident_reporting_counts,
+    GetPausedIncidentReportCounts
+);
+
+#[derive(praiya_macro::PraiyaParamsBuilder)]
+#[doc = "[PausedIncidentReportsClient::get_paused_incident_report_alerts]"]
+#[allow(dead_code)]
+struct GetPausedIncidentReportAlerts {
+    since: chrono::DateTime<chrono::Utc>,
+    until: chrono::DateTime<chrono::Utc>,
+    service_id: String,
+    suspended_by: PausedIncidentSuspendedBy,
+}
+
+#[derive(praiya_macro::PraiyaParamsBuilder)]
+#[doc = "[PausedIncidentReportsClient::get_paused_incident_report_counts]"]
+#[allow(dead_code)]
+struct GetPausedIncidentReportCounts {
+    since: chrono::DateTime<chrono::Utc>,
+    until: chrono::DateTime<chrono::Utc>,
+    service_id: String,
+    suspended_by: PausedIncidentSuspendedBy,
+}
+
+impl PausedIncidentReportsClient {
+    /// ---
+    ///
+    /// # Get Paused Incident Reporting on Alerts
+    ///
+    /// Returns the 5 most recent alerts that were triggered after being paused, and the 5 most
+    /// recent alerts that were resolved after being paused, for the account or a single service.
+    ///
+    /// The report covers at most the last 6 months, and defaults to the last 30 days.
+    ///
+    /// ---
+    pub async fn get_paused_incident_report_alerts(
+        &self,
+        query_params: GetPausedIncidentReportAlertsParams,
+    ) -> Result<PausedIncidentReportingAlerts, Error> {
+        let url = Praiya::parse_url(
+            &self.api_endpoint,
+            "/paused_incident_reports/alerts",
+            Some(&query_params.qs),
+        )?;
+
+        let early_access: &str = PraiyaCustomHeaders::EarlyAccess(None).into();
+        let req = self.client.build_request(
+            url,
+            http::request::Builder::new()
+                .header(early_access, PAUSED_INCIDENT_REPORTS_EARLY_ACCESS)
+                .method(http::method::Method::GET),
+            hyper::Body::empty(),
+        );
+
+        self.client
+            .process_into_value::<_, GetPausedIncidentReportAlertsResponse>(req)
+            .await
+    }
+
+    /// ---
+    ///
+    /// # Get Paused Incident Reporting counts
+    ///
+    /// Returns the number of alerts that were paused, triggered after being paused and
+    /// resolved after being paused, for the account or a single service.
+    ///
+    /// The report covers at most the last 6 months, and defaults to the last 30 days.
+    ///
+    /// ---
+    pub async fn get_paused_incident_report_counts(
+        &self,
+        query_params: GetPausedIncidentReportCountsParams,
+    ) -> Result<PausedIncidentReportingCounts, Error> {
+        let url = Praiya::parse_url(
+            &self.api_endpoint,
+            "/paused_incident_reports/counts",
+            Some(&query_params.qs),
+        )?;
+
+        let early_access: &str = PraiyaCustomHeaders::EarlyAccess(None).into();
+        let req = self.client.build_request(
+            url,
+            http::request::Builder::new()
+                .header(early_access, PAUSED_INCIDENT_REPORTS_EARLY_ACCESS)
+                .method(http::method::Method::GET),
+            hyper::Body::empty(),
+        );
+
+        self.client
+            .process_into_value::<_, GetPausedIncidentReportCountsResponse>(req)
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::PausedIncidentSuspendedBy;
+    use crate::models::*;
+    use crate::praiya::ParamsBuilder;
+
+    #[test]
+    fn test_transient_ratio() {
+        let counts = PausedIncidentReportingCounts {
+            paused_count: Some(8.0),
+            triggered_after_pause_count: Some(2.0),
+            resolved_after_pause_count: Some(6.0),
+            ..Default::default()
+        };
+        assert_eq!(counts.transient_ratio(), Some(0.75));
+
+        let counts = PausedIncidentReportingCounts {
+            paused_count: Some(0.0),
+            resolved_after_pause_count: Some(0.0),
+            ..Default::default()
+        };
+        assert_eq!(counts.transient_ratio(), None);
+    }
+
+    #[tokio::test]
+    async fn test_get_paused_incident_report_alerts() {
+        let pagerduty = crate::Praiya::new("test");
+        let mut opts_builder = super::GetPausedIncidentReportAlertsParamsBuilder::new();
+        opts_builder.service_id("PIJ90N7");
+        let opts = opts_builder.build();
+        let alerts = pagerduty
+            .paused_incident_reports()
+            .get_paused_incident_report_alerts(opts)
+            .await
+            .unwrap();
+
+        assert_eq!(
+            alerts.triggered_after_pause_alerts.unwrap()[0].service_id,
+            Some(String::from("PIJ90N7"))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_get_paused_incident_report_counts() {
+        let pagerduty = crate::Praiya::new("test");
+        let mut opts_builder = super::GetPausedIncidentReportCountsParamsBuilder::new();
+        let now = chrono::Utc::now();
+        let since = now - chrono::Duration::days(30);
+        opts_builder.since(&since);
+        opts_builder.until(&now);
+        opts_builder.suspended_by(&PausedIncidentSuspendedBy::AutoPause);
+        let opts = opts_builder.build();
+        let counts = pagerduty
+            .paused_incident_reports()
+            .get_paused_incident_report_counts(opts)
+            .await
+            .unwrap();
+
+        assert_eq!(counts.paused_count, Some(4.0));
+    }
+}
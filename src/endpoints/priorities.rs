@@ -0,0 +1,73 @@
+//! Method, error and parameter types for the Priorities endpoint.
+
+use futures_core::Stream;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+use crate::errors::Error;
+use crate::models::*;
+use crate::praiya::{NoopParams, PraiyaCustomHeaders};
+use crate::{
+    BaseOption, BaseRequest, PaginatedResponse, PaginationQueryComponent, Praiya, SubSystem,
+    DEFAULT_PAGERDUTY_API_LIMIT,
+};
+
+pub const API_ENDPOINT: &str = "https://api.pagerduty.com";
+
+/// A client for the PagerDuty priorities API
+pub struct PrioritiesClient {
+    pub(crate) api_endpoint: String,
+    pub(crate) client: Praiya,
+}
+
+impl Praiya {
+    pub fn priorities(&self) -> PrioritiesClient {
+        PrioritiesClient {
+            api_endpoint: std::env::var("PAGERDUTY_API_ENDPOINT")
+                .unwrap_or_else(|_| String::from(API_ENDPOINT)),
+            client: Praiya::clone(self),
+        }
+    }
+}
+
+list_response_type!(ListPriorities, priorities, Priority);
+
+impl PrioritiesClient {
+    /// ---
+    ///
+    /// # List priorities
+    ///
+    /// List existing priorities, in order (most to least severe).
+    ///
+    /// A priority is a label representing the importance and impact of an incident. This is
+    /// only available on certain account plans.
+    ///
+    /// ---
+    pub fn list_priorities(&self) -> impl Stream<Item = Result<Priority, Error>> + '_ {
+        self.client.list_request::<_, _, ListPrioritiesResponse>(
+            &self.api_endpoint,
+            "/priorities",
+            NoopParams {},
+            PraiyaCustomHeaders::None,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::models::*;
+    use futures_util::TryStreamExt;
+
+    #[tokio::test]
+    async fn test_list_priorities() {
+        let pagerduty = crate::Praiya::new("test");
+        let priority: Option<Priority> = pagerduty
+            .priorities()
+            .list_priorities()
+            .try_next()
+            .await
+            .unwrap();
+
+        assert_eq!(priority.unwrap().id, Some(String::from("PSO75BM")));
+    }
+}
@@ -0,0 +1,319 @@
+//! Method, error and parameter types for the Response Plays endpoint.
+
+use futures_core::Stream;
+use http::header::FROM;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+use crate::errors::Error;
+use crate::models::*;
+use crate::praiya::PraiyaCustomHeaders;
+use crate::{
+    BaseOption, BaseRequest, PaginatedResponse, PaginationQueryComponent, Praiya, SingleResponse,
+    SubSystem, DEFAULT_PAGERDUTY_API_LIMIT,
+};
+
+pub const API_ENDPOINT: &str = "https://api.pagerduty.com";
+
+/// A client for the PagerDuty response plays API
+pub struct ResponsePlaysClient {
+    pub(crate) api_endpoint: String,
+    pub(crate) client: Praiya,
+    pub(crate) from_email: String,
+}
+
+impl Praiya {
+    pub fn response_plays(&self, from_email: &str) -> ResponsePlaysClient {
+        ResponsePlaysClient {
+            api_endpoint: std::env::var("PAGERDUTY_API_ENDPOINT")
+                .unwrap_or_else(|_| String::from(API_ENDPOINT)),
+            client: Praiya::clone(self),
+            from_email: String::from(from_email),
+        }
+    }
+}
+
+impl RunResponsePlay {
+    /// Run the response play on the incident with the given id.
+    pub fn incident(incident_id: &str) -> Self {
+        RunResponsePlay {
+            incident: Incident {
+                id: Some(String::from(incident_id)),
+                _type: String::from("incident_reference"),
+                ..Default::default()
+            },
+        }
+    }
+}
+
+single_response_type!(ResponsePlay, response_play, CreateResponsePlay);
+
+single_response_type!(ResponsePlay, response_play, GetResponsePlay);
+
+list_response_type!(ListResponsePlays, response_plays, ResponsePlay);
+
+single_response_type!(ResponsePlay, response_play, UpdateResponsePlay);
+
+#[derive(praiya_macro::PraiyaParamsBuilder)]
+#[doc = "[ResponsePlaysClient::list_response_plays]"]
+#[allow(dead_code)]
+struct ListResponsePlays {
+    query: String,
+    filter_for_manual_run: bool,
+}
+
+impl ResponsePlaysClient {
+    /// ---
+    ///
+    /// # Create a Response Play
+    ///
+    /// Creates a new Response Play.
+    ///
+    /// A Response Play allows you to create packages of Incident Actions that can be applied
+    /// during an Incident's life cycle.
+    ///
+    /// ---
+    pub async fn create_response_play(
+        &self,
+        body: CreateResponsePlay,
+    ) -> Result<ResponsePlay, Error> {
+        let url = Praiya::parse_url(&self.api_endpoint, "/response_plays", None)?;
+
+        let req = self.client.build_request(
+            url,
+            http::request::Builder::new()
+                .header(FROM, &self.from_email)
+                .method(http::method::Method::POST),
+            Praiya::serialize_payload(body)?,
+        );
+
+        self.client
+            .process_into_value::<_, CreateResponsePlayResponse>(req)
+            .await
+    }
+
+    /// ---
+    ///
+    /// # Delete a Response Play
+    ///
+    /// Delete an existing Response Play.
+    ///
+    /// ---
+    pub async fn delete_response_play(&self, id: &str) -> Result<(), Error> {
+        let url = Praiya::parse_url(
+            &self.api_endpoint,
+            &format!("/response_plays/{}", &id),
+            None,
+        )?;
+
+        let req = self.client.build_request(
+            url,
+            http::request::Builder::new()
+                .header(FROM, &self.from_email)
+                .method(http::method::Method::DELETE),
+            hyper::Body::empty(),
+        );
+
+        self.client.process_into_unit(req).await
+    }
+
+    /// ---
+    ///
+    /// # Get a Response Play
+    ///
+    /// Get details about an existing Response Play.
+    ///
+    /// ---
+    pub async fn get_response_play(&self, id: &str) -> Result<ResponsePlay, Error> {
+        let url = Praiya::parse_url(
+            &self.api_endpoint,
+            &format!("/response_plays/{}", &id),
+            None,
+        )?;
+
+        let req = self.client.build_request(
+            url,
+            http::request::Builder::new()
+                .header(FROM, &self.from_email)
+                .method(http::method::Method::GET),
+            hyper::Body::empty(),
+        );
+
+        self.client
+            .process_into_value::<_, GetResponsePlayResponse>(req)
+            .await
+    }
+
+    /// ---
+    ///
+    /// # List Response Plays
+    ///
+    /// List all of the existing Response Plays. Set `filter_for_manual_run` to only list the
+    /// plays that can be run manually on an incident.
+    ///
+    /// ---
+    pub fn list_response_plays(
+        &self,
+        query_params: ListResponsePlaysParams,
+    ) -> impl Stream<Item = Result<ResponsePlay, Error>> + '_ {
+        self.client.list_request::<_, _, ListResponsePlaysResponse>(
+            &self.api_endpoint,
+            "/response_plays",
+            query_params,
+            PraiyaCustomHeaders::From(&self.from_email),
+        )
+    }
+
+    /// ---
+    ///
+    /// # Run a response play
+    ///
+    /// Run a specified response play on a given incident.
+    ///
+    /// ---
+    pub async fn run_response_play(&self, id: &str, body: RunResponsePlay) -> Result<(), Error> {
+        let url = Praiya::parse_url(
+            &self.api_endpoint,
+            &format!("/response_plays/{}/run", &id),
+            None,
+        )?;
+
+        let req = self.client.build_request(
+            url,
+            http::request::Builder::new()
+                .header(FROM, &self.from_email)
+                .method(http::method::Method::POST),
+            Praiya::serialize_payload(body)?,
+        );
+
+        self.client.process_into_unit(req).await
+    }
+
+    /// ---
+    ///
+    /// # Update a Response Play
+    ///
+    /// Updates an existing Response Play.
+    ///
+    /// ---
+    pub async fn update_response_play(
+        &self,
+        id: &str,
+        body: UpdateResponsePlay,
+    ) -> Result<ResponsePlay, Error> {
+        let url = Praiya::parse_url(
+            &self.api_endpoint,
+            &format!("/response_plays/{}", &id),
+            None,
+        )?;
+
+        let req = self.client.build_request(
+            url,
+            http::request::Builder::new()
+                .header(FROM, &self.from_email)
+                .method(http::method::Method::PUT),
+            Praiya::serialize_payload(body)?,
+        );
+
+        self.client
+            .process_into_value::<_, UpdateResponsePlayResponse>(req)
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::models::*;
+    use crate::praiya::ParamsBuilder;
+    use futures_util::TryStreamExt;
+
+    #[tokio::test]
+    async fn test_create_response_play() {
+        let pagerduty = crate::Praiya::new("test");
+        let response_play = pagerduty
+            .response_plays("test@example.com")
+            .create_response_play(CreateResponsePlay {
+                response_play: ResponsePlay {
+                    name: Some(String::from("Sev 1")),
+                    runnability: Some(ResponsePlayRunnabilityEnum::RESPONDERS),
+                    ..Default::default()
+                },
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(response_play.id, Some(String::from("P4ZFMX1")));
+    }
+
+    #[tokio::test]
+    async fn test_delete_response_play() {
+        let pagerduty = crate::Praiya::new("test");
+        let unit = pagerduty
+            .response_plays("test@example.com")
+            .delete_response_play("P4ZFMX1")
+            .await
+            .unwrap();
+
+        assert_eq!(unit, ());
+    }
+
+    #[tokio::test]
+    async fn test_get_response_play() {
+        let pagerduty = crate::Praiya::new("test");
+        let response_play = pagerduty
+            .response_plays("test@example.com")
+            .get_response_play("P4ZFMX1")
+            .await
+            .unwrap();
+
+        assert_eq!(response_play.id, Some(String::from("P4ZFMX1")));
+    }
+
+    #[tokio::test]
+    async fn test_list_response_plays() {
+        let pagerduty = crate::Praiya::new("test");
+        let mut opts_builder = super::ListResponsePlaysParamsBuilder::new();
+        opts_builder.filter_for_manual_run(true);
+        let opts = opts_builder.build();
+        let response_plays = pagerduty.response_plays("test@example.com");
+        let response_play: Option<ResponsePlay> = response_plays
+            .list_response_plays(opts)
+            .try_next()
+            .await
+            .unwrap();
+
+        assert_eq!(response_play.unwrap().id, Some(String::from("P4ZFMX1")));
+    }
+
+    #[tokio::test]
+    async fn test_run_response_play() {
+        let pagerduty = crate::Praiya::new("test");
+        let unit = pagerduty
+            .response_plays("test@example.com")
+            .run_response_play("P4ZFMX1", RunResponsePlay::incident("PT4KHLK"))
+            .await
+            .unwrap();
+
+        assert_eq!(unit, ());
+    }
+
+    #[tokio::test]
+    async fn test_update_response_play() {
+        let pagerduty = crate::Praiya::new("test");
+        let response_play = pagerduty
+            .response_plays("test@example.com")
+            .update_response_play(
+                "P4ZFMX1",
+                UpdateResponsePlay {
+                    response_play: ResponsePlay {
+                        subscribers_message: Some(String::from("Sev 1 declared")),
+                        ..Default::default()
+                    },
+                },
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response_play.id, Some(String::from("P4ZFMX1")));
+    }
+}
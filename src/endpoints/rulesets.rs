@@ -0,0 +1,396 @@
+//! Method, error and parameter types for the Rulesets endpoint.
+//!
+//! Event Rules are returned as [EventRuleDefinition], which keeps the conditions, time frame and
+//! variables that the generated [EventRule] model omits, so that they can be audited or passed
+//! to the [evaluator](crate::evaluator).
+
+use futures_core::Stream;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+use crate::errors::Error;
+use crate::evaluator::EventRuleDefinition;
+use crate::models::*;
+use crate::praiya::{NoopParams, PraiyaCustomHeaders};
+use crate::{
+    BaseOption, BaseRequest, PaginatedResponse, PaginationQueryComponent, Praiya, SingleResponse,
+    SubSystem, DEFAULT_PAGERDUTY_API_LIMIT,
+};
+
+pub const API_ENDPOINT: &str = "https://api.pagerduty.com";
+
+/// A client for the PagerDuty rulesets API
+pub struct RulesetsClient {
+    pub(crate) api_endpoint: String,
+    pub(crate) client: Praiya,
+}
+
+impl Praiya {
+    pub fn rulesets(&self) -> RulesetsClient {
+        RulesetsClient {
+            api_endpoint: std::env::var("PAGERDUTY_API_ENDPOINT")
+                .unwrap_or_else(|_| String::from(API_ENDPOINT)),
+            client: Praiya::clone(self),
+        }
+    }
+}
+
+single_response_type!(Ruleset, ruleset, CreateRuleset);
+
+single_response_type!(EventRuleDefinition, rule, CreateRulesetEventRule);
+
+single_response_type!(Ruleset, ruleset, GetRuleset);
+
+single_response_type!(EventRuleDefinition, rule, GetRulesetEventRule);
+
+list_response_type!(ListRulesets, rulesets, Ruleset);
+
+list_response_type!(ListRulesetEventRules, rules, EventRuleDefinition);
+
+single_response_type!(Ruleset, ruleset, UpdateRuleset);
+
+single_response_type!(EventRuleDefinition, rule, UpdateRulesetEventRule);
+
+impl RulesetsClient {
+    /// ---
+    ///
+    /// # Create a Ruleset
+    ///
+    /// Create a new Global Ruleset.
+    ///
+    /// Rulesets allow you to route events to an endpoint and create collections of Event Rules,
+    /// which define sets of actions to take based on event content.
+    ///
+    /// ---
+    pub async fn create_ruleset(&self, body: CreateRuleset) -> Result<Ruleset, Error> {
+        let url = Praiya::parse_url(&self.api_endpoint, "/rulesets", None)?;
+
+        let req = self.client.build_request(
+            url,
+            http::request::Builder::new().method(http::method::Method::POST),
+            Praiya::serialize_payload(body)?,
+        );
+
+        self.client
+            .process_into_value::<_, CreateRulesetResponse>(req)
+            .await
+    }
+
+    /// ---
+    ///
+    /// # Create an Event Rule
+    ///
+    /// Create a new Event Rule in a Ruleset.
+    ///
+    /// ---
+    pub async fn create_ruleset_event_rule(
+        &self,
+        id: &str,
+        body: CreateRulesetEventRule,
+    ) -> Result<EventRuleDefinition, Error> {
+        let url = Praiya::parse_url(
+            &self.api_endpoint,
+            &format!("/rulesets/{}/rules", &id),
+            None,
+        )?;
+
+        let req = self.client.build_request(
+            url,
+            http::request::Builder::new().method(http::method::Method::POST),
+            Praiya::serialize_payload(body)?,
+        );
+
+        self.client
+            .process_into_value::<_, CreateRulesetEventRuleResponse>(req)
+            .await
+    }
+
+    /// ---
+    ///
+    /// # Delete a Ruleset
+    ///
+    /// Delete a Ruleset, along with its Event Rules.
+    ///
+    /// ---
+    pub async fn delete_ruleset(&self, id: &str) -> Result<(), Error> {
+        let url = Praiya::parse_url(&self.api_endpoint, &format!("/rulesets/{}", &id), None)?;
+
+        let req = self.client.build_request(
+            url,
+            http::request::Builder::new().method(http::method::Method::DELETE),
+            hyper::Body::empty(),
+        );
+
+        self.client.process_into_unit(req).await
+    }
+
+    /// ---
+    ///
+    /// # Delete an Event Rule
+    ///
+    /// Delete an Event Rule from a Ruleset.
+    ///
+    /// ---
+    pub async fn delete_ruleset_event_rule(&self, id: &str, rule_id: &str) -> Result<(), Error> {
+        let url = Praiya::parse_url(
+            &self.api_endpoint,
+            &format!("/rulesets/{}/rules/{}", &id, &rule_id),
+            None,
+        )?;
+
+        let req = self.client.build_request(
+            url,
+            http::request::Builder::new().method(http::method::Method::DELETE),
+            hyper::Body::empty(),
+        );
+
+        self.client.process_into_unit(req).await
+    }
+
+    /// ---
+    ///
+    /// # Get a Ruleset
+    ///
+    /// Get a Ruleset.
+    ///
+    /// ---
+    pub async fn get_ruleset(&self, id: &str) -> Result<Ruleset, Error> {
+        let url = Praiya::parse_url(&self.api_endpoint, &format!("/rulesets/{}", &id), None)?;
+
+        let req = self.client.build_request(
+            url,
+            http::request::Builder::new().method(http::method::Method::GET),
+            hyper::Body::empty(),
+        );
+
+        self.client
+            .process_into_value::<_, GetRulesetResponse>(req)
+            .await
+    }
+
+    /// ---
+    ///
+    /// # Get an Event Rule
+    ///
+    /// Get an Event Rule of a Ruleset.
+    ///
+    /// ---
+    pub async fn get_ruleset_event_rule(
+        &self,
+        id: &str,
+        rule_id: &str,
+    ) -> Result<EventRuleDefinition, Error> {
+        let url = Praiya::parse_url(
+            &self.api_endpoint,
+            &format!("/rulesets/{}/rules/{}", &id, &rule_id),
+            None,
+        )?;
+
+        let req = self.client.build_request(
+            url,
+            http::request::Builder::new().method(http::method::Method::GET),
+            hyper::Body::empty(),
+        );
+
+        self.client
+            .process_into_value::<_, GetRulesetEventRuleResponse>(req)
+            .await
+    }
+
+    /// ---
+    ///
+    /// # List Event Rules
+    ///
+    /// List all Event Rules of a Ruleset, in the order they are evaluated.
+    ///
+    /// ---
+    pub fn list_ruleset_event_rules(
+        &self,
+        id: &str,
+    ) -> impl Stream<Item = Result<EventRuleDefinition, Error>> + '_ {
+        self.client
+            .list_request::<_, _, ListRulesetEventRulesResponse>(
+                &self.api_endpoint,
+                &format!("/rulesets/{}/rules", &id),
+                NoopParams {},
+                PraiyaCustomHeaders::None,
+            )
+    }
+
+    /// ---
+    ///
+    /// # List Rulesets
+    ///
+    /// List all Rulesets of the account.
+    ///
+    /// ---
+    pub fn list_rulesets(&self) -> impl Stream<Item = Result<Ruleset, Error>> + '_ {
+        self.client.list_request::<_, _, ListRulesetsResponse>(
+            &self.api_endpoint,
+            "/rulesets",
+            NoopParams {},
+            PraiyaCustomHeaders::None,
+        )
+    }
+
+    /// ---
+    ///
+    /// # Update a Ruleset
+    ///
+    /// Update a Ruleset.
+    ///
+    /// ---
+    pub async fn update_ruleset(&self, id: &str, body: UpdateRuleset) -> Result<Ruleset, Error> {
+        let url = Praiya::parse_url(&self.api_endpoint, &format!("/rulesets/{}", &id), None)?;
+
+        let req = self.client.build_request(
+            url,
+            http::request::Builder::new().method(http::method::Method::PUT),
+            Praiya::serialize_payload(body)?,
+        );
+
+        self.client
+            .process_into_value::<_, UpdateRulesetResponse>(req)
+            .await
+    }
+
+    /// ---
+    ///
+    /// # Update an Event Rule
+    ///
+    /// Update an Event Rule of a Ruleset. Only the fields being updated need to be included.
+    ///
+    /// ---
+    pub async fn update_ruleset_event_rule(
+        &self,
+        id: &str,
+        body: UpdateRulesetEventRule,
+    ) -> Result<EventRuleDefinition, Error> {
+        let url = Praiya::parse_url(
+            &self.api_endpoint,
+            &format!("/rulesets/{}/rules/{}", &id, &body.rule_id),
+            None,
+        )?;
+
+        let req = self.client.build_request(
+            url,
+            http::request::Builder::new().method(http::method::Method::PUT),
+            Praiya::serialize_payload(body)?,
+        );
+
+        self.client
+            .process_into_value::<_, UpdateRulesetEventRuleResponse>(req)
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::models::*;
+    use futures_util::TryStreamExt;
+
+    #[tokio::test]
+    async fn test_create_ruleset() {
+        let pagerduty = crate::Praiya::new("test");
+        let create_ruleset = CreateRuleset {
+            ruleset: AllOfrulesetsBodyRuleset {
+                name: String::from("MySQL Clusters"),
+                ..Default::default()
+            },
+        };
+        let ruleset = pagerduty
+            .rulesets()
+            .create_ruleset(create_ruleset)
+            .await
+            .unwrap();
+
+        assert_eq!(
+            ruleset.id,
+            Some(String::from("0e84de00-9511-4380-9f4f-a7b568bb49a0"))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_delete_ruleset_event_rule() {
+        let pagerduty = crate::Praiya::new("test");
+        let unit = pagerduty
+            .rulesets()
+            .delete_ruleset_event_rule(
+                "0e84de00-9511-4380-9f4f-a7b568bb49a0",
+                "14e56445-ebab-4dce-aa9c-2a4c67e2b0c6",
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(unit, ());
+    }
+
+    #[tokio::test]
+    async fn test_get_ruleset_event_rule() {
+        let pagerduty = crate::Praiya::new("test");
+        let rule = pagerduty
+            .rulesets()
+            .get_ruleset_event_rule(
+                "0e84de00-9511-4380-9f4f-a7b568bb49a0",
+                "14e56445-ebab-4dce-aa9c-2a4c67e2b0c6",
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(
+            rule.id,
+            Some(String::from("14e56445-ebab-4dce-aa9c-2a4c67e2b0c6"))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_list_ruleset_event_rules() {
+        let pagerduty = crate::Praiya::new("test");
+        let rule = pagerduty
+            .rulesets()
+            .list_ruleset_event_rules("0e84de00-9511-4380-9f4f-a7b568bb49a0")
+            .try_next()
+            .await
+            .unwrap();
+
+        assert!(rule.unwrap().conditions.is_some());
+    }
+
+    #[tokio::test]
+    async fn test_list_rulesets() {
+        let pagerduty = crate::Praiya::new("test");
+        let ruleset: Option<Ruleset> = pagerduty
+            .rulesets()
+            .list_rulesets()
+            .try_next()
+            .await
+            .unwrap();
+
+        assert_eq!(
+            ruleset.unwrap().id,
+            Some(String::from("0e84de00-9511-4380-9f4f-a7b568bb49a0"))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_update_ruleset_event_rule() {
+        let pagerduty = crate::Praiya::new("test");
+        let update_rule = UpdateRulesetEventRule {
+            rule_id: String::from("14e56445-ebab-4dce-aa9c-2a4c67e2b0c6"),
+            rule: Some(EventRule {
+                position: Some(0),
+                ..Default::default()
+            }),
+        };
+        let rule = pagerduty
+            .rulesets()
+            .update_ruleset_event_rule("0e84de00-9511-4380-9f4f-a7b568bb49a0", update_rule)
+            .await
+            .unwrap();
+
+        assert_eq!(
+            rule.id,
+            Some(String::from("14e56445-ebab-4dce-aa9c-2a4c67e2b0c6"))
+        );
+    }
+}
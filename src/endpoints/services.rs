@@ -87,6 +87,25 @@ struct ListServices {
     include: Vec<String>,
 }
 
+impl AutoPauseNotificationsParameters {
+    /// Pause transient alerts for `timeout` seconds before they trigger an incident. PagerDuty
+    /// accepts a timeout of 120, 180, 300, 600 or 900 seconds.
+    pub fn enabled(timeout: isize) -> Self {
+        AutoPauseNotificationsParameters {
+            enabled: Some(true),
+            timeout: Some(timeout),
+        }
+    }
+
+    /// Trigger incidents for transient alerts straight away.
+    pub fn disabled() -> Self {
+        AutoPauseNotificationsParameters {
+            enabled: Some(false),
+            timeout: None,
+        }
+    }
+}
+
 impl ServicesClient {
     /// ---
     ///
@@ -404,6 +423,30 @@ impl ServicesClient {
             .await
     }
 
+    /// ---
+    ///
+    /// # Update the auto-pause settings of a service
+    ///
+    /// Enable or disable auto-pause incident notifications on a service, leaving its other
+    /// settings untouched. Auto-pause requires the AIOps add-on.
+    ///
+    /// ---
+    pub async fn update_service_auto_pause_notifications(
+        &self,
+        id: &str,
+        params: AutoPauseNotificationsParameters,
+    ) -> Result<Service, Error> {
+        let body = UpdateService {
+            service: Service {
+                _type: ServiceTypeEnum::SERVICE,
+                auto_pause_notifications_parameters: Some(params),
+                ..Default::default()
+            },
+        };
+
+        self.update_service(id, body).await
+    }
+
     /// ---
     ///
     /// # Update an Event Rule on a Service
@@ -815,6 +858,26 @@ mod tests {
         assert_eq!(service.id, Some(String::from("PIJ90N7")));
     }
 
+    #[test]
+    fn test_update_service_auto_pause_notifications_body() {
+        let body = UpdateService {
+            service: Service {
+                auto_pause_notifications_parameters: Some(
+                    AutoPauseNotificationsParameters::enabled(300),
+                ),
+                ..Default::default()
+            },
+        };
+        let json = serde_json::to_value(&body).unwrap();
+
+        assert_eq!(json["service"]["type"], "service");
+        assert_eq!(
+            json["service"]["auto_pause_notifications_parameters"],
+            serde_json::json!({ "enabled": true, "timeout": 300 })
+        );
+        assert!(json["service"].get("escalation_policy").is_none());
+    }
+
     #[tokio::test]
     async fn test_update_service_event_rule() {
         let pagerduty = crate::Praiya::new("test");
@@ -0,0 +1,233 @@
+//! Method, error and parameter types for the Status Dashboards endpoint.
+//!
+//! Status dashboards are in early access, and send the `status-dashboards` header.
+
+use futures_core::Stream;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+use crate::endpoints::business_services::ServiceImpacts;
+use crate::errors::Error;
+use crate::models::*;
+use crate::praiya::{NoopParams, PraiyaCustomHeaders};
+use crate::{
+    BaseOption, BaseRequest, PaginatedResponse, PaginationQueryComponent, Praiya, SingleResponse,
+    SubSystem, DEFAULT_PAGERDUTY_API_LIMIT,
+};
+
+pub const API_ENDPOINT: &str = "https://api.pagerduty.com";
+
+const STATUS_DASHBOARDS_EARLY_ACCESS: &str = "status-dashboards";
+
+/// A client for the PagerDuty status dashboards API
+pub struct StatusDashboardsClient {
+    pub(crate) api_endpoint: String,
+    pub(crate) client: Praiya,
+}
+
+impl Praiya {
+    pub fn status_dashboards(&self) -> StatusDashboardsClient {
+        StatusDashboardsClient {
+            api_endpoint: std::env::var("PAGERDUTY_API_ENDPOINT")
+                .unwrap_or_else(|_| String::from(API_ENDPOINT)),
+            client: Praiya::clone(self),
+        }
+    }
+}
+
+single_response_type!(StatusDashboard, status_dashboard, GetStatusDashboard);
+
+list_response_type!(ListStatusDashboards, status_dashboards, StatusDashboard);
+
+#[derive(praiya_macro::PraiyaParamsBuilder)]
+#[doc = "[StatusDashboardsClient::get_status_dashboard_service_impacts]"]
+#[allow(dead_code)]
+struct GetStatusDashboardServiceImpacts {
+    additional_fields: Vec<String>,
+}
+
+impl StatusDashboardsClient {
+    async fn get_status_dashboard_path(&self, path: &str) -> Result<StatusDashboard, Error> {
+        let url = Praiya::parse_url(&self.api_endpoint, path, None)?;
+
+        let early_access: &str = PraiyaCustomHeaders::EarlyAccess(None).into();
+        let req = self.client.build_request(
+            url,
+            http::request::Builder::new()
+                .header(early_access, STATUS_DASHBOARDS_EARLY_ACCESS)
+                .method(http::method::Method::GET),
+            hyper::Body::empty(),
+        );
+
+        self.client
+            .process_into_value::<_, GetStatusDashboardResponse>(req)
+            .await
+    }
+
+    async fn get_service_impacts_path(
+        &self,
+        path: &str,
+        query_params: GetStatusDashboardServiceImpactsParams,
+    ) -> Result<ServiceImpacts, Error> {
+        let url = Praiya::parse_url(&self.api_endpoint, path, Some(&query_params.qs))?;
+
+        let early_access: &str = PraiyaCustomHeaders::EarlyAccess(None).into();
+        let req = self.client.build_request(
+            url,
+            http::request::Builder::new()
+                .header(early_access, STATUS_DASHBOARDS_EARLY_ACCESS)
+                .method(http::method::Method::GET),
+            hyper::Body::empty(),
+        );
+
+        self.client
+            .process_into_value::<_, ServiceImpacts>(req)
+            .await
+    }
+
+    /// ---
+    ///
+    /// # Get a status dashboard
+    ///
+    /// Get a custom status dashboard by its id.
+    ///
+    /// ---
+    pub async fn get_status_dashboard(&self, id: &str) -> Result<StatusDashboard, Error> {
+        self.get_status_dashboard_path(&format!("/status_dashboards/{}", &id))
+            .await
+    }
+
+    /// ---
+    ///
+    /// # Get a status dashboard by url slug
+    ///
+    /// Get a custom status dashboard by its human-readable url slug, as shown in the PagerDuty
+    /// web app.
+    ///
+    /// ---
+    pub async fn get_status_dashboard_by_url_slug(
+        &self,
+        url_slug: &str,
+    ) -> Result<StatusDashboard, Error> {
+        self.get_status_dashboard_path(&format!("/status_dashboards/url_slugs/{}", &url_slug))
+            .await
+    }
+
+    /// ---
+    ///
+    /// # Get the service impacts of a status dashboard
+    ///
+    /// Get the business services shown on a status dashboard, sorted by highest impact, along
+    /// with their impact status.
+    ///
+    /// ---
+    pub async fn get_status_dashboard_service_impacts(
+        &self,
+        id: &str,
+        query_params: GetStatusDashboardServiceImpactsParams,
+    ) -> Result<ServiceImpacts, Error> {
+        self.get_service_impacts_path(
+            &format!("/status_dashboards/{}/service_impacts", &id),
+            query_params,
+        )
+        .await
+    }
+
+    /// ---
+    ///
+    /// # Get the service impacts of a status dashboard by url slug
+    ///
+    /// Get the business services shown on a status dashboard, identified by its url slug,
+    /// sorted by highest impact, along with their impact status.
+    ///
+    /// ---
+    pub async fn get_status_dashboard_service_impacts_by_url_slug(
+        &self,
+        url_slug: &str,
+        query_params: GetStatusDashboardServiceImpactsParams,
+    ) -> Result<ServiceImpacts, Error> {
+        self.get_service_impacts_path(
+            &format!("/status_dashboards/url_slugs/{}/service_impacts", &url_slug),
+            query_params,
+        )
+        .await
+    }
+
+    /// ---
+    ///
+    /// # List status dashboards
+    ///
+    /// List the custom status dashboards of the account.
+    ///
+    /// ---
+    pub fn list_status_dashboards(
+        &self,
+    ) -> impl Stream<Item = Result<StatusDashboard, Error>> + '_ {
+        self.client
+            .list_request::<_, _, ListStatusDashboardsResponse>(
+                &self.api_endpoint,
+                "/status_dashboards",
+                NoopParams {},
+                PraiyaCustomHeaders::EarlyAccess(Some(STATUS_DASHBOARDS_EARLY_ACCESS)),
+            )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::models::*;
+    use crate::praiya::ParamsBuilder;
+    use futures_util::TryStreamExt;
+
+    #[tokio::test]
+    async fn test_get_status_dashboard() {
+        let pagerduty = crate::Praiya::new("test");
+        let status_dashboard = pagerduty
+            .status_dashboards()
+            .get_status_dashboard("PSCVAOY")
+            .await
+            .unwrap();
+
+        assert_eq!(status_dashboard.url_slug, Some(String::from("my-dash")));
+    }
+
+    #[tokio::test]
+    async fn test_get_status_dashboard_by_url_slug() {
+        let pagerduty = crate::Praiya::new("test");
+        let status_dashboard = pagerduty
+            .status_dashboards()
+            .get_status_dashboard_by_url_slug("my-dash")
+            .await
+            .unwrap();
+
+        assert_eq!(status_dashboard.id, Some(String::from("PSCVAOY")));
+    }
+
+    #[tokio::test]
+    async fn test_get_status_dashboard_service_impacts() {
+        let pagerduty = crate::Praiya::new("test");
+        let mut opts_builder = super::GetStatusDashboardServiceImpactsParamsBuilder::new();
+        opts_builder.additional_fields(vec!["total_impacted_count"]);
+        let opts = opts_builder.build();
+        let impacts = pagerduty
+            .status_dashboards()
+            .get_status_dashboard_service_impacts_by_url_slug("my-dash", opts)
+            .await
+            .unwrap();
+
+        assert_eq!(impacts.services[0]._type, ImpactTypeEnum::BUSINESS_SERVICE);
+    }
+
+    #[tokio::test]
+    async fn test_list_status_dashboards() {
+        let pagerduty = crate::Praiya::new("test");
+        let status_dashboards = pagerduty.status_dashboards();
+        let status_dashboard: Option<StatusDashboard> = status_dashboards
+            .list_status_dashboards()
+            .try_next()
+            .await
+            .unwrap();
+
+        assert_eq!(status_dashboard.unwrap().id, Some(String::from("PSCVAOY")));
+    }
+}
@@ -0,0 +1,366 @@
+//! Method, error and parameter types for the Tags endpoint.
+
+use futures_core::Stream;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+use crate::errors::Error;
+use crate::models::*;
+use crate::praiya::{NoopParams, PraiyaCustomHeaders};
+use crate::{
+    BaseOption, BaseRequest, PaginatedResponse, PaginationQueryComponent, Praiya, SingleResponse,
+    SubSystem, DEFAULT_PAGERDUTY_API_LIMIT,
+};
+
+pub const API_ENDPOINT: &str = "https://api.pagerduty.com";
+
+/// A client for the PagerDuty tags API
+pub struct TagsClient {
+    pub(crate) api_endpoint: String,
+    pub(crate) client: Praiya,
+}
+
+impl Praiya {
+    pub fn tags(&self) -> TagsClient {
+        TagsClient {
+            api_endpoint: std::env::var("PAGERDUTY_API_ENDPOINT")
+                .unwrap_or_else(|_| String::from(API_ENDPOINT)),
+            client: Praiya::clone(self),
+        }
+    }
+}
+
+/// The kinds of entities that can be tagged
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TagEntityType {
+    EscalationPolicies,
+    Teams,
+    Users,
+}
+
+impl AsRef<str> for TagEntityType {
+    fn as_ref(&self) -> &str {
+        match self {
+            TagEntityType::EscalationPolicies => "escalation_policies",
+            TagEntityType::Teams => "teams",
+            TagEntityType::Users => "users",
+        }
+    }
+}
+
+impl CreateEntityTypeByIdChangeTags {
+    /// Add the tag with the given label, creating it if it does not exist yet.
+    pub fn add_label(mut self, label: &str) -> Self {
+        self.add.get_or_insert_with(Vec::new).push(TagsToAdd {
+            _type: TagsToAddTypeEnum::TAG,
+            label: String::from(label),
+            id: None,
+        });
+        self
+    }
+
+    /// Add an existing tag, by id.
+    pub fn add_tag(mut self, id: &str) -> Self {
+        self.add.get_or_insert_with(Vec::new).push(TagsToAdd {
+            _type: TagsToAddTypeEnum::TAG_REFERENCE,
+            id: Some(String::from(id)),
+            ..Default::default()
+        });
+        self
+    }
+
+    /// Remove a tag, by id.
+    pub fn remove_tag(mut self, id: &str) -> Self {
+        self.remove
+            .get_or_insert_with(Vec::new)
+            .push(TagsToRemove_ {
+                _type: TagsToRemove_TypeEnum::TAG_REFERENCE,
+                id: String::from(id),
+            });
+        self
+    }
+}
+
+single_response_type!(Tag, tag, CreateTags);
+
+single_response_type!(Tag, tag, GetTag);
+
+list_response_type!(ListEntityTags, tags, Tag);
+
+list_response_type!(
+    ListTagEscalationPolicies,
+    escalation_policies,
+    EscalationPolicy
+);
+
+list_response_type!(ListTagTeams, teams, Team);
+
+list_response_type!(ListTagUsers, users, User);
+
+list_response_type!(ListTags, tags, Tag);
+
+#[derive(praiya_macro::PraiyaParamsBuilder)]
+#[doc = "[TagsClient::list_tags]"]
+#[allow(dead_code)]
+struct ListTags {
+    query: String,
+}
+
+impl TagsClient {
+    /// ---
+    ///
+    /// # Assign tags
+    ///
+    /// Assign existing or new tags to, or remove tags from, a user, team or escalation policy.
+    ///
+    /// ---
+    pub async fn change_tags(
+        &self,
+        entity_type: TagEntityType,
+        id: &str,
+        body: CreateEntityTypeByIdChangeTags,
+    ) -> Result<(), Error> {
+        let url = Praiya::parse_url(
+            &self.api_endpoint,
+            &format!("/{}/{}/change_tags", entity_type.as_ref(), &id),
+            None,
+        )?;
+
+        let req = self.client.build_request(
+            url,
+            http::request::Builder::new().method(http::method::Method::POST),
+            Praiya::serialize_payload(body)?,
+        );
+
+        self.client.process_into_unit(req).await
+    }
+
+    /// ---
+    ///
+    /// # Create a tag
+    ///
+    /// Create a tag, which can be used to filter users, teams and escalation policies.
+    ///
+    /// ---
+    pub async fn create_tags(&self, body: CreateTags) -> Result<Tag, Error> {
+        let url = Praiya::parse_url(&self.api_endpoint, "/tags", None)?;
+
+        let req = self.client.build_request(
+            url,
+            http::request::Builder::new().method(http::method::Method::POST),
+            Praiya::serialize_payload(body)?,
+        );
+
+        self.client
+            .process_into_value::<_, CreateTagsResponse>(req)
+            .await
+    }
+
+    /// ---
+    ///
+    /// # Delete a tag
+    ///
+    /// Remove an existing tag. The tag is also removed from every entity it was assigned to.
+    ///
+    /// ---
+    pub async fn delete_tag(&self, id: &str) -> Result<(), Error> {
+        let url = Praiya::parse_url(&self.api_endpoint, &format!("/tags/{}", &id), None)?;
+
+        let req = self.client.build_request(
+            url,
+            http::request::Builder::new().method(http::method::Method::DELETE),
+            hyper::Body::empty(),
+        );
+
+        self.client.process_into_unit(req).await
+    }
+
+    /// ---
+    ///
+    /// # Get a tag
+    ///
+    /// Get details about an existing tag.
+    ///
+    /// ---
+    pub async fn get_tag(&self, id: &str) -> Result<Tag, Error> {
+        let url = Praiya::parse_url(&self.api_endpoint, &format!("/tags/{}", &id), None)?;
+
+        let req = self.client.build_request(
+            url,
+            http::request::Builder::new().method(http::method::Method::GET),
+            hyper::Body::empty(),
+        );
+
+        self.client
+            .process_into_value::<_, GetTagResponse>(req)
+            .await
+    }
+
+    /// ---
+    ///
+    /// # Get tags for entities
+    ///
+    /// Get the tags assigned to a user, team or escalation policy.
+    ///
+    /// ---
+    pub fn list_entity_tags(
+        &self,
+        entity_type: TagEntityType,
+        id: &str,
+    ) -> impl Stream<Item = Result<Tag, Error>> + '_ {
+        self.client.list_request::<_, _, ListEntityTagsResponse>(
+            &self.api_endpoint,
+            &format!("/{}/{}/tags", entity_type.as_ref(), &id),
+            NoopParams {},
+            PraiyaCustomHeaders::None,
+        )
+    }
+
+    /// ---
+    ///
+    /// # Get escalation policies by tag
+    ///
+    /// Get the escalation policies related to a tag.
+    ///
+    /// ---
+    pub fn list_tag_escalation_policies(
+        &self,
+        id: &str,
+    ) -> impl Stream<Item = Result<EscalationPolicy, Error>> + '_ {
+        self.client
+            .list_request::<_, _, ListTagEscalationPoliciesResponse>(
+                &self.api_endpoint,
+                &format!("/tags/{}/escalation_policies", &id),
+                NoopParams {},
+                PraiyaCustomHeaders::None,
+            )
+    }
+
+    /// ---
+    ///
+    /// # Get teams by tag
+    ///
+    /// Get the teams related to a tag.
+    ///
+    /// ---
+    pub fn list_tag_teams(&self, id: &str) -> impl Stream<Item = Result<Team, Error>> + '_ {
+        self.client.list_request::<_, _, ListTagTeamsResponse>(
+            &self.api_endpoint,
+            &format!("/tags/{}/teams", &id),
+            NoopParams {},
+            PraiyaCustomHeaders::None,
+        )
+    }
+
+    /// ---
+    ///
+    /// # Get users by tag
+    ///
+    /// Get the users related to a tag.
+    ///
+    /// ---
+    pub fn list_tag_users(&self, id: &str) -> impl Stream<Item = Result<User, Error>> + '_ {
+        self.client.list_request::<_, _, ListTagUsersResponse>(
+            &self.api_endpoint,
+            &format!("/tags/{}/users", &id),
+            NoopParams {},
+            PraiyaCustomHeaders::None,
+        )
+    }
+
+    /// ---
+    ///
+    /// # List tags
+    ///
+    /// List all of your account's tags, optionally filtered by a `query` on their labels.
+    ///
+    /// ---
+    pub fn list_tags(
+        &self,
+        query_params: ListTagsParams,
+    ) -> impl Stream<Item = Result<Tag, Error>> + '_ {
+        self.client.list_request::<_, _, ListTagsResponse>(
+            &self.api_endpoint,
+            "/tags",
+            query_params,
+            PraiyaCustomHeaders::None,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::TagEntityType;
+    use crate::models::*;
+    use crate::praiya::ParamsBuilder;
+    use futures_util::TryStreamExt;
+
+    #[test]
+    fn test_change_tags_body() {
+        let body = CreateEntityTypeByIdChangeTags::default()
+            .add_label("Payments")
+            .add_tag("P0Y6LLM")
+            .remove_tag("PQ5D2CN");
+
+        let json = serde_json::to_value(&body).unwrap();
+        assert_eq!(json["add"][0]["type"], "tag");
+        assert_eq!(json["add"][0]["label"], "Payments");
+        assert_eq!(json["add"][1]["type"], "tag_reference");
+        assert_eq!(json["add"][1]["id"], "P0Y6LLM");
+        assert_eq!(json["remove"][0]["id"], "PQ5D2CN");
+    }
+
+    #[tokio::test]
+    async fn test_change_tags() {
+        let pagerduty = crate::Praiya::new("test");
+        let unit = pagerduty
+            .tags()
+            .change_tags(
+                TagEntityType::Teams,
+                "PQ9K7I8",
+                CreateEntityTypeByIdChangeTags::default().add_label("Payments"),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(unit, ());
+    }
+
+    #[tokio::test]
+    async fn test_create_tags() {
+        let pagerduty = crate::Praiya::new("test");
+        let create_tags = CreateTags {
+            tag: Tag {
+                _type: TagTypeEnum::TAG,
+                label: String::from("Batman"),
+            },
+        };
+        let tag = pagerduty.tags().create_tags(create_tags).await.unwrap();
+
+        assert_eq!(tag.label, String::from("Batman"));
+    }
+
+    #[tokio::test]
+    async fn test_list_tag_users() {
+        let pagerduty = crate::Praiya::new("test");
+        let user: Option<User> = pagerduty
+            .tags()
+            .list_tag_users("P0Y6LLM")
+            .try_next()
+            .await
+            .unwrap();
+
+        assert_eq!(user.unwrap().id, Some(String::from("PXPGF42")));
+    }
+
+    #[tokio::test]
+    async fn test_list_tags() {
+        let pagerduty = crate::Praiya::new("test");
+        let mut opts_builder = super::ListTagsParamsBuilder::new();
+        opts_builder.query("Bat");
+        let opts = opts_builder.build();
+        let tag: Option<Tag> = pagerduty.tags().list_tags(opts).try_next().await.unwrap();
+
+        assert_eq!(tag.unwrap().label, String::from("Batman"));
+    }
+}
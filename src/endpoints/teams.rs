@@ -0,0 +1,232 @@
+//! Method, error and parameter types for the Teams endpoint.
+
+use futures_core::Stream;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+use crate::errors::Error;
+use crate::models::*;
+use crate::praiya::{NoopParams, PraiyaCustomHeaders};
+use crate::{
+    BaseOption, BaseRequest, PaginatedResponse, PaginationQueryComponent, Praiya, SingleResponse,
+    SubSystem, DEFAULT_PAGERDUTY_API_LIMIT,
+};
+
+pub const API_ENDPOINT: &str = "https://api.pagerduty.com";
+
+/// A client for the PagerDuty teams API
+pub struct TeamsClient {
+    pub(crate) api_endpoint: String,
+    pub(crate) client: Praiya,
+}
+
+impl Praiya {
+    pub fn teams(&self) -> TeamsClient {
+        TeamsClient {
+            api_endpoint: std::env::var("PAGERDUTY_API_ENDPOINT")
+                .unwrap_or_else(|_| String::from(API_ENDPOINT)),
+            client: Praiya::clone(self),
+        }
+    }
+}
+
+single_response_type!(Team, team, CreateTeam);
+
+single_response_type!(Team, team, GetTeam);
+
+list_response_type!(ListTeams, teams, Team);
+
+single_response_type!(Team, team, UpdateTeam);
+
+#[derive(praiya_macro::PraiyaParamsBuilder)]
+#[doc = "[TeamsClient::get_team]"]
+#[allow(dead_code)]
+struct GetTeam {
+    include: Vec<String>,
+}
+
+#[derive(praiya_macro::PraiyaParamsBuilder)]
+#[doc = "[TeamsClient::list_teams]"]
+#[allow(dead_code)]
+struct ListTeams {
+    query: String,
+}
+
+impl TeamsClient {
+    /// ---
+    ///
+    /// # Create a team
+    ///
+    /// Create a new Team.
+    ///
+    /// ---
+    pub async fn create_team(&self, body: CreateTeam) -> Result<Team, Error> {
+        let url = Praiya::parse_url(&self.api_endpoint, "/teams", None)?;
+
+        let req = self.client.build_request(
+            url,
+            http::request::Builder::new().method(http::method::Method::POST),
+            Praiya::serialize_payload(body)?,
+        );
+
+        self.client
+            .process_into_value::<_, CreateTeamResponse>(req)
+            .await
+    }
+
+    /// ---
+    ///
+    /// # Delete a team
+    ///
+    /// Remove an existing team.
+    ///
+    /// ---
+    pub async fn delete_team(&self, id: &str) -> Result<(), Error> {
+        let url = Praiya::parse_url(&self.api_endpoint, &format!("/teams/{}", &id), None)?;
+
+        let req = self.client.build_request(
+            url,
+            http::request::Builder::new().method(http::method::Method::DELETE),
+            hyper::Body::empty(),
+        );
+
+        self.client.process_into_unit(req).await
+    }
+
+    /// ---
+    ///
+    /// # Get a team
+    ///
+    /// Get details about an existing team.
+    ///
+    /// ---
+    pub async fn get_team(&self, id: &str, query_params: GetTeamParams) -> Result<Team, Error> {
+        let url = Praiya::parse_url(
+            &self.api_endpoint,
+            &format!("/teams/{}", &id),
+            Some(&query_params.qs),
+        )?;
+
+        let req = self.client.build_request(
+            url,
+            http::request::Builder::new().method(http::method::Method::GET),
+            hyper::Body::empty(),
+        );
+
+        self.client
+            .process_into_value::<_, GetTeamResponse>(req)
+            .await
+    }
+
+    /// ---
+    ///
+    /// # List teams
+    ///
+    /// List teams of your PagerDuty account, optionally filtered by a search query.
+    ///
+    /// ---
+    pub fn list_teams(
+        &self,
+        query_params: ListTeamsParams,
+    ) -> impl Stream<Item = Result<Team, Error>> + '_ {
+        self.client.list_request::<_, _, ListTeamsResponse>(
+            &self.api_endpoint,
+            "/teams",
+            query_params,
+            PraiyaCustomHeaders::None,
+        )
+    }
+
+    /// ---
+    ///
+    /// # Update a team
+    ///
+    /// Update an existing team.
+    ///
+    /// ---
+    pub async fn update_team(&self, id: &str, body: UpdateTeam) -> Result<Team, Error> {
+        let url = Praiya::parse_url(&self.api_endpoint, &format!("/teams/{}", &id), None)?;
+
+        let req = self.client.build_request(
+            url,
+            http::request::Builder::new().method(http::method::Method::PUT),
+            Praiya::serialize_payload(body)?,
+        );
+
+        self.client
+            .process_into_value::<_, UpdateTeamResponse>(req)
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::models::*;
+    use crate::praiya::ParamsBuilder;
+    use futures_util::TryStreamExt;
+
+    #[tokio::test]
+    async fn test_create_team() {
+        let pagerduty = crate::Praiya::new("test");
+        let create_team = CreateTeam {
+            team: Team {
+                name: Some(String::from("Engineering")),
+                description: Some(String::from("All engineering")),
+                ..Default::default()
+            },
+        };
+        let team = pagerduty.teams().create_team(create_team).await.unwrap();
+
+        assert_eq!(team.id, Some(String::from("PQ9K7I8")));
+    }
+
+    #[tokio::test]
+    async fn test_delete_team() {
+        let pagerduty = crate::Praiya::new("test");
+        let unit = pagerduty.teams().delete_team("PQ9K7I8").await.unwrap();
+
+        assert_eq!(unit, ());
+    }
+
+    #[tokio::test]
+    async fn test_get_team() {
+        let pagerduty = crate::Praiya::new("test");
+        let mut opts_builder = super::GetTeamParamsBuilder::new();
+        opts_builder.include(vec![]);
+        let opts = opts_builder.build();
+
+        let team = pagerduty.teams().get_team("PQ9K7I8", opts).await.unwrap();
+
+        assert_eq!(team.id, Some(String::from("PQ9K7I8")));
+    }
+
+    #[tokio::test]
+    async fn test_list_teams() {
+        let pagerduty = crate::Praiya::new("test");
+        let mut opts_builder = super::ListTeamsParamsBuilder::new();
+        opts_builder.query("eng");
+        let opts = opts_builder.build();
+
+        let team: Option<Team> = pagerduty.teams().list_teams(opts).try_next().await.unwrap();
+
+        assert_eq!(team.unwrap().id.as_ref().unwrap(), &String::from("PQ9K7I8"));
+    }
+
+    #[tokio::test]
+    async fn test_update_team() {
+        let pagerduty = crate::Praiya::new("test");
+        let update_team = UpdateTeam {
+            team: Team {
+                name: Some(String::from("Engineering")),
+                ..Default::default()
+            },
+        };
+        let team = pagerduty
+            .teams()
+            .update_team("PQ9K7I8", update_team)
+            .await
+            .unwrap();
+
+        assert_eq!(team.id, Some(String::from("PQ9K7I8")));
+    }
+}
@@ -0,0 +1,99 @@
+//! Method, error and parameter types for the Vendors endpoint.
+
+use futures_core::Stream;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+use crate::errors::Error;
+use crate::models::*;
+use crate::praiya::{NoopParams, PraiyaCustomHeaders};
+use crate::{
+    BaseOption, BaseRequest, PaginatedResponse, PaginationQueryComponent, Praiya, SingleResponse,
+    SubSystem, DEFAULT_PAGERDUTY_API_LIMIT,
+};
+
+pub const API_ENDPOINT: &str = "https://api.pagerduty.com";
+
+/// A client for the PagerDuty vendors API
+pub struct VendorsClient {
+    pub(crate) api_endpoint: String,
+    pub(crate) client: Praiya,
+}
+
+impl Praiya {
+    pub fn vendors(&self) -> VendorsClient {
+        VendorsClient {
+            api_endpoint: std::env::var("PAGERDUTY_API_ENDPOINT")
+                .unwrap_or_else(|_| String::from(API_ENDPOINT)),
+            client: Praiya::clone(self),
+        }
+    }
+}
+
+single_response_type!(Vendor, vendor, GetVendor);
+
+list_response_type!(ListVendors, vendors, Vendor);
+
+impl VendorsClient {
+    /// ---
+    ///
+    /// # Get a vendor
+    ///
+    /// Get details about one specific vendor.
+    ///
+    /// A PagerDuty Vendor represents a specific type of integration, e.g. AWS Cloudwatch,
+    /// Splunk or Datadog.
+    ///
+    /// ---
+    pub async fn get_vendor(&self, id: &str) -> Result<Vendor, Error> {
+        let url = Praiya::parse_url(&self.api_endpoint, &format!("/vendors/{}", &id), None)?;
+
+        let req = self.client.build_request(
+            url,
+            http::request::Builder::new().method(http::method::Method::GET),
+            hyper::Body::empty(),
+        );
+
+        self.client
+            .process_into_value::<_, GetVendorResponse>(req)
+            .await
+    }
+
+    /// ---
+    ///
+    /// # List vendors
+    ///
+    /// List all vendors.
+    ///
+    /// ---
+    pub fn list_vendors(&self) -> impl Stream<Item = Result<Vendor, Error>> + '_ {
+        self.client.list_request::<_, _, ListVendorsResponse>(
+            &self.api_endpoint,
+            "/vendors",
+            NoopParams {},
+            PraiyaCustomHeaders::None,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::models::*;
+    use futures_util::TryStreamExt;
+
+    #[tokio::test]
+    async fn test_get_vendor() {
+        let pagerduty = crate::Praiya::new("test");
+        let vendor = pagerduty.vendors().get_vendor("PZD94QK").await.unwrap();
+
+        assert_eq!(vendor.id, Some(String::from("PZD94QK")));
+    }
+
+    #[tokio::test]
+    async fn test_list_vendors() {
+        let pagerduty = crate::Praiya::new("test");
+        let vendor: Option<Vendor> = pagerduty.vendors().list_vendors().try_next().await.unwrap();
+
+        assert_eq!(vendor.unwrap().id, Some(String::from("PZD94QK")));
+    }
+}
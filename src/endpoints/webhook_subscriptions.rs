@@ -0,0 +1,627 @@
+//! Method, error and parameter types for the Webhook Subscriptions endpoint.
+
+use futures_core::Stream;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+use crate::errors::Error;
+use crate::models::*;
+use crate::praiya::PraiyaCustomHeaders;
+use crate::{
+    BaseOption, BaseRequest, PaginatedResponse, PaginationQueryComponent, Praiya, SingleResponse,
+    SubSystem, DEFAULT_PAGERDUTY_API_LIMIT,
+};
+
+pub const API_ENDPOINT: &str = "https://api.pagerduty.com";
+
+/// A client for the PagerDuty webhook subscriptions API
+pub struct WebhookSubscriptionsClient {
+    pub(crate) api_endpoint: String,
+    pub(crate) client: Praiya,
+}
+
+impl Praiya {
+    pub fn webhook_subscriptions(&self) -> WebhookSubscriptionsClient {
+        WebhookSubscriptionsClient {
+            api_endpoint: std::env::var("PAGERDUTY_API_ENDPOINT")
+                .unwrap_or_else(|_| String::from(API_ENDPOINT)),
+            client: Praiya::clone(self),
+        }
+    }
+}
+
+single_response_type!(
+    WebhookSubscription,
+    webhook_subscription,
+    CreateWebhookSubscription
+);
+
+single_response_type!(
+    WebhookSubscription,
+    webhook_subscription,
+    EnableWebhookSubscription
+);
+
+single_response_type!(
+    WebhookSubscription,
+    webhook_subscription,
+    GetWebhookSubscription
+);
+
+list_response_type!(
+    ListWebhookSubscriptions,
+    webhook_subscriptions,
+    WebhookSubscription
+);
+
+single_response_type!(
+    WebhookSubscription,
+    webhook_subscription,
+    UpdateWebhookSubscription
+);
+
+#[derive(praiya_macro::PraiyaParamsBuilder)]
+#[doc = "[WebhookSubscriptionsClient::list_webhook_subscriptions]"]
+#[allow(dead_code)]
+struct ListWebhookSubscriptions {
+    filter_type: WebhookSubscriptionFilterTypeEnum,
+    filter_id: String,
+}
+
+impl<'req> ListWebhookSubscriptionsParamsBuilder<'req> {
+    /// Only list the webhook subscriptions of the given scope.
+    pub fn scope(&mut self, scope: &'req WebhookSubscriptionScope) -> &mut Self {
+        let filter = WebhookSubscriptionFilter::from(scope);
+        self.qs.append_pair("filter_type", filter._type.as_ref());
+        if let Some(id) = &filter.id {
+            self.qs.append_pair("filter_id", id);
+        }
+
+        self
+    }
+}
+
+/// The outbound event types a webhook subscription can receive
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum WebhookEventType {
+    #[serde(rename = "incident.acknowledged")]
+    IncidentAcknowledged,
+    #[serde(rename = "incident.annotated")]
+    IncidentAnnotated,
+    #[serde(rename = "incident.conference_bridge.updated")]
+    IncidentConferenceBridgeUpdated,
+    #[serde(rename = "incident.custom_field_values.updated")]
+    IncidentCustomFieldValuesUpdated,
+    #[serde(rename = "incident.delegated")]
+    IncidentDelegated,
+    #[serde(rename = "incident.escalated")]
+    IncidentEscalated,
+    #[serde(rename = "incident.priority_updated")]
+    IncidentPriorityUpdated,
+    #[serde(rename = "incident.reassigned")]
+    IncidentReassigned,
+    #[serde(rename = "incident.reopened")]
+    IncidentReopened,
+    #[serde(rename = "incident.resolved")]
+    IncidentResolved,
+    #[serde(rename = "incident.responder.added")]
+    IncidentResponderAdded,
+    #[serde(rename = "incident.responder.replied")]
+    IncidentResponderReplied,
+    #[serde(rename = "incident.status_update_published")]
+    IncidentStatusUpdatePublished,
+    #[serde(rename = "incident.triggered")]
+    IncidentTriggered,
+    #[serde(rename = "incident.unacknowledged")]
+    IncidentUnacknowledged,
+    #[serde(rename = "incident.workflow.started")]
+    IncidentWorkflowStarted,
+    #[serde(rename = "incident.workflow.completed")]
+    IncidentWorkflowCompleted,
+    #[serde(rename = "service.created")]
+    ServiceCreated,
+    #[serde(rename = "service.deleted")]
+    ServiceDeleted,
+    #[serde(rename = "service.updated")]
+    ServiceUpdated,
+    #[serde(rename = "pagey.ping")]
+    PageyPing,
+}
+
+impl WebhookEventType {
+    pub const ALL: [WebhookEventType; 21] = [
+        WebhookEventType::IncidentAcknowledged,
+        WebhookEventType::IncidentAnnotated,
+        WebhookEventType::IncidentConferenceBridgeUpdated,
+        WebhookEventType::IncidentCustomFieldValuesUpdated,
+        WebhookEventType::IncidentDelegated,
+        WebhookEventType::IncidentEscalated,
+        WebhookEventType::IncidentPriorityUpdated,
+        WebhookEventType::IncidentReassigned,
+        WebhookEventType::IncidentReopened,
+        WebhookEventType::IncidentResolved,
+        WebhookEventType::IncidentResponderAdded,
+        WebhookEventType::IncidentResponderReplied,
+        WebhookEventType::IncidentStatusUpdatePublished,
+        WebhookEventType::IncidentTriggered,
+        WebhookEventType::IncidentUnacknowledged,
+        WebhookEventType::IncidentWorkflowStarted,
+        WebhookEventType::IncidentWorkflowCompleted,
+        WebhookEventType::ServiceCreated,
+        WebhookEventType::ServiceDeleted,
+        WebhookEventType::ServiceUpdated,
+        WebhookEventType::PageyPing,
+    ];
+}
+
+impl AsRef<str> for WebhookEventType {
+    fn as_ref(&self) -> &str {
+        match self {
+            WebhookEventType::IncidentAcknowledged => "incident.acknowledged",
+            WebhookEventType::IncidentAnnotated => "incident.annotated",
+            WebhookEventType::IncidentConferenceBridgeUpdated => {
+                "incident.conference_bridge.updated"
+            }
+            WebhookEventType::IncidentCustomFieldValuesUpdated => {
+                "incident.custom_field_values.updated"
+            }
+            WebhookEventType::IncidentDelegated => "incident.delegated",
+            WebhookEventType::IncidentEscalated => "incident.escalated",
+            WebhookEventType::IncidentPriorityUpdated => "incident.priority_updated",
+            WebhookEventType::IncidentReassigned => "incident.reassigned",
+            WebhookEventType::IncidentReopened => "incident.reopened",
+            WebhookEventType::IncidentResolved => "incident.resolved",
+            WebhookEventType::IncidentResponderAdded => "incident.responder.added",
+            WebhookEventType::IncidentResponderReplied => "incident.responder.replied",
+            WebhookEventType::IncidentStatusUpdatePublished => "incident.status_update_published",
+            WebhookEventType::IncidentTriggered => "incident.triggered",
+            WebhookEventType::IncidentUnacknowledged => "incident.unacknowledged",
+            WebhookEventType::IncidentWorkflowStarted => "incident.workflow.started",
+            WebhookEventType::IncidentWorkflowCompleted => "incident.workflow.completed",
+            WebhookEventType::ServiceCreated => "service.created",
+            WebhookEventType::ServiceDeleted => "service.deleted",
+            WebhookEventType::ServiceUpdated => "service.updated",
+            WebhookEventType::PageyPing => "pagey.ping",
+        }
+    }
+}
+
+impl std::fmt::Display for WebhookEventType {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str(self.as_ref())
+    }
+}
+
+impl std::str::FromStr for WebhookEventType {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        WebhookEventType::ALL
+            .iter()
+            .find(|event_type| event_type.as_ref() == s)
+            .copied()
+            .ok_or_else(|| format!("Invalid webhook event type: {}", s))
+    }
+}
+
+/// The scope of the events a webhook subscription receives
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum WebhookSubscriptionScope {
+    Account,
+    Service(String),
+    Team(String),
+}
+
+impl From<&WebhookSubscriptionScope> for WebhookSubscriptionFilter {
+    fn from(scope: &WebhookSubscriptionScope) -> Self {
+        match scope {
+            WebhookSubscriptionScope::Account => WebhookSubscriptionFilter {
+                id: None,
+                _type: WebhookSubscriptionFilterTypeEnum::ACCOUNT_REFERENCE,
+            },
+            WebhookSubscriptionScope::Service(id) => WebhookSubscriptionFilter {
+                id: Some(String::clone(id)),
+                _type: WebhookSubscriptionFilterTypeEnum::SERVICE_REFERENCE,
+            },
+            WebhookSubscriptionScope::Team(id) => WebhookSubscriptionFilter {
+                id: Some(String::clone(id)),
+                _type: WebhookSubscriptionFilterTypeEnum::TEAM_REFERENCE,
+            },
+        }
+    }
+}
+
+impl From<&WebhookSubscriptionScope> for WebhookSubscriptionUpdateWebhookSubscriptionFilter {
+    fn from(scope: &WebhookSubscriptionScope) -> Self {
+        let filter = WebhookSubscriptionFilter::from(scope);
+        WebhookSubscriptionUpdateWebhookSubscriptionFilter {
+            id: filter.id,
+            _type: match filter._type {
+                WebhookSubscriptionFilterTypeEnum::ACCOUNT_REFERENCE => {
+                    WebhookSubscriptionUpdateWebhookSubscriptionFilterTypeEnum::ACCOUNT_REFERENCE
+                }
+                WebhookSubscriptionFilterTypeEnum::SERVICE_REFERENCE => {
+                    WebhookSubscriptionUpdateWebhookSubscriptionFilterTypeEnum::SERVICE_REFERENCE
+                }
+                WebhookSubscriptionFilterTypeEnum::TEAM_REFERENCE => {
+                    WebhookSubscriptionUpdateWebhookSubscriptionFilterTypeEnum::TEAM_REFERENCE
+                }
+                WebhookSubscriptionFilterTypeEnum::UNKNOWN => {
+                    WebhookSubscriptionUpdateWebhookSubscriptionFilterTypeEnum::UNKNOWN
+                }
+            },
+        }
+    }
+}
+
+impl WebhookSubscription {
+    /// A webhook subscription delivering the given events of a scope to a URL.
+    pub fn new(url: &str, scope: &WebhookSubscriptionScope, events: &[WebhookEventType]) -> Self {
+        WebhookSubscription {
+            delivery_method: WebhookSubscriptionDeliveryMethod {
+                url: String::from(url),
+                _type: WebhookSubscriptionDeliveryMethodTypeEnum::HTTP_DELIVERY_METHOD,
+                ..Default::default()
+            },
+            events: events.iter().map(WebhookEventType::to_string).collect(),
+            filter: WebhookSubscriptionFilter::from(scope),
+            ..Default::default()
+        }
+    }
+
+    /// The event types of this subscription.
+    ///
+    /// Fails on an event type not known to Praiya, rather than dropping it, so that writing the
+    /// event types back does not unsubscribe from it.
+    pub fn event_types(&self) -> Result<Vec<WebhookEventType>, String> {
+        self.events.iter().map(|event| event.parse()).collect()
+    }
+}
+
+impl WebhookSubscriptionUpdateWebhookSubscription {
+    /// Replace the event types of the subscription.
+    pub fn event_types(mut self, events: &[WebhookEventType]) -> Self {
+        self.events = Some(events.iter().map(WebhookEventType::to_string).collect());
+        self
+    }
+
+    /// Replace the scope of the subscription.
+    pub fn scope(mut self, scope: &WebhookSubscriptionScope) -> Self {
+        self.filter = Some(WebhookSubscriptionUpdateWebhookSubscriptionFilter::from(
+            scope,
+        ));
+        self
+    }
+}
+
+impl WebhookSubscriptionsClient {
+    /// ---
+    ///
+    /// # Create a webhook subscription
+    ///
+    /// Creates a new webhook subscription. The signing secret of the subscription is only
+    /// returned in this response.
+    ///
+    /// ---
+    pub async fn create_webhook_subscription(
+        &self,
+        body: CreateWebhookSubscription,
+    ) -> Result<WebhookSubscription, Error> {
+        let url = Praiya::parse_url(&self.api_endpoint, "/webhook_subscriptions", None)?;
+
+        let req = self.client.build_request(
+            url,
+            http::request::Builder::new().method(http::method::Method::POST),
+            Praiya::serialize_payload(body)?,
+        );
+
+        self.client
+            .process_into_value::<_, CreateWebhookSubscriptionResponse>(req)
+            .await
+    }
+
+    /// ---
+    ///
+    /// # Delete a webhook subscription
+    ///
+    /// Deletes a webhook subscription.
+    ///
+    /// ---
+    pub async fn delete_webhook_subscription(&self, id: &str) -> Result<(), Error> {
+        let url = Praiya::parse_url(
+            &self.api_endpoint,
+            &format!("/webhook_subscriptions/{}", &id),
+            None,
+        )?;
+
+        let req = self.client.build_request(
+            url,
+            http::request::Builder::new().method(http::method::Method::DELETE),
+            hyper::Body::empty(),
+        );
+
+        self.client.process_into_unit(req).await
+    }
+
+    /// ---
+    ///
+    /// # Enable a webhook subscription
+    ///
+    /// Enable a webhook subscription that is temporarily disabled, after its delivery method URL
+    /// was repeatedly rejected by the server.
+    ///
+    /// ---
+    pub async fn enable_webhook_subscription(
+        &self,
+        id: &str,
+    ) -> Result<WebhookSubscription, Error> {
+        let url = Praiya::parse_url(
+            &self.api_endpoint,
+            &format!("/webhook_subscriptions/{}/enable", &id),
+            None,
+        )?;
+
+        let req = self.client.build_request(
+            url,
+            http::request::Builder::new().method(http::method::Method::POST),
+            hyper::Body::empty(),
+        );
+
+        self.client
+            .process_into_value::<_, EnableWebhookSubscriptionResponse>(req)
+            .await
+    }
+
+    /// ---
+    ///
+    /// # Get a webhook subscription
+    ///
+    /// Gets details about an existing webhook subscription.
+    ///
+    /// ---
+    pub async fn get_webhook_subscription(&self, id: &str) -> Result<WebhookSubscription, Error> {
+        let url = Praiya::parse_url(
+            &self.api_endpoint,
+            &format!("/webhook_subscriptions/{}", &id),
+            None,
+        )?;
+
+        let req = self.client.build_request(
+            url,
+            http::request::Builder::new().method(http::method::Method::GET),
+            hyper::Body::empty(),
+        );
+
+        self.client
+            .process_into_value::<_, GetWebhookSubscriptionResponse>(req)
+            .await
+    }
+
+    /// ---
+    ///
+    /// # List webhook subscriptions
+    ///
+    /// List existing webhook subscriptions, optionally filtered by account, service or team
+    /// scope.
+    ///
+    /// ---
+    pub fn list_webhook_subscriptions(
+        &self,
+        query_params: ListWebhookSubscriptionsParams,
+    ) -> impl Stream<Item = Result<WebhookSubscription, Error>> + '_ {
+        self.client
+            .list_request::<_, _, ListWebhookSubscriptionsResponse>(
+                &self.api_endpoint,
+                "/webhook_subscriptions",
+                query_params,
+                PraiyaCustomHeaders::None,
+            )
+    }
+
+    /// ---
+    ///
+    /// # Test a webhook subscription
+    ///
+    /// Send a `pagey.ping` test event to the delivery method of a webhook subscription.
+    ///
+    /// ---
+    pub async fn test_webhook_subscription(&self, id: &str) -> Result<(), Error> {
+        let url = Praiya::parse_url(
+            &self.api_endpoint,
+            &format!("/webhook_subscriptions/{}/ping", &id),
+            None,
+        )?;
+
+        let req = self.client.build_request(
+            url,
+            http::request::Builder::new().method(http::method::Method::POST),
+            hyper::Body::empty(),
+        );
+
+        self.client.process_into_unit(req).await
+    }
+
+    /// ---
+    ///
+    /// # Update a webhook subscription
+    ///
+    /// Updates an existing webhook subscription. Only the fields being updated need to be
+    /// included.
+    ///
+    /// ---
+    pub async fn update_webhook_subscription(
+        &self,
+        id: &str,
+        body: UpdateWebhookSubscription,
+    ) -> Result<WebhookSubscription, Error> {
+        let url = Praiya::parse_url(
+            &self.api_endpoint,
+            &format!("/webhook_subscriptions/{}", &id),
+            None,
+        )?;
+
+        let req = self.client.build_request(
+            url,
+            http::request::Builder::new().method(http::method::Method::PUT),
+            Praiya::serialize_payload(body)?,
+        );
+
+        self.client
+            .process_into_value::<_, UpdateWebhookSubscriptionResponse>(req)
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{WebhookEventType, WebhookSubscriptionScope};
+    use crate::models::*;
+    use crate::praiya::ParamsBuilder;
+    use futures_util::TryStreamExt;
+
+    #[test]
+    fn test_webhook_subscription_new() {
+        let subscription = WebhookSubscription::new(
+            "https://example.com/webhooks",
+            &WebhookSubscriptionScope::Service(String::from("PIJ90N7")),
+            &[
+                WebhookEventType::IncidentTriggered,
+                WebhookEventType::IncidentResolved,
+            ],
+        );
+        let value = serde_json::to_value(&subscription).unwrap();
+
+        assert_eq!(
+            value["events"],
+            serde_json::json!(["incident.triggered", "incident.resolved"])
+        );
+        assert_eq!(
+            value["filter"],
+            serde_json::json!({"id": "PIJ90N7", "type": "service_reference"})
+        );
+        assert_eq!(
+            subscription.event_types(),
+            Ok(vec![
+                WebhookEventType::IncidentTriggered,
+                WebhookEventType::IncidentResolved
+            ])
+        );
+
+        let mut unknown = subscription.clone();
+        unknown.events.push(String::from("incident.merged"));
+        assert_eq!(
+            unknown.event_types(),
+            Err(String::from("Invalid webhook event type: incident.merged"))
+        );
+        assert_eq!(
+            "pagey.ping".parse::<WebhookEventType>(),
+            Ok(WebhookEventType::PageyPing)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_create_webhook_subscription() {
+        let pagerduty = crate::Praiya::new("test");
+        let create_webhook_subscription = CreateWebhookSubscription {
+            webhook_subscription: WebhookSubscription::new(
+                "https://example.com/receive_a_pagerduty_webhook",
+                &WebhookSubscriptionScope::Service(String::from("PTHTBCW")),
+                &[WebhookEventType::IncidentTriggered],
+            ),
+        };
+        let webhook_subscription = pagerduty
+            .webhook_subscriptions()
+            .create_webhook_subscription(create_webhook_subscription)
+            .await
+            .unwrap();
+
+        assert_eq!(webhook_subscription.id, Some(String::from("PY1OQP4")));
+    }
+
+    #[tokio::test]
+    async fn test_delete_webhook_subscription() {
+        let pagerduty = crate::Praiya::new("test");
+        let unit = pagerduty
+            .webhook_subscriptions()
+            .delete_webhook_subscription("PY1OQP4")
+            .await
+            .unwrap();
+
+        assert_eq!(unit, ());
+    }
+
+    #[tokio::test]
+    async fn test_enable_webhook_subscription() {
+        let pagerduty = crate::Praiya::new("test");
+        let webhook_subscription = pagerduty
+            .webhook_subscriptions()
+            .enable_webhook_subscription("PY1OQP4")
+            .await
+            .unwrap();
+
+        assert_eq!(webhook_subscription.id, Some(String::from("PY1OQP4")));
+    }
+
+    #[tokio::test]
+    async fn test_get_webhook_subscription() {
+        let pagerduty = crate::Praiya::new("test");
+        let webhook_subscription = pagerduty
+            .webhook_subscriptions()
+            .get_webhook_subscription("PY1OQP4")
+            .await
+            .unwrap();
+
+        assert_eq!(webhook_subscription.id, Some(String::from("PY1OQP4")));
+    }
+
+    #[tokio::test]
+    async fn test_list_webhook_subscriptions() {
+        let pagerduty = crate::Praiya::new("test");
+        let scope = WebhookSubscriptionScope::Service(String::from("PTHTBCW"));
+        let mut opts_builder = super::ListWebhookSubscriptionsParamsBuilder::new();
+        opts_builder.scope(&scope);
+        let opts = opts_builder.build();
+
+        let webhook_subscription: Option<WebhookSubscription> = pagerduty
+            .webhook_subscriptions()
+            .list_webhook_subscriptions(opts)
+            .try_next()
+            .await
+            .unwrap();
+
+        assert_eq!(
+            webhook_subscription.unwrap().id,
+            Some(String::from("PY1OQP4"))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_test_webhook_subscription() {
+        let pagerduty = crate::Praiya::new("test");
+        let unit = pagerduty
+            .webhook_subscriptions()
+            .test_webhook_subscription("PY1OQP4")
+            .await
+            .unwrap();
+
+        assert_eq!(unit, ());
+    }
+
+    #[tokio::test]
+    async fn test_update_webhook_subscription() {
+        let pagerduty = crate::Praiya::new("test");
+        let update_webhook_subscription = UpdateWebhookSubscription {
+            webhook_subscription: Some(
+                WebhookSubscriptionUpdateWebhookSubscription::default()
+                    .event_types(&[WebhookEventType::IncidentAcknowledged])
+                    .scope(&WebhookSubscriptionScope::Team(String::from("PQ9K7I8"))),
+            ),
+        };
+        let webhook_subscription = pagerduty
+            .webhook_subscriptions()
+            .update_webhook_subscription("PY1OQP4", update_webhook_subscription)
+            .await
+            .unwrap();
+
+        assert_eq!(webhook_subscription.id, Some(String::from("PY1OQP4")));
+    }
+}
@@ -45,6 +45,36 @@ pub enum Error {
         #[from]
         err: http::header::ToStrError,
     },
+    #[error(transparent)]
+    YamlError {
+        #[from]
+        err: serde_yaml::Error,
+    },
+    #[error("Unable to resolve {kind} reference: {name}")]
+    UnresolvedReferenceError { kind: String, name: String },
+    #[error("Bulk update of incident {id} failed: {message}")]
+    IncidentBulkUpdateError {
+        id: String,
+        status_code: Option<u16>,
+        app_code: Option<i16>,
+        message: String,
+    },
+    #[error("Unable to evaluate rule: {message}")]
+    RuleEvaluationError { message: String },
+    #[error("Webhook signature verification failed")]
+    WebhookSignatureError,
+    #[error("Webhook event occurred at {occurred_at}, older than the maximum age")]
+    StaleWebhookError {
+        occurred_at: chrono::DateTime<chrono::FixedOffset>,
+    },
+    #[error("The PagerDuty account lacks the {ability} ability")]
+    MissingAbilityError { ability: String },
+    #[error("Unsupported archive version {version}")]
+    UnsupportedArchiveVersionError { version: u32 },
+    #[error("PagerDuty returned a {kind} without an id")]
+    MissingIdError { kind: String },
+    #[error("Invocation {id} did not reach a final state after {polls} polls")]
+    InvocationTimeoutError { id: String, polls: usize },
 }
 
 #[derive(Debug, Clone, PartialEq, Deserialize)]
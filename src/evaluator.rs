@@ -0,0 +1,946 @@
+//! Offline evaluation of event rules and event orchestration rules against sample events.
+//!
+//! Routing changes can be tested before they are pushed: given a sample PD-CEF event, the
+//! evaluator reports which rule matches and which actions would be applied to the resulting
+//! alert, such as the severity, priority, suppression, route, variables and extractions.
+//!
+//! The generated [EventRule] model omits the conditions, time frame and variables of a rule, and
+//! the generated service and unrouted orchestration models omit rule conditions. The evaluator
+//! therefore works on [EventRuleDefinition] and [OrchestrationPathDefinition], which deserialize
+//! from the JSON returned by the API.
+//!
+//! Orchestration conditions are written in the PagerDuty Condition Language. The evaluator
+//! supports the `exists`, `matches`, `matches part` and `matches regex` operators combined with
+//! `and`, `or`, `not` and parentheses. Conditions on the current time, such as `now in ...`, are
+//! reported as errors.
+
+use std::collections::{BTreeMap, HashMap};
+
+use chrono::{DateTime, Datelike, Duration, TimeZone, Timelike, Utc};
+use regex::Regex;
+use serde_json::{Map, Value};
+
+use crate::errors::Error;
+use crate::models::*;
+
+const MILLISECONDS_PER_DAY: i64 = 24 * 60 * 60 * 1000;
+
+/// An event rule, along with the conditions, time frame and variables omitted by [EventRule]
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EventRuleDefinition {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disabled: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub conditions: Option<EventRuleConditions>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time_frame: Option<EventRuleTimeFrame>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub variables: Option<Vec<EventRuleVariables>>,
+    #[serde(flatten)]
+    pub rule: EventRule,
+}
+
+/// The actions that would be applied to the alert resulting from an event
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AppliedActions {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub severity: Option<String>,
+    /// The id of the priority set on the resulting incident.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub priority: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub suppress: Option<bool>,
+    /// The number of seconds the alert is suspended for.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub suspend: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub annotate: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub event_action: Option<String>,
+    /// The id of the service the event is routed to.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub route: Option<String>,
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub variables: BTreeMap<String, String>,
+    /// The values written to event fields, keyed by target field.
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub extractions: BTreeMap<String, String>,
+}
+
+/// The event rule matching an event, and the actions it applies
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EventRuleMatch {
+    /// The index of the matching rule in the evaluated rules.
+    pub index: usize,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rule_id: Option<String>,
+    pub catch_all: bool,
+    pub actions: AppliedActions,
+}
+
+impl EventRuleDefinition {
+    /// Whether the event matches the conditions of this rule at the given time. A disabled rule
+    /// never matches, and a catch-all rule always does.
+    pub fn matches(&self, event: &Value, at: DateTime<Utc>) -> Result<bool, Error> {
+        if self.disabled.unwrap_or(false) {
+            return Ok(false);
+        }
+        if self.rule.catch_all.unwrap_or(false) {
+            return Ok(true);
+        }
+        if let Some(time_frame) = &self.time_frame {
+            if !time_frame_contains(time_frame, at)? {
+                return Ok(false);
+            }
+        }
+
+        let conditions = match &self.conditions {
+            Some(conditions) => conditions,
+            None => return Ok(true),
+        };
+        let mut results = conditions
+            .subconditions
+            .iter()
+            .map(|subcondition| subcondition_matches(subcondition, event));
+
+        match conditions.operator {
+            EventRuleConditionsOperatorEnum::AND => {
+                results.try_fold(true, |all, result| Ok(all && result?))
+            }
+            EventRuleConditionsOperatorEnum::OR => {
+                results.try_fold(false, |any, result| Ok(any || result?))
+            }
+            EventRuleConditionsOperatorEnum::UNKNOWN => Err(evaluation_error(String::from(
+                "unknown conditions operator",
+            ))),
+        }
+    }
+
+    /// The actions this rule would apply to the event, regardless of whether it matches.
+    pub fn actions(&self, event: &Value) -> Result<AppliedActions, Error> {
+        let mut applied = AppliedActions::default();
+        for variable in self.variables.iter().flatten() {
+            if variable._type != EventRuleVariablesTypeEnum::REGEX {
+                return Err(evaluation_error(format!(
+                    "unsupported variable type for {}",
+                    variable.name
+                )));
+            }
+            if let Some(value) =
+                extract(event, &variable.parameters.path, &variable.parameters.value)?
+            {
+                applied
+                    .variables
+                    .insert(String::clone(&variable.name), value);
+            }
+        }
+
+        let actions = match &self.rule.actions {
+            Some(Value::Object(actions)) => actions,
+            _ => return Ok(applied),
+        };
+        let value_of = |key: &str| actions.get(key).and_then(|action| action.get("value"));
+
+        applied.severity = value_of("severity").and_then(string_value);
+        applied.priority = value_of("priority").and_then(string_value);
+        applied.suppress = value_of("suppress").and_then(Value::as_bool);
+        applied.suspend = value_of("suspend").and_then(Value::as_i64);
+        applied.annotate = value_of("annotate").and_then(string_value);
+        applied.event_action = value_of("event_action").and_then(string_value);
+        applied.route = value_of("route").and_then(string_value);
+
+        if let Some(extractions) = actions.get("extractions") {
+            let extractions: Vec<EventRuleActionsCommonExtractionsItems> =
+                serde_json::from_value(Value::clone(extractions))?;
+            for extraction in extractions {
+                let (target, value) = match extraction {
+                    EventRuleActionsCommonExtractionsItems::ExtractionsItems0 {
+                        target,
+                        source,
+                        regex,
+                    } => (target, extract(event, &source, &regex)?),
+                    EventRuleActionsCommonExtractionsItems::ExtractionsItems1 {
+                        target,
+                        template,
+                    } => (
+                        target,
+                        Some(render_template(&template, event, &applied.variables)),
+                    ),
+                };
+                if let Some(value) = value {
+                    applied.extractions.insert(target, value);
+                }
+            }
+        }
+
+        Ok(applied)
+    }
+}
+
+/// Evaluate event rules in order against an event at the given time, returning the first rule
+/// that matches.
+pub fn evaluate_event_rules(
+    rules: &[EventRuleDefinition],
+    event: &Value,
+    at: DateTime<Utc>,
+) -> Result<Option<EventRuleMatch>, Error> {
+    for (index, rule) in rules.iter().enumerate() {
+        if rule.matches(event, at)? {
+            return Ok(Some(EventRuleMatch {
+                index,
+                rule_id: rule.id.clone(),
+                catch_all: rule.rule.catch_all.unwrap_or(false),
+                actions: rule.actions(event)?,
+            }));
+        }
+    }
+
+    Ok(None)
+}
+
+fn subcondition_matches(
+    subcondition: &EventRuleConditionsSubconditions,
+    event: &Value,
+) -> Result<bool, Error> {
+    let parameters = &subcondition.parameters;
+    let value = lookup(event, &parameters.path).map(value_to_string);
+    let expected = &parameters.value;
+
+    Ok(match subcondition.operator {
+        EventRuleConditionsSubconditionsOperatorEnum::EXISTS => value.is_some(),
+        EventRuleConditionsSubconditionsOperatorEnum::NEXISTS => value.is_none(),
+        EventRuleConditionsSubconditionsOperatorEnum::EQUALS => value.as_ref() == Some(expected),
+        EventRuleConditionsSubconditionsOperatorEnum::NEQUALS => value.as_ref() != Some(expected),
+        EventRuleConditionsSubconditionsOperatorEnum::CONTAINS => {
+            value.is_some_and(|value| value.contains(expected.as_str()))
+        }
+        EventRuleConditionsSubconditionsOperatorEnum::NCONTAINS => {
+            !value.is_some_and(|value| value.contains(expected.as_str()))
+        }
+        EventRuleConditionsSubconditionsOperatorEnum::MATCHES => {
+            let regex = compile(expected)?;
+            value.is_some_and(|value| regex.is_match(&value))
+        }
+        EventRuleConditionsSubconditionsOperatorEnum::NMATCHES => {
+            let regex = compile(expected)?;
+            !value.is_some_and(|value| regex.is_match(&value))
+        }
+        EventRuleConditionsSubconditionsOperatorEnum::UNKNOWN => {
+            return Err(evaluation_error(format!(
+                "unknown subcondition operator on {}",
+                parameters.path
+            )))
+        }
+    })
+}
+
+fn time_frame_contains(time_frame: &EventRuleTimeFrame, at: DateTime<Utc>) -> Result<bool, Error> {
+    if let Some(active_between) = &time_frame.active_between {
+        let now = at.timestamp_millis();
+        if now < active_between.start_time as i64 || now >= active_between.end_time as i64 {
+            return Ok(false);
+        }
+    }
+
+    if let Some(scheduled_weekly) = &time_frame.scheduled_weekly {
+        let time_zone: chrono_tz::Tz = scheduled_weekly.timezone.parse().map_err(|_| {
+            evaluation_error(format!("unknown time zone {}", scheduled_weekly.timezone))
+        })?;
+        let local = time_zone.from_utc_datetime(&at.naive_utc());
+        let into_day =
+            local.num_seconds_from_midnight() as i64 * 1000 + local.nanosecond() as i64 / 1_000_000;
+        let start = scheduled_weekly.start_time as i64;
+        let end = start + scheduled_weekly.duration as i64;
+        let scheduled_on = |date: DateTime<chrono_tz::Tz>| {
+            scheduled_weekly
+                .weekdays
+                .contains(&(date.weekday().number_from_monday() as i32))
+        };
+
+        // A window may start on the previous day and run past midnight.
+        let today = scheduled_on(local) && (start..end).contains(&into_day);
+        let yesterday = scheduled_on(local - Duration::days(1))
+            && (start..end).contains(&(into_day + MILLISECONDS_PER_DAY));
+        if !today && !yesterday {
+            return Ok(false);
+        }
+    }
+
+    Ok(true)
+}
+
+/// A global, router, unrouted or service orchestration, as returned by the API
+///
+/// [OrchestrationPathDefinition::from_router] converts the generated router model, which keeps
+/// rule conditions. Service and unrouted orchestrations should be deserialized from the JSON of
+/// the API response.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub struct OrchestrationPathDefinition {
+    #[serde(default)]
+    pub sets: Vec<OrchestrationSetDefinition>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub catch_all: Option<OrchestrationCatchAllDefinition>,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub struct OrchestrationSetDefinition {
+    pub id: String,
+    #[serde(default)]
+    pub rules: Vec<OrchestrationRuleDefinition>,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub struct OrchestrationRuleDefinition {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub label: Option<String>,
+    /// The rule matches when any of its conditions match, or when it has none.
+    #[serde(default)]
+    pub conditions: Vec<OrchestrationConditionDefinition>,
+    #[serde(default)]
+    pub actions: Map<String, Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disabled: Option<bool>,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub struct OrchestrationConditionDefinition {
+    pub expression: String,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub struct OrchestrationCatchAllDefinition {
+    #[serde(default)]
+    pub actions: Map<String, Value>,
+}
+
+/// An orchestration rule matching an event
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MatchedOrchestrationRule {
+    pub set_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rule_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub label: Option<String>,
+}
+
+/// The rules of an orchestration matching an event, and the actions they apply
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub struct OrchestrationEvaluation {
+    /// The matching rules, in the order the event was routed through their sets.
+    pub matched_rules: Vec<MatchedOrchestrationRule>,
+    /// Whether no rule matched in the last set evaluated, applying the catch-all actions.
+    pub catch_all: bool,
+    pub actions: AppliedActions,
+}
+
+impl OrchestrationPathDefinition {
+    /// Convert a router orchestration, as returned by
+    /// [EventOrchestrationsClient::get_orch_path_router](crate::endpoints::event_orchestrations::EventOrchestrationsClient::get_orch_path_router).
+    pub fn from_router(router: &OrchestrationRouterOrchestrationPath) -> Result<Self, Error> {
+        Ok(serde_json::from_value(serde_json::to_value(router)?)?)
+    }
+
+    /// Route an event through the sets of this orchestration, starting with the `start` set.
+    ///
+    /// In each set, the first enabled rule with a matching condition applies its actions. A
+    /// `route_to` action naming another set continues evaluation in that set; any other
+    /// `route_to` is reported as the route of the event. When no rule of a set matches, the
+    /// catch-all actions apply.
+    pub fn evaluate(&self, event: &Value) -> Result<OrchestrationEvaluation, Error> {
+        let sets: HashMap<&str, &OrchestrationSetDefinition> =
+            self.sets.iter().map(|set| (set.id.as_str(), set)).collect();
+        let mut evaluation = OrchestrationEvaluation::default();
+        let mut next = sets.get("start").copied().or_else(|| self.sets.first());
+
+        while let Some(set) = next.take() {
+            if evaluation.matched_rules.len() > self.sets.len() {
+                return Err(evaluation_error(format!(
+                    "orchestration rules route in a cycle through set {}",
+                    set.id
+                )));
+            }
+
+            let mut matched = None;
+            for rule in &set.rules {
+                if rule_matches(rule, event)? {
+                    matched = Some(rule);
+                    break;
+                }
+            }
+
+            match matched {
+                Some(rule) => {
+                    evaluation.matched_rules.push(MatchedOrchestrationRule {
+                        set_id: String::clone(&set.id),
+                        rule_id: rule.id.clone(),
+                        label: rule.label.clone(),
+                    });
+                    apply_actions(&rule.actions, event, &mut evaluation.actions)?;
+                    if let Some(route_to) = rule.actions.get("route_to").and_then(Value::as_str) {
+                        match sets.get(route_to) {
+                            Some(set) => next = Some(*set),
+                            None => evaluation.actions.route = Some(String::from(route_to)),
+                        }
+                    }
+                }
+                None => {
+                    evaluation.catch_all = true;
+                    if let Some(catch_all) = &self.catch_all {
+                        apply_actions(&catch_all.actions, event, &mut evaluation.actions)?;
+                        if let Some(route_to) =
+                            catch_all.actions.get("route_to").and_then(Value::as_str)
+                        {
+                            evaluation.actions.route = Some(String::from(route_to));
+                        }
+                    }
+                }
+            }
+        }
+
+        Ok(evaluation)
+    }
+}
+
+fn rule_matches(rule: &OrchestrationRuleDefinition, event: &Value) -> Result<bool, Error> {
+    if rule.disabled.unwrap_or(false) {
+        return Ok(false);
+    }
+    if rule.conditions.is_empty() {
+        return Ok(true);
+    }
+
+    for condition in &rule.conditions {
+        if Condition::parse(&condition.expression)?.matches(event) {
+            return Ok(true);
+        }
+    }
+
+    Ok(false)
+}
+
+fn apply_actions(
+    actions: &Map<String, Value>,
+    event: &Value,
+    applied: &mut AppliedActions,
+) -> Result<(), Error> {
+    if let Some(severity) = actions.get("severity").and_then(string_value) {
+        applied.severity = Some(severity);
+    }
+    if let Some(priority) = actions.get("priority").and_then(string_value) {
+        applied.priority = Some(priority);
+    }
+    if let Some(suppress) = actions.get("suppress").and_then(Value::as_bool) {
+        applied.suppress = Some(suppress);
+    }
+    if let Some(suspend) = actions.get("suspend").and_then(Value::as_i64) {
+        applied.suspend = Some(suspend);
+    }
+    if let Some(annotate) = actions.get("annotate").and_then(string_value) {
+        applied.annotate = Some(annotate);
+    }
+    if let Some(event_action) = actions.get("event_action").and_then(string_value) {
+        applied.event_action = Some(event_action);
+    }
+
+    for variable in actions
+        .get("variables")
+        .and_then(Value::as_array)
+        .into_iter()
+        .flatten()
+    {
+        let field = |key: &str| variable.get(key).and_then(Value::as_str).unwrap_or("");
+        if field("type") != "regex" {
+            return Err(evaluation_error(format!(
+                "unsupported variable type for {}",
+                field("name")
+            )));
+        }
+        if let Some(value) = extract(event, field("path"), field("value"))? {
+            applied.variables.insert(String::from(field("name")), value);
+        }
+    }
+
+    for extraction in actions
+        .get("extractions")
+        .and_then(Value::as_array)
+        .into_iter()
+        .flatten()
+    {
+        let field = |key: &str| extraction.get(key).and_then(Value::as_str);
+        let target = match field("target") {
+            Some(target) => String::from(target),
+            None => continue,
+        };
+        let value = match (field("template"), field("source"), field("regex")) {
+            (Some(template), _, _) => Some(render_template(template, event, &applied.variables)),
+            (None, Some(source), Some(regex)) => extract(event, source, regex)?,
+            _ => None,
+        };
+        if let Some(value) = value {
+            applied.extractions.insert(target, value);
+        }
+    }
+
+    Ok(())
+}
+
+/// A parsed PagerDuty Condition Language expression
+#[derive(Debug)]
+enum Condition {
+    And(Box<Condition>, Box<Condition>),
+    Or(Box<Condition>, Box<Condition>),
+    Not(Box<Condition>),
+    Exists(String),
+    Matches(String, String),
+    MatchesPart(String, String),
+    MatchesRegex(String, Regex),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Word(String),
+    Literal(String),
+    Open,
+    Close,
+}
+
+impl Condition {
+    fn parse(expression: &str) -> Result<Condition, Error> {
+        let tokens = tokenize(expression)?;
+        let mut position = 0;
+        let condition = Condition::parse_or(&tokens, &mut position)?;
+        if position != tokens.len() {
+            return Err(evaluation_error(format!(
+                "unexpected {:?} in condition: {}",
+                tokens[position], expression
+            )));
+        }
+
+        Ok(condition)
+    }
+
+    fn parse_or(tokens: &[Token], position: &mut usize) -> Result<Condition, Error> {
+        let mut condition = Condition::parse_and(tokens, position)?;
+        while keyword_at(tokens, *position, "or") {
+            *position += 1;
+            let right = Condition::parse_and(tokens, position)?;
+            condition = Condition::Or(Box::new(condition), Box::new(right));
+        }
+
+        Ok(condition)
+    }
+
+    fn parse_and(tokens: &[Token], position: &mut usize) -> Result<Condition, Error> {
+        let mut condition = Condition::parse_unary(tokens, position)?;
+        while keyword_at(tokens, *position, "and") {
+            *position += 1;
+            let right = Condition::parse_unary(tokens, position)?;
+            condition = Condition::And(Box::new(condition), Box::new(right));
+        }
+
+        Ok(condition)
+    }
+
+    fn parse_unary(tokens: &[Token], position: &mut usize) -> Result<Condition, Error> {
+        if keyword_at(tokens, *position, "not") {
+            *position += 1;
+            return Ok(Condition::Not(Box::new(Condition::parse_unary(
+                tokens, position,
+            )?)));
+        }
+
+        if tokens.get(*position) == Some(&Token::Open) {
+            *position += 1;
+            let condition = Condition::parse_or(tokens, position)?;
+            if tokens.get(*position) != Some(&Token::Close) {
+                return Err(evaluation_error(String::from(
+                    "unbalanced parentheses in condition",
+                )));
+            }
+            *position += 1;
+            return Ok(condition);
+        }
+
+        let path = match tokens.get(*position) {
+            Some(Token::Word(path)) if path == "now" => {
+                return Err(evaluation_error(String::from(
+                    "conditions on the current time are not supported",
+                )))
+            }
+            Some(Token::Word(path)) => String::clone(path),
+            other => {
+                return Err(evaluation_error(format!(
+                    "expected a field path in condition, found {:?}",
+                    other
+                )))
+            }
+        };
+        *position += 1;
+
+        if keyword_at(tokens, *position, "exists") {
+            *position += 1;
+            return Ok(Condition::Exists(path));
+        }
+        if !keyword_at(tokens, *position, "matches") {
+            return Err(evaluation_error(format!(
+                "unsupported operator after {} in condition",
+                path
+            )));
+        }
+        *position += 1;
+
+        let operator =
+            if keyword_at(tokens, *position, "part") || keyword_at(tokens, *position, "regex") {
+                *position += 1;
+                match &tokens[*position - 1] {
+                    Token::Word(operator) => Some(operator.to_lowercase()),
+                    _ => None,
+                }
+            } else {
+                None
+            };
+
+        let literal = match tokens.get(*position) {
+            Some(Token::Literal(literal)) => String::clone(literal),
+            other => {
+                return Err(evaluation_error(format!(
+                    "expected a quoted value after {} in condition, found {:?}",
+                    path, other
+                )))
+            }
+        };
+        *position += 1;
+
+        Ok(match operator.as_deref() {
+            Some("part") => Condition::MatchesPart(path, literal),
+            Some("regex") => Condition::MatchesRegex(path, compile(&literal)?),
+            _ => Condition::Matches(path, literal),
+        })
+    }
+
+    fn matches(&self, event: &Value) -> bool {
+        let value_at = |path: &str| lookup(event, path).map(value_to_string);
+        match self {
+            Condition::And(left, right) => left.matches(event) && right.matches(event),
+            Condition::Or(left, right) => left.matches(event) || right.matches(event),
+            Condition::Not(condition) => !condition.matches(event),
+            Condition::Exists(path) => lookup(event, path).is_some(),
+            Condition::Matches(path, expected) => value_at(path).as_ref() == Some(expected),
+            Condition::MatchesPart(path, expected) => value_at(path)
+                .is_some_and(|value| value.to_lowercase().contains(&expected.to_lowercase())),
+            Condition::MatchesRegex(path, regex) => {
+                value_at(path).is_some_and(|value| regex.is_match(&value))
+            }
+        }
+    }
+}
+
+fn keyword_at(tokens: &[Token], position: usize, keyword: &str) -> bool {
+    matches!(tokens.get(position), Some(Token::Word(word)) if word.eq_ignore_ascii_case(keyword))
+}
+
+fn tokenize(expression: &str) -> Result<Vec<Token>, Error> {
+    let mut tokens = vec![];
+    let mut chars = expression.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            c if c.is_whitespace() => {}
+            '(' => tokens.push(Token::Open),
+            ')' => tokens.push(Token::Close),
+            '\'' | '"' => {
+                let mut literal = String::new();
+                loop {
+                    match chars.next() {
+                        Some('\\') => literal.extend(chars.next()),
+                        Some(next) if next == c => break,
+                        Some(next) => literal.push(next),
+                        None => {
+                            return Err(evaluation_error(format!(
+                                "unterminated string in condition: {}",
+                                expression
+                            )))
+                        }
+                    }
+                }
+                tokens.push(Token::Literal(literal));
+            }
+            c => {
+                let mut word = String::from(c);
+                while let Some(next) = chars.peek() {
+                    if next.is_whitespace() || matches!(next, '(' | ')' | '\'' | '"') {
+                        break;
+                    }
+                    word.extend(chars.next());
+                }
+                tokens.push(Token::Word(word));
+            }
+        }
+    }
+
+    Ok(tokens)
+}
+
+/// Look up a dot-notation path in a PD-CEF event. Paths starting with `event.` refer to the
+/// event payload, falling back to the top level, and paths starting with `raw_event.` refer to
+/// the top level of the event.
+fn lookup<'a>(event: &'a Value, path: &str) -> Option<&'a Value> {
+    let walk = |root: &'a Value, path: &str| {
+        path.split('.')
+            .try_fold(root, |value, key| match value {
+                Value::Array(values) => key.parse::<usize>().ok().and_then(|i| values.get(i)),
+                _ => value.get(key),
+            })
+            .filter(|value| !value.is_null())
+    };
+
+    if let Some(path) = path.strip_prefix("raw_event.") {
+        walk(event, path)
+    } else if let Some(path) = path.strip_prefix("event.") {
+        event
+            .get("payload")
+            .and_then(|payload| walk(payload, path))
+            .or_else(|| walk(event, path))
+    } else {
+        walk(event, path).or_else(|| event.get("payload").and_then(|payload| walk(payload, path)))
+    }
+}
+
+/// Apply a regular expression to the value at a path, returning the first capture group, or the
+/// whole match when the expression has no groups.
+fn extract(event: &Value, path: &str, regex: &str) -> Result<Option<String>, Error> {
+    let regex = compile(regex)?;
+    let value = match lookup(event, path) {
+        Some(value) => value_to_string(value),
+        None => return Ok(None),
+    };
+
+    Ok(regex.captures(&value).and_then(|captures| {
+        captures
+            .get(1)
+            .or_else(|| captures.get(0))
+            .map(|capture| String::from(capture.as_str()))
+    }))
+}
+
+/// Substitute `{{path}}` placeholders with values of the event, or with variables when the
+/// path starts with `variables.`.
+fn render_template(template: &str, event: &Value, variables: &BTreeMap<String, String>) -> String {
+    let mut rendered = String::new();
+    let mut rest = template;
+    while let Some(start) = rest.find("{{") {
+        let end = match rest[start..].find("}}") {
+            Some(end) => start + end,
+            None => break,
+        };
+        rendered.push_str(&rest[..start]);
+        let path = rest[start + 2..end].trim();
+        let value = match path.strip_prefix("variables.") {
+            Some(name) => variables.get(name).cloned(),
+            None => lookup(event, path).map(value_to_string),
+        };
+        rendered.push_str(&value.unwrap_or_default());
+        rest = &rest[end + 2..];
+    }
+    rendered.push_str(rest);
+
+    rendered
+}
+
+fn compile(regex: &str) -> Result<Regex, Error> {
+    Regex::new(regex).map_err(|e| evaluation_error(format!("invalid regular expression: {}", e)))
+}
+
+fn string_value(value: &Value) -> Option<String> {
+    value.as_str().map(String::from)
+}
+
+fn value_to_string(value: &Value) -> String {
+    match value {
+        Value::String(value) => String::clone(value),
+        value => value.to_string(),
+    }
+}
+
+fn evaluation_error(message: String) -> Error {
+    Error::RuleEvaluationError { message }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn event() -> Value {
+        serde_json::json!({
+            "routing_key": "R015GRFKJQP2O7XJ4GJ8YGQX3O2XWB6Z",
+            "event_action": "trigger",
+            "payload": {
+                "summary": "DISK at 99% on machine prod-datapipe03.example.com",
+                "source": "prod-datapipe03.example.com",
+                "severity": "warning",
+                "component": "mysql",
+                "custom_details": {
+                    "free space": "1%",
+                    "region": "eu-west-1"
+                }
+            }
+        })
+    }
+
+    #[test]
+    fn test_evaluate_event_rules() {
+        let rules: Vec<EventRuleDefinition> = serde_json::from_value(serde_json::json!([
+            {
+                "id": "14e56445-ebab-4dce-aa9c-2a4c67e2b0c6",
+                "conditions": {
+                    "operator": "and",
+                    "subconditions": [
+                        {"operator": "contains", "parameters": {"path": "payload.summary", "value": "DISK"}},
+                        {"operator": "equals", "parameters": {"path": "payload.component", "value": "redis"}}
+                    ]
+                },
+                "actions": {"severity": {"value": "info"}}
+            },
+            {
+                "id": "9ed1a5a0-ab0f-4b5e-a9b4-8b5b0d4b8b2e",
+                "conditions": {
+                    "operator": "or",
+                    "subconditions": [
+                        {"operator": "matches", "parameters": {"path": "payload.source", "value": "^prod-"}},
+                        {"operator": "nexists", "parameters": {"path": "payload.class", "value": ""}}
+                    ]
+                },
+                "variables": [
+                    {"type": "regex", "name": "host", "parameters": {"path": "payload.source", "value": "^([a-z0-9-]+)\\."}}
+                ],
+                "actions": {
+                    "severity": {"value": "critical"},
+                    "priority": {"value": "PCMUB6F"},
+                    "route": {"value": "PBZUP2B"},
+                    "extractions": [
+                        {"target": "dedup_key", "source": "payload.custom_details.region", "regex": "(.*)"},
+                        {"target": "summary", "template": "{{variables.host}}: {{payload.summary}}"}
+                    ]
+                }
+            },
+            {"id": "2c1f3f12-3c1c-4b27-a9a4-8c9d8b1a0f7d", "catch_all": true, "actions": {"suppress": {"value": true}}}
+        ]))
+        .unwrap();
+
+        let matched = evaluate_event_rules(&rules, &event(), Utc::now())
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(matched.index, 1);
+        assert!(!matched.catch_all);
+        assert_eq!(matched.actions.severity, Some(String::from("critical")));
+        assert_eq!(matched.actions.priority, Some(String::from("PCMUB6F")));
+        assert_eq!(matched.actions.route, Some(String::from("PBZUP2B")));
+        assert_eq!(
+            matched.actions.variables.get("host"),
+            Some(&String::from("prod-datapipe03"))
+        );
+        assert_eq!(
+            matched.actions.extractions.get("summary"),
+            Some(&String::from(
+                "prod-datapipe03: DISK at 99% on machine prod-datapipe03.example.com"
+            ))
+        );
+        assert_eq!(
+            matched.actions.extractions.get("dedup_key"),
+            Some(&String::from("eu-west-1"))
+        );
+    }
+
+    #[test]
+    fn test_time_frame() {
+        let rule = EventRuleDefinition {
+            time_frame: Some(EventRuleTimeFrame {
+                scheduled_weekly: Some(EventRuleTimeFrameScheduledWeekly {
+                    // 22:00 for four hours, running past midnight.
+                    start_time: 22 * 60 * 60 * 1000,
+                    duration: 4 * 60 * 60 * 1000,
+                    timezone: String::from("America/New_York"),
+                    weekdays: vec![1],
+                }),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+
+        // Monday 23:00 and Tuesday 01:00 in New York.
+        let monday = "2024-01-16T04:00:00Z".parse().unwrap();
+        let tuesday = "2024-01-16T06:00:00Z".parse().unwrap();
+        let wednesday = "2024-01-17T06:00:00Z".parse().unwrap();
+        assert!(rule.matches(&event(), monday).unwrap());
+        assert!(rule.matches(&event(), tuesday).unwrap());
+        assert!(!rule.matches(&event(), wednesday).unwrap());
+    }
+
+    #[test]
+    fn test_evaluate_orchestration() {
+        let orchestration: OrchestrationPathDefinition =
+            serde_json::from_value(serde_json::json!({
+                "type": "service",
+                "sets": [
+                    {
+                        "id": "start",
+                        "rules": [
+                            {
+                                "id": "1",
+                                "label": "Disabled",
+                                "disabled": true,
+                                "actions": {"severity": "info"}
+                            },
+                            {
+                                "id": "2",
+                                "label": "Production disks",
+                                "conditions": [
+                                    {"expression": "event.source matches regex '^staging-'"},
+                                    {"expression": "event.summary matches part 'disk' and not (event.custom_details.region matches 'us-east-1')"}
+                                ],
+                                "actions": {
+                                    "route_to": "disks",
+                                    "variables": [
+                                        {"name": "usage", "path": "event.summary", "type": "regex", "value": "at (\\d+)%"}
+                                    ]
+                                }
+                            }
+                        ]
+                    },
+                    {
+                        "id": "disks",
+                        "rules": [
+                            {
+                                "id": "3",
+                                "conditions": [{"expression": "event.component exists and event.component matches 'postgres'"}],
+                                "actions": {"priority": "PCMUB6F"}
+                            }
+                        ]
+                    }
+                ],
+                "catch_all": {"actions": {"severity": "critical", "annotate": "Disk usage {{variables.usage}}%"}}
+            }))
+            .unwrap();
+
+        let evaluation = orchestration.evaluate(&event()).unwrap();
+
+        assert_eq!(evaluation.matched_rules.len(), 1);
+        assert_eq!(evaluation.matched_rules[0].rule_id, Some(String::from("2")));
+        assert!(evaluation.catch_all);
+        assert_eq!(evaluation.actions.severity, Some(String::from("critical")));
+        assert_eq!(evaluation.actions.priority, None);
+        assert_eq!(
+            evaluation.actions.variables.get("usage"),
+            Some(&String::from("99"))
+        );
+
+        assert!(matches!(
+            Condition::parse("now in Mon,Tue 09:00:00 to 17:00:00 America/New_York"),
+            Err(Error::RuleEvaluationError { .. })
+        ));
+    }
+}
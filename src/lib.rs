@@ -30,30 +30,37 @@
 //!
 //! Praiya has currently implemented the following API endpoints:
 //!
-//! - [ ] abilities
-//! - [ ] add_ons
-//! - [ ] analytics
+//! - [X] abilities
+//! - [X] add_ons
+//! - [X] analytics
 //! - [ ] audit
+//! - [X] automation_actions
 //! - [ ] business_services
+//! - [X] custom_fields
 //! - [X] escalation_policies
-//! - [ ] extension_schemas
-//! - [ ] extensions
+//! - [X] event_orchestrations
+//! - [X] extension_schemas
+//! - [X] extensions
+//! - [X] incident_workflows
 //! - [X] incidents
-//! - [ ] log_entries
+//! - [X] log_entries
 //! - [ ] maintenance_windows
-//! - [ ] notifications
+//! - [X] notifications
 //! - [X] on_calls
-//! - [ ] priorities
-//! - [ ] response_plays
-//! - [ ] rulesets
+//! - [X] paused_incident_reports
+//! - [X] priorities
+//! - [X] response_plays
+//! - [X] rulesets
 //! - [X] schedules
 //! - [ ] service_dependencies
 //! - [X] services
 //! - [X] slack_connections
-//! - [ ] tags
-//! - [ ] teams
+//! - [X] status_dashboards
+//! - [X] tags
+//! - [X] teams
 //! - [X] users
-//! - [ ] vendors
+//! - [X] vendors
+//! - [X] webhook_subscriptions
 //!
 //! # Usage
 //!
@@ -93,6 +100,24 @@
 //! };
 //! ```
 //!
+//! ## Command-line client
+//!
+//! A `praiya` binary is available behind the `cli` feature:
+//!
+//! ```bash
+//! cargo install praiya --features cli
+//! export PAGERDUTY_TOKEN=...
+//! praiya incidents list --status triggered
+//! praiya incidents ack PT4KHLK --from oncall@example.com
+//! praiya oncall who --schedule PI7DH85 --output json
+//! ```
+//!
+//! ## Receiving webhooks
+//!
+//! The [webhooks] module verifies the signature of inbound V3 webhook deliveries and decodes
+//! them into typed events. A hyper service for mounting the endpoint is available behind the
+//! `webhook-service` feature.
+//!
 //! # Development
 //!
 //! Contributions are welcome, please observe the following advice.
@@ -166,6 +191,14 @@ mod macros;
 
 use std::collections::HashMap;
 
+pub mod account_config;
+pub mod archive;
+pub mod evaluator;
+pub mod metrics;
+pub mod notification_stats;
+pub mod timeline;
+pub mod triage;
+pub mod webhooks;
 #[rustfmt::skip]
 pub mod default_models;
 pub mod endpoints;
@@ -0,0 +1,485 @@
+//! Local incident response metrics: time to acknowledge and resolve, escalations and responder
+//! interruptions, computed from incidents and their log entries without the analytics API.
+//!
+//! An [IncidentMetricsBuilder] takes each [Incident] along with its [TypedLogEntry]s, for
+//! example from [IncidentsClient::list_incident_log_entries], and builds an
+//! [IncidentMetricsReport] grouped by service, team, urgency and priority, plus the load on each
+//! responder.
+//!
+//! Every `notify_log_entry` counts as an interruption of the notified user, classified by the
+//! time of day in that user's time zone into the same periods the analytics API uses: sleep
+//! hours from 10pm to 8am, business hours from 8am to 6pm on weekdays, off hours from 6pm to
+//! 10pm on weekdays, and weekends outside of sleep hours.
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use chrono::{DateTime, Datelike, FixedOffset, Timelike, Weekday};
+use chrono_tz::Tz;
+use futures_util::TryStreamExt;
+
+use crate::endpoints::incidents::{
+    IncidentsClient, ListIncidentLogEntriesParams, ListIncidentsParams,
+};
+use crate::endpoints::log_entries::TypedLogEntry;
+use crate::errors::Error;
+use crate::models::*;
+use crate::notification_stats::UserTimeZones;
+
+/// The period of the week an interruption falls into
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum InterruptionPeriod {
+    /// 8am to 6pm, Monday to Friday.
+    BusinessHours,
+    /// 6pm to 10pm, Monday to Friday.
+    OffHours,
+    /// Saturday and Sunday, outside of sleep hours.
+    Weekend,
+    /// 10pm to 8am, every day.
+    SleepHours,
+}
+
+impl InterruptionPeriod {
+    pub fn of(at: &DateTime<Tz>) -> Self {
+        let hour = at.hour();
+        if !(8..22).contains(&hour) {
+            InterruptionPeriod::SleepHours
+        } else if matches!(at.weekday(), Weekday::Sat | Weekday::Sun) {
+            InterruptionPeriod::Weekend
+        } else if hour < 18 {
+            InterruptionPeriod::BusinessHours
+        } else {
+            InterruptionPeriod::OffHours
+        }
+    }
+}
+
+/// Interruption counts per period of the week
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub struct InterruptionCounts {
+    pub total: u64,
+    pub business_hours: u64,
+    pub off_hours: u64,
+    pub weekend: u64,
+    pub sleep_hours: u64,
+}
+
+impl InterruptionCounts {
+    fn add(&mut self, period: InterruptionPeriod) {
+        self.total += 1;
+        match period {
+            InterruptionPeriod::BusinessHours => self.business_hours += 1,
+            InterruptionPeriod::OffHours => self.off_hours += 1,
+            InterruptionPeriod::Weekend => self.weekend += 1,
+            InterruptionPeriod::SleepHours => self.sleep_hours += 1,
+        }
+    }
+
+    fn merge(&mut self, other: &InterruptionCounts) {
+        self.total += other.total;
+        self.business_hours += other.business_hours;
+        self.off_hours += other.off_hours;
+        self.weekend += other.weekend;
+        self.sleep_hours += other.sleep_hours;
+    }
+}
+
+/// The response metrics of a single incident
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct IncidentResponseMetrics {
+    pub incident_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<DateTime<FixedOffset>>,
+    /// Seconds from the creation of the incident to its first acknowledgement.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub seconds_to_first_ack: Option<i64>,
+    /// Seconds from the creation of the incident to its resolution.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub seconds_to_resolve: Option<i64>,
+    pub escalation_count: u64,
+    pub interruptions: InterruptionCounts,
+}
+
+/// Aggregated metrics of a group of incidents
+#[derive(Debug, Default, Clone, PartialEq, Serialize)]
+pub struct IncidentMetricsSummary {
+    /// The name of the service, team or priority the group is keyed by, when known.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    pub incident_count: u64,
+    pub acknowledged_count: u64,
+    pub resolved_count: u64,
+    /// Mean time to acknowledge (MTTA) of the acknowledged incidents.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mean_seconds_to_first_ack: Option<f64>,
+    /// Mean time to resolve (MTTR) of the resolved incidents.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mean_seconds_to_resolve: Option<f64>,
+    pub escalation_count: u64,
+    pub interruptions: InterruptionCounts,
+    #[serde(skip)]
+    total_seconds_to_first_ack: i64,
+    #[serde(skip)]
+    total_seconds_to_resolve: i64,
+}
+
+impl IncidentMetricsSummary {
+    fn add(&mut self, metrics: &IncidentResponseMetrics) {
+        self.incident_count += 1;
+        if let Some(seconds) = metrics.seconds_to_first_ack {
+            self.acknowledged_count += 1;
+            self.total_seconds_to_first_ack += seconds;
+            self.mean_seconds_to_first_ack =
+                Some(self.total_seconds_to_first_ack as f64 / self.acknowledged_count as f64);
+        }
+        if let Some(seconds) = metrics.seconds_to_resolve {
+            self.resolved_count += 1;
+            self.total_seconds_to_resolve += seconds;
+            self.mean_seconds_to_resolve =
+                Some(self.total_seconds_to_resolve as f64 / self.resolved_count as f64);
+        }
+        self.escalation_count += metrics.escalation_count;
+        self.interruptions.merge(&metrics.interruptions);
+    }
+}
+
+/// The interruptions of a single responder
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ResponderLoad {
+    pub user_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// The time zone in which interruptions were classified.
+    pub time_zone: Tz,
+    /// The number of distinct incidents the responder was notified about.
+    pub incident_count: u64,
+    pub interruptions: InterruptionCounts,
+    #[serde(skip)]
+    incident_ids: BTreeSet<String>,
+}
+
+/// Incident response metrics, as built by an [IncidentMetricsBuilder]
+#[derive(Debug, Default, Clone, PartialEq, Serialize)]
+pub struct IncidentMetricsReport {
+    pub overall: IncidentMetricsSummary,
+    /// Keyed by service id.
+    pub by_service: BTreeMap<String, IncidentMetricsSummary>,
+    /// Keyed by team id. Incidents belonging to several teams count towards each of them.
+    pub by_team: BTreeMap<String, IncidentMetricsSummary>,
+    /// Keyed by urgency, e.g. `high`.
+    pub by_urgency: BTreeMap<String, IncidentMetricsSummary>,
+    /// Keyed by priority id. Incidents without a priority are left out.
+    pub by_priority: BTreeMap<String, IncidentMetricsSummary>,
+    /// Keyed by user id.
+    pub by_responder: BTreeMap<String, ResponderLoad>,
+    pub incidents: Vec<IncidentResponseMetrics>,
+}
+
+/// Collects incidents and their log entries into an [IncidentMetricsReport]
+#[derive(Debug, Clone)]
+pub struct IncidentMetricsBuilder {
+    time_zones: UserTimeZones,
+    report: IncidentMetricsReport,
+}
+
+fn seconds_between(from: DateTime<FixedOffset>, to: DateTime<FixedOffset>) -> i64 {
+    (to - from).num_seconds().max(0)
+}
+
+fn group<'a>(
+    groups: &'a mut BTreeMap<String, IncidentMetricsSummary>,
+    key: &str,
+    name: Option<&String>,
+) -> &'a mut IncidentMetricsSummary {
+    let summary = groups.entry(String::from(key)).or_default();
+    if summary.name.is_none() {
+        summary.name = name.cloned();
+    }
+    summary
+}
+
+impl IncidentMetricsBuilder {
+    /// Create a builder, classifying interruptions in `default_time_zone` for users whose time
+    /// zone is unknown.
+    pub fn new(default_time_zone: Tz) -> Self {
+        IncidentMetricsBuilder {
+            time_zones: UserTimeZones::new(default_time_zone),
+            report: IncidentMetricsReport::default(),
+        }
+    }
+
+    /// Set the time zone of a user, overriding the one embedded in the log entries added
+    /// afterwards.
+    pub fn user_time_zone(&mut self, user_id: &str, time_zone: Tz) -> &mut Self {
+        self.time_zones.insert(user_id, time_zone);
+        self
+    }
+
+    /// Add an incident along with its log entries. Incidents without an id are ignored.
+    pub fn incident<'a>(
+        &mut self,
+        incident: &Incident,
+        log_entries: impl IntoIterator<Item = &'a TypedLogEntry>,
+    ) -> &mut Self {
+        let incident_id = match &incident.id {
+            Some(id) => id,
+            None => return self,
+        };
+
+        let mut first_ack: Option<DateTime<FixedOffset>> = None;
+        let mut resolved: Option<DateTime<FixedOffset>> = None;
+        let mut escalation_count = 0;
+        let mut interruptions = InterruptionCounts::default();
+
+        for log_entry in log_entries {
+            let at = match log_entry.common().created_at {
+                Some(at) => at,
+                None => continue,
+            };
+            match log_entry {
+                TypedLogEntry::Acknowledge { .. } => {
+                    first_ack = Some(first_ack.map_or(at, |first| first.min(at)));
+                }
+                TypedLogEntry::Resolve { .. } => {
+                    resolved = Some(resolved.map_or(at, |first| first.min(at)));
+                }
+                TypedLogEntry::Escalate { .. } => escalation_count += 1,
+                TypedLogEntry::Notify { log_entry, .. } => {
+                    if let Some(
+                        user @ User {
+                            id: Some(user_id), ..
+                        },
+                    ) = &log_entry.user
+                    {
+                        let period = self.interruption(incident_id, user_id, user, at);
+                        interruptions.add(period);
+                    }
+                }
+                _ => (),
+            }
+        }
+
+        if resolved.is_none() && incident.status == Some(IncidentStatusEnum::RESOLVED) {
+            resolved = incident.last_status_change_at;
+        }
+
+        let metrics = IncidentResponseMetrics {
+            incident_id: String::clone(incident_id),
+            created_at: incident.created_at,
+            seconds_to_first_ack: incident
+                .created_at
+                .zip(first_ack)
+                .map(|(created_at, at)| seconds_between(created_at, at)),
+            seconds_to_resolve: incident
+                .created_at
+                .zip(resolved)
+                .map(|(created_at, at)| seconds_between(created_at, at)),
+            escalation_count,
+            interruptions,
+        };
+
+        let report = &mut self.report;
+        report.overall.add(&metrics);
+        if let Some(service) = &incident.service {
+            if let Some(id) = &service.id {
+                group(&mut report.by_service, id, service.summary.as_ref()).add(&metrics);
+            }
+        }
+        for team in incident.teams.iter().flatten() {
+            if let Some(id) = &team.id {
+                group(&mut report.by_team, id, team.summary.as_ref()).add(&metrics);
+            }
+        }
+        if let Some(urgency) = &incident.urgency {
+            group(&mut report.by_urgency, urgency.as_ref(), None).add(&metrics);
+        }
+        if let Some(priority) = &incident.priority {
+            if let Some(id) = &priority.id {
+                group(&mut report.by_priority, id, priority.summary.as_ref()).add(&metrics);
+            }
+        }
+        report.incidents.push(metrics);
+
+        self
+    }
+
+    fn interruption(
+        &mut self,
+        incident_id: &str,
+        user_id: &str,
+        user: &User,
+        at: DateTime<FixedOffset>,
+    ) -> InterruptionPeriod {
+        let time_zone = self.time_zones.get(user_id, user);
+        let period = InterruptionPeriod::of(&at.with_timezone(&time_zone));
+
+        let load = self
+            .report
+            .by_responder
+            .entry(String::from(user_id))
+            .or_insert_with(|| ResponderLoad {
+                user_id: String::from(user_id),
+                name: user.name.clone().or_else(|| user.summary.clone()),
+                time_zone,
+                incident_count: 0,
+                interruptions: InterruptionCounts::default(),
+                incident_ids: BTreeSet::new(),
+            });
+        if load.incident_ids.insert(String::from(incident_id)) {
+            load.incident_count += 1;
+        }
+        load.interruptions.add(period);
+
+        period
+    }
+
+    pub fn build(&self) -> IncidentMetricsReport {
+        IncidentMetricsReport::clone(&self.report)
+    }
+}
+
+impl IncidentsClient {
+    /// ---
+    ///
+    /// # Compute incident response metrics
+    ///
+    /// Praiya convenience method listing the incidents matching the query along with the log
+    /// entries of each, and computing an [IncidentMetricsReport] from them. Log entries are
+    /// fetched one incident at a time, so narrow the query to the period being reported on.
+    ///
+    /// ---
+    pub async fn incident_metrics(
+        &self,
+        query_params: ListIncidentsParams,
+        default_time_zone: Tz,
+    ) -> Result<IncidentMetricsReport, Error> {
+        let incidents: Vec<Incident> = self.list_incidents(query_params).try_collect().await?;
+
+        let mut builder = IncidentMetricsBuilder::new(default_time_zone);
+        for incident in incidents.iter() {
+            let id = match &incident.id {
+                Some(id) => id,
+                None => continue,
+            };
+            let log_entries: Vec<TypedLogEntry> = self
+                .list_incident_log_entries(id, ListIncidentLogEntriesParams::default())
+                .try_collect()
+                .await?;
+            builder.incident(incident, &log_entries);
+        }
+
+        Ok(builder.build())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::endpoints::log_entries::LogEntryCommon;
+
+    fn at(timestamp: &str) -> Option<DateTime<FixedOffset>> {
+        Some(DateTime::parse_from_rfc3339(timestamp).unwrap())
+    }
+
+    fn common(timestamp: &str) -> LogEntryCommon {
+        LogEntryCommon {
+            created_at: at(timestamp),
+            ..Default::default()
+        }
+    }
+
+    fn notify(timestamp: &str, user_id: &str, time_zone: &str) -> TypedLogEntry {
+        TypedLogEntry::Notify {
+            common: common(timestamp),
+            log_entry: Box::new(NotifyLogEntry {
+                user: Some(User {
+                    id: Some(String::from(user_id)),
+                    time_zone: Some(String::from(time_zone)),
+                    ..Default::default()
+                }),
+                ..Default::default()
+            }),
+        }
+    }
+
+    #[test]
+    fn test_incident_metrics_builder() {
+        let incident = Incident {
+            id: Some(String::from("PT4KHLK")),
+            created_at: at("2024-01-06T02:00:00Z"),
+            status: Some(IncidentStatusEnum::RESOLVED),
+            urgency: Some(IncidentUrgencyEnum::HIGH),
+            service: Some(Service {
+                id: Some(String::from("PIJ90N7")),
+                summary: Some(String::from("My Application Service")),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        // Saturday 2024-01-06: 03:00 and 11:00 in Berlin
+        let log_entries = vec![
+            TypedLogEntry::Trigger {
+                common: common("2024-01-06T02:00:00Z"),
+            },
+            notify("2024-01-06T02:00:00Z", "PXPGF42", "Europe/Berlin"),
+            TypedLogEntry::Escalate {
+                common: common("2024-01-06T02:05:00Z"),
+                log_entry: Box::default(),
+            },
+            notify("2024-01-06T10:00:00Z", "PXPGF42", "Europe/Berlin"),
+            TypedLogEntry::Acknowledge {
+                common: common("2024-01-06T02:10:00Z"),
+                acknowledgement_timeout: None,
+            },
+            TypedLogEntry::Resolve {
+                common: common("2024-01-06T03:00:00Z"),
+            },
+        ];
+
+        let second = Incident {
+            id: Some(String::from("PT4KHLM")),
+            created_at: at("2024-01-08T09:00:00Z"),
+            urgency: Some(IncidentUrgencyEnum::HIGH),
+            ..Default::default()
+        };
+        // Monday 2024-01-08: 10:05 in Berlin
+        let second_log_entries = vec![
+            notify("2024-01-08T09:05:00Z", "PXPGF42", "Europe/Berlin"),
+            TypedLogEntry::Acknowledge {
+                common: common("2024-01-08T09:30:00Z"),
+                acknowledgement_timeout: None,
+            },
+        ];
+
+        let report = IncidentMetricsBuilder::new(chrono_tz::UTC)
+            .incident(&incident, &log_entries)
+            .incident(&second, &second_log_entries)
+            .build();
+
+        assert_eq!(report.overall.incident_count, 2);
+        assert_eq!(report.overall.acknowledged_count, 2);
+        assert_eq!(report.overall.resolved_count, 1);
+        assert_eq!(report.overall.mean_seconds_to_first_ack, Some(1200.0));
+        assert_eq!(report.overall.mean_seconds_to_resolve, Some(3600.0));
+        assert_eq!(report.overall.escalation_count, 1);
+
+        let service = &report.by_service["PIJ90N7"];
+        assert_eq!(service.incident_count, 1);
+        assert_eq!(service.name.as_deref(), Some("My Application Service"));
+        assert_eq!(report.by_urgency["high"].incident_count, 2);
+
+        let load = &report.by_responder["PXPGF42"];
+        assert_eq!(load.incident_count, 2);
+        assert_eq!(load.interruptions.total, 3);
+        assert_eq!(load.interruptions.sleep_hours, 1);
+        assert_eq!(load.interruptions.weekend, 1);
+        assert_eq!(load.interruptions.business_hours, 1);
+
+        let json = serde_json::to_value(&report).unwrap();
+        assert_eq!(
+            json["by_responder"]["PXPGF42"]["time_zone"],
+            "Europe/Berlin"
+        );
+        assert!(json["overall"].get("total_seconds_to_first_ack").is_none());
+    }
+}
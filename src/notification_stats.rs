@@ -0,0 +1,322 @@
+//! Notification analytics: count notifications per user, per contact type and per hour of day.
+//!
+//! A [NotificationAggregator] collects [Notification]s, for example from
+//! [NotificationsClient::list_notifications], into [NotificationStats]. Hours of day are taken in
+//! the notified user's own time zone when it is known, so that a page at 3am for the engineer
+//! counts as 3am regardless of where the account is configured. Listing notifications with
+//! `include[]=users` embeds the users and their time zones.
+
+use std::collections::BTreeMap;
+
+use chrono::Timelike;
+use chrono_tz::Tz;
+use futures_util::TryStreamExt;
+
+use crate::endpoints::notifications::{ListNotificationsParams, NotificationsClient};
+use crate::errors::Error;
+use crate::models::*;
+
+/// Notification counts of a single user
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct UserNotificationStats {
+    pub user_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// The time zone in which [by_hour](UserNotificationStats::by_hour) was counted.
+    pub time_zone: Tz,
+    pub total: u64,
+    pub by_contact_type: BTreeMap<NotificationTypeEnum, u64>,
+    /// Notifications per hour of day, `by_hour[3]` counting those sent between 3am and 4am.
+    pub by_hour: [u64; 24],
+}
+
+impl UserNotificationStats {
+    fn new(user_id: &str, name: Option<String>, time_zone: Tz) -> Self {
+        UserNotificationStats {
+            user_id: String::from(user_id),
+            name,
+            time_zone,
+            total: 0,
+            by_contact_type: BTreeMap::new(),
+            by_hour: [0; 24],
+        }
+    }
+
+    /// Count the notifications sent from the start hour up to, but excluding, the end hour. The
+    /// range wraps around midnight when `start > end`, e.g. `between_hours(22, 6)` counts
+    /// night-time pages.
+    pub fn between_hours(&self, start: u32, end: u32) -> u64 {
+        count_between_hours(&self.by_hour, start, end)
+    }
+}
+
+/// Aggregated notification counts, as built by a [NotificationAggregator]
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub struct NotificationStats {
+    pub total: u64,
+    /// Notifications without a user or timestamp, which are left out of every other count.
+    pub skipped: u64,
+    /// Per-user counts, keyed by user id.
+    pub by_user: BTreeMap<String, UserNotificationStats>,
+    pub by_contact_type: BTreeMap<NotificationTypeEnum, u64>,
+    /// Notifications per hour of day, each counted in the notified user's time zone.
+    pub by_hour: [u64; 24],
+}
+
+impl NotificationStats {
+    /// Users with at least `threshold` notifications from the start hour up to, but excluding,
+    /// the end hour, most notified first.
+    pub fn users_notified_between(
+        &self,
+        start: u32,
+        end: u32,
+        threshold: u64,
+    ) -> Vec<&UserNotificationStats> {
+        let mut users: Vec<_> = self
+            .by_user
+            .values()
+            .filter(|user| user.between_hours(start, end) >= threshold)
+            .collect();
+        users.sort_by_key(|user| std::cmp::Reverse(user.between_hours(start, end)));
+        users
+    }
+}
+
+fn count_between_hours(by_hour: &[u64; 24], start: u32, end: u32) -> u64 {
+    (0..24)
+        .filter(|hour| {
+            if start <= end {
+                (start..end).contains(hour)
+            } else {
+                *hour >= start || *hour < end
+            }
+        })
+        .map(|hour| by_hour[hour as usize])
+        .sum()
+}
+
+/// The time zones of users: set explicitly, embedded in the user, or a default.
+#[derive(Debug, Clone)]
+pub(crate) struct UserTimeZones {
+    default_time_zone: Tz,
+    user_time_zones: BTreeMap<String, Tz>,
+}
+
+impl UserTimeZones {
+    pub(crate) fn new(default_time_zone: Tz) -> Self {
+        UserTimeZones {
+            default_time_zone,
+            user_time_zones: BTreeMap::new(),
+        }
+    }
+
+    pub(crate) fn insert(&mut self, user_id: &str, time_zone: Tz) {
+        self.user_time_zones
+            .insert(String::from(user_id), time_zone);
+    }
+
+    /// The time zone set for `user_id`, else the one embedded in `user` if it parses, else the
+    /// default.
+    pub(crate) fn get(&self, user_id: &str, user: &User) -> Tz {
+        self.user_time_zones
+            .get(user_id)
+            .copied()
+            .or_else(|| {
+                user.time_zone
+                    .as_deref()
+                    .and_then(|time_zone| time_zone.parse::<Tz>().ok())
+            })
+            .unwrap_or(self.default_time_zone)
+    }
+}
+
+/// Collects notifications into [NotificationStats]
+#[derive(Debug, Clone)]
+pub struct NotificationAggregator {
+    time_zones: UserTimeZones,
+    stats: NotificationStats,
+}
+
+impl NotificationAggregator {
+    /// Create an aggregator, counting hours of day in `default_time_zone` for users whose time
+    /// zone is unknown.
+    pub fn new(default_time_zone: Tz) -> Self {
+        NotificationAggregator {
+            time_zones: UserTimeZones::new(default_time_zone),
+            stats: NotificationStats::default(),
+        }
+    }
+
+    /// Set the time zone of a user, overriding the one embedded in the notifications added
+    /// afterwards.
+    pub fn user_time_zone(&mut self, user_id: &str, time_zone: Tz) -> &mut Self {
+        self.time_zones.insert(user_id, time_zone);
+        self
+    }
+
+    pub fn add(&mut self, notification: &Notification) -> &mut Self {
+        let user = notification.user.as_ref();
+        let (user, user_id, started_at) = match (
+            user,
+            user.and_then(|user| user.id.as_ref()),
+            notification.started_at,
+        ) {
+            (Some(user), Some(user_id), Some(started_at)) => (user, user_id, started_at),
+            _ => {
+                self.stats.skipped += 1;
+                return self;
+            }
+        };
+
+        let time_zone = self.time_zones.get(user_id, user);
+        let hour = started_at.with_timezone(&time_zone).hour() as usize;
+        let contact_type = NotificationTypeEnum::clone(&notification._type);
+
+        let user_stats = self
+            .stats
+            .by_user
+            .entry(String::clone(user_id))
+            .or_insert_with(|| {
+                UserNotificationStats::new(
+                    user_id,
+                    user.name.clone().or_else(|| user.summary.clone()),
+                    time_zone,
+                )
+            });
+        user_stats.total += 1;
+        *user_stats
+            .by_contact_type
+            .entry(NotificationTypeEnum::clone(&contact_type))
+            .or_insert(0) += 1;
+        user_stats.by_hour[hour] += 1;
+
+        self.stats.total += 1;
+        *self.stats.by_contact_type.entry(contact_type).or_insert(0) += 1;
+        self.stats.by_hour[hour] += 1;
+
+        self
+    }
+
+    pub fn extend<'a>(
+        &mut self,
+        notifications: impl IntoIterator<Item = &'a Notification>,
+    ) -> &mut Self {
+        for notification in notifications {
+            self.add(notification);
+        }
+        self
+    }
+
+    pub fn build(&self) -> NotificationStats {
+        NotificationStats::clone(&self.stats)
+    }
+}
+
+impl NotificationsClient {
+    /// ---
+    ///
+    /// # Aggregate notifications
+    ///
+    /// Praiya convenience method listing the notifications matching the query and counting them
+    /// per user, per contact type and per hour of day. Include `users` in the query so that
+    /// hours are counted in each user's time zone; `default_time_zone` is used otherwise.
+    ///
+    /// ---
+    pub async fn notification_stats(
+        &self,
+        query_params: ListNotificationsParams,
+        default_time_zone: Tz,
+    ) -> Result<NotificationStats, Error> {
+        let notifications: Vec<Notification> =
+            self.list_notifications(query_params).try_collect().await?;
+
+        Ok(NotificationAggregator::new(default_time_zone)
+            .extend(&notifications)
+            .build())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::DateTime;
+
+    fn notification(
+        user_id: &str,
+        time_zone: Option<&str>,
+        _type: NotificationTypeEnum,
+        started_at: &str,
+    ) -> Notification {
+        Notification {
+            _type,
+            started_at: Some(DateTime::parse_from_rfc3339(started_at).unwrap()),
+            user: Some(User {
+                id: Some(String::from(user_id)),
+                time_zone: time_zone.map(String::from),
+                ..Default::default()
+            }),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_notification_aggregator() {
+        let notifications = vec![
+            // 03:10 in Berlin
+            notification(
+                "PXPGF42",
+                Some("Europe/Berlin"),
+                NotificationTypeEnum::PHONE_NOTIFICATION,
+                "2024-01-10T02:10:00Z",
+            ),
+            // 03:40 in Berlin
+            notification(
+                "PXPGF42",
+                Some("Europe/Berlin"),
+                NotificationTypeEnum::SMS_NOTIFICATION,
+                "2024-01-11T02:40:00Z",
+            ),
+            // 14:00 in Berlin
+            notification(
+                "PXPGF42",
+                Some("Europe/Berlin"),
+                NotificationTypeEnum::PUSH_NOTIFICATION,
+                "2024-01-11T13:00:00Z",
+            ),
+            // 21:00 in New York, the default time zone
+            notification(
+                "PAM4FGS",
+                None,
+                NotificationTypeEnum::PHONE_NOTIFICATION,
+                "2024-01-11T02:00:00Z",
+            ),
+            Notification::default(),
+        ];
+
+        let stats = NotificationAggregator::new(chrono_tz::America::New_York)
+            .extend(&notifications)
+            .build();
+
+        assert_eq!(stats.total, 4);
+        assert_eq!(stats.skipped, 1);
+        assert_eq!(
+            stats.by_contact_type[&NotificationTypeEnum::PHONE_NOTIFICATION],
+            2
+        );
+        assert_eq!(stats.by_hour[3], 2);
+        assert_eq!(stats.by_hour[21], 1);
+
+        let user = &stats.by_user["PXPGF42"];
+        assert_eq!(user.total, 3);
+        assert_eq!(user.time_zone, chrono_tz::Europe::Berlin);
+        assert_eq!(user.between_hours(22, 6), 2);
+
+        let night_owls = stats.users_notified_between(0, 6, 2);
+        assert_eq!(night_owls.len(), 1);
+        assert_eq!(night_owls[0].user_id, "PXPGF42");
+
+        let json = serde_json::to_value(&stats).unwrap();
+        assert_eq!(json["by_contact_type"]["phone_notification"], 2);
+        assert_eq!(json["by_user"]["PXPGF42"]["time_zone"], "Europe/Berlin");
+    }
+}
@@ -23,6 +23,7 @@ use serde::de::DeserializeOwned;
 use serde::ser;
 use url::form_urlencoded;
 
+use crate::endpoints::abilities::Capabilities;
 use crate::errors::Error::*;
 use crate::errors::{self, Error};
 use crate::models::*;
@@ -34,6 +35,7 @@ pub struct Praiya {
     pub(crate) client: Arc<Client>,
     pub(crate) client_timeout: u64,
     pub(crate) token: Arc<String>,
+    pub(crate) capabilities: Arc<std::sync::Mutex<Option<Capabilities>>>,
 }
 
 #[derive(Debug)]
@@ -57,6 +59,7 @@ pub enum PraiyaCustomHeaders<'req> {
     None,
     EarlyAccess(Option<&'req str>),
     AuditEarlyAccess,
+    From(&'req str),
 }
 
 impl<'req> From<PraiyaCustomHeaders<'req>> for &'static str {
@@ -64,6 +67,7 @@ impl<'req> From<PraiyaCustomHeaders<'req>> for &'static str {
         match headers {
             PraiyaCustomHeaders::EarlyAccess(_) => "x-early-access",
             PraiyaCustomHeaders::AuditEarlyAccess => "x-audit-early-access",
+            PraiyaCustomHeaders::From(_) => "from",
             PraiyaCustomHeaders::None => panic!("no key for this header"),
         }
     }
@@ -89,6 +93,7 @@ impl Praiya {
             client,
             client_timeout: DEFAULT_TIMEOUT,
             token: Arc::new(token.to_string()),
+            capabilities: Arc::new(std::sync::Mutex::new(None)),
         }
     }
 
@@ -349,6 +354,10 @@ impl Praiya {
                 let key: &str = headers.into();
                 header_map.insert(String::from(key), String::from(value));
             }
+            PraiyaCustomHeaders::From(value) => {
+                let key: &str = headers.into();
+                header_map.insert(String::from(key), String::from(value));
+            }
             _ => {
                 let key: &str = headers.into();
                 header_map.insert(String::from(key), String::from("true"));
@@ -0,0 +1,423 @@
+//! Incident timelines: merge log entries, notes, alerts and status updates into typed events.
+//!
+//! A [TimelineBuilder] collects the records of an incident, normalises each into a
+//! [TimelineEvent] and orders them by time. The resulting [Timeline] renders to Markdown or JSON
+//! in any time zone, which is convenient when writing postmortems.
+//!
+//! The REST API has no endpoint listing the status updates of an incident, so those are added to
+//! the builder by the caller, for example from the responses of
+//! [IncidentsClient::create_incident_status_update].
+
+use std::fmt::Write;
+
+use chrono::{DateTime, FixedOffset};
+use futures_util::TryStreamExt;
+
+use crate::endpoints::incidents::{
+    IncidentsClient, ListIncidentAlertsParams, ListIncidentLogEntriesParams,
+};
+use crate::endpoints::log_entries::{LogEntryChannel, LogEntryCommon, TypedLogEntry};
+use crate::errors::Error;
+use crate::models::*;
+
+/// The kind of an incident timeline event, along with the details specific to it
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum TimelineEventKind {
+    Trigger {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        channel: Option<String>,
+    },
+    Acknowledge,
+    Unacknowledge,
+    Assign,
+    Escalate,
+    Notify {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        method: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        address: Option<String>,
+    },
+    Snooze,
+    Resolve,
+    Annotate,
+    Note {
+        content: String,
+    },
+    Alert {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        alert_key: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        severity: Option<String>,
+    },
+    StatusUpdate {
+        message: String,
+    },
+    /// A log entry type without a dedicated event kind, such as `reach_trigger_limit_log_entry`.
+    Other {
+        log_entry_type: String,
+    },
+}
+
+impl TimelineEventKind {
+    /// A short label for the kind of event.
+    pub fn label(&self) -> &'static str {
+        match self {
+            TimelineEventKind::Trigger { .. } => "Triggered",
+            TimelineEventKind::Acknowledge => "Acknowledged",
+            TimelineEventKind::Unacknowledge => "Unacknowledged",
+            TimelineEventKind::Assign => "Assigned",
+            TimelineEventKind::Escalate => "Escalated",
+            TimelineEventKind::Notify { .. } => "Notified",
+            TimelineEventKind::Snooze => "Snoozed",
+            TimelineEventKind::Resolve => "Resolved",
+            TimelineEventKind::Annotate => "Annotated",
+            TimelineEventKind::Note { .. } => "Note",
+            TimelineEventKind::Alert { .. } => "Alert",
+            TimelineEventKind::StatusUpdate { .. } => "Status update",
+            TimelineEventKind::Other { .. } => "Other",
+        }
+    }
+}
+
+/// A single event of an incident timeline
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TimelineEvent {
+    pub at: DateTime<FixedOffset>,
+    #[serde(flatten)]
+    pub kind: TimelineEventKind,
+    /// The user, service or integration responsible for the event.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub agent: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub summary: Option<String>,
+    /// The id of the log entry, note, alert or status update the event was built from.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub source_id: Option<String>,
+}
+
+impl TimelineEvent {
+    fn from_log_entry(log_entry: TypedLogEntry) -> Option<TimelineEvent> {
+        let kind = match &log_entry {
+            TypedLogEntry::Trigger { common } => TimelineEventKind::Trigger {
+                channel: common
+                    .channel
+                    .as_ref()
+                    .map(|channel| String::from(channel.channel_type())),
+            },
+            TypedLogEntry::Acknowledge { .. } => TimelineEventKind::Acknowledge,
+            TypedLogEntry::Unacknowledge { .. } => TimelineEventKind::Unacknowledge,
+            TypedLogEntry::Assign { .. } => TimelineEventKind::Assign,
+            TypedLogEntry::Escalate { .. } => TimelineEventKind::Escalate,
+            TypedLogEntry::Notify { common, .. } => match &common.channel {
+                Some(LogEntryChannel::Notification {
+                    notification: Some(notification),
+                    ..
+                }) => TimelineEventKind::Notify {
+                    method: Some(notification._type.to_string()),
+                    address: notification.address.clone(),
+                },
+                channel => TimelineEventKind::Notify {
+                    method: channel
+                        .as_ref()
+                        .map(|channel| String::from(channel.channel_type())),
+                    address: None,
+                },
+            },
+            TypedLogEntry::Snooze { .. } => TimelineEventKind::Snooze,
+            TypedLogEntry::Resolve { .. } => TimelineEventKind::Resolve,
+            TypedLogEntry::Annotate { .. } => TimelineEventKind::Annotate,
+            other => TimelineEventKind::Other {
+                log_entry_type: String::from(other.log_entry_type()),
+            },
+        };
+
+        let common = log_entry.common();
+        Some(TimelineEvent {
+            at: common.created_at?,
+            kind,
+            agent: common
+                .agent
+                .as_ref()
+                .and_then(|agent| agent.summary.clone()),
+            summary: common.summary.clone(),
+            source_id: common.id.clone(),
+        })
+    }
+
+    fn from_note(note: IncidentNote) -> Option<TimelineEvent> {
+        Some(TimelineEvent {
+            at: note.created_at?,
+            kind: TimelineEventKind::Note {
+                content: note.content,
+            },
+            agent: note.user.and_then(|user| user.summary),
+            summary: None,
+            source_id: note.id,
+        })
+    }
+
+    fn from_alert(alert: Alert) -> Option<TimelineEvent> {
+        Some(TimelineEvent {
+            at: alert.created_at?,
+            kind: TimelineEventKind::Alert {
+                alert_key: alert.alert_key,
+                severity: alert.severity.map(|severity| severity.to_string()),
+            },
+            agent: alert
+                .integration
+                .and_then(|integration| integration.summary)
+                .or_else(|| alert.service.and_then(|service| service.summary)),
+            summary: alert.summary,
+            source_id: alert.id,
+        })
+    }
+
+    fn from_status_update(status_update: StatusUpdate) -> Option<TimelineEvent> {
+        let at = DateTime::parse_from_rfc3339(status_update.created_at.as_deref()?).ok()?;
+
+        Some(TimelineEvent {
+            at,
+            kind: TimelineEventKind::StatusUpdate {
+                message: status_update.message.unwrap_or_default(),
+            },
+            agent: status_update.sender.and_then(|sender| sender.summary),
+            summary: status_update.subject,
+            source_id: status_update.id,
+        })
+    }
+
+    fn details(&self) -> String {
+        match &self.kind {
+            TimelineEventKind::Note { content } => String::clone(content),
+            TimelineEventKind::StatusUpdate { message } => String::clone(message),
+            TimelineEventKind::Notify { method, address } => [method, address]
+                .iter()
+                .filter_map(|value| value.as_deref())
+                .collect::<Vec<_>>()
+                .join(" "),
+            _ => self.summary.clone().unwrap_or_default(),
+        }
+    }
+}
+
+/// The ordered events of an incident
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Timeline {
+    pub incident_id: String,
+    pub events: Vec<TimelineEvent>,
+}
+
+impl Timeline {
+    /// Convert the timestamps of every event into the given time zone.
+    pub fn in_time_zone(&self, time_zone: &chrono_tz::Tz) -> Timeline {
+        Timeline {
+            incident_id: String::clone(&self.incident_id),
+            events: self
+                .events
+                .iter()
+                .map(|event| TimelineEvent {
+                    at: event.at.with_timezone(time_zone).fixed_offset(),
+                    ..TimelineEvent::clone(event)
+                })
+                .collect(),
+        }
+    }
+
+    /// Render the timeline as a Markdown table, with timestamps in the given time zone.
+    pub fn to_markdown(&self, time_zone: &chrono_tz::Tz) -> String {
+        let mut markdown = String::new();
+        let _ = writeln!(markdown, "# Incident {} timeline", self.incident_id);
+        let _ = writeln!(markdown);
+        let _ = writeln!(
+            markdown,
+            "| Time ({}) | Event | Agent | Details |",
+            time_zone
+        );
+        let _ = writeln!(markdown, "| --- | --- | --- | --- |");
+        for event in &self.events {
+            let _ = writeln!(
+                markdown,
+                "| {} | {} | {} | {} |",
+                event
+                    .at
+                    .with_timezone(time_zone)
+                    .format("%Y-%m-%d %H:%M:%S"),
+                event.kind.label(),
+                escape_markdown_cell(event.agent.as_deref().unwrap_or("")),
+                escape_markdown_cell(&event.details()),
+            );
+        }
+
+        markdown
+    }
+
+    /// Render the timeline as JSON, with timestamps in the given time zone.
+    pub fn to_json(&self, time_zone: &chrono_tz::Tz) -> Result<String, Error> {
+        Ok(serde_json::to_string_pretty(&self.in_time_zone(time_zone))?)
+    }
+}
+
+fn escape_markdown_cell(value: &str) -> String {
+    value.replace('|', "\\|").replace(['\r', '\n'], " ")
+}
+
+/// Collects the records of an incident into a [Timeline]
+///
+/// Records without a creation time cannot be placed on the timeline and are skipped.
+#[derive(Debug, Default, Clone)]
+pub struct TimelineBuilder {
+    incident_id: String,
+    events: Vec<TimelineEvent>,
+}
+
+impl TimelineBuilder {
+    pub fn new(incident_id: &str) -> Self {
+        TimelineBuilder {
+            incident_id: String::from(incident_id),
+            events: vec![],
+        }
+    }
+
+    pub fn log_entries(
+        &mut self,
+        log_entries: impl IntoIterator<Item = TypedLogEntry>,
+    ) -> &mut Self {
+        self.events.extend(
+            log_entries
+                .into_iter()
+                .filter_map(TimelineEvent::from_log_entry),
+        );
+        self
+    }
+
+    pub fn notes(&mut self, notes: impl IntoIterator<Item = IncidentNote>) -> &mut Self {
+        self.events
+            .extend(notes.into_iter().filter_map(TimelineEvent::from_note));
+        self
+    }
+
+    pub fn alerts(&mut self, alerts: impl IntoIterator<Item = Alert>) -> &mut Self {
+        self.events
+            .extend(alerts.into_iter().filter_map(TimelineEvent::from_alert));
+        self
+    }
+
+    pub fn status_updates(
+        &mut self,
+        status_updates: impl IntoIterator<Item = StatusUpdate>,
+    ) -> &mut Self {
+        self.events.extend(
+            status_updates
+                .into_iter()
+                .filter_map(TimelineEvent::from_status_update),
+        );
+        self
+    }
+
+    /// Order the collected events by time. Events sharing a timestamp keep the order in which
+    /// they were added.
+    pub fn build(&self) -> Timeline {
+        let mut events = self.events.clone();
+        events.sort_by_key(|event| event.at);
+
+        Timeline {
+            incident_id: String::clone(&self.incident_id),
+            events,
+        }
+    }
+}
+
+impl IncidentsClient {
+    /// ---
+    ///
+    /// # Build an incident timeline
+    ///
+    /// Praiya convenience method fetching the log entries, notes and alerts of an incident into
+    /// a [TimelineBuilder]. Status updates can be added to the builder before calling
+    /// [TimelineBuilder::build].
+    ///
+    /// ---
+    pub async fn timeline(&self, id: &str) -> Result<TimelineBuilder, Error> {
+        let log_entries: Vec<TypedLogEntry> = self
+            .list_incident_log_entries(id, ListIncidentLogEntriesParams::default())
+            .try_collect()
+            .await?;
+        let notes: Vec<IncidentNote> = self.list_incident_notes(id).try_collect().await?;
+        let alerts: Vec<Alert> = self
+            .list_incident_alerts(id, ListIncidentAlertsParams::default())
+            .try_collect()
+            .await?;
+
+        let mut builder = TimelineBuilder::new(id);
+        builder.log_entries(log_entries).notes(notes).alerts(alerts);
+
+        Ok(builder)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn common(id: &str, created_at: &str) -> LogEntryCommon {
+        LogEntryCommon {
+            id: Some(String::from(id)),
+            created_at: Some(DateTime::parse_from_rfc3339(created_at).unwrap()),
+            summary: Some(format!("{} summary", id)),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_timeline_builder() {
+        let timeline = TimelineBuilder::new("PT4KHLK")
+            .log_entries(vec![
+                TypedLogEntry::Resolve {
+                    common: common("R1", "2024-01-01T10:30:00Z"),
+                },
+                TypedLogEntry::Trigger {
+                    common: common("R2", "2024-01-01T10:00:00Z"),
+                },
+                TypedLogEntry::ReachTriggerLimit {
+                    common: common("R3", "2024-01-01T10:01:00Z"),
+                },
+            ])
+            .notes(vec![IncidentNote {
+                id: Some(String::from("N1")),
+                content: String::from("Rolled back | deploy"),
+                created_at: Some(DateTime::parse_from_rfc3339("2024-01-01T10:15:00Z").unwrap()),
+                ..Default::default()
+            }])
+            .status_updates(vec![StatusUpdate {
+                id: Some(String::from("S1")),
+                message: Some(String::from("Mitigated")),
+                created_at: Some(String::from("2024-01-01T10:20:00Z")),
+                ..Default::default()
+            }])
+            .build();
+
+        let ids: Vec<_> = timeline
+            .events
+            .iter()
+            .map(|event| event.source_id.as_deref().unwrap())
+            .collect();
+        assert_eq!(ids, vec!["R2", "R3", "N1", "S1", "R1"]);
+        assert_eq!(
+            timeline.events[1].kind,
+            TimelineEventKind::Other {
+                log_entry_type: String::from("reach_trigger_limit_log_entry")
+            }
+        );
+
+        let markdown = timeline.to_markdown(&chrono_tz::America::New_York);
+        assert!(markdown.contains("| 2024-01-01 05:00:00 | Triggered |  | R2 summary |"));
+        assert!(markdown.contains("| Note |  | Rolled back \\| deploy |"));
+
+        let json: serde_json::Value =
+            serde_json::from_str(&timeline.to_json(&chrono_tz::Asia::Tokyo).unwrap()).unwrap();
+        assert_eq!(json["events"][0]["at"], "2024-01-01T19:00:00+09:00");
+        assert_eq!(json["events"][0]["type"], "trigger");
+        assert_eq!(json["events"][3]["message"], "Mitigated");
+    }
+}
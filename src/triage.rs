@@ -0,0 +1,317 @@
+//! Incident triage: gather what is known about an incident in a single call.
+//!
+//! [IncidentsClient::triage] fetches the outlier classification, past incidents, related
+//! incidents and alerts of an incident concurrently, into an [IncidentContext].
+//!
+//! Outlier, past and related incidents are only available to accounts with the AIOps add-on.
+//! When PagerDuty refuses them, the context is still returned with those parts left empty, and
+//! [IncidentContext::aiops_available] set to `false`.
+
+use futures_util::future::try_join4;
+use futures_util::TryStreamExt;
+
+use crate::endpoints::incidents::{
+    GetOutlierIncidentParams, GetOutlierIncidentParamsBuilder, GetRelatedIncidentsParamsBuilder,
+    IncidentsClient, ListIncidentAlertsParams,
+};
+use crate::errors::Error;
+use crate::models::*;
+use crate::praiya::ParamsBuilder;
+use crate::{Praiya, SingleResponse};
+
+/// The reason two incidents are considered related, along with its details
+///
+/// Unlike the generated [Relationships] model, the details are decoded according to the
+/// relationship `type`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(
+    tag = "type",
+    content = "metadata",
+    rename_all = "snake_case",
+    try_from = "RawRelationship"
+)]
+pub enum RelatedIncidentRelationship {
+    /// The incidents were inferred to be related from past groupings.
+    #[serde(rename = "machine_learning_inferred")]
+    MachineLearning(RelatedIncidentMachineLearningRelationship),
+    /// The incidents are on services that depend on one another.
+    ServiceDependency(RelatedIncidentServiceDependencyRelationship),
+    /// A relationship type this version of Praiya does not know about.
+    Unknown,
+}
+
+#[derive(Deserialize)]
+struct RawRelationship {
+    #[serde(rename = "type")]
+    relationship_type: RelationshipsTypeEnum,
+    #[serde(default)]
+    metadata: serde_json::Value,
+}
+
+impl TryFrom<RawRelationship> for RelatedIncidentRelationship {
+    type Error = serde_json::Error;
+
+    fn try_from(raw: RawRelationship) -> Result<Self, Self::Error> {
+        Ok(match raw.relationship_type {
+            RelationshipsTypeEnum::MACHINE_LEARNING_INFERRED => {
+                RelatedIncidentRelationship::MachineLearning(serde_json::from_value(raw.metadata)?)
+            }
+            RelationshipsTypeEnum::SERVICE_DEPENDENCY => {
+                RelatedIncidentRelationship::ServiceDependency(serde_json::from_value(
+                    raw.metadata,
+                )?)
+            }
+            RelationshipsTypeEnum::UNKNOWN => RelatedIncidentRelationship::Unknown,
+        })
+    }
+}
+
+/// An incident related to the one being triaged
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RelatedIncident {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub incident: Option<Incident>,
+    #[serde(default)]
+    pub relationships: Vec<RelatedIncidentRelationship>,
+}
+
+plural_response_type!(RelatedIncident, related_incidents, TriageRelatedIncidents);
+
+/// Everything known about an incident, as gathered by [IncidentsClient::triage]
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub struct IncidentContext {
+    pub incident_id: String,
+    /// Whether the incident is rare, anomalous or frequent, compared to past incidents.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub outlier: Option<OutlierIncident>,
+    /// Similar incidents on the same service, with their similarity score.
+    pub past_incidents: Vec<PastIncidents>,
+    /// Incidents currently impacting other responders and services.
+    pub related_incidents: Vec<RelatedIncident>,
+    pub alerts: Vec<Alert>,
+    pub alert_counts: AlertCount,
+    /// Whether the outlier, past and related incidents could be fetched.
+    pub aiops_available: bool,
+}
+
+impl IncidentContext {
+    /// The past incident with the highest similarity score, ignoring missing or NaN scores.
+    pub fn most_similar_past_incident(&self) -> Option<&PastIncidents> {
+        self.past_incidents
+            .iter()
+            .filter(|past| matches!(past.score, Some(score) if !score.is_nan()))
+            .max_by(|a, b| a.score.unwrap().total_cmp(&b.score.unwrap()))
+    }
+
+    /// The related incidents sharing a relationship of the given kind, such as
+    /// `|r| matches!(r, RelatedIncidentRelationship::ServiceDependency(_))`.
+    pub fn related_by(
+        &self,
+        predicate: impl Fn(&RelatedIncidentRelationship) -> bool,
+    ) -> impl Iterator<Item = &RelatedIncident> {
+        self.related_incidents
+            .iter()
+            .filter(move |related| related.relationships.iter().any(&predicate))
+    }
+}
+
+fn count_alerts(alerts: &[Alert]) -> AlertCount {
+    let count = |status: AlertStatusEnum| {
+        alerts
+            .iter()
+            .filter(|alert| alert.status.as_ref() == Some(&status))
+            .count() as isize
+    };
+
+    AlertCount {
+        triggered: Some(count(AlertStatusEnum::TRIGGERED)),
+        resolved: Some(count(AlertStatusEnum::RESOLVED)),
+        all: Some(alerts.len() as isize),
+    }
+}
+
+// PagerDuty responds with `402 Payment Required` or `403 Forbidden` when the account or token
+// may not use AIOps features.
+async fn unless_unavailable<T>(
+    fut: impl std::future::Future<Output = Result<T, Error>>,
+) -> Result<Option<T>, Error> {
+    match fut.await {
+        Ok(value) => Ok(Some(value)),
+        Err(Error::PraiyaResponseServerError {
+            status_code: 402 | 403,
+            ..
+        }) => Ok(None),
+        Err(e) => Err(e),
+    }
+}
+
+impl IncidentsClient {
+    async fn get_related_incident_relationships(
+        &self,
+        id: &str,
+    ) -> Result<Vec<RelatedIncident>, Error> {
+        let mut opts_builder = GetRelatedIncidentsParamsBuilder::new();
+        opts_builder.additional_details(vec!["incident"]);
+        let query_params = opts_builder.build();
+
+        let url = Praiya::parse_url(
+            &self.api_endpoint,
+            &format!("/incidents/{}/related_incidents", &id),
+            Some(&query_params.qs),
+        )?;
+
+        let req = self.client.build_request(
+            url,
+            http::request::Builder::new().method(http::Method::GET),
+            hyper::Body::empty(),
+        );
+
+        self.client
+            .process_into_value::<_, TriageRelatedIncidentsResponse>(req)
+            .await
+    }
+
+    /// ---
+    ///
+    /// # Triage an incident
+    ///
+    /// Praiya convenience method fetching the outlier classification, past incidents, related
+    /// incidents and alerts of an incident concurrently, into an [IncidentContext].
+    ///
+    /// Accounts without AIOps still get the alerts, with `aiops_available` set to `false`. Any
+    /// other error fails the whole call.
+    ///
+    /// ---
+    pub async fn triage(&self, id: &str) -> Result<IncidentContext, Error> {
+        let mut opts_builder = GetOutlierIncidentParamsBuilder::new();
+        opts_builder.additional_details(vec!["incident"]);
+        let outlier_params: GetOutlierIncidentParams = opts_builder.build();
+
+        let (outlier, past_incidents, related_incidents, alerts) = try_join4(
+            unless_unavailable(self.get_outlier_incident(id, outlier_params)),
+            unless_unavailable(self.list_past_incidents(id).try_collect::<Vec<_>>()),
+            unless_unavailable(self.get_related_incident_relationships(id)),
+            self.list_incident_alerts(id, ListIncidentAlertsParams::default())
+                .try_collect::<Vec<_>>(),
+        )
+        .await?;
+
+        let aiops_available =
+            outlier.is_some() && past_incidents.is_some() && related_incidents.is_some();
+        let alert_counts = count_alerts(&alerts);
+
+        Ok(IncidentContext {
+            incident_id: String::from(id),
+            outlier,
+            past_incidents: past_incidents.unwrap_or_default(),
+            related_incidents: related_incidents.unwrap_or_default(),
+            alerts,
+            alert_counts,
+            aiops_available,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_related_incident_relationships() {
+        let response: TriageRelatedIncidentsResponse = serde_json::from_str(
+            r#"{"related_incidents": [{
+                "incident": {"id": "PR2P3RW", "type": "incident", "title": "Disk full"},
+                "relationships": [
+                    {"type": "machine_learning_inferred", "metadata": {
+                        "grouping_classification": "prior_feedback",
+                        "user_feedback": {"positive_feedback_count": 2, "negative_feedback_count": 0}
+                    }},
+                    {"type": "service_dependency", "metadata": {
+                        "dependent_services": [{"id": "PD4Q5BT", "type": "business_service_reference"}],
+                        "supporting_services": [{"id": "P3ZRDCQ", "type": "technical_service_reference"}]
+                    }},
+                    {"type": "shared_responder", "metadata": {}}
+                ]
+            }]}"#,
+        )
+        .unwrap();
+        let related = response.inner();
+
+        assert_eq!(related[0].relationships.len(), 3);
+        match &related[0].relationships[1] {
+            RelatedIncidentRelationship::ServiceDependency(dependency) => assert_eq!(
+                dependency.supporting_services.as_ref().unwrap()[0].id,
+                Some(String::from("P3ZRDCQ"))
+            ),
+            other => panic!("unexpected relationship {:?}", other),
+        }
+        assert_eq!(
+            related[0].relationships[2],
+            RelatedIncidentRelationship::Unknown
+        );
+
+        let context = IncidentContext {
+            incident_id: String::from("PT4KHLK"),
+            related_incidents: related,
+            past_incidents: vec![
+                PastIncidents {
+                    score: Some(46.8),
+                    ..Default::default()
+                },
+                PastIncidents {
+                    score: Some(190.2),
+                    ..Default::default()
+                },
+                PastIncidents {
+                    score: Some(f64::NAN),
+                    ..Default::default()
+                },
+                PastIncidents::default(),
+            ],
+            ..Default::default()
+        };
+        assert_eq!(
+            context.most_similar_past_incident().unwrap().score,
+            Some(190.2)
+        );
+        assert_eq!(
+            context
+                .related_by(|r| matches!(r, RelatedIncidentRelationship::MachineLearning(_)))
+                .count(),
+            1
+        );
+    }
+
+    #[test]
+    fn test_count_alerts() {
+        let alert = |status: AlertStatusEnum| Alert {
+            status: Some(status),
+            ..Default::default()
+        };
+        let counts = count_alerts(&[
+            alert(AlertStatusEnum::TRIGGERED),
+            alert(AlertStatusEnum::RESOLVED),
+            alert(AlertStatusEnum::TRIGGERED),
+        ]);
+
+        assert_eq!(counts.triggered, Some(2));
+        assert_eq!(counts.resolved, Some(1));
+        assert_eq!(counts.all, Some(3));
+    }
+
+    #[tokio::test]
+    async fn test_triage() {
+        let pagerduty = crate::Praiya::new("test");
+        let context = pagerduty
+            .incidents("from@example.com")
+            .triage("PT4KHLK")
+            .await
+            .unwrap();
+
+        assert!(context.aiops_available);
+        assert_eq!(
+            context.alert_counts.all,
+            Some(context.alerts.len() as isize)
+        );
+    }
+}
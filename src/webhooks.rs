@@ -0,0 +1,552 @@
+//! Inbound V3 webhooks: verify the signature of a delivery and decode it into a typed event.
+//!
+//! PagerDuty signs each delivery with the secret of the webhook subscription, sending one or
+//! more `v1=<hex digest>` HMAC-SHA256 signatures in the `X-PagerDuty-Signature` header. A
+//! [WebhookReceiver] holds every secret currently in use, so that a delivery signed with either
+//! the old or the new secret is accepted while a secret is being rotated. Deliveries whose event
+//! occurred longer ago than the receiver's maximum age are rejected as stale.
+//!
+//! With the `webhook-service` feature, [WebhookService] wraps a receiver and a handler into a
+//! hyper service that can be mounted on a server.
+
+use chrono::{DateTime, Duration, FixedOffset, Utc};
+use serde_json::Value;
+
+use crate::endpoints::webhook_subscriptions::WebhookEventType;
+use crate::errors::Error;
+use crate::models::*;
+
+/// The header carrying the signatures of a webhook delivery.
+pub const SIGNATURE_HEADER: &str = "X-PagerDuty-Signature";
+
+const SIGNATURE_VERSION: &str = "v1=";
+
+/// The body of a V3 webhook delivery
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct WebhookEnvelope {
+    pub event: WebhookEventEnvelope,
+}
+
+/// The metadata of a webhook event, along with the typed event itself
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(try_from = "RawWebhookEventEnvelope")]
+pub struct WebhookEventEnvelope {
+    pub id: String,
+    pub resource_type: String,
+    pub occurred_at: DateTime<FixedOffset>,
+    pub agent: Option<AgentReference>,
+    pub client: Option<Value>,
+    pub event: WebhookEvent,
+}
+
+#[derive(Deserialize)]
+struct RawWebhookEventEnvelope {
+    id: String,
+    event_type: String,
+    resource_type: String,
+    occurred_at: DateTime<FixedOffset>,
+    #[serde(default)]
+    agent: Option<AgentReference>,
+    #[serde(default)]
+    client: Option<Value>,
+    #[serde(default)]
+    data: Value,
+}
+
+impl TryFrom<RawWebhookEventEnvelope> for WebhookEventEnvelope {
+    type Error = serde_json::Error;
+
+    fn try_from(raw: RawWebhookEventEnvelope) -> Result<Self, Self::Error> {
+        Ok(WebhookEventEnvelope {
+            id: raw.id,
+            resource_type: raw.resource_type,
+            occurred_at: raw.occurred_at,
+            agent: raw.agent,
+            client: raw.client,
+            event: WebhookEvent::from_parts(&raw.event_type, raw.data)?,
+        })
+    }
+}
+
+/// The note added by an `incident.annotated` event
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct WebhookIncidentNote {
+    pub id: Option<String>,
+    pub incident: Incident,
+    pub content: String,
+}
+
+/// The responder request of an `incident.responder.added` or `incident.responder.replied` event
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct WebhookIncidentResponder {
+    pub incident: Incident,
+    pub user: Option<User>,
+    pub escalation_policy: Option<EscalationPolicy>,
+    pub message: Option<String>,
+    pub state: Option<String>,
+}
+
+/// The status update of an `incident.status_update_published` event
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct WebhookIncidentStatusUpdate {
+    pub id: Option<String>,
+    pub incident: Incident,
+    pub message: Option<String>,
+}
+
+/// The conference bridge of an `incident.conference_bridge.updated` event
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct WebhookIncidentConferenceBridge {
+    pub incident: Incident,
+    pub conference_number: Option<String>,
+    pub conference_url: Option<String>,
+}
+
+/// The custom field values of an `incident.custom_field_values.updated` event
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct WebhookIncidentCustomFieldValues {
+    pub incident: Incident,
+    #[serde(default)]
+    pub custom_fields: Vec<Value>,
+}
+
+/// The workflow instance of an `incident.workflow.started` or `incident.workflow.completed`
+/// event
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct WebhookIncidentWorkflowInstance {
+    pub id: Option<String>,
+    pub incident: Incident,
+    pub incident_workflow: Option<Value>,
+}
+
+/// A V3 webhook event, keyed by its `event_type`
+#[derive(Debug, Clone, PartialEq)]
+pub enum WebhookEvent {
+    IncidentAcknowledged(Incident),
+    IncidentAnnotated(WebhookIncidentNote),
+    IncidentConferenceBridgeUpdated(WebhookIncidentConferenceBridge),
+    IncidentCustomFieldValuesUpdated(WebhookIncidentCustomFieldValues),
+    IncidentDelegated(Incident),
+    IncidentEscalated(Incident),
+    IncidentPriorityUpdated(Incident),
+    IncidentReassigned(Incident),
+    IncidentReopened(Incident),
+    IncidentResolved(Incident),
+    IncidentResponderAdded(WebhookIncidentResponder),
+    IncidentResponderReplied(WebhookIncidentResponder),
+    IncidentStatusUpdatePublished(WebhookIncidentStatusUpdate),
+    IncidentTriggered(Incident),
+    IncidentUnacknowledged(Incident),
+    IncidentWorkflowStarted(WebhookIncidentWorkflowInstance),
+    IncidentWorkflowCompleted(WebhookIncidentWorkflowInstance),
+    ServiceCreated(WebhookObject),
+    ServiceDeleted(WebhookObject),
+    ServiceUpdated(WebhookObject),
+    PageyPing,
+    /// An event type not yet known to Praiya.
+    Unknown {
+        event_type: String,
+        data: Value,
+    },
+}
+
+impl WebhookEvent {
+    fn from_parts(event_type: &str, data: Value) -> Result<WebhookEvent, serde_json::Error> {
+        let event_type = match event_type.parse::<WebhookEventType>() {
+            Ok(event_type) => event_type,
+            Err(_) => {
+                return Ok(WebhookEvent::Unknown {
+                    event_type: String::from(event_type),
+                    data,
+                })
+            }
+        };
+
+        Ok(match event_type {
+            WebhookEventType::IncidentAcknowledged => {
+                WebhookEvent::IncidentAcknowledged(serde_json::from_value(data)?)
+            }
+            WebhookEventType::IncidentAnnotated => {
+                WebhookEvent::IncidentAnnotated(serde_json::from_value(data)?)
+            }
+            WebhookEventType::IncidentConferenceBridgeUpdated => {
+                WebhookEvent::IncidentConferenceBridgeUpdated(serde_json::from_value(data)?)
+            }
+            WebhookEventType::IncidentCustomFieldValuesUpdated => {
+                WebhookEvent::IncidentCustomFieldValuesUpdated(serde_json::from_value(data)?)
+            }
+            WebhookEventType::IncidentDelegated => {
+                WebhookEvent::IncidentDelegated(serde_json::from_value(data)?)
+            }
+            WebhookEventType::IncidentEscalated => {
+                WebhookEvent::IncidentEscalated(serde_json::from_value(data)?)
+            }
+            WebhookEventType::IncidentPriorityUpdated => {
+                WebhookEvent::IncidentPriorityUpdated(serde_json::from_value(data)?)
+            }
+            WebhookEventType::IncidentReassigned => {
+                WebhookEvent::IncidentReassigned(serde_json::from_value(data)?)
+            }
+            WebhookEventType::IncidentReopened => {
+                WebhookEvent::IncidentReopened(serde_json::from_value(data)?)
+            }
+            WebhookEventType::IncidentResolved => {
+                WebhookEvent::IncidentResolved(serde_json::from_value(data)?)
+            }
+            WebhookEventType::IncidentResponderAdded => {
+                WebhookEvent::IncidentResponderAdded(serde_json::from_value(data)?)
+            }
+            WebhookEventType::IncidentResponderReplied => {
+                WebhookEvent::IncidentResponderReplied(serde_json::from_value(data)?)
+            }
+            WebhookEventType::IncidentStatusUpdatePublished => {
+                WebhookEvent::IncidentStatusUpdatePublished(serde_json::from_value(data)?)
+            }
+            WebhookEventType::IncidentTriggered => {
+                WebhookEvent::IncidentTriggered(serde_json::from_value(data)?)
+            }
+            WebhookEventType::IncidentUnacknowledged => {
+                WebhookEvent::IncidentUnacknowledged(serde_json::from_value(data)?)
+            }
+            WebhookEventType::IncidentWorkflowStarted => {
+                WebhookEvent::IncidentWorkflowStarted(serde_json::from_value(data)?)
+            }
+            WebhookEventType::IncidentWorkflowCompleted => {
+                WebhookEvent::IncidentWorkflowCompleted(serde_json::from_value(data)?)
+            }
+            WebhookEventType::ServiceCreated => {
+                WebhookEvent::ServiceCreated(serde_json::from_value(data)?)
+            }
+            WebhookEventType::ServiceDeleted => {
+                WebhookEvent::ServiceDeleted(serde_json::from_value(data)?)
+            }
+            WebhookEventType::ServiceUpdated => {
+                WebhookEvent::ServiceUpdated(serde_json::from_value(data)?)
+            }
+            WebhookEventType::PageyPing => WebhookEvent::PageyPing,
+        })
+    }
+
+    /// The event type of this event, or `None` for an [WebhookEvent::Unknown] event.
+    pub fn event_type(&self) -> Option<WebhookEventType> {
+        Some(match self {
+            WebhookEvent::IncidentAcknowledged(_) => WebhookEventType::IncidentAcknowledged,
+            WebhookEvent::IncidentAnnotated(_) => WebhookEventType::IncidentAnnotated,
+            WebhookEvent::IncidentConferenceBridgeUpdated(_) => {
+                WebhookEventType::IncidentConferenceBridgeUpdated
+            }
+            WebhookEvent::IncidentCustomFieldValuesUpdated(_) => {
+                WebhookEventType::IncidentCustomFieldValuesUpdated
+            }
+            WebhookEvent::IncidentDelegated(_) => WebhookEventType::IncidentDelegated,
+            WebhookEvent::IncidentEscalated(_) => WebhookEventType::IncidentEscalated,
+            WebhookEvent::IncidentPriorityUpdated(_) => WebhookEventType::IncidentPriorityUpdated,
+            WebhookEvent::IncidentReassigned(_) => WebhookEventType::IncidentReassigned,
+            WebhookEvent::IncidentReopened(_) => WebhookEventType::IncidentReopened,
+            WebhookEvent::IncidentResolved(_) => WebhookEventType::IncidentResolved,
+            WebhookEvent::IncidentResponderAdded(_) => WebhookEventType::IncidentResponderAdded,
+            WebhookEvent::IncidentResponderReplied(_) => WebhookEventType::IncidentResponderReplied,
+            WebhookEvent::IncidentStatusUpdatePublished(_) => {
+                WebhookEventType::IncidentStatusUpdatePublished
+            }
+            WebhookEvent::IncidentTriggered(_) => WebhookEventType::IncidentTriggered,
+            WebhookEvent::IncidentUnacknowledged(_) => WebhookEventType::IncidentUnacknowledged,
+            WebhookEvent::IncidentWorkflowStarted(_) => WebhookEventType::IncidentWorkflowStarted,
+            WebhookEvent::IncidentWorkflowCompleted(_) => {
+                WebhookEventType::IncidentWorkflowCompleted
+            }
+            WebhookEvent::ServiceCreated(_) => WebhookEventType::ServiceCreated,
+            WebhookEvent::ServiceDeleted(_) => WebhookEventType::ServiceDeleted,
+            WebhookEvent::ServiceUpdated(_) => WebhookEventType::ServiceUpdated,
+            WebhookEvent::PageyPing => WebhookEventType::PageyPing,
+            WebhookEvent::Unknown { .. } => return None,
+        })
+    }
+}
+
+/// Verifies and decodes inbound webhook deliveries
+#[derive(Debug, Clone)]
+pub struct WebhookReceiver {
+    secrets: Vec<String>,
+    max_age: Option<Duration>,
+}
+
+impl WebhookReceiver {
+    /// A receiver accepting deliveries signed with any of the given secrets, rejecting events
+    /// that occurred more than five minutes ago.
+    pub fn new<I: IntoIterator<Item = S>, S: Into<String>>(secrets: I) -> Self {
+        WebhookReceiver {
+            secrets: secrets.into_iter().map(Into::into).collect(),
+            max_age: Some(Duration::minutes(5)),
+        }
+    }
+
+    /// Accept deliveries signed with an additional secret, such as the secret of a newly
+    /// created subscription during rotation.
+    pub fn add_secret(&mut self, secret: &str) -> &mut Self {
+        self.secrets.push(String::from(secret));
+        self
+    }
+
+    /// Stop accepting deliveries signed with a secret that has been rotated out.
+    pub fn remove_secret(&mut self, secret: &str) -> &mut Self {
+        self.secrets.retain(|existing| existing != secret);
+        self
+    }
+
+    /// Set the maximum age of an accepted event, or `None` to accept events of any age.
+    pub fn max_age(&mut self, max_age: Option<Duration>) -> &mut Self {
+        self.max_age = max_age;
+        self
+    }
+
+    /// Verify that the `X-PagerDuty-Signature` header value carries a signature of the body
+    /// made with one of the receiver's secrets.
+    pub fn verify_signature(&self, signature_header: &str, body: &[u8]) -> Result<(), Error> {
+        let signatures: Vec<Vec<u8>> = signature_header
+            .split(',')
+            .filter_map(|signature| signature.trim().strip_prefix(SIGNATURE_VERSION))
+            .filter_map(decode_hex)
+            .collect();
+
+        let verified = self.secrets.iter().any(|secret| {
+            let key = ring::hmac::Key::new(ring::hmac::HMAC_SHA256, secret.as_bytes());
+            signatures
+                .iter()
+                .any(|signature| ring::hmac::verify(&key, body, signature).is_ok())
+        });
+
+        if verified {
+            Ok(())
+        } else {
+            Err(Error::WebhookSignatureError)
+        }
+    }
+
+    /// Verify a delivery from its headers and raw body, and decode its event.
+    pub fn receive(
+        &self,
+        headers: &http::HeaderMap,
+        body: &[u8],
+    ) -> Result<WebhookEventEnvelope, Error> {
+        let signature_header = headers
+            .get(SIGNATURE_HEADER)
+            .ok_or(Error::WebhookSignatureError)?
+            .to_str()?;
+        self.verify_signature(signature_header, body)?;
+
+        let envelope: WebhookEnvelope = serde_json::from_slice(body)?;
+        if let Some(max_age) = self.max_age {
+            if Utc::now().signed_duration_since(envelope.event.occurred_at) > max_age {
+                return Err(Error::StaleWebhookError {
+                    occurred_at: envelope.event.occurred_at,
+                });
+            }
+        }
+
+        Ok(envelope.event)
+    }
+}
+
+fn decode_hex(value: &str) -> Option<Vec<u8>> {
+    if value.len() % 2 == 1 {
+        return None;
+    }
+
+    (0..value.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(value.get(i..i + 2)?, 16).ok())
+        .collect()
+}
+
+#[cfg(feature = "webhook-service")]
+pub use self::service::WebhookService;
+
+#[cfg(feature = "webhook-service")]
+mod service {
+    use std::future::Future;
+    use std::pin::Pin;
+    use std::sync::Arc;
+    use std::task::{Context, Poll};
+
+    use http::{Method, Request, Response, StatusCode};
+    use hyper::Body;
+
+    use super::{WebhookEventEnvelope, WebhookReceiver};
+    use crate::errors::Error;
+
+    /// A hyper service verifying webhook deliveries and passing their events to a handler
+    ///
+    /// Verified deliveries are answered with `202 Accepted` once the handler completes, deliveries
+    /// with a missing or invalid signature with `401 Unauthorized`, and stale or malformed
+    /// deliveries with `400 Bad Request`.
+    pub struct WebhookService<F> {
+        receiver: Arc<WebhookReceiver>,
+        handler: Arc<F>,
+    }
+
+    impl<F> WebhookService<F> {
+        pub fn new(receiver: WebhookReceiver, handler: F) -> Self {
+            WebhookService {
+                receiver: Arc::new(receiver),
+                handler: Arc::new(handler),
+            }
+        }
+    }
+
+    impl<F> Clone for WebhookService<F> {
+        fn clone(&self) -> Self {
+            WebhookService {
+                receiver: Arc::clone(&self.receiver),
+                handler: Arc::clone(&self.handler),
+            }
+        }
+    }
+
+    fn status(status_code: StatusCode) -> Response<Body> {
+        let mut response = Response::new(Body::empty());
+        *response.status_mut() = status_code;
+        response
+    }
+
+    impl<F, Fut> hyper::service::Service<Request<Body>> for WebhookService<F>
+    where
+        F: Fn(WebhookEventEnvelope) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = ()> + Send + 'static,
+    {
+        type Response = Response<Body>;
+        type Error = hyper::Error;
+        type Future = Pin<Box<dyn Future<Output = Result<Response<Body>, hyper::Error>> + Send>>;
+
+        fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+            Poll::Ready(Ok(()))
+        }
+
+        fn call(&mut self, req: Request<Body>) -> Self::Future {
+            let receiver = Arc::clone(&self.receiver);
+            let handler = Arc::clone(&self.handler);
+
+            Box::pin(async move {
+                if req.method() != Method::POST {
+                    return Ok(status(StatusCode::METHOD_NOT_ALLOWED));
+                }
+
+                let (parts, body) = req.into_parts();
+                let body = hyper::body::to_bytes(body).await?;
+
+                Ok(match receiver.receive(&parts.headers, &body) {
+                    Ok(event) => {
+                        handler(event).await;
+                        status(StatusCode::ACCEPTED)
+                    }
+                    Err(Error::WebhookSignatureError) | Err(Error::ToStrError { .. }) => {
+                        status(StatusCode::UNAUTHORIZED)
+                    }
+                    Err(_) => status(StatusCode::BAD_REQUEST),
+                })
+            })
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sign(secret: &str, body: &[u8]) -> String {
+        let key = ring::hmac::Key::new(ring::hmac::HMAC_SHA256, secret.as_bytes());
+        ring::hmac::sign(&key, body)
+            .as_ref()
+            .iter()
+            .map(|byte| format!("{:02x}", byte))
+            .collect()
+    }
+
+    fn delivery(occurred_at: DateTime<Utc>) -> Vec<u8> {
+        serde_json::to_vec(&serde_json::json!({
+            "event": {
+                "id": "5ac64822-4adc-4fda-ade0-410becf0de4f",
+                "event_type": "incident.triggered",
+                "resource_type": "incident",
+                "occurred_at": occurred_at.to_rfc3339(),
+                "agent": {
+                    "html_url": "https://acme.pagerduty.com/users/PLH1HKV",
+                    "id": "PLH1HKV",
+                    "self": "https://api.pagerduty.com/users/PLH1HKV",
+                    "summary": "Tenex Engineer",
+                    "type": "user_reference"
+                },
+                "client": null,
+                "data": {
+                    "id": "PGR0VU2",
+                    "type": "incident",
+                    "self": "https://api.pagerduty.com/incidents/PGR0VU2",
+                    "html_url": "https://acme.pagerduty.com/incidents/PGR0VU2",
+                    "number": 2,
+                    "status": "triggered",
+                    "title": "A little bump in the road",
+                    "urgency": "high"
+                }
+            }
+        }))
+        .unwrap()
+    }
+
+    fn headers(signature: &str) -> http::HeaderMap {
+        let mut headers = http::HeaderMap::new();
+        headers.insert(SIGNATURE_HEADER, signature.parse().unwrap());
+        headers
+    }
+
+    #[test]
+    fn test_receive() {
+        let body = delivery(Utc::now());
+        let receiver = WebhookReceiver::new(vec!["old-secret", "new-secret"]);
+
+        let signature = format!(
+            "v1={},v1={}",
+            sign("unrelated-secret", &body),
+            sign("new-secret", &body)
+        );
+        let event = receiver.receive(&headers(&signature), &body).unwrap();
+
+        assert_eq!(event.agent.unwrap().id, Some(String::from("PLH1HKV")));
+        assert_eq!(
+            event.event.event_type(),
+            Some(WebhookEventType::IncidentTriggered)
+        );
+        match event.event {
+            WebhookEvent::IncidentTriggered(incident) => {
+                assert_eq!(incident.id, Some(String::from("PGR0VU2")))
+            }
+            other => panic!("unexpected event {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_receive_rejected() {
+        let body = delivery(Utc::now());
+        let mut receiver = WebhookReceiver::new(vec!["old-secret", "new-secret"]);
+        receiver.remove_secret("old-secret");
+
+        let signature = format!("v1={}", sign("old-secret", &body));
+        assert!(matches!(
+            receiver.receive(&headers(&signature), &body),
+            Err(Error::WebhookSignatureError)
+        ));
+        assert!(matches!(
+            receiver.receive(&http::HeaderMap::new(), &body),
+            Err(Error::WebhookSignatureError)
+        ));
+
+        let stale = delivery(Utc::now() - Duration::hours(1));
+        let signature = format!("v1={}", sign("new-secret", &stale));
+        assert!(matches!(
+            receiver.receive(&headers(&signature), &stale),
+            Err(Error::StaleWebhookError { .. })
+        ));
+
+        receiver.max_age(None);
+        assert!(receiver.receive(&headers(&signature), &stale).is_ok());
+    }
+}